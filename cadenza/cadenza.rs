@@ -1,356 +0,0 @@
-// pub const CADENZA_3 : &'static [PathSegment] = &[ M(301.0,281.0), L(301.0,160.0), L(0.0,-1.0), L(0.0,124.0), L(301.0,281.0), Z ];
-
-// pub const CADENZA_4 : &'static [PathSegment] = &[ M(141.0,18.0), L(373.0,482.0), Q(381.0,500.0,402.0,500.0), L(424.0,500.0), Q(436.0,500.0,436.0,488.0), Q(436.0,484.0,435.0,482.0), L(203.0,18.0), Q(195.0,0.0,174.0,0.0), L(152.0,0.0), Q(145.0,0.0,141.5,6.0), Q(138.0,12.0,141.0,18.0), Z , M(1.0,18.0), L(233.0,482.0), Q(241.0,500.0,262.0,500.0), L(284.0,500.0), Q(296.0,500.0,296.0,488.0), Q(296.0,484.0,295.0,482.0), L(63.0,18.0), Q(55.0,0.0,34.0,0.0), L(12.0,0.0), Q(0.0,0.0,0.0,12.0), Q(0.0,16.0,1.0,18.0), Z ];
-
-// pub const CADENZA_5 : &'static [PathSegment] = &[ M(216.0,96.0), L(216.0,-26.0), Q(216.0,-34.0,221.0,-39.0), Q(226.0,-44.0,234.0,-44.0), Q(237.0,-44.0,240.0,-43.0), L(248.0,-40.0), Q(251.0,-39.0,255.0,-39.0), Q(263.0,-39.0,269.0,-45.0), Q(275.0,-51.0,275.0,-59.0), L(275.0,-119.0), Q(275.0,-132.0,262.0,-137.0), L(228.0,-150.0), Q(216.0,-155.0,216.0,-166.0), L(216.0,-312.0), Q(216.0,-320.0,210.5,-325.5), Q(205.0,-331.0,198.0,-331.0), Q(190.0,-331.0,184.5,-325.5), Q(179.0,-320.0,179.0,-312.0), L(179.0,-193.0), Q(179.0,-185.0,173.5,-180.0), Q(168.0,-175.0,161.0,-175.0), Q(157.0,-175.0,154.0,-176.0), L(108.0,-194.0), Q(96.0,-198.0,96.0,-210.0), L(96.0,-356.0), Q(96.0,-364.0,90.0,-369.5), Q(84.0,-375.0,77.0,-375.0), Q(70.0,-375.0,64.5,-369.5), Q(59.0,-364.0,59.0,-356.0), L(59.0,-237.0), Q(59.0,-229.0,53.5,-224.0), Q(48.0,-219.0,41.0,-219.0), Q(38.0,-219.0,35.0,-220.0), L(27.0,-223.0), Q(25.0,-224.0,20.0,-224.0), Q(12.0,-224.0,6.0,-218.0), Q(0.0,-212.0,0.0,-204.0), L(0.0,-144.0), Q(0.0,-130.0,13.0,-125.0), L(47.0,-113.0), Q(59.0,-110.0,59.0,-96.0), L(59.0,26.0), Q(59.0,34.0,53.5,39.0), Q(48.0,44.0,41.0,44.0), Q(38.0,44.0,36.0,43.0), L(27.0,40.0), Q(25.0,39.0,20.0,39.0), Q(12.0,39.0,6.0,45.0), Q(0.0,51.0,0.0,59.0), L(0.0,119.0), Q(0.0,132.0,13.0,137.0), L(48.0,150.0), Q(59.0,155.0,59.0,166.0), L(59.0,312.0), Q(59.0,320.0,64.5,325.5), Q(70.0,331.0,77.0,331.0), Q(84.0,331.0,90.0,325.5), Q(96.0,320.0,96.0,312.0), L(96.0,193.0), Q(96.0,185.0,101.5,180.0), Q(107.0,175.0,115.0,175.0), Q(118.0,175.0,121.0,176.0), L(167.0,194.0), Q(179.0,199.0,179.0,211.0), L(179.0,356.0), Q(179.0,364.0,184.5,369.5), Q(190.0,375.0,198.0,375.0), Q(205.0,375.0,210.5,369.5), Q(216.0,364.0,216.0,356.0), L(216.0,236.0), Q(216.0,229.0,221.0,224.0), Q(226.0,219.0,233.0,219.0), Q(236.0,219.0,240.0,220.0), L(248.0,223.0), Q(250.0,224.0,255.0,224.0), Q(263.0,224.0,269.0,218.0), Q(275.0,212.0,275.0,204.0), L(275.0,144.0), Q(275.0,130.0,262.0,125.0), L(228.0,113.0), Q(216.0,110.0,216.0,96.0), Z , M(179.0,-52.0), L(179.0,69.0), Q(179.0,76.0,173.5,81.5), Q(168.0,87.0,161.0,87.0), Q(158.0,87.0,155.0,86.0), L(108.0,69.0), Q(96.0,64.0,96.0,52.0), L(96.0,-69.0), Q(96.0,-77.0,101.0,-82.0), Q(106.0,-87.0,114.0,-87.0), Q(117.0,-87.0,120.0,-86.0), L(167.0,-69.0), Q(179.0,-64.0,179.0,-52.0), Z ];
-
-// pub const CADENZA_6 : &'static [PathSegment] = &[ M(216.0,236.0), Q(216.0,229.0,221.0,224.0), Q(226.0,219.0,233.0,219.0), Q(236.0,219.0,240.0,220.0), L(248.0,223.0), Q(250.0,224.0,255.0,224.0), Q(263.0,224.0,269.0,218.0), Q(275.0,212.0,275.0,204.0), L(275.0,144.0), Q(275.0,130.0,262.0,125.0), L(228.0,113.0), Q(216.0,110.0,216.0,96.0), L(216.0,-26.0), Q(216.0,-34.0,221.0,-39.0), Q(226.0,-44.0,234.0,-44.0), Q(237.0,-44.0,240.0,-43.0), L(248.0,-40.0), Q(251.0,-39.0,255.0,-39.0), Q(263.0,-39.0,269.0,-45.0), Q(275.0,-51.0,275.0,-59.0), L(275.0,-119.0), Q(275.0,-132.0,262.0,-137.0), L(228.0,-150.0), Q(216.0,-155.0,216.0,-166.0), L(216.0,-312.0), Q(216.0,-320.0,210.5,-325.5), Q(205.0,-331.0,198.0,-331.0), Q(190.0,-331.0,184.5,-325.5), Q(179.0,-320.0,179.0,-312.0), L(179.0,-193.0), Q(179.0,-185.0,173.5,-180.0), Q(168.0,-175.0,161.0,-175.0), Q(157.0,-175.0,154.0,-176.0), L(108.0,-194.0), Q(96.0,-198.0,96.0,-210.0), L(96.0,-356.0), Q(96.0,-364.0,90.0,-369.5), Q(84.0,-375.0,77.0,-375.0), Q(70.0,-375.0,64.5,-369.5), Q(59.0,-364.0,59.0,-356.0), L(59.0,-237.0), Q(59.0,-229.0,53.5,-224.0), Q(48.0,-219.0,41.0,-219.0), Q(38.0,-219.0,35.0,-220.0), L(27.0,-223.0), Q(25.0,-224.0,20.0,-224.0), Q(12.0,-224.0,6.0,-218.0), Q(0.0,-212.0,0.0,-204.0), L(0.0,-144.0), Q(0.0,-130.0,13.0,-125.0), L(47.0,-113.0), Q(59.0,-110.0,59.0,-96.0), L(59.0,26.0), Q(59.0,34.0,53.5,39.0), Q(48.0,44.0,41.0,44.0), Q(38.0,44.0,36.0,43.0), L(27.0,40.0), Q(25.0,39.0,20.0,39.0), Q(12.0,39.0,6.0,45.0), Q(0.0,51.0,0.0,59.0), L(0.0,119.0), Q(0.0,132.0,13.0,137.0), L(48.0,150.0), Q(59.0,155.0,59.0,166.0), L(59.0,312.0), Q(59.0,320.0,64.5,325.5), Q(70.0,331.0,77.0,331.0), Q(84.0,331.0,90.0,325.5), Q(96.0,320.0,96.0,312.0), L(96.0,193.0), Q(96.0,185.0,101.5,180.0), Q(107.0,175.0,115.0,175.0), Q(118.0,175.0,121.0,176.0), L(167.0,194.0), Q(179.0,199.0,179.0,211.0), L(179.0,422.0), Q(179.0,440.0,165.0,440.0), Q(159.0,440.0,149.5,436.0), Q(140.0,432.0,132.0,427.5), Q(124.0,423.0,110.0,414.5), Q(96.0,406.0,89.0,402.0), Q(82.0,398.0,77.0,403.5), Q(72.0,409.0,76.0,416.0), Q(85.0,429.0,94.0,444.0), Q(103.0,459.0,110.0,470.5), Q(117.0,482.0,126.0,500.5), Q(135.0,519.0,139.5,528.5), Q(144.0,538.0,153.0,560.0), Q(162.0,582.0,165.0,588.5), Q(168.0,595.0,178.0,620.0), Q(188.0,645.0,190.0,650.0), Q(192.0,655.0,198.0,655.0), Q(203.0,655.0,205.0,650.0), Q(207.0,645.0,217.0,620.0), Q(227.0,595.0,230.0,588.5), Q(233.0,582.0,242.0,560.0), Q(251.0,538.0,255.5,528.5), Q(260.0,519.0,269.0,500.5), Q(278.0,482.0,285.0,470.5), Q(292.0,459.0,301.0,444.0), Q(310.0,429.0,319.0,416.0), Q(321.0,412.0,321.0,411.0), Q(321.0,406.0,316.5,402.5), Q(312.0,399.0,307.0,402.0), Q(301.0,405.0,286.0,414.0), Q(271.0,423.0,263.5,427.5), Q(256.0,432.0,246.0,436.0), Q(236.0,440.0,230.0,440.0), Q(216.0,440.0,216.0,422.0), L(216.0,236.0), Z , M(179.0,-52.0), L(179.0,69.0), Q(179.0,76.0,173.5,81.5), Q(168.0,87.0,161.0,87.0), Q(158.0,87.0,155.0,86.0), L(108.0,69.0), Q(96.0,64.0,96.0,52.0), L(96.0,-69.0), Q(96.0,-77.0,101.0,-82.0), Q(106.0,-87.0,114.0,-87.0), Q(117.0,-87.0,120.0,-86.0), L(167.0,-69.0), Q(179.0,-64.0,179.0,-52.0), Z ];
-
-// pub const CADENZA_7 : &'static [PathSegment] = &[ M(0.0,325.0), Q(0.0,350.0,17.0,367.5), Q(34.0,385.0,60.0,385.0), Q(86.0,385.0,103.0,367.5), Q(120.0,350.0,120.0,325.0), Q(120.0,300.0,103.0,282.5), Q(86.0,265.0,60.0,265.0), Q(34.0,265.0,17.0,282.5), Q(0.0,300.0,0.0,325.0), Z , M(380.0,425.0), Q(380.0,450.0,397.0,467.5), Q(414.0,485.0,440.0,485.0), Q(466.0,485.0,483.0,467.5), Q(500.0,450.0,500.0,425.0), Q(500.0,400.0,483.0,382.5), Q(466.0,365.0,440.0,365.0), Q(414.0,365.0,397.0,382.5), Q(380.0,400.0,380.0,425.0), Z , M(205.0,687.0), Q(185.0,720.0,144.0,720.0), Q(116.0,720.0,97.0,698.0), Q(78.0,676.0,78.0,645.0), Q(78.0,529.0,234.0,435.0), Q(240.0,431.0,247.5,433.0), Q(255.0,435.0,259.0,442.0), L(440.0,750.0), L(500.0,750.0), L(305.0,417.0), Q(296.0,402.0,312.0,392.0), Q(399.0,342.0,449.5,285.5), Q(500.0,229.0,500.0,156.0), Q(500.0,91.0,458.5,45.5), Q(417.0,0.0,356.0,0.0), Q(297.0,0.0,256.0,43.5), Q(215.0,87.0,215.0,148.0), Q(215.0,175.0,228.5,191.0), Q(242.0,207.0,269.0,207.0), Q(297.0,207.0,312.0,188.5), Q(327.0,170.0,327.0,145.0), Q(327.0,108.0,299.0,88.0), Q(285.0,78.0,295.0,64.0), Q(315.0,30.0,356.0,30.0), Q(384.0,30.0,403.0,52.0), Q(422.0,74.0,422.0,105.0), Q(422.0,221.0,266.0,315.0), Q(250.0,324.0,241.0,309.0), L(60.0,0.0), L(0.0,0.0), L(195.0,334.0), Q(204.0,349.0,188.0,358.0), Q(101.0,408.0,50.5,464.5), Q(0.0,521.0,0.0,594.0), Q(0.0,659.0,41.5,704.5), Q(83.0,750.0,144.0,750.0), Q(203.0,750.0,244.0,706.5), Q(285.0,663.0,285.0,602.0), Q(285.0,575.0,271.5,559.0), Q(258.0,543.0,231.0,543.0), Q(203.0,543.0,188.0,561.5), Q(173.0,580.0,173.0,605.0), Q(173.0,642.0,201.0,662.0), Q(215.0,671.0,205.0,687.0), Z ];
-
-// pub const CADENZA_8 : &'static [PathSegment] = &[ M(248.0,565.0), Q(225.0,638.0,216.0,695.0), Q(208.0,743.0,208.0,807.0), Q(208.0,829.0,209.0,851.0), Q(213.0,946.0,248.0,1025.5), Q(283.0,1105.0,353.0,1147.0), Q(358.0,1150.0,366.0,1150.0), Q(373.0,1150.0,378.0,1145.0), Q(434.0,1089.0,464.0,1002.0), Q(494.0,915.0,494.0,826.0), Q(494.0,723.0,450.0,624.0), Q(408.0,525.0,332.0,449.0), Q(326.0,443.0,328.0,433.0), L(368.0,273.0), Q(371.0,257.0,388.0,259.0), Q(404.0,261.0,419.0,261.0), Q(477.0,261.0,524.0,238.0), Q(571.0,215.0,600.0,178.0), Q(629.0,141.0,644.5,96.5), Q(660.0,52.0,660.0,5.0), Q(660.0,-81.0,612.0,-150.5), Q(564.0,-220.0,497.0,-242.0), Q(483.0,-247.0,486.0,-263.0), Q(511.0,-381.0,512.0,-462.0), Q(512.0,-493.0,508.0,-523.0), Q(500.0,-592.0,448.0,-644.0), Q(396.0,-696.0,324.0,-696.0), Q(236.0,-696.0,175.0,-643.0), Q(114.0,-590.0,114.0,-504.0), Q(114.0,-460.0,145.0,-428.0), Q(176.0,-396.0,218.0,-396.0), Q(261.0,-396.0,290.5,-426.5), Q(320.0,-457.0,320.0,-499.0), Q(320.0,-535.0,296.0,-562.0), Q(272.0,-590.0,238.0,-593.0), Q(226.0,-594.0,222.0,-605.0), Q(218.0,-616.0,230.0,-625.0), Q(266.0,-653.0,318.0,-653.0), Q(375.0,-653.0,416.5,-612.0), Q(458.0,-571.0,465.0,-519.0), Q(470.0,-483.0,470.0,-447.0), Q(470.0,-369.0,446.0,-274.0), Q(442.0,-257.0,424.0,-259.0), Q(384.0,-264.0,350.0,-264.0), Q(202.0,-264.0,101.0,-171.0), Q(0.0,-78.0,0.0,82.0), Q(0.0,131.0,11.5,178.5), Q(23.0,226.0,37.5,260.5), Q(52.0,295.0,82.5,340.5), Q(113.0,386.0,131.5,410.0), Q(150.0,434.0,190.0,481.5), Q(230.0,529.0,245.0,548.0), Q(251.0,555.0,248.0,565.0), Z , M(404.0,108.0), L(469.0,-187.0), Q(472.0,-200.0,487.0,-200.0), Q(491.0,-200.0,494.0,-199.0), Q(535.0,-182.0,562.0,-133.0), Q(589.0,-84.0,589.0,-36.0), Q(589.0,34.0,548.0,82.0), Q(506.0,131.0,422.0,131.0), Q(415.0,131.0,409.5,125.5), Q(404.0,120.0,404.0,113.0), Q(403.0,110.0,404.0,108.0), Z , M(266.0,386.0), Q(250.0,368.0,218.5,334.5), Q(187.0,301.0,171.5,283.5), Q(156.0,266.0,133.5,234.0), Q(111.0,202.0,100.0,175.0), Q(89.0,148.0,80.5,109.0), Q(72.0,70.0,72.0,26.0), Q(72.0,-35.0,109.5,-92.0), Q(147.0,-149.0,212.5,-185.0), Q(278.0,-221.0,353.0,-221.0), Q(380.0,-221.0,413.0,-218.0), Q(421.0,-217.0,426.0,-210.5), Q(431.0,-204.0,429.0,-196.0), L(364.0,103.0), Q(361.0,117.0,345.0,117.0), Q(342.0,117.0,338.0,116.0), Q(292.0,99.0,273.0,68.0), Q(254.0,37.0,254.0,-10.0), Q(254.0,-65.0,314.0,-105.0), Q(321.0,-110.0,321.0,-120.0), Q(321.0,-128.0,314.5,-135.0), Q(308.0,-142.0,299.0,-142.0), Q(294.0,-142.0,290.0,-140.0), Q(233.0,-110.0,205.5,-58.5), Q(178.0,-7.0,178.0,50.0), Q(178.0,105.0,217.5,163.5), Q(257.0,222.0,316.0,244.0), Q(331.0,250.0,328.0,265.0), L(298.0,378.0), Q(296.0,389.0,284.0,391.0), Q(272.0,393.0,266.0,386.0), Z , M(434.0,968.0), Q(426.0,994.0,407.0,994.0), Q(401.0,994.0,396.0,991.0), Q(332.0,953.0,294.5,884.0), Q(257.0,815.0,257.0,738.0), Q(257.0,688.0,278.0,615.0), Q(281.0,604.0,293.0,602.0), Q(303.0,601.0,310.0,609.0), Q(313.0,613.0,338.5,644.5), Q(364.0,676.0,372.0,686.5), Q(380.0,697.0,399.0,726.5), Q(418.0,756.0,425.5,775.5), Q(433.0,795.0,440.0,824.0), Q(447.0,853.0,447.0,882.0), Q(447.0,924.0,434.0,968.0), Z ];
-
-// pub const CADENZA_9 : &'static [PathSegment] = &[ M(42.0,264.0), Q(44.0,278.0,56.0,278.0), Q(68.0,278.0,70.0,264.0), L(111.0,44.0), Q(111.0,43.0,111.5,40.0), Q(112.0,37.0,112.0,36.0), Q(112.0,21.0,95.5,10.5), Q(79.0,0.0,56.0,0.0), Q(33.0,0.0,16.5,10.5), Q(0.0,21.0,0.0,36.0), Q(0.0,37.0,0.5,40.0), Q(1.0,43.0,1.0,44.0), L(42.0,264.0), Z ];
-
-// pub const CADENZA_10 : &'static [PathSegment] = &[ M(57.0,100.0), Q(57.0,-57.0,126.0,-143.0), Q(129.0,-146.0,129.0,-150.0), Q(129.0,-163.0,115.0,-163.0), Q(107.0,-163.0,103.0,-157.0), Q(0.0,-29.0,0.0,100.0), Q(0.0,229.0,103.0,357.0), Q(107.0,363.0,115.0,363.0), Q(129.0,363.0,129.0,350.0), Q(129.0,346.0,126.0,343.0), Q(57.0,257.0,57.0,100.0), Z ];
-
-// pub const CADENZA_11 : &'static [PathSegment] = &[ M(72.0,100.0), Q(72.0,257.0,3.0,343.0), Q(0.0,346.0,0.0,350.0), Q(0.0,363.0,15.0,363.0), Q(22.0,363.0,26.0,357.0), Q(130.0,227.0,130.0,100.0), Q(130.0,-27.0,26.0,-157.0), Q(22.0,-163.0,15.0,-163.0), Q(0.0,-163.0,0.0,-150.0), Q(0.0,-146.0,3.0,-143.0), Q(72.0,-57.0,72.0,100.0), Z ];
-
-// pub const CADENZA_12 : &'static [PathSegment] = &[ M(171.0,196.0), Q(171.0,186.0,178.5,178.5), Q(186.0,171.0,196.0,171.0), Q(208.0,171.0,215.5,178.5), Q(223.0,186.0,223.0,196.5), Q(223.0,207.0,215.0,215.0), Q(207.0,223.0,196.5,223.0), Q(186.0,223.0,178.5,215.5), Q(171.0,208.0,171.0,196.0), Z , M(94.0,352.0), Q(112.0,352.0,125.5,338.0), Q(139.0,324.0,139.0,304.0), L(139.0,292.0), Q(139.0,282.0,143.0,273.0), Q(149.0,258.0,161.0,258.0), Q(165.0,258.0,171.0,260.0), Q(184.0,266.0,184.0,280.0), Q(184.0,292.0,165.0,310.0), Q(146.0,328.0,146.0,344.0), Q(146.0,368.0,161.0,381.0), Q(176.0,394.0,196.5,394.0), Q(217.0,394.0,232.0,380.0), Q(248.0,368.0,248.0,344.0), Q(248.0,328.0,228.0,310.0), Q(209.0,292.0,209.0,280.0), Q(209.0,273.0,215.0,266.0), Q(222.0,258.0,232.0,259.0), Q(240.0,259.0,246.0,264.0), Q(255.0,272.0,255.0,292.0), Q(255.0,294.0,254.0,298.0), Q(254.0,302.0,254.0,304.0), Q(254.0,324.0,268.0,338.0), Q(282.0,352.0,300.0,352.0), Q(320.0,352.0,336.0,336.0), Q(352.0,320.0,352.0,300.0), Q(352.0,282.0,338.0,268.0), Q(324.0,254.0,304.0,254.0), Q(302.0,254.0,298.0,254.5), Q(294.0,255.0,292.0,255.0), Q(282.0,255.0,273.0,251.0), Q(258.0,245.0,258.0,233.0), Q(258.0,229.0,260.0,223.0), Q(266.0,209.0,280.0,209.0), Q(292.0,209.0,310.0,228.5), Q(328.0,248.0,344.0,248.0), Q(368.0,248.0,381.0,232.5), Q(394.0,217.0,394.0,196.5), Q(394.0,176.0,380.0,161.0), Q(368.0,146.0,344.0,146.0), Q(328.0,146.0,310.0,165.0), Q(292.0,184.0,280.0,184.0), Q(273.0,184.0,266.0,178.0), Q(258.0,171.0,259.0,161.0), Q(259.0,153.0,264.0,147.0), Q(272.0,139.0,292.0,139.0), L(304.0,139.0), Q(324.0,139.0,338.0,126.0), Q(352.0,112.0,352.0,94.0), Q(352.0,74.0,336.0,58.0), Q(320.0,42.0,300.0,42.0), Q(282.0,42.0,268.0,56.0), Q(254.0,70.0,254.0,90.0), Q(254.0,92.0,254.5,96.0), Q(255.0,100.0,255.0,102.0), Q(255.0,112.0,251.0,121.0), Q(245.0,136.0,233.0,136.0), Q(229.0,136.0,223.0,134.0), Q(209.0,128.0,209.0,114.0), Q(209.0,102.0,228.5,84.0), Q(248.0,66.0,248.0,50.0), Q(248.0,26.0,232.5,13.0), Q(217.0,0.0,196.5,0.0), Q(176.0,0.0,161.0,14.0), Q(146.0,26.0,146.0,50.0), Q(146.0,66.0,165.0,84.0), Q(184.0,102.0,184.0,114.0), Q(184.0,121.0,178.0,128.0), Q(171.0,136.0,161.0,135.0), Q(153.0,135.0,147.0,130.0), Q(139.0,122.0,139.0,102.0), L(139.0,90.0), Q(139.0,70.0,126.0,56.0), Q(112.0,42.0,94.0,42.0), Q(74.0,42.0,58.0,58.0), Q(42.0,74.0,42.0,94.0), Q(42.0,112.0,56.0,126.0), Q(70.0,139.0,90.0,139.0), L(102.0,139.0), Q(112.0,139.0,121.0,143.0), Q(136.0,149.0,136.0,161.0), Q(136.0,165.0,134.0,171.0), Q(128.0,184.0,114.0,184.0), Q(102.0,184.0,84.0,165.0), Q(66.0,146.0,50.0,146.0), Q(26.0,146.0,13.0,161.0), Q(0.0,176.0,0.0,196.5), Q(0.0,217.0,14.0,232.0), Q(26.0,248.0,50.0,248.0), Q(66.0,248.0,84.0,228.0), Q(102.0,209.0,114.0,209.0), Q(121.0,209.0,128.0,215.0), Q(136.0,222.0,135.0,232.0), Q(135.0,240.0,130.0,246.0), Q(122.0,255.0,102.0,255.0), Q(100.0,255.0,96.0,254.5), Q(92.0,254.0,90.0,254.0), Q(70.0,254.0,56.0,268.0), Q(42.0,282.0,42.0,300.0), Q(42.0,320.0,58.0,336.0), Q(74.0,352.0,94.0,352.0), Z ];
-
-// pub const CADENZA_13 : &'static [PathSegment] = &[ M(0.0,0.0), Q(0.0,10.0,7.0,17.5), Q(14.0,25.0,25.0,25.0), L(82.0,25.0), Q(89.0,25.0,94.5,30.0), Q(100.0,35.0,100.0,43.0), L(100.0,100.0), Q(100.0,110.0,107.0,117.5), Q(114.0,125.0,125.0,125.0), Q(136.0,125.0,143.0,117.5), Q(150.0,110.0,150.0,100.0), L(150.0,43.0), Q(150.0,35.0,155.5,30.0), Q(161.0,25.0,168.0,25.0), L(225.0,25.0), Q(236.0,25.0,243.0,17.5), Q(250.0,10.0,250.0,0.0), Q(250.0,-10.0,243.0,-17.5), Q(236.0,-25.0,225.0,-25.0), L(168.0,-25.0), Q(161.0,-25.0,155.5,-30.0), Q(150.0,-35.0,150.0,-43.0), L(150.0,-100.0), Q(150.0,-110.0,143.0,-117.5), Q(136.0,-125.0,125.0,-125.0), Q(114.0,-125.0,107.0,-117.5), Q(100.0,-110.0,100.0,-100.0), L(100.0,-43.0), Q(100.0,-35.0,94.5,-30.0), Q(89.0,-25.0,82.0,-25.0), L(25.0,-25.0), Q(14.0,-25.0,7.0,-17.5), Q(0.0,-10.0,0.0,0.0), Z ];
-
-// pub const CADENZA_14 : &'static [PathSegment] = &[ M(15.0,-153.0), Q(0.0,-153.0,0.0,-138.0), Q(0.0,-134.0,3.0,-129.0), Q(52.0,-60.0,52.0,6.0), Q(52.0,58.0,24.0,99.0), Q(16.0,110.0,16.0,122.0), Q(16.0,138.0,28.0,151.0), Q(40.0,164.0,57.0,164.0), Q(87.0,164.0,103.5,127.5), Q(120.0,91.0,120.0,56.0), Q(120.0,-23.0,28.0,-146.0), Q(23.0,-153.0,15.0,-153.0), Z ];
-
-// pub const CADENZA_15 : &'static [PathSegment] = &[ M(280.0,-22.0), L(20.0,-22.0), Q(12.0,-22.0,6.0,-15.5), Q(0.0,-9.0,0.0,0.0), Q(0.0,9.0,6.0,15.5), Q(12.0,22.0,20.0,22.0), L(280.0,22.0), Q(288.0,22.0,294.0,15.5), Q(300.0,9.0,300.0,0.0), Q(300.0,-9.0,294.0,-15.5), Q(288.0,-22.0,280.0,-22.0), Z ];
-
-// pub const CADENZA_16 : &'static [PathSegment] = &[ M(0.0,0.0), Q(0.0,23.0,16.0,39.0), Q(32.0,55.0,55.0,55.0), Q(78.0,55.0,94.0,39.0), Q(110.0,23.0,110.0,0.0), Q(110.0,-23.0,94.0,-39.0), Q(78.0,-55.0,55.0,-55.0), Q(32.0,-55.0,16.0,-39.0), Q(0.0,-23.0,0.0,0.0), Z ];
-
-// pub const CADENZA_17 : &'static [PathSegment] = &[ M(0.0,664.0), L(60.0,664.0), L(60.0,-154.0), L(0.0,-154.0), L(0.0,664.0), Z , M(132.0,664.0), L(192.0,664.0), L(192.0,-154.0), L(132.0,-154.0), L(132.0,664.0), Z ];
-
-// pub const CADENZA_18 : &'static [PathSegment] = &[ M(183.0,221.0), Q(161.0,221.0,147.0,207.0), Q(133.0,193.0,126.5,166.5), Q(120.0,140.0,118.0,115.0), Q(116.0,90.0,116.0,55.0), Q(116.0,45.0,116.5,26.5), Q(117.0,8.0,117.0,0.0), Q(117.0,-8.0,116.5,-26.5), Q(116.0,-45.0,116.0,-55.0), Q(116.0,-90.0,118.0,-115.0), Q(120.0,-140.0,126.5,-166.5), Q(133.0,-193.0,147.0,-207.0), Q(161.0,-221.0,183.0,-221.0), Q(205.0,-221.0,219.0,-207.0), Q(233.0,-193.0,239.5,-166.5), Q(246.0,-140.0,248.0,-115.0), Q(250.0,-90.0,250.0,-55.0), L(250.0,0.0), L(250.0,55.0), Q(250.0,90.0,248.0,115.0), Q(246.0,140.0,239.5,166.5), Q(233.0,193.0,219.0,207.0), Q(205.0,221.0,183.0,221.0), Z , M(183.0,250.0), Q(266.0,250.0,316.5,175.5), Q(367.0,101.0,367.0,0.0), Q(367.0,-101.0,316.5,-175.5), Q(266.0,-250.0,183.0,-250.0), Q(100.0,-250.0,50.0,-175.5), Q(0.0,-101.0,0.0,0.0), Q(0.0,101.0,50.0,175.5), Q(100.0,250.0,183.0,250.0), Z ];
-
-// pub const CADENZA_19 : &'static [PathSegment] = &[ M(83.0,104.0), L(28.0,-14.0), Q(24.0,-22.0,16.0,-22.0), Q(10.0,-22.0,5.0,-18.0), Q(0.0,-14.0,0.0,-8.0), Q(0.0,-6.0,2.0,-2.0), L(119.0,248.0), Q(121.0,251.0,124.0,252.0), Q(133.0,252.0,150.0,244.0), Q(167.0,236.0,177.0,236.0), Q(191.0,236.0,219.0,248.0), Q(220.0,250.0,223.0,250.0), Q(235.0,250.0,235.0,232.0), L(235.0,-134.0), Q(235.0,-172.0,255.5,-200.0), Q(276.0,-228.0,308.0,-229.0), Q(320.0,-230.0,320.0,-240.0), Q(320.0,-252.0,308.0,-252.0), Q(286.0,-252.0,242.5,-245.0), Q(199.0,-238.0,177.0,-238.0), Q(155.0,-238.0,111.5,-245.0), Q(68.0,-252.0,46.0,-252.0), Q(34.0,-252.0,34.0,-240.0), Q(34.0,-229.0,46.0,-229.0), Q(78.0,-229.0,98.5,-200.5), Q(119.0,-172.0,119.0,-134.0), L(119.0,97.0), Q(119.0,105.0,113.5,110.0), Q(108.0,115.0,101.0,115.0), Q(88.0,115.0,83.0,104.0), Z ];
-
-// pub const CADENZA_20 : &'static [PathSegment] = &[ M(169.0,-117.0), Q(205.0,-127.0,234.0,-148.0), Q(266.0,-171.0,300.0,-171.0), Q(337.0,-171.0,344.0,-142.0), Q(347.0,-133.0,355.0,-133.0), Q(367.0,-133.0,367.0,-145.0), Q(367.0,-191.0,319.5,-220.5), Q(272.0,-250.0,229.0,-250.0), Q(170.0,-250.0,133.0,-206.0), Q(115.0,-184.0,85.0,-184.0), Q(61.0,-184.0,43.0,-199.0), Q(25.0,-214.0,23.0,-239.0), Q(21.0,-250.0,12.0,-250.0), Q(0.0,-250.0,0.0,-239.0), Q(0.0,-198.0,17.5,-165.0), Q(35.0,-132.0,61.0,-111.5), Q(87.0,-91.0,117.5,-68.5), Q(148.0,-46.0,174.0,-25.0), Q(200.0,-4.0,217.5,29.5), Q(235.0,63.0,235.0,105.0), Q(235.0,156.0,218.0,191.5), Q(201.0,227.0,165.0,227.0), Q(144.0,227.0,129.0,217.0), Q(114.0,207.0,114.0,190.0), Q(114.0,176.0,131.5,156.0), Q(149.0,136.0,149.0,122.0), Q(149.0,93.0,129.0,73.0), Q(109.0,53.0,81.0,53.0), Q(52.0,53.0,32.0,73.5), Q(12.0,94.0,12.0,122.0), Q(12.0,178.0,57.0,214.0), Q(102.0,250.0,165.0,250.0), Q(240.0,250.0,296.0,209.5), Q(352.0,169.0,352.0,105.0), Q(352.0,85.0,349.0,68.0), Q(346.0,51.0,338.5,36.5), Q(331.0,22.0,324.5,11.0), Q(318.0,0.0,303.5,-11.0), Q(289.0,-22.0,281.5,-28.5), Q(274.0,-35.0,254.0,-44.5), Q(234.0,-54.0,226.5,-57.5), Q(219.0,-61.0,196.0,-70.5), Q(173.0,-80.0,167.0,-83.0), Q(154.0,-89.0,156.0,-103.0), Q(158.0,-115.0,169.0,-117.0), Z ];
-
-// pub const CADENZA_21 : &'static [PathSegment] = &[ M(150.0,227.0), Q(91.0,227.0,91.0,194.0), Q(91.0,183.0,109.0,170.5), Q(127.0,158.0,127.0,147.0), Q(127.0,123.0,110.0,106.0), Q(93.0,89.0,69.0,89.0), Q(45.0,89.0,28.5,106.0), Q(12.0,123.0,12.0,147.0), Q(12.0,194.0,53.0,222.0), Q(94.0,250.0,150.0,250.0), Q(224.0,250.0,271.5,221.5), Q(319.0,193.0,319.0,136.0), Q(319.0,98.0,311.0,76.0), Q(303.0,54.0,277.0,42.0), Q(263.0,36.0,263.0,21.0), Q(263.0,5.0,277.0,-1.0), Q(333.0,-27.0,333.0,-110.0), Q(333.0,-177.0,282.5,-213.5), Q(232.0,-250.0,154.0,-250.0), Q(92.0,-250.0,46.0,-219.0), Q(0.0,-188.0,0.0,-137.0), Q(0.0,-110.0,19.5,-90.5), Q(39.0,-71.0,66.0,-71.0), Q(93.0,-71.0,112.0,-90.5), Q(131.0,-110.0,131.0,-137.0), Q(131.0,-150.0,111.0,-164.0), Q(91.0,-178.0,91.0,-190.0), Q(91.0,-209.0,109.5,-218.0), Q(128.0,-227.0,154.0,-227.0), Q(188.0,-227.0,202.5,-193.0), Q(217.0,-159.0,217.0,-110.0), L(217.0,-94.0), Q(217.0,-60.0,215.0,-42.5), Q(213.0,-25.0,201.5,-9.5), Q(190.0,6.0,167.0,6.0), L(87.0,6.0), Q(73.0,6.0,73.0,21.0), Q(73.0,35.0,87.0,35.0), L(167.0,35.0), Q(185.0,35.0,195.5,41.0), Q(206.0,47.0,210.5,61.5), Q(215.0,76.0,216.0,89.0), Q(217.0,102.0,217.0,128.0), L(217.0,136.0), Q(217.0,227.0,150.0,227.0), Z ];
-
-// pub const CADENZA_22 : &'static [PathSegment] = &[ M(316.0,-146.0), Q(319.0,-181.0,338.0,-204.5), Q(357.0,-228.0,387.0,-227.0), Q(399.0,-226.0,399.0,-238.0), Q(399.0,-250.0,387.0,-250.0), Q(365.0,-250.0,321.5,-244.0), Q(278.0,-238.0,256.0,-238.0), Q(234.0,-238.0,190.5,-244.0), Q(147.0,-250.0,125.0,-250.0), Q(113.0,-250.0,113.0,-238.0), Q(113.0,-227.0,125.0,-227.0), Q(155.0,-227.0,176.0,-203.0), Q(196.0,-178.0,198.0,-144.0), Q(199.0,-137.0,193.0,-131.5), Q(187.0,-126.0,179.0,-126.0), L(31.0,-126.0), Q(15.0,-126.0,8.0,-118.0), Q(0.0,-110.0,0.0,-102.0), Q(0.0,-98.0,2.0,-97.0), Q(140.0,64.0,140.0,234.0), Q(140.0,241.0,144.0,245.5), Q(148.0,250.0,153.0,250.0), L(156.0,250.0), Q(199.0,237.0,227.0,236.5), Q(255.0,236.0,298.0,250.0), Q(300.0,250.0,303.0,250.0), Q(311.0,250.0,316.0,244.0), Q(320.0,240.0,315.0,234.0), L(57.0,-66.0), Q(53.0,-71.0,53.0,-79.0), Q(53.0,-86.0,57.5,-91.0), Q(62.0,-96.0,70.0,-97.0), L(180.0,-97.0), Q(188.0,-97.0,193.0,-91.5), Q(198.0,-86.0,198.0,-78.0), L(198.0,34.0), Q(198.0,50.0,206.0,56.0), Q(249.0,82.0,266.0,106.0), Q(276.0,118.0,293.0,156.0), Q(296.0,162.0,302.0,162.0), Q(307.0,162.0,311.0,158.0), Q(315.0,154.0,315.0,148.0), L(315.0,-78.0), Q(315.0,-86.0,320.0,-91.0), Q(325.0,-96.0,333.0,-97.0), L(387.0,-97.0), Q(394.0,-97.0,398.0,-101.0), Q(402.0,-105.0,402.0,-110.5), Q(402.0,-116.0,398.0,-121.0), Q(394.0,-126.0,387.0,-126.0), L(334.0,-126.0), Q(325.0,-126.0,320.0,-132.5), Q(315.0,-139.0,316.0,-146.0), Z ];
-
-// pub const CADENZA_23 : &'static [PathSegment] = &[ M(86.0,50.0), Q(120.0,71.0,163.0,71.0), Q(252.0,71.0,300.5,31.0), Q(349.0,-9.0,349.0,-90.0), Q(349.0,-159.0,294.0,-204.5), Q(239.0,-250.0,163.0,-250.0), Q(97.0,-250.0,48.5,-220.0), Q(0.0,-190.0,0.0,-137.0), Q(0.0,-110.0,19.5,-90.5), Q(39.0,-71.0,66.0,-71.0), Q(93.0,-71.0,112.0,-90.5), Q(131.0,-110.0,131.0,-137.0), Q(131.0,-150.0,111.0,-164.0), Q(91.0,-178.0,91.0,-190.0), Q(91.0,-227.0,163.0,-227.0), Q(233.0,-227.0,233.0,-90.0), Q(233.0,42.0,163.0,42.0), Q(89.0,42.0,56.0,-3.0), Q(52.0,-9.0,44.0,-9.0), Q(29.0,-9.0,29.0,6.0), L(29.0,234.0), Q(29.0,241.0,33.5,245.5), Q(38.0,250.0,44.0,250.0), L(46.0,250.0), Q(121.0,237.0,183.0,237.0), Q(246.0,237.0,321.0,250.0), L(325.0,250.0), Q(334.0,250.0,338.5,244.0), Q(343.0,238.0,338.0,234.0), Q(245.0,141.0,75.0,141.0), Q(68.0,141.0,63.0,136.0), Q(58.0,131.0,58.0,124.0), L(58.0,65.0), Q(58.0,58.0,63.5,52.5), Q(69.0,47.0,76.0,47.0), Q(81.0,47.0,86.0,50.0), Z ];
-
-// pub const CADENZA_24 : &'static [PathSegment] = &[ M(170.0,6.0), Q(152.0,6.0,140.5,-1.0), Q(129.0,-8.0,124.0,-24.0), Q(119.0,-40.0,118.0,-54.5), Q(117.0,-69.0,117.0,-96.0), L(117.0,-110.0), L(117.0,-125.0), Q(117.0,-152.0,118.0,-166.5), Q(119.0,-181.0,124.0,-197.0), Q(129.0,-213.0,140.5,-220.0), Q(152.0,-227.0,170.0,-227.0), Q(191.0,-227.0,203.5,-219.0), Q(216.0,-211.0,222.0,-192.5), Q(228.0,-174.0,229.5,-156.5), Q(231.0,-139.0,231.0,-110.0), Q(231.0,-51.0,220.0,-22.5), Q(209.0,6.0,170.0,6.0), Z , M(140.0,24.0), Q(157.0,29.0,170.0,29.0), Q(249.0,29.0,294.5,-6.0), Q(340.0,-41.0,340.0,-110.0), Q(340.0,-179.0,294.0,-214.5), Q(248.0,-250.0,170.0,-250.0), Q(90.0,-250.0,45.0,-176.0), Q(0.0,-102.0,0.0,0.0), Q(0.0,101.0,52.5,175.5), Q(105.0,250.0,190.0,250.0), Q(246.0,250.0,287.0,221.5), Q(328.0,193.0,328.0,147.0), Q(328.0,120.0,309.0,100.5), Q(290.0,81.0,263.0,81.0), Q(236.0,81.0,216.5,100.5), Q(197.0,120.0,197.0,147.0), Q(197.0,159.0,216.5,171.5), Q(236.0,184.0,236.0,196.0), Q(236.0,210.0,222.0,218.5), Q(208.0,227.0,190.0,227.0), Q(145.0,227.0,130.0,190.5), Q(115.0,154.0,115.0,88.0), Q(115.0,57.0,116.0,41.0), Q(116.0,34.0,121.5,28.5), Q(127.0,23.0,135.0,23.0), Q(137.0,23.0,140.0,24.0), Z ];
-
-// pub const CADENZA_25 : &'static [PathSegment] = &[ M(60.0,226.0), Q(88.0,252.0,122.0,252.0), Q(167.0,252.0,206.0,212.0), Q(223.0,196.0,249.0,196.0), Q(272.0,196.0,294.5,209.0), Q(317.0,222.0,333.0,246.0), Q(337.0,252.0,344.0,252.0), Q(350.0,252.0,355.0,248.0), Q(360.0,244.0,360.0,238.0), Q(360.0,234.0,357.0,228.0), Q(284.0,124.0,241.5,30.0), Q(199.0,-64.0,199.0,-158.0), Q(199.0,-192.0,207.0,-234.0), Q(208.0,-241.0,204.0,-246.5), Q(200.0,-252.0,194.0,-252.0), L(192.0,-251.0), L(190.0,-250.0), Q(139.0,-236.0,117.0,-236.0), Q(94.0,-236.0,43.0,-250.0), L(42.0,-251.0), Q(40.0,-252.0,39.0,-252.0), Q(32.0,-252.0,28.0,-246.0), Q(24.0,-240.0,27.0,-234.0), Q(82.0,-104.0,212.0,78.0), Q(218.0,88.0,212.0,96.0), Q(205.0,108.0,195.0,106.0), Q(193.0,106.0,189.5,105.0), Q(186.0,104.0,184.0,104.0), Q(150.0,104.0,124.0,130.0), Q(93.0,162.0,67.0,162.0), Q(46.0,162.0,37.5,140.0), Q(29.0,118.0,29.0,92.0), L(29.0,16.0), Q(29.0,9.0,25.0,5.5), Q(21.0,2.0,15.0,2.0), Q(9.0,2.0,4.0,6.0), Q(0.0,8.0,0.0,16.0), L(0.0,236.0), Q(0.0,243.0,4.5,247.5), Q(9.0,252.0,15.0,252.0), Q(21.0,252.0,25.0,248.0), Q(29.0,244.0,29.0,236.0), Q(29.0,226.0,41.0,222.0), Q(52.0,218.0,60.0,226.0), Z ];
-
-// pub const CADENZA_26 : &'static [PathSegment] = &[ M(270.0,50.0), Q(308.0,95.0,308.0,140.0), Q(308.0,180.0,273.5,203.5), Q(239.0,227.0,191.0,227.0), Q(157.0,227.0,136.5,205.5), Q(116.0,184.0,116.0,156.0), Q(116.0,122.0,146.0,104.0), L(246.0,45.0), Q(261.0,37.0,270.0,50.0), Z , M(60.0,22.0), Q(15.0,62.0,15.0,116.0), Q(15.0,171.0,62.0,210.5), Q(109.0,250.0,191.0,250.0), Q(256.0,250.0,304.0,220.5), Q(352.0,191.0,352.0,140.0), Q(352.0,90.0,304.0,37.0), Q(292.0,22.0,307.0,10.0), Q(372.0,-37.0,372.0,-106.0), Q(372.0,-165.0,321.5,-207.5), Q(271.0,-250.0,183.0,-250.0), Q(109.0,-250.0,54.5,-211.0), Q(0.0,-172.0,0.0,-110.0), Q(0.0,-63.0,61.0,-4.0), Q(74.0,9.0,60.0,22.0), Z , M(96.0,-17.0), Q(44.0,-64.0,44.0,-110.0), Q(44.0,-160.0,85.5,-193.5), Q(127.0,-227.0,183.0,-227.0), Q(222.0,-227.0,245.5,-202.0), Q(269.0,-177.0,269.0,-144.0), Q(269.0,-102.0,234.0,-82.0), L(117.0,-15.0), Q(105.0,-8.0,96.0,-17.0), Z ];
-
-// pub const CADENZA_27 : &'static [PathSegment] = &[ M(170.0,-6.0), Q(188.0,-6.0,199.5,1.0), Q(211.0,8.0,216.0,24.0), Q(221.0,40.0,222.0,54.5), Q(223.0,69.0,223.0,96.0), L(223.0,110.0), L(223.0,125.0), Q(223.0,152.0,222.0,166.5), Q(221.0,181.0,216.0,197.0), Q(211.0,213.0,199.5,220.0), Q(188.0,227.0,170.0,227.0), Q(149.0,227.0,136.5,219.0), Q(124.0,211.0,118.0,192.5), Q(112.0,174.0,110.5,156.5), Q(109.0,139.0,109.0,110.0), Q(109.0,51.0,120.0,22.5), Q(131.0,-6.0,170.0,-6.0), Z , M(201.0,-24.0), Q(184.0,-29.0,170.0,-29.0), Q(91.0,-29.0,45.5,6.0), Q(0.0,41.0,0.0,110.0), Q(0.0,179.0,46.0,214.5), Q(92.0,250.0,170.0,250.0), Q(250.0,250.0,295.0,176.0), Q(340.0,102.0,340.0,0.0), Q(340.0,-101.0,287.5,-175.5), Q(235.0,-250.0,150.0,-250.0), Q(94.0,-250.0,53.0,-221.5), Q(12.0,-193.0,12.0,-147.0), Q(12.0,-120.0,31.0,-100.5), Q(50.0,-81.0,77.0,-81.0), Q(104.0,-81.0,123.5,-100.5), Q(143.0,-120.0,143.0,-147.0), Q(143.0,-159.0,123.5,-171.5), Q(104.0,-184.0,104.0,-196.0), Q(104.0,-210.0,118.0,-218.5), Q(132.0,-227.0,150.0,-227.0), Q(195.0,-227.0,210.0,-190.5), Q(225.0,-154.0,225.0,-88.0), Q(225.0,-57.0,224.0,-41.0), Q(224.0,-34.0,218.5,-28.5), Q(213.0,-23.0,205.0,-23.0), Q(203.0,-23.0,201.0,-24.0), Z ];
-
-// pub const CADENZA_28 : &'static [PathSegment] = &[ M(40.0,-407.0), Q(174.0,-323.0,174.0,-245.0), Q(174.0,-192.0,146.0,-136.0), Q(145.0,-129.0,150.0,-123.5), Q(155.0,-118.0,161.0,-118.0), Q(170.0,-118.0,175.0,-125.0), Q(202.0,-185.0,202.0,-245.0), Q(202.0,-272.0,192.0,-299.0), Q(182.0,-326.0,166.0,-348.5), Q(150.0,-371.0,131.0,-393.0), Q(112.0,-415.0,92.5,-437.5), Q(73.0,-460.0,57.5,-482.5), Q(42.0,-505.0,31.5,-532.0), Q(21.0,-559.0,21.0,-586.0), L(11.0,-586.0), L(0.0,-586.0), L(0.0,-23.0), Q(0.0,17.0,44.0,54.5), Q(88.0,92.0,135.0,92.0), Q(154.0,92.0,171.0,87.0), Q(188.0,82.0,203.0,65.5), Q(218.0,49.0,218.0,23.0), Q(218.0,-14.0,173.5,-53.0), Q(129.0,-92.0,84.0,-92.0), Q(65.0,-92.0,48.0,-87.0), Q(34.0,-83.0,21.0,-72.0), L(21.0,-372.0), L(21.0,-397.0), Q(21.0,-402.0,25.0,-405.5), Q(29.0,-409.0,34.0,-409.0), Q(37.0,-409.0,40.0,-407.0), Z ];
-
-// pub const CADENZA_29 : &'static [PathSegment] = &[ M(0.0,-23.0), Q(0.0,17.0,44.0,54.5), Q(88.0,92.0,135.0,92.0), Q(154.0,92.0,171.0,87.0), Q(185.0,83.0,197.0,72.0), L(197.0,586.0), L(208.0,586.0), L(218.0,586.0), Q(218.0,550.0,232.5,512.0), Q(247.0,474.0,267.5,441.0), Q(288.0,408.0,309.0,375.0), Q(330.0,342.0,344.0,303.5), Q(358.0,265.0,358.0,229.0), Q(358.0,153.0,330.0,90.0), Q(325.0,82.0,318.0,82.0), Q(311.0,82.0,306.0,88.0), Q(301.0,92.0,302.0,100.0), Q(330.0,156.0,330.0,226.0), Q(330.0,302.0,239.0,397.0), Q(235.0,401.0,230.5,400.5), Q(226.0,400.0,222.0,396.5), Q(218.0,393.0,218.0,388.0), L(218.0,366.0), L(218.0,23.0), Q(218.0,-14.0,173.5,-53.0), Q(129.0,-92.0,84.0,-92.0), Q(65.0,-92.0,48.0,-87.0), Q(31.0,-82.0,15.5,-65.5), Q(0.0,-49.0,0.0,-23.0), Z ];
-
-// pub const CADENZA_30 : &'static [PathSegment] = &[ M(106.0,185.0), Q(106.0,203.0,119.0,216.0), Q(132.0,229.0,150.0,229.0), Q(168.0,229.0,181.0,216.0), Q(194.0,203.0,194.0,185.0), Q(194.0,167.0,181.0,154.0), Q(168.0,141.0,150.0,141.0), Q(132.0,141.0,119.0,154.0), Q(106.0,167.0,106.0,185.0), Z , M(282.0,0.0), L(18.0,0.0), Q(11.0,0.0,5.5,6.0), Q(0.0,12.0,0.0,20.0), Q(0.0,28.0,5.5,34.0), Q(11.0,40.0,18.0,40.0), L(282.0,40.0), Q(289.0,40.0,294.5,34.0), Q(300.0,28.0,300.0,20.0), Q(300.0,12.0,294.5,6.0), Q(289.0,0.0,282.0,0.0), Z ];
-
-// pub const CADENZA_31 : &'static [PathSegment] = &[ M(0.0,-12.0), L(0.0,13.0), L(1000.0,13.0), L(1000.0,-12.0), L(0.0,-12.0), Z , M(0.0,238.0), L(0.0,263.0), L(1000.0,263.0), L(1000.0,238.0), L(0.0,238.0), Z , M(0.0,488.0), L(0.0,513.0), L(1000.0,513.0), L(1000.0,488.0), L(0.0,488.0), Z , M(0.0,738.0), L(0.0,763.0), L(1000.0,763.0), L(1000.0,738.0), L(0.0,738.0), Z , M(0.0,988.0), L(0.0,1013.0), L(1000.0,1013.0), L(1000.0,988.0), L(0.0,988.0), Z ];
-
-// pub const CADENZA_32 : &'static [PathSegment] = &[ M(285.0,142.0), L(14.0,213.0), Q(0.0,216.0,0.0,231.0), Q(0.0,238.0,6.0,244.0), Q(12.0,250.0,19.0,250.0), Q(20.0,250.0,22.0,250.0), L(23.0,249.0), L(431.0,144.0), Q(450.0,144.0,450.0,125.0), Q(450.0,106.0,431.0,106.0), L(23.0,1.0), L(22.0,0.0), Q(20.0,0.0,19.0,0.0), Q(12.0,0.0,6.0,6.0), Q(0.0,12.0,0.0,19.0), Q(0.0,34.0,14.0,37.0), L(285.0,108.0), Q(299.0,113.0,299.0,125.0), Q(299.0,137.0,285.0,142.0), Z ];
-
-// pub const CADENZA_33 : &'static [PathSegment] = &[ M(570.0,-125.0), Q(570.0,-104.0,585.0,-89.0), Q(600.0,-74.0,621.0,-74.0), Q(642.0,-74.0,657.0,-89.0), Q(672.0,-104.0,672.0,-125.0), Q(672.0,-146.0,657.0,-161.0), Q(642.0,-176.0,621.0,-176.0), Q(600.0,-176.0,585.0,-161.0), Q(570.0,-146.0,570.0,-125.0), Z , M(570.0,125.0), Q(570.0,146.0,585.0,161.0), Q(600.0,176.0,621.0,176.0), Q(642.0,176.0,657.0,161.0), Q(672.0,146.0,672.0,125.0), Q(672.0,104.0,657.0,89.0), Q(642.0,74.0,621.0,74.0), Q(600.0,74.0,585.0,89.0), Q(570.0,104.0,570.0,125.0), Z , M(122.0,119.0), Q(167.0,119.0,196.0,90.0), Q(225.0,61.0,225.0,19.0), Q(225.0,-25.0,196.0,-56.0), Q(167.0,-87.0,125.0,-87.0), Q(80.0,-87.0,46.5,-56.0), Q(13.0,-25.0,13.0,19.0), Q(13.0,127.0,85.0,195.0), Q(157.0,263.0,264.0,263.0), Q(385.0,263.0,461.5,193.5), Q(538.0,124.0,538.0,12.0), Q(538.0,-342.0,24.0,-569.0), Q(17.0,-572.0,13.0,-572.0), Q(0.0,-572.0,0.0,-559.0), Q(0.0,-552.0,13.0,-544.0), Q(93.0,-497.0,145.5,-461.0), Q(198.0,-425.0,254.0,-374.0), Q(310.0,-323.0,342.0,-271.5), Q(374.0,-220.0,395.0,-150.0), Q(416.0,-80.0,416.0,2.0), Q(416.0,106.0,370.5,169.0), Q(325.0,232.0,246.0,232.0), Q(198.0,232.0,159.0,209.5), Q(120.0,187.0,105.0,148.0), Q(101.0,135.0,106.0,128.0), Q(113.0,119.0,122.0,119.0), Z ];
-
-// pub const CADENZA_34 : &'static [PathSegment] = &[ M(301.0,281.0), L(301.0,160.0), L(0.0,-1.0), L(0.0,124.0), L(301.0,281.0), Z , M(301.0,461.0), L(301.0,340.0), L(0.0,179.0), L(0.0,304.0), L(301.0,461.0), Z ];
-
-// pub const CADENZA_35 : &'static [PathSegment] = &[ M(440.0,0.0), Q(440.0,174.0,371.0,243.0), Q(368.0,246.0,368.0,250.0), Q(368.0,263.0,383.0,263.0), Q(390.0,263.0,394.0,257.0), Q(498.0,127.0,498.0,0.0), Q(498.0,-101.0,394.0,-257.0), Q(390.0,-263.0,383.0,-263.0), Q(368.0,-263.0,368.0,-250.0), Q(368.0,-246.0,371.0,-243.0), Q(440.0,-157.0,440.0,0.0), Z , M(204.0,-86.0), Q(204.0,-98.0,214.0,-102.0), Q(226.0,-107.0,234.0,-99.0), Q(300.0,-39.0,300.0,37.0), Q(300.0,64.0,288.5,84.0), Q(277.0,104.0,257.0,104.0), Q(235.0,104.0,221.0,85.0), Q(207.0,66.0,206.0,41.0), L(204.0,-86.0), Z , M(232.0,135.0), Q(274.0,156.0,301.0,156.0), Q(339.0,156.0,362.0,126.0), Q(385.0,96.0,385.0,54.0), Q(385.0,25.0,376.5,1.5), Q(368.0,-22.0,350.0,-41.5), Q(332.0,-61.0,316.5,-74.0), Q(301.0,-87.0,276.0,-105.5), Q(251.0,-124.0,238.0,-135.0), Q(233.0,-139.0,225.0,-150.0), Q(217.0,-161.0,209.5,-167.0), Q(202.0,-173.0,193.0,-173.0), Q(183.0,-173.0,176.5,-166.0), Q(170.0,-159.0,170.0,-148.0), L(158.0,457.0), Q(171.0,465.0,185.0,465.0), Q(199.0,465.0,212.0,457.0), L(206.0,151.0), Q(206.0,141.0,214.0,136.0), Q(223.0,130.0,232.0,135.0), Z , M(57.0,0.0), Q(57.0,-157.0,126.0,-243.0), Q(129.0,-246.0,129.0,-250.0), Q(129.0,-263.0,115.0,-263.0), Q(107.0,-263.0,103.0,-257.0), Q(0.0,-129.0,0.0,0.0), Q(0.0,129.0,103.0,257.0), Q(107.0,263.0,115.0,263.0), Q(129.0,263.0,129.0,250.0), Q(129.0,246.0,126.0,243.0), Q(57.0,157.0,57.0,0.0), Z ];
-
-// pub const CADENZA_36 : &'static [PathSegment] = &[ M(329.0,0.0), Q(329.0,-73.0,374.0,-73.0), Q(388.0,-73.0,411.5,-57.5), Q(435.0,-42.0,451.0,-42.0), Q(545.0,-42.0,612.5,-102.0), Q(680.0,-162.0,680.0,-250.0), Q(680.0,-500.0,415.0,-500.0), Q(352.0,-500.0,307.5,-459.0), Q(263.0,-418.0,263.0,-358.0), Q(263.0,-326.0,285.5,-303.5), Q(308.0,-281.0,339.0,-281.0), Q(370.0,-281.0,392.5,-303.5), Q(415.0,-326.0,415.0,-358.0), Q(415.0,-378.0,390.5,-396.0), Q(366.0,-414.0,366.0,-434.0), Q(366.0,-451.0,380.5,-461.5), Q(395.0,-472.0,415.0,-472.0), Q(496.0,-472.0,525.5,-416.0), Q(555.0,-360.0,555.0,-250.0), Q(555.0,-205.0,551.5,-176.0), Q(548.0,-147.0,537.5,-121.0), Q(527.0,-95.0,505.5,-82.5), Q(484.0,-70.0,451.0,-70.0), Q(399.0,-70.0,370.0,-113.5), Q(341.0,-157.0,341.0,-219.0), Q(341.0,-233.0,327.0,-233.0), Q(314.0,-233.0,314.0,-219.0), Q(314.0,-176.0,296.5,-124.5), Q(279.0,-73.0,254.0,-37.0), Q(247.0,-26.0,233.0,-30.0), Q(221.0,-33.0,221.0,-47.0), L(221.0,-490.0), Q(221.0,-500.0,211.0,-500.0), L(190.0,-500.0), Q(180.0,-500.0,180.0,-490.0), L(180.0,490.0), Q(180.0,500.0,190.0,500.0), L(211.0,500.0), Q(221.0,500.0,221.0,490.0), L(221.0,49.0), Q(221.0,35.0,233.0,31.0), Q(246.0,26.0,254.0,38.0), Q(279.0,74.0,296.5,125.0), Q(314.0,176.0,314.0,219.0), Q(314.0,233.0,327.0,233.0), Q(341.0,233.0,341.0,219.0), Q(341.0,157.0,370.0,113.5), Q(399.0,70.0,451.0,70.0), Q(484.0,70.0,505.5,82.5), Q(527.0,95.0,537.5,121.0), Q(548.0,147.0,551.5,176.0), Q(555.0,205.0,555.0,250.0), Q(555.0,360.0,525.5,416.0), Q(496.0,472.0,415.0,472.0), Q(395.0,472.0,380.5,461.5), Q(366.0,451.0,366.0,434.0), Q(366.0,414.0,390.5,396.0), Q(415.0,378.0,415.0,358.0), Q(415.0,326.0,392.5,303.5), Q(370.0,281.0,339.0,281.0), Q(308.0,281.0,285.5,303.5), Q(263.0,326.0,263.0,358.0), Q(263.0,418.0,307.5,459.0), Q(352.0,500.0,415.0,500.0), Q(680.0,500.0,680.0,250.0), Q(680.0,162.0,612.5,102.0), Q(545.0,42.0,451.0,42.0), Q(435.0,42.0,411.5,57.5), Q(388.0,73.0,374.0,73.0), Q(329.0,73.0,329.0,0.0), Z , M(129.0,-500.0), L(10.0,-500.0), Q(0.0,-500.0,0.0,-490.0), L(0.0,490.0), Q(0.0,500.0,10.0,500.0), L(129.0,500.0), Q(139.0,500.0,139.0,490.0), L(139.0,-490.0), Q(139.0,-500.0,129.0,-500.0), Z ];
-
-// pub const CADENZA_37 : &'static [PathSegment] = &[ M(256.0,-238.0), Q(311.0,-236.0,352.0,-193.0), Q(393.0,-150.0,408.0,-87.0), Q(411.0,-77.0,420.0,-77.0), Q(433.0,-77.0,433.0,-89.0), Q(433.0,-156.0,372.5,-209.5), Q(312.0,-263.0,250.0,-263.0), Q(248.0,-263.0,243.5,-262.5), Q(239.0,-262.0,237.0,-262.0), L(237.0,-340.0), Q(237.0,-350.0,227.0,-350.0), L(210.0,-350.0), Q(200.0,-350.0,200.0,-340.0), L(200.0,-259.0), Q(116.0,-246.0,66.0,-193.0), Q(0.0,-124.0,0.0,0.0), Q(0.0,124.0,66.0,193.0), Q(116.0,246.0,200.0,259.0), L(200.0,340.0), Q(200.0,350.0,210.0,350.0), L(227.0,350.0), Q(237.0,350.0,237.0,340.0), L(237.0,262.0), L(250.0,262.0), Q(315.0,262.0,364.5,219.5), Q(414.0,177.0,425.0,112.0), Q(426.0,107.0,426.0,99.0), Q(426.0,69.0,406.5,48.0), Q(387.0,27.0,359.0,27.0), Q(325.0,27.0,304.5,50.5), Q(284.0,74.0,284.0,102.0), Q(284.0,126.0,297.5,147.5), Q(311.0,169.0,334.0,176.0), Q(346.0,179.0,347.0,189.0), Q(349.0,200.0,340.0,207.0), Q(302.0,235.0,255.0,237.0), Q(247.0,237.0,242.0,232.0), Q(237.0,227.0,237.0,219.0), L(237.0,-220.0), Q(237.0,-228.0,242.0,-233.0), Q(247.0,-238.0,256.0,-238.0), Z , M(200.0,-197.0), L(200.0,197.0), Q(200.0,209.0,189.0,214.0), Q(177.0,219.0,169.0,209.0), Q(149.0,188.0,143.0,151.0), Q(137.0,114.0,137.0,51.0), L(137.0,0.0), L(137.0,-51.0), Q(137.0,-114.0,143.0,-151.0), Q(149.0,-188.0,169.0,-209.0), Q(178.0,-218.0,189.0,-214.0), Q(200.0,-209.0,200.0,-197.0), Z ];
-
-// pub const CADENZA_38 : &'static [PathSegment] = &[ M(59.0,-483.0), Q(259.0,-358.0,259.0,-241.0), Q(259.0,-162.0,218.0,-78.0), Q(216.0,-67.0,223.5,-59.0), Q(231.0,-51.0,241.0,-51.0), Q(254.0,-51.0,261.0,-62.0), Q(301.0,-152.0,301.0,-241.0), Q(301.0,-282.0,286.0,-322.0), Q(271.0,-362.0,247.5,-395.5), Q(224.0,-429.0,195.5,-462.0), Q(167.0,-495.0,138.0,-528.5), Q(109.0,-562.0,85.5,-595.5), Q(62.0,-629.0,47.0,-669.0), Q(32.0,-709.0,32.0,-750.0), L(16.0,-750.0), L(0.0,-750.0), L(0.0,91.0), Q(0.0,151.0,66.0,206.5), Q(132.0,262.0,201.0,262.0), Q(229.0,262.0,254.5,255.0), Q(280.0,248.0,303.0,223.0), Q(326.0,198.0,326.0,159.0), Q(326.0,104.0,259.0,46.0), Q(192.0,-12.0,125.0,-12.0), Q(98.0,-12.0,72.0,-5.0), Q(51.0,1.0,32.0,18.0), L(32.0,-430.0), L(32.0,-467.0), Q(32.0,-475.0,37.5,-480.0), Q(43.0,-485.0,50.0,-485.0), Q(55.0,-485.0,59.0,-483.0), Z ];
-
-// pub const CADENZA_39 : &'static [PathSegment] = &[ M(556.0,295.0), Q(585.0,368.0,642.5,421.0), Q(700.0,474.0,765.0,474.0), Q(800.0,474.0,826.0,454.5), Q(852.0,435.0,852.0,405.0), Q(852.0,373.0,838.5,350.5), Q(825.0,328.0,800.0,328.0), Q(782.0,328.0,769.0,340.5), Q(756.0,353.0,756.0,371.0), Q(756.0,380.0,760.5,385.5), Q(765.0,391.0,773.5,397.0), Q(782.0,403.0,785.0,405.0), Q(795.0,415.0,789.0,427.0), Q(785.0,437.0,772.0,437.0), L(765.0,437.0), Q(752.0,437.0,741.0,432.5), Q(730.0,428.0,722.0,416.0), Q(714.0,404.0,709.0,395.0), Q(704.0,386.0,698.5,366.0), Q(693.0,346.0,691.0,337.5), Q(689.0,329.0,684.0,305.0), Q(683.0,302.0,683.0,300.0), Q(683.0,292.0,688.5,287.5), Q(694.0,283.0,701.0,283.0), L(748.0,283.0), Q(767.0,283.0,767.0,264.0), Q(767.0,245.0,748.0,245.0), L(681.0,245.0), Q(667.0,245.0,664.0,232.0), L(618.0,82.0), Q(591.0,-7.0,528.5,-90.0), Q(466.0,-173.0,400.0,-173.0), Q(365.0,-173.0,339.0,-153.5), Q(313.0,-134.0,313.0,-104.0), Q(313.0,-72.0,326.5,-49.5), Q(340.0,-27.0,365.0,-27.0), Q(383.0,-27.0,396.0,-40.0), Q(409.0,-53.0,409.0,-71.0), Q(409.0,-78.0,406.0,-83.5), Q(403.0,-89.0,400.5,-91.0), Q(398.0,-93.0,391.5,-97.5), Q(385.0,-102.0,383.0,-103.0), Q(372.0,-111.0,377.0,-124.0), Q(381.0,-136.0,394.0,-136.0), L(400.0,-136.0), Q(417.0,-136.0,429.5,-127.5), Q(442.0,-119.0,450.5,-99.5), Q(459.0,-80.0,463.0,-67.5), Q(467.0,-55.0,473.0,-28.0), L(535.0,223.0), Q(536.0,225.0,536.0,228.0), Q(536.0,235.0,531.0,240.0), Q(526.0,245.0,518.0,245.0), L(481.0,245.0), Q(462.0,245.0,462.0,264.0), Q(462.0,283.0,481.0,283.0), L(539.0,283.0), Q(551.0,283.0,556.0,295.0), Z , M(429.0,66.0), Q(431.0,68.0,450.0,84.0), Q(456.0,90.0,463.0,90.0), Q(474.0,90.0,474.0,80.0), Q(474.0,75.0,467.0,68.0), Q(388.0,-7.0,336.0,-7.0), Q(321.0,-7.0,312.5,2.0), Q(304.0,11.0,304.0,25.0), Q(304.0,32.0,305.0,36.0), L(344.0,201.0), Q(346.0,211.0,346.0,215.0), Q(346.0,222.0,338.0,227.5), Q(330.0,233.0,322.0,233.0), Q(310.0,233.0,299.0,223.5), Q(288.0,214.0,283.0,201.0), L(230.0,18.0), Q(225.0,0.0,208.0,0.0), L(167.0,0.0), Q(159.0,0.0,155.5,5.5), Q(152.0,11.0,154.0,18.0), L(206.0,201.0), Q(209.0,210.0,209.0,217.0), Q(209.0,223.0,201.0,228.0), Q(193.0,233.0,186.0,233.0), Q(174.0,233.0,162.0,223.5), Q(150.0,214.0,145.0,201.0), L(83.0,18.0), Q(78.0,0.0,59.0,0.0), L(18.0,0.0), Q(1.0,0.0,6.0,18.0), L(69.0,201.0), Q(71.0,206.0,71.0,216.0), Q(71.0,233.0,58.0,233.0), Q(23.0,233.0,-8.0,146.0), Q(-14.0,129.0,-31.0,129.0), Q(-43.0,129.0,-43.0,140.0), Q(-43.0,142.0,-41.0,146.0), Q(-20.0,209.0,20.5,254.0), Q(61.0,299.0,108.0,299.0), Q(130.0,299.0,141.0,278.0), Q(146.0,269.0,157.0,269.0), Q(163.0,269.0,168.0,273.0), Q(199.0,299.0,230.0,299.0), Q(242.0,299.0,255.5,292.0), Q(269.0,285.0,275.0,276.0), Q(280.0,267.0,290.0,267.0), Q(297.0,267.0,302.0,272.0), Q(332.0,299.0,364.0,299.0), Q(389.0,299.0,411.0,277.0), Q(433.0,255.0,433.0,230.0), Q(433.0,225.0,431.0,215.0), L(400.0,84.0), Q(396.0,72.0,408.0,65.0), Q(418.0,59.0,429.0,66.0), Z ];
-
-// pub const CADENZA_40 : &'static [PathSegment] = &[ M(0.0,-7.0), L(0.0,33.0), L(175.0,126.0), L(175.0,86.0), L(0.0,-7.0), Z ];
-
-// pub const CADENZA_41 : &'static [PathSegment] = &[ M(249.0,226.0), Q(220.0,226.0,126.5,160.5), Q(33.0,95.0,33.0,65.0), Q(33.0,44.0,51.0,34.0), Q(69.0,24.0,94.0,24.0), Q(123.0,24.0,216.5,89.5), Q(310.0,155.0,310.0,185.0), Q(310.0,206.0,292.0,216.0), Q(274.0,226.0,249.0,226.0), Z , M(32.0,17.0), L(32.0,-750.0), L(0.0,-750.0), L(0.0,87.0), Q(0.0,159.0,62.0,210.5), Q(124.0,262.0,212.0,262.0), Q(242.0,262.0,268.0,256.0), Q(294.0,250.0,318.0,226.0), Q(342.0,202.0,342.0,163.0), Q(342.0,91.0,280.0,39.5), Q(218.0,-12.0,130.0,-12.0), Q(100.0,-12.0,74.0,-6.0), Q(52.0,-1.0,32.0,17.0), Z ];
-
-// pub const CADENZA_42 : &'static [PathSegment] = &[ M(144.0,64.0), L(144.0,-17.0), Q(144.0,-22.0,147.5,-25.5), Q(151.0,-29.0,156.0,-29.0), Q(158.0,-29.0,160.0,-29.0), L(165.0,-27.0), Q(167.0,-26.0,170.0,-26.0), Q(176.0,-26.0,179.5,-30.0), Q(183.0,-34.0,183.0,-39.0), L(183.0,-79.0), Q(183.0,-88.0,175.0,-91.0), L(152.0,-100.0), Q(144.0,-103.0,144.0,-111.0), L(144.0,-208.0), Q(144.0,-213.0,140.5,-217.0), Q(137.0,-221.0,132.0,-221.0), Q(127.0,-221.0,123.0,-217.0), Q(119.0,-213.0,119.0,-208.0), L(119.0,-129.0), Q(119.0,-124.0,115.5,-120.0), Q(112.0,-116.0,107.0,-116.0), Q(105.0,-116.0,103.0,-117.0), L(72.0,-129.0), Q(64.0,-132.0,64.0,-140.0), L(64.0,-237.0), Q(64.0,-242.0,60.0,-246.0), Q(56.0,-250.0,51.0,-250.0), Q(46.0,-250.0,42.5,-246.5), Q(39.0,-243.0,39.0,-237.0), L(39.0,-158.0), Q(39.0,-153.0,35.5,-149.5), Q(32.0,-146.0,27.0,-146.0), Q(25.0,-146.0,23.0,-147.0), L(18.0,-149.0), Q(17.0,-150.0,13.0,-149.0), Q(7.0,-149.0,3.5,-145.5), Q(0.0,-142.0,0.0,-136.0), L(0.0,-96.0), Q(0.0,-87.0,9.0,-83.0), L(31.0,-75.0), Q(39.0,-73.0,39.0,-64.0), L(39.0,17.0), Q(39.0,22.0,35.5,25.5), Q(32.0,29.0,28.0,29.0), Q(26.0,29.0,24.0,29.0), L(18.0,27.0), Q(17.0,26.0,13.0,26.0), Q(7.0,26.0,3.5,30.0), Q(0.0,34.0,0.0,39.0), L(0.0,79.0), Q(0.0,88.0,9.0,91.0), L(32.0,100.0), Q(39.0,103.0,39.0,111.0), L(39.0,208.0), Q(39.0,213.0,42.5,217.0), Q(46.0,221.0,51.0,221.0), Q(56.0,221.0,60.0,217.0), Q(64.0,213.0,64.0,208.0), L(64.0,129.0), Q(64.0,124.0,67.5,120.5), Q(71.0,117.0,76.0,117.0), Q(78.0,117.0,81.0,117.0), L(111.0,129.0), Q(119.0,132.0,119.0,141.0), L(119.0,237.0), Q(119.0,242.0,123.0,246.0), Q(127.0,250.0,132.0,250.0), Q(137.0,250.0,140.5,246.5), Q(144.0,243.0,144.0,237.0), L(144.0,157.0), Q(144.0,152.0,147.5,149.0), Q(151.0,146.0,156.0,146.0), Q(158.0,146.0,160.0,147.0), L(165.0,149.0), Q(166.0,150.0,170.0,149.0), Q(176.0,149.0,179.5,145.5), Q(183.0,142.0,183.0,136.0), L(183.0,96.0), Q(183.0,87.0,175.0,83.0), L(152.0,75.0), Q(144.0,73.0,144.0,64.0), Z , M(119.0,-35.0), L(119.0,46.0), Q(119.0,51.0,115.5,54.5), Q(112.0,58.0,107.0,58.0), Q(105.0,58.0,103.0,57.0), L(72.0,46.0), Q(64.0,43.0,64.0,35.0), L(64.0,-46.0), Q(64.0,-51.0,67.5,-54.5), Q(71.0,-58.0,76.0,-58.0), Q(78.0,-58.0,80.0,-57.0), L(111.0,-46.0), Q(119.0,-43.0,119.0,-35.0), Z ];
-
-// pub const CADENZA_43 : &'static [PathSegment] = &[ M(59.0,267.0), Q(259.0,392.0,259.0,509.0), Q(259.0,588.0,218.0,672.0), Q(216.0,683.0,223.5,691.0), Q(231.0,699.0,241.0,699.0), Q(254.0,699.0,261.0,688.0), Q(301.0,598.0,301.0,509.0), Q(301.0,468.0,286.0,428.0), Q(271.0,388.0,247.5,354.5), Q(224.0,321.0,195.5,288.0), Q(167.0,255.0,138.0,221.5), Q(109.0,188.0,85.5,154.5), Q(62.0,121.0,47.0,81.0), Q(32.0,41.0,32.0,0.0), L(16.0,0.0), L(16.0,320.0), L(32.0,320.0), L(32.0,283.0), Q(32.0,275.0,37.5,270.0), Q(43.0,265.0,50.0,265.0), Q(55.0,265.0,59.0,267.0), Z ];
-
-// pub const CADENZA_44 : &'static [PathSegment] = &[ M(192.0,-470.0), Q(192.0,-373.0,69.0,-250.0), Q(59.0,-240.0,50.0,-240.0), Q(32.0,-240.0,32.0,-265.0), L(32.0,-298.0), L(16.0,-298.0), L(16.0,0.0), L(32.0,0.0), Q(32.0,-49.0,53.0,-99.0), Q(74.0,-149.0,103.5,-192.0), Q(133.0,-235.0,163.0,-278.0), Q(193.0,-321.0,214.0,-371.0), Q(235.0,-421.0,235.0,-470.0), Q(235.0,-520.0,202.0,-564.0), Q(193.0,-576.0,180.0,-576.0), Q(173.0,-576.0,168.5,-570.5), Q(164.0,-565.0,164.0,-557.0), Q(164.0,-551.0,167.0,-547.0), Q(192.0,-506.0,192.0,-470.0), Z ];
-
-// pub const CADENZA_45 : &'static [PathSegment] = &[ M(188.0,9.0), Q(179.0,0.0,173.0,0.0), Q(165.0,0.0,160.0,7.0), L(83.0,107.0), Q(78.0,114.0,68.0,114.0), Q(59.0,114.0,54.0,106.0), L(36.0,79.0), Q(30.0,70.0,20.0,70.0), Q(12.0,70.0,6.0,76.0), Q(0.0,82.0,0.0,90.0), Q(0.0,96.0,3.0,101.0), L(96.0,241.0), Q(102.0,250.0,113.0,250.0), Q(122.0,250.0,128.0,243.0), L(199.0,157.0), Q(205.0,150.0,214.0,150.0), Q(221.0,150.0,226.0,155.0), Q(231.0,160.0,231.0,168.0), L(231.0,291.0), Q(231.0,301.0,237.0,307.0), Q(243.0,313.0,251.0,313.0), Q(259.0,313.0,265.0,307.0), Q(271.0,301.0,271.0,291.0), L(271.0,240.0), Q(271.0,232.0,276.5,227.0), Q(282.0,222.0,289.0,222.0), Q(297.0,222.0,303.0,228.0), L(314.0,241.0), Q(322.0,250.0,329.0,250.0), Q(337.0,250.0,342.0,243.0), L(420.0,142.0), Q(425.0,136.0,433.0,136.0), Q(443.0,136.0,448.0,144.0), L(466.0,171.0), Q(472.0,180.0,482.0,180.0), Q(490.0,180.0,496.0,174.0), Q(502.0,168.0,502.0,160.0), Q(502.0,154.0,499.0,149.0), L(406.0,9.0), Q(400.0,0.0,389.0,0.0), Q(380.0,0.0,374.0,7.0), L(303.0,93.0), Q(297.0,100.0,288.0,100.0), Q(281.0,100.0,276.0,95.0), Q(271.0,90.0,271.0,82.0), L(271.0,-41.0), Q(271.0,-51.0,265.0,-57.0), Q(259.0,-63.0,251.0,-63.0), Q(243.0,-63.0,237.0,-57.0), Q(231.0,-51.0,231.0,-41.0), L(231.0,10.0), Q(231.0,18.0,225.5,23.0), Q(220.0,28.0,213.0,28.0), Q(205.0,29.0,199.0,22.0), L(188.0,9.0), Z ];
-
-// pub const CADENZA_46 : &'static [PathSegment] = &[ M(451.0,0.0), Q(451.0,157.0,382.0,243.0), Q(379.0,246.0,379.0,250.0), Q(379.0,263.0,394.0,263.0), Q(400.0,263.0,405.0,257.0), Q(509.0,127.0,509.0,0.0), Q(509.0,-127.0,405.0,-257.0), Q(400.0,-263.0,394.0,-263.0), Q(379.0,-263.0,379.0,-250.0), Q(379.0,-246.0,382.0,-243.0), Q(451.0,-157.0,451.0,0.0), Z , M(219.0,180.0), L(327.0,202.0), L(330.0,202.0), Q(348.0,202.0,348.0,185.0), L(355.0,-375.0), Q(341.0,-382.0,330.0,-382.0), Q(319.0,-382.0,305.0,-375.0), L(308.0,-198.0), Q(308.0,-191.0,303.0,-185.5), Q(298.0,-180.0,290.0,-180.0), Q(288.0,-180.0,287.0,-180.0), L(179.0,-203.0), L(176.0,-203.0), Q(158.0,-203.0,158.0,-186.0), L(150.0,375.0), Q(164.0,382.0,176.0,382.0), Q(187.0,382.0,201.0,375.0), L(198.0,199.0), Q(198.0,191.0,203.0,185.5), Q(208.0,180.0,216.0,180.0), Q(217.0,180.0,219.0,180.0), Z , M(215.0,-100.0), L(294.0,-82.0), Q(309.0,-79.0,309.0,-64.0), L(312.0,81.0), Q(312.0,89.0,306.0,95.0), Q(300.0,101.0,292.0,99.0), L(212.0,82.0), Q(197.0,79.0,197.0,65.0), L(194.0,-83.0), Q(194.0,-91.0,200.5,-96.5), Q(207.0,-102.0,215.0,-100.0), Z , M(57.0,0.0), Q(57.0,-157.0,126.0,-243.0), Q(129.0,-246.0,129.0,-250.0), Q(129.0,-263.0,115.0,-263.0), Q(107.0,-263.0,103.0,-257.0), Q(0.0,-129.0,0.0,0.0), Q(0.0,129.0,103.0,257.0), Q(107.0,263.0,115.0,263.0), Q(129.0,263.0,129.0,250.0), Q(129.0,246.0,126.0,243.0), Q(57.0,157.0,57.0,0.0), Z ];
-
-// pub const CADENZA_47 : &'static [PathSegment] = &[ M(87.0,46.0), Q(84.0,43.0,84.0,40.0), Q(84.0,37.0,87.0,34.0), L(196.0,-74.0), Q(199.0,-77.0,203.0,-77.0), Q(207.0,-77.0,210.0,-74.0), L(238.0,-46.0), Q(241.0,-43.0,241.0,-40.0), Q(241.0,-37.0,238.0,-34.0), L(129.0,74.0), Q(126.0,77.0,122.0,77.0), Q(118.0,77.0,115.0,74.0), L(87.0,46.0), Z , M(0.0,0.0), Q(0.0,3.0,3.0,6.0), Q(86.0,76.0,155.0,158.0), Q(158.0,161.0,163.0,161.0), Q(167.0,161.0,170.0,158.0), Q(239.0,76.0,322.0,6.0), Q(325.0,3.0,325.0,0.0), Q(325.0,-3.0,322.0,-6.0), Q(239.0,-76.0,170.0,-158.0), Q(167.0,-161.0,163.0,-161.0), Q(158.0,-161.0,155.0,-158.0), Q(86.0,-76.0,3.0,-6.0), Q(0.0,-3.0,0.0,0.0), Z ];
-
-// pub const CADENZA_48 : &'static [PathSegment] = &[ M(715.0,250.0), Q(676.0,250.0,644.5,187.5), Q(613.0,125.0,613.0,70.0), Q(613.0,32.0,643.0,32.0), Q(665.0,32.0,684.5,53.5), Q(704.0,75.0,715.5,105.5), Q(727.0,136.0,733.5,166.0), Q(740.0,196.0,740.0,217.0), Q(740.0,250.0,715.0,250.0), Z , M(467.0,153.0), Q(460.0,136.0,443.0,136.0), Q(429.0,136.0,429.0,148.0), Q(429.0,150.0,431.0,156.0), Q(494.0,299.0,563.0,299.0), Q(603.0,299.0,621.0,268.0), Q(625.0,262.0,633.0,260.5), Q(641.0,259.0,647.0,264.0), Q(690.0,299.0,732.0,299.0), Q(782.0,299.0,810.5,267.0), Q(839.0,235.0,839.0,185.0), Q(839.0,152.0,827.0,118.0), Q(811.0,73.0,766.0,32.5), Q(721.0,-8.0,680.0,-8.0), Q(637.0,-8.0,607.0,9.0), Q(601.0,12.0,596.0,12.0), Q(583.0,12.0,580.0,3.0), L(551.0,-83.0), Q(550.0,-85.0,550.0,-90.0), Q(550.0,-114.0,594.0,-118.0), Q(607.0,-119.0,607.0,-132.0), Q(607.0,-146.0,594.0,-146.0), Q(575.0,-146.0,536.0,-143.5), Q(497.0,-141.0,478.0,-141.0), Q(458.0,-141.0,419.5,-143.5), Q(381.0,-146.0,363.0,-146.0), Q(349.0,-146.0,349.0,-132.0), Q(349.0,-127.0,352.0,-123.5), Q(355.0,-120.0,361.5,-118.5), Q(368.0,-117.0,372.5,-116.5), Q(377.0,-116.0,386.0,-116.0), Q(395.0,-116.0,397.0,-116.0), Q(443.0,-114.0,451.0,-91.0), L(553.0,216.0), Q(555.0,223.0,555.0,229.0), Q(555.0,236.0,551.0,240.5), Q(547.0,245.0,544.0,246.0), Q(541.0,247.0,538.0,247.0), Q(524.0,247.0,501.5,214.5), Q(479.0,182.0,467.0,153.0), Z , M(429.0,66.0), Q(431.0,68.0,450.0,84.0), Q(456.0,90.0,463.0,90.0), Q(474.0,90.0,474.0,80.0), Q(474.0,75.0,467.0,68.0), Q(388.0,-7.0,336.0,-7.0), Q(321.0,-7.0,312.5,2.0), Q(304.0,11.0,304.0,25.0), Q(304.0,32.0,305.0,36.0), L(344.0,201.0), Q(346.0,211.0,346.0,215.0), Q(346.0,222.0,338.0,227.5), Q(330.0,233.0,322.0,233.0), Q(310.0,233.0,299.0,223.5), Q(288.0,214.0,283.0,201.0), L(230.0,18.0), Q(225.0,0.0,208.0,0.0), L(167.0,0.0), Q(159.0,0.0,155.5,5.5), Q(152.0,11.0,154.0,18.0), L(206.0,201.0), Q(209.0,210.0,209.0,217.0), Q(209.0,223.0,201.0,228.0), Q(193.0,233.0,186.0,233.0), Q(174.0,233.0,162.0,223.5), Q(150.0,214.0,145.0,201.0), L(83.0,18.0), Q(78.0,0.0,59.0,0.0), L(18.0,0.0), Q(1.0,0.0,6.0,18.0), L(69.0,201.0), Q(71.0,206.0,71.0,216.0), Q(71.0,233.0,58.0,233.0), Q(23.0,233.0,-8.0,146.0), Q(-14.0,129.0,-31.0,129.0), Q(-43.0,129.0,-43.0,140.0), Q(-43.0,142.0,-41.0,146.0), Q(-20.0,209.0,20.5,254.0), Q(61.0,299.0,108.0,299.0), Q(130.0,299.0,141.0,278.0), Q(146.0,269.0,157.0,269.0), Q(163.0,269.0,168.0,273.0), Q(199.0,299.0,230.0,299.0), Q(242.0,299.0,255.5,292.0), Q(269.0,285.0,275.0,276.0), Q(280.0,267.0,290.0,267.0), Q(297.0,267.0,302.0,272.0), Q(332.0,299.0,364.0,299.0), Q(389.0,299.0,411.0,277.0), Q(433.0,255.0,433.0,230.0), Q(433.0,225.0,431.0,215.0), L(400.0,84.0), Q(396.0,72.0,408.0,65.0), Q(418.0,59.0,429.0,66.0), Z ];
-
-// pub const CADENZA_49 : &'static [PathSegment] = &[ M(32.0,18.0), L(32.0,-750.0), L(0.0,-750.0), L(0.0,91.0), Q(0.0,151.0,66.0,206.5), Q(132.0,262.0,201.0,262.0), Q(229.0,262.0,254.5,255.0), Q(280.0,248.0,303.0,223.0), Q(326.0,198.0,326.0,159.0), Q(326.0,104.0,259.0,46.0), Q(192.0,-12.0,125.0,-12.0), Q(98.0,-12.0,72.0,-5.0), Q(52.0,1.0,32.0,18.0), Z ];
-
-// pub const CADENZA_50 : &'static [PathSegment] = &[ M(211.0,486.0), Q(197.0,472.0,166.0,443.5), Q(135.0,415.0,116.0,395.5), Q(97.0,376.0,75.5,349.0), Q(54.0,322.0,43.0,294.0), Q(32.0,266.0,32.0,237.0), Q(32.0,229.0,38.0,224.0), Q(44.0,219.0,51.0,219.0), Q(56.0,219.0,60.0,221.0), Q(246.0,330.0,246.0,438.0), Q(246.0,461.0,242.0,478.0), Q(239.0,492.0,225.0,492.0), Q(217.0,492.0,211.0,486.0), Z , M(267.0,538.0), Q(288.0,490.0,288.0,438.0), Q(288.0,388.0,261.5,341.0), Q(235.0,294.0,197.5,256.5), Q(160.0,219.0,122.5,181.5), Q(85.0,144.0,58.5,97.0), Q(32.0,50.0,32.0,0.0), L(16.0,0.0), L(16.0,500.0), L(32.0,500.0), L(32.0,460.0), Q(32.0,452.0,37.5,447.0), Q(43.0,442.0,50.0,442.0), Q(54.0,442.0,58.0,444.0), Q(259.0,550.0,259.0,663.0), Q(259.0,674.0,252.0,696.0), Q(245.0,718.0,245.0,730.0), Q(245.0,740.0,252.0,746.0), Q(259.0,752.0,268.0,752.0), Q(301.0,752.0,301.0,663.0), Q(301.0,608.0,268.0,555.0), Q(263.0,547.0,267.0,538.0), Z ];
-
-// pub const CADENZA_51 : &'static [PathSegment] = &[ M(347.0,295.0), Q(376.0,368.0,433.5,421.0), Q(491.0,474.0,556.0,474.0), Q(591.0,474.0,617.0,454.5), Q(643.0,435.0,643.0,405.0), Q(643.0,373.0,629.5,350.5), Q(616.0,328.0,591.0,328.0), Q(573.0,328.0,560.0,340.5), Q(547.0,353.0,547.0,371.0), Q(547.0,380.0,551.5,385.5), Q(556.0,391.0,564.5,397.0), Q(573.0,403.0,576.0,405.0), Q(586.0,415.0,580.0,427.0), Q(576.0,437.0,563.0,437.0), L(556.0,437.0), Q(543.0,437.0,532.0,432.5), Q(521.0,428.0,513.0,416.0), Q(505.0,404.0,500.0,395.0), Q(495.0,386.0,489.5,366.0), Q(484.0,346.0,482.0,337.5), Q(480.0,329.0,475.0,305.0), Q(474.0,302.0,474.0,300.0), Q(474.0,292.0,479.5,287.5), Q(485.0,283.0,492.0,283.0), L(539.0,283.0), Q(558.0,283.0,558.0,264.0), Q(558.0,245.0,539.0,245.0), L(472.0,245.0), Q(458.0,245.0,455.0,232.0), L(409.0,82.0), Q(382.0,-7.0,319.5,-90.0), Q(257.0,-173.0,191.0,-173.0), Q(156.0,-173.0,130.0,-153.5), Q(104.0,-134.0,104.0,-104.0), Q(104.0,-72.0,117.5,-49.5), Q(131.0,-27.0,156.0,-27.0), Q(174.0,-27.0,187.0,-40.0), Q(200.0,-53.0,200.0,-71.0), Q(200.0,-78.0,197.0,-83.5), Q(194.0,-89.0,191.5,-91.0), Q(189.0,-93.0,182.5,-97.5), Q(176.0,-102.0,174.0,-103.0), Q(163.0,-111.0,168.0,-124.0), Q(172.0,-136.0,185.0,-136.0), L(191.0,-136.0), Q(208.0,-136.0,220.5,-127.5), Q(233.0,-119.0,241.5,-99.5), Q(250.0,-80.0,254.0,-67.5), Q(258.0,-55.0,264.0,-28.0), L(326.0,223.0), Q(327.0,225.0,327.0,228.0), Q(327.0,235.0,322.0,240.0), Q(317.0,245.0,309.0,245.0), L(272.0,245.0), Q(253.0,245.0,253.0,264.0), Q(253.0,283.0,272.0,283.0), L(330.0,283.0), Q(342.0,283.0,347.0,295.0), Z , M(180.0,216.0), Q(180.0,221.0,184.5,230.5), Q(189.0,240.0,189.0,245.0), Q(189.0,268.0,171.0,268.0), Q(151.0,268.0,134.5,255.0), Q(118.0,242.0,118.0,224.0), Q(118.0,206.0,149.0,187.0), Q(181.0,169.0,199.0,151.0), Q(217.0,133.0,217.0,107.0), Q(217.0,61.0,184.0,30.5), Q(151.0,0.0,103.0,0.0), Q(70.0,0.0,46.0,19.5), Q(22.0,39.0,22.0,68.0), Q(22.0,85.0,30.5,97.5), Q(39.0,110.0,53.0,110.0), Q(66.0,110.0,75.5,101.0), Q(85.0,92.0,85.0,78.0), Q(85.0,72.0,74.5,64.0), Q(64.0,56.0,64.0,49.0), Q(64.0,27.0,99.0,27.0), Q(124.0,27.0,140.5,40.0), Q(157.0,53.0,157.0,75.0), Q(157.0,91.0,141.5,105.0), Q(126.0,119.0,107.0,127.5), Q(88.0,136.0,72.5,153.0), Q(57.0,170.0,57.0,192.0), Q(57.0,232.0,89.0,262.0), Q(121.0,292.0,162.0,292.0), Q(201.0,292.0,221.0,273.0), Q(241.0,254.0,241.0,230.0), Q(241.0,215.0,233.0,204.5), Q(225.0,194.0,211.0,194.0), Q(198.0,194.0,189.0,200.5), Q(180.0,207.0,180.0,216.0), Z ];
-
-// pub const CADENZA_52 : &'static [PathSegment] = &[ M(483.0,52.0), Q(469.0,59.0,460.0,46.0), Q(446.0,26.0,418.0,26.0), Q(397.0,26.0,380.5,40.0), Q(364.0,54.0,364.0,79.0), Q(364.0,102.0,384.5,117.0), Q(405.0,132.0,432.0,132.0), Q(481.0,132.0,513.5,93.0), Q(546.0,54.0,546.0,0.0), Q(546.0,-55.0,507.0,-93.5), Q(468.0,-132.0,412.0,-132.0), Q(368.0,-132.0,330.5,-108.5), Q(293.0,-85.0,248.0,-43.0), Q(244.0,-39.0,225.0,-21.0), Q(206.0,-3.0,200.0,2.5), Q(194.0,8.0,177.0,21.5), Q(160.0,35.0,149.0,39.5), Q(138.0,44.0,122.0,49.0), Q(106.0,54.0,91.0,54.0), Q(66.0,54.0,48.0,38.5), Q(30.0,23.0,30.0,0.0), Q(30.0,-36.0,63.0,-52.0), Q(76.0,-59.0,86.0,-46.0), Q(100.0,-26.0,128.0,-26.0), Q(149.0,-26.0,165.5,-40.0), Q(182.0,-54.0,182.0,-79.0), Q(182.0,-102.0,161.5,-117.0), Q(141.0,-132.0,114.0,-132.0), Q(65.0,-132.0,32.5,-93.0), Q(0.0,-54.0,0.0,0.0), Q(0.0,55.0,39.0,93.5), Q(78.0,132.0,134.0,132.0), Q(178.0,132.0,215.5,108.5), Q(253.0,85.0,298.0,43.0), Q(302.0,39.0,321.0,21.0), Q(340.0,3.0,346.0,-2.5), Q(352.0,-8.0,369.0,-21.5), Q(386.0,-35.0,397.0,-39.5), Q(408.0,-44.0,424.0,-49.0), Q(440.0,-54.0,455.0,-54.0), Q(480.0,-54.0,498.0,-38.5), Q(516.0,-23.0,516.0,0.0), Q(516.0,36.0,483.0,52.0), Z ];
-
-// pub const CADENZA_53 : &'static [PathSegment] = &[ M(258.0,53.0), Q(258.0,84.0,279.5,105.0), Q(301.0,126.0,332.0,126.0), Q(363.0,126.0,384.5,105.0), Q(406.0,84.0,406.0,53.0), Q(406.0,22.0,384.5,0.5), Q(363.0,-21.0,332.0,-21.0), Q(301.0,-21.0,279.5,0.5), Q(258.0,22.0,258.0,53.0), Z , M(332.0,363.0), Q(390.0,363.0,450.0,332.0), Q(510.0,301.0,557.0,251.5), Q(604.0,202.0,634.0,134.0), Q(664.0,66.0,664.0,-3.0), Q(664.0,-9.0,658.0,-13.5), Q(652.0,-18.0,645.0,-18.0), Q(629.0,-18.0,626.0,0.0), Q(606.0,112.0,524.0,187.0), Q(442.0,262.0,332.0,262.0), Q(222.0,262.0,138.5,187.0), Q(55.0,112.0,38.0,0.0), Q(35.0,-18.0,19.0,-18.0), Q(12.0,-18.0,6.0,-13.5), Q(0.0,-9.0,0.0,-3.0), Q(0.0,66.0,30.0,134.0), Q(60.0,202.0,107.0,251.5), Q(154.0,301.0,214.0,332.0), Q(274.0,363.0,332.0,363.0), Z ];
-
-// pub const CADENZA_54 : &'static [PathSegment] = &[ M(248.0,565.0), Q(225.0,638.0,216.0,695.0), Q(208.0,743.0,208.0,807.0), Q(208.0,829.0,209.0,851.0), Q(213.0,946.0,248.0,1025.5), Q(283.0,1105.0,353.0,1147.0), Q(358.0,1150.0,366.0,1150.0), Q(373.0,1150.0,378.0,1145.0), Q(434.0,1089.0,464.0,1002.0), Q(494.0,915.0,494.0,826.0), Q(494.0,723.0,450.0,624.0), Q(408.0,525.0,332.0,449.0), Q(326.0,443.0,328.0,433.0), L(368.0,273.0), Q(371.0,257.0,388.0,259.0), Q(404.0,261.0,419.0,261.0), Q(477.0,261.0,524.0,238.0), Q(571.0,215.0,600.0,178.0), Q(629.0,141.0,644.5,96.5), Q(660.0,52.0,660.0,5.0), Q(660.0,-81.0,612.0,-150.5), Q(564.0,-220.0,497.0,-242.0), Q(483.0,-247.0,486.0,-263.0), Q(511.0,-381.0,512.0,-462.0), Q(512.0,-493.0,508.0,-523.0), Q(500.0,-592.0,449.0,-644.0), Q(415.0,-679.0,371.0,-690.0), Q(373.0,-691.0,375.0,-693.0), Q(397.0,-712.0,397.0,-741.0), Q(397.0,-775.0,369.0,-797.0), Q(359.0,-804.0,337.0,-815.0), Q(355.0,-827.0,363.0,-837.0), Q(377.0,-857.0,378.0,-881.0), Q(378.0,-923.0,346.5,-949.5), Q(315.0,-976.0,266.0,-976.0), Q(226.0,-976.0,202.0,-958.0), Q(178.0,-940.0,178.0,-909.0), Q(178.0,-852.0,250.0,-822.0), Q(236.0,-811.0,229.0,-798.0), Q(222.0,-784.0,222.0,-765.0), Q(222.0,-727.0,250.0,-701.0), Q(257.0,-695.0,265.0,-689.0), Q(214.0,-677.0,175.0,-643.0), Q(114.0,-590.0,114.0,-504.0), Q(114.0,-460.0,145.0,-428.0), Q(176.0,-396.0,218.0,-396.0), Q(261.0,-396.0,290.5,-426.5), Q(320.0,-457.0,320.0,-499.0), Q(320.0,-535.0,296.0,-562.0), Q(272.0,-590.0,238.0,-593.0), Q(226.0,-594.0,222.0,-605.0), Q(218.0,-616.0,230.0,-625.0), Q(266.0,-653.0,318.0,-653.0), Q(375.0,-653.0,416.5,-612.0), Q(458.0,-571.0,465.0,-519.0), Q(470.0,-483.0,470.0,-447.0), Q(470.0,-369.0,446.0,-274.0), Q(442.0,-257.0,424.0,-259.0), Q(384.0,-264.0,350.0,-264.0), Q(202.0,-264.0,101.0,-171.0), Q(0.0,-78.0,0.0,82.0), Q(0.0,131.0,11.5,178.5), Q(23.0,226.0,37.5,260.5), Q(52.0,295.0,82.5,340.5), Q(113.0,386.0,131.5,410.0), Q(150.0,434.0,190.0,481.5), Q(230.0,529.0,245.0,548.0), Q(251.0,555.0,248.0,565.0), Z , M(404.0,108.0), L(469.0,-187.0), Q(472.0,-200.0,487.0,-200.0), Q(491.0,-200.0,494.0,-199.0), Q(535.0,-182.0,562.0,-133.0), Q(589.0,-84.0,589.0,-36.0), Q(589.0,34.0,548.0,82.0), Q(506.0,131.0,422.0,131.0), Q(415.0,131.0,409.5,125.5), Q(404.0,120.0,404.0,113.0), Q(403.0,110.0,404.0,108.0), Z , M(266.0,386.0), Q(250.0,368.0,218.5,334.5), Q(187.0,301.0,171.5,283.5), Q(156.0,266.0,133.5,234.0), Q(111.0,202.0,100.0,175.0), Q(89.0,148.0,80.5,109.0), Q(72.0,70.0,72.0,26.0), Q(72.0,-35.0,109.5,-92.0), Q(147.0,-149.0,212.5,-185.0), Q(278.0,-221.0,353.0,-221.0), Q(380.0,-221.0,413.0,-218.0), Q(421.0,-217.0,426.0,-210.5), Q(431.0,-204.0,429.0,-196.0), L(364.0,103.0), Q(361.0,117.0,345.0,117.0), Q(342.0,117.0,338.0,116.0), Q(292.0,99.0,273.0,68.0), Q(254.0,37.0,254.0,-10.0), Q(254.0,-65.0,314.0,-105.0), Q(321.0,-110.0,321.0,-120.0), Q(321.0,-128.0,314.5,-135.0), Q(308.0,-142.0,299.0,-142.0), Q(294.0,-142.0,290.0,-140.0), Q(233.0,-110.0,205.5,-58.5), Q(178.0,-7.0,178.0,50.0), Q(178.0,105.0,217.5,163.5), Q(257.0,222.0,316.0,244.0), Q(331.0,250.0,328.0,265.0), L(298.0,378.0), Q(296.0,389.0,284.0,391.0), Q(272.0,393.0,266.0,386.0), Z , M(434.0,968.0), Q(426.0,994.0,407.0,994.0), Q(401.0,994.0,396.0,991.0), Q(332.0,953.0,294.5,884.0), Q(257.0,815.0,257.0,738.0), Q(257.0,688.0,278.0,615.0), Q(281.0,604.0,293.0,602.0), Q(303.0,601.0,310.0,609.0), Q(313.0,613.0,338.5,644.5), Q(364.0,676.0,372.0,686.5), Q(380.0,697.0,399.0,726.5), Q(418.0,756.0,425.5,775.5), Q(433.0,795.0,440.0,824.0), Q(447.0,853.0,447.0,882.0), Q(447.0,924.0,434.0,968.0), Z , M(271.0,-836.0), Q(270.0,-837.0,268.0,-838.0), Q(266.0,-839.0,265.0,-838.0), L(264.0,-839.0), L(264.0,-839.0), Q(242.0,-849.0,229.0,-866.0), Q(216.0,-883.0,216.0,-903.0), Q(216.0,-926.0,231.0,-939.5), Q(246.0,-953.0,271.0,-953.0), Q(299.0,-953.0,318.0,-937.5), Q(337.0,-922.0,337.0,-900.0), Q(337.0,-873.0,306.0,-854.0), Q(296.0,-848.0,271.0,-836.0), Z , M(314.0,-802.0), Q(317.0,-801.0,320.0,-800.0), Q(338.0,-792.0,349.5,-775.0), Q(361.0,-758.0,361.0,-739.0), Q(361.0,-721.0,348.0,-709.0), Q(335.0,-697.0,315.0,-697.0), Q(292.0,-697.0,276.0,-712.0), Q(260.0,-726.0,260.0,-745.0), Q(260.0,-761.0,272.0,-774.0), Q(284.0,-787.0,314.0,-802.0), Z ];
-
-// pub const CADENZA_55 : &'static [PathSegment] = &[ M(633.0,-160.0), Q(633.0,-168.0,627.0,-174.0), Q(621.0,-180.0,613.0,-180.0), Q(605.0,-180.0,599.0,-174.0), Q(593.0,-168.0,593.0,-160.0), L(593.0,160.0), Q(593.0,168.0,599.0,174.0), Q(605.0,180.0,613.0,180.0), Q(621.0,180.0,627.0,174.0), Q(633.0,168.0,633.0,160.0), L(633.0,-160.0), Z , M(40.0,-160.0), Q(40.0,-168.0,34.0,-174.0), Q(28.0,-180.0,20.0,-180.0), Q(12.0,-180.0,6.0,-174.0), Q(0.0,-168.0,0.0,-160.0), L(0.0,160.0), Q(0.0,168.0,6.0,174.0), Q(12.0,180.0,20.0,180.0), Q(28.0,180.0,34.0,174.0), Q(40.0,168.0,40.0,160.0), L(40.0,-160.0), Z , M(294.0,110.0), Q(206.0,110.0,206.0,79.0), Q(206.0,44.0,259.5,-33.0), Q(313.0,-110.0,349.0,-110.0), Q(435.0,-110.0,435.0,-79.0), Q(435.0,-41.0,379.5,34.5), Q(324.0,110.0,294.0,110.0), Z , M(492.0,-91.0), Q(445.0,-137.0,353.0,-137.0), Q(201.0,-137.0,142.0,-75.0), Q(137.0,-70.0,129.5,-70.0), Q(122.0,-70.0,116.5,-75.0), Q(111.0,-80.0,111.0,-88.0), L(111.0,-160.0), Q(111.0,-168.0,105.0,-174.0), Q(99.0,-180.0,91.0,-180.0), Q(83.0,-180.0,77.0,-174.0), Q(71.0,-168.0,71.0,-160.0), L(71.0,160.0), Q(71.0,168.0,77.0,174.0), Q(83.0,180.0,91.0,180.0), Q(99.0,180.0,105.0,174.0), Q(111.0,168.0,111.0,160.0), L(111.0,99.0), Q(111.0,91.0,116.5,86.0), Q(122.0,81.0,130.0,81.0), Q(137.0,81.0,142.0,86.0), Q(195.0,137.0,294.0,137.0), Q(355.0,137.0,407.5,121.0), Q(460.0,105.0,491.0,73.0), Q(496.0,68.0,503.5,68.0), Q(511.0,68.0,516.5,73.0), Q(522.0,78.0,522.0,86.0), L(522.0,160.0), Q(522.0,168.0,528.0,174.0), Q(534.0,180.0,542.0,180.0), Q(550.0,180.0,556.0,174.0), Q(562.0,168.0,562.0,160.0), L(562.0,-160.0), Q(562.0,-168.0,556.0,-174.0), Q(550.0,-180.0,542.0,-180.0), Q(534.0,-180.0,528.0,-174.0), Q(522.0,-168.0,522.0,-160.0), L(522.0,-105.0), Q(522.0,-97.0,516.5,-92.0), Q(511.0,-87.0,503.0,-87.0), Q(497.0,-87.0,492.0,-91.0), Z ];
-
-// pub const CADENZA_56 : &'static [PathSegment] = &[ M(211.0,-264.0), Q(197.0,-278.0,166.0,-306.5), Q(135.0,-335.0,116.0,-354.5), Q(97.0,-374.0,75.5,-401.0), Q(54.0,-428.0,43.0,-456.0), Q(33.0,-482.0,32.0,-510.0), L(32.0,-514.0), Q(32.0,-521.0,38.0,-526.0), Q(44.0,-531.0,51.0,-531.0), Q(56.0,-531.0,60.0,-529.0), Q(246.0,-420.0,246.0,-312.0), Q(246.0,-289.0,242.0,-272.0), Q(239.0,-258.0,225.0,-258.0), Q(217.0,-258.0,211.0,-264.0), Z , M(267.0,-212.0), Q(288.0,-260.0,288.0,-312.0), Q(288.0,-362.0,261.5,-409.0), Q(235.0,-456.0,197.5,-493.5), Q(160.0,-531.0,122.5,-568.5), Q(85.0,-606.0,58.5,-653.0), Q(32.0,-700.0,32.0,-750.0), L(16.0,-750.0), L(0.0,-750.0), L(0.0,91.0), Q(0.0,151.0,66.0,206.5), Q(132.0,262.0,201.0,262.0), Q(229.0,262.0,254.5,255.0), Q(280.0,248.0,303.0,223.0), Q(326.0,198.0,326.0,159.0), Q(326.0,104.0,259.0,46.0), Q(192.0,-12.0,125.0,-12.0), Q(98.0,-12.0,72.0,-5.0), Q(51.0,1.0,32.0,18.0), L(32.0,-250.0), L(32.0,-290.0), Q(32.0,-298.0,37.5,-303.0), Q(43.0,-308.0,50.0,-308.0), Q(54.0,-308.0,58.0,-306.0), Q(259.0,-200.0,259.0,-87.0), Q(259.0,-76.0,252.0,-54.0), Q(245.0,-32.0,245.0,-20.0), Q(245.0,-10.0,252.0,-4.0), Q(259.0,2.0,268.0,2.0), Q(301.0,2.0,301.0,-87.0), Q(301.0,-142.0,268.0,-195.0), Q(263.0,-203.0,267.0,-212.0), Z ];
-
-// pub const CADENZA_57 : &'static [PathSegment] = &[ M(349.0,12.0), Q(321.0,12.0,313.0,6.0), Q(305.0,0.0,303.0,0.0), Q(284.0,0.0,284.0,25.0), Q(284.0,31.0,288.0,35.0), L(435.0,174.0), Q(444.0,183.0,440.0,195.0), Q(435.0,208.0,422.0,206.0), Q(394.0,204.0,369.0,200.0), Q(364.0,199.0,349.5,196.0), Q(335.0,193.0,329.0,193.0), Q(312.0,193.0,312.0,208.0), Q(312.0,232.0,335.0,255.0), Q(358.0,278.0,383.0,278.0), Q(399.0,278.0,430.0,267.0), Q(461.0,256.0,477.0,256.0), Q(481.0,256.0,488.5,255.5), Q(496.0,255.0,500.0,255.0), Q(521.0,255.0,530.0,264.0), Q(534.0,268.0,540.0,268.0), Q(545.0,268.0,549.5,258.5), Q(554.0,249.0,554.0,243.0), Q(554.0,238.0,549.0,233.0), L(403.0,95.0), Q(395.0,88.0,398.0,76.0), Q(401.0,66.0,413.0,64.0), Q(423.0,63.0,441.0,60.5), Q(459.0,58.0,474.0,56.5), Q(489.0,55.0,502.0,55.0), Q(517.0,55.0,527.0,66.0), Q(537.0,77.0,537.0,93.0), Q(537.0,96.0,535.0,103.0), Q(533.0,110.0,533.0,114.0), Q(533.0,127.0,548.0,127.0), Q(570.0,127.0,570.0,66.0), Q(570.0,27.0,542.0,-0.5), Q(514.0,-28.0,474.0,-28.0), Q(444.0,-28.0,408.5,-8.0), Q(373.0,12.0,349.0,12.0), Z , M(106.0,295.0), Q(135.0,368.0,192.5,421.0), Q(250.0,474.0,315.0,474.0), Q(350.0,474.0,376.0,454.5), Q(402.0,435.0,402.0,405.0), Q(402.0,373.0,388.5,350.5), Q(375.0,328.0,350.0,328.0), Q(332.0,328.0,319.0,340.5), Q(306.0,353.0,306.0,371.0), Q(306.0,380.0,310.5,385.5), Q(315.0,391.0,323.5,397.0), Q(332.0,403.0,335.0,405.0), Q(345.0,415.0,339.0,427.0), Q(335.0,437.0,322.0,437.0), L(315.0,437.0), Q(302.0,437.0,291.0,432.5), Q(280.0,428.0,272.0,416.0), Q(264.0,404.0,259.0,395.0), Q(254.0,386.0,248.5,366.0), Q(243.0,346.0,241.0,337.5), Q(239.0,329.0,234.0,305.0), Q(233.0,302.0,233.0,300.0), Q(233.0,292.0,238.5,287.5), Q(244.0,283.0,251.0,283.0), L(298.0,283.0), Q(317.0,283.0,317.0,264.0), Q(317.0,245.0,298.0,245.0), L(231.0,245.0), Q(217.0,245.0,214.0,232.0), L(168.0,82.0), Q(141.0,-7.0,78.5,-90.0), Q(16.0,-173.0,-50.0,-173.0), Q(-85.0,-173.0,-111.0,-153.5), Q(-137.0,-134.0,-137.0,-104.0), Q(-137.0,-72.0,-123.5,-49.5), Q(-110.0,-27.0,-85.0,-27.0), Q(-67.0,-27.0,-54.0,-40.0), Q(-41.0,-53.0,-41.0,-71.0), Q(-41.0,-78.0,-44.0,-83.5), Q(-47.0,-89.0,-49.5,-91.0), Q(-52.0,-93.0,-58.5,-97.5), Q(-65.0,-102.0,-67.0,-103.0), Q(-78.0,-111.0,-73.0,-124.0), Q(-69.0,-136.0,-56.0,-136.0), L(-50.0,-136.0), Q(-33.0,-136.0,-20.5,-127.5), Q(-8.0,-119.0,0.5,-99.5), Q(9.0,-80.0,13.0,-67.5), Q(17.0,-55.0,23.0,-28.0), L(85.0,223.0), Q(86.0,225.0,86.0,228.0), Q(86.0,235.0,81.0,240.0), Q(76.0,245.0,68.0,245.0), L(31.0,245.0), Q(12.0,245.0,12.0,264.0), Q(12.0,283.0,31.0,283.0), L(89.0,283.0), Q(101.0,283.0,106.0,295.0), Z ];
-
-// pub const CADENZA_58 : &'static [PathSegment] = &[ M(400.0,0.0), Q(400.0,118.0,349.0,182.0), Q(346.0,184.0,346.0,188.0), Q(346.0,198.0,358.0,197.0), Q(363.0,197.0,366.0,193.0), Q(444.0,95.0,444.0,0.0), Q(444.0,-95.0,366.0,-193.0), Q(363.0,-197.0,358.0,-197.0), Q(346.0,-198.0,346.0,-188.0), Q(346.0,-185.0,349.0,-182.0), Q(400.0,-118.0,400.0,0.0), Z , M(281.0,72.0), L(281.0,-20.0), Q(281.0,-26.0,284.5,-29.5), Q(288.0,-33.0,294.0,-33.0), Q(296.0,-33.0,298.0,-32.0), L(304.0,-30.0), Q(306.0,-29.0,310.0,-29.0), Q(316.0,-29.0,320.5,-33.5), Q(325.0,-38.0,325.0,-44.0), L(325.0,-89.0), Q(325.0,-99.0,315.0,-103.0), L(290.0,-112.0), Q(281.0,-116.0,281.0,-124.0), L(281.0,-234.0), Q(281.0,-240.0,276.5,-244.0), Q(272.0,-248.0,267.0,-248.0), Q(261.0,-248.0,257.0,-244.0), Q(253.0,-240.0,253.0,-234.0), L(253.0,-145.0), Q(253.0,-139.0,249.0,-135.0), Q(245.0,-131.0,239.0,-131.0), Q(236.0,-131.0,234.0,-132.0), L(200.0,-146.0), Q(191.0,-150.0,191.0,-158.0), L(191.0,-267.0), Q(191.0,-273.0,186.5,-277.0), Q(182.0,-281.0,176.5,-281.0), Q(171.0,-281.0,167.0,-277.0), Q(163.0,-273.0,163.0,-267.0), L(163.0,-178.0), Q(163.0,-172.0,159.0,-168.0), Q(155.0,-164.0,149.0,-164.0), Q(147.0,-164.0,145.0,-165.0), L(139.0,-167.0), Q(138.0,-168.0,134.0,-168.0), Q(128.0,-168.0,123.0,-163.5), Q(118.0,-159.0,118.0,-153.0), L(118.0,-108.0), Q(118.0,-98.0,128.0,-94.0), L(154.0,-85.0), Q(163.0,-83.0,163.0,-72.0), L(163.0,20.0), Q(163.0,26.0,159.0,29.5), Q(155.0,33.0,150.0,33.0), Q(148.0,33.0,146.0,32.0), L(139.0,30.0), Q(138.0,29.0,134.0,29.0), Q(128.0,29.0,123.0,33.5), Q(118.0,38.0,118.0,44.0), L(118.0,89.0), Q(118.0,99.0,128.0,103.0), L(154.0,112.0), Q(162.0,116.0,163.0,124.0), L(163.0,234.0), Q(163.0,240.0,167.0,244.0), Q(171.0,248.0,176.5,248.0), Q(182.0,248.0,186.0,244.0), Q(190.0,240.0,191.0,234.0), L(191.0,145.0), Q(191.0,139.0,195.0,135.0), Q(199.0,131.0,204.0,131.0), Q(206.0,131.0,209.0,132.0), L(244.0,146.0), Q(253.0,150.0,253.0,158.0), L(253.0,267.0), Q(253.0,273.0,257.0,277.0), Q(261.0,281.0,267.0,281.0), Q(272.0,281.0,276.0,277.0), Q(280.0,273.0,281.0,267.0), L(281.0,177.0), Q(281.0,172.0,284.5,168.0), Q(288.0,164.0,293.0,164.0), Q(296.0,164.0,298.0,165.0), L(304.0,167.0), Q(306.0,168.0,310.0,168.0), Q(316.0,168.0,320.5,163.5), Q(325.0,159.0,325.0,153.0), L(325.0,108.0), Q(325.0,98.0,315.0,94.0), L(290.0,85.0), Q(282.0,82.0,281.0,72.0), Z , M(253.0,-39.0), L(253.0,52.0), Q(253.0,58.0,249.0,61.5), Q(245.0,65.0,239.0,65.0), Q(237.0,64.0,235.0,64.0), L(200.0,52.0), Q(191.0,48.0,191.0,39.0), L(191.0,-52.0), Q(191.0,-58.0,194.5,-61.5), Q(198.0,-65.0,204.0,-65.0), Q(206.0,-64.0,208.0,-64.0), L(244.0,-52.0), Q(253.0,-48.0,253.0,-39.0), Z , M(43.0,0.0), Q(42.0,-118.0,94.0,-182.0), Q(96.0,-184.0,97.0,-188.0), Q(97.0,-198.0,86.0,-197.0), Q(80.0,-197.0,77.0,-193.0), Q(0.0,-97.0,0.0,0.0), Q(0.0,97.0,77.0,193.0), Q(80.0,197.0,86.0,197.0), Q(96.0,198.0,97.0,188.0), Q(96.0,185.0,94.0,182.0), Q(42.0,118.0,43.0,0.0), Z ];
-
-// pub const CADENZA_59 : &'static [PathSegment] = &[ M(352.0,6.0), Q(352.0,130.0,300.0,182.0), Q(298.0,184.0,298.0,188.0), Q(298.0,198.0,309.0,197.0), Q(313.0,197.0,317.0,193.0), Q(395.0,95.0,395.5,-2.0), Q(396.0,-99.0,317.0,-193.0), Q(313.0,-197.0,309.0,-197.0), Q(297.0,-198.0,298.0,-188.0), Q(298.0,-185.0,300.0,-182.0), Q(352.0,-118.0,352.0,6.0), Z , M(232.0,0.0), Q(232.0,-13.0,252.0,-20.0), Q(270.0,-28.0,292.0,-27.0), Q(299.0,-27.0,300.0,-35.0), L(303.0,-94.0), Q(303.0,-102.0,296.0,-101.0), L(296.0,-101.0), L(237.0,-98.0), Q(230.0,-97.0,230.0,-91.0), Q(230.0,-70.0,222.0,-50.0), Q(214.0,-30.0,201.5,-30.0), Q(189.0,-30.0,181.5,-49.5), Q(174.0,-69.0,174.0,-91.0), Q(174.0,-99.0,167.0,-98.0), L(108.0,-101.0), L(107.0,-101.0), Q(98.0,-101.0,100.0,-94.0), L(104.0,-35.0), Q(104.0,-27.0,111.0,-27.0), Q(132.0,-27.0,152.0,-20.0), Q(172.0,-13.0,172.0,0.0), Q(172.0,13.0,152.5,20.5), Q(133.0,28.0,111.0,28.0), Q(104.0,28.0,104.0,35.0), L(100.0,94.0), Q(100.0,102.0,107.0,102.0), L(108.0,102.0), L(167.0,98.0), Q(174.0,98.0,174.0,91.0), Q(174.0,70.0,181.5,50.0), Q(189.0,30.0,201.5,30.0), Q(214.0,30.0,222.0,50.0), Q(230.0,70.0,230.0,91.0), Q(230.0,98.0,237.0,98.0), L(296.0,102.0), L(296.0,102.0), Q(304.0,102.0,303.0,94.0), L(300.0,35.0), Q(300.0,27.0,292.0,28.0), Q(271.0,28.0,251.5,20.5), Q(232.0,13.0,232.0,0.0), Z , M(43.0,-6.0), Q(42.0,-130.0,94.0,-182.0), Q(96.0,-184.0,97.0,-188.0), Q(97.0,-198.0,86.0,-197.0), Q(81.0,-197.0,77.0,-193.0), Q(0.0,-97.0,0.0,2.0), Q(0.0,100.0,77.0,193.0), Q(81.0,197.0,86.0,197.0), Q(97.0,198.0,97.0,188.0), Q(96.0,185.0,94.0,182.0), Q(42.0,118.0,43.0,-6.0), Z ];
-
-// pub const CADENZA_60 : &'static [PathSegment] = &[ M(75.0,120.0), L(24.0,7.0), Q(21.0,0.0,13.0,0.0), Q(0.0,0.0,0.0,12.0), Q(0.0,16.0,1.0,18.0), L(114.0,268.0), Q(117.0,275.0,125.0,275.0), Q(133.0,275.0,136.0,268.0), L(249.0,18.0), Q(250.0,16.0,250.0,12.0), Q(250.0,0.0,237.0,0.0), L(171.0,0.0), Q(162.0,0.0,159.0,7.0), L(108.0,120.0), Q(103.0,131.0,91.0,131.0), Q(80.0,131.0,75.0,120.0), Z ];
-
-// pub const CADENZA_61 : &'static [PathSegment] = &[ M(0.0,-12.0), L(0.0,13.0), L(423.0,13.0), L(423.0,-12.0), L(0.0,-12.0), Z ];
-
-// pub const CADENZA_62 : &'static [PathSegment] = &[ M(1170.0,246.0), Q(1132.0,246.0,1107.0,203.0), Q(1079.0,155.0,1079.0,114.0), Q(1079.0,111.0,1079.0,108.0), Q(1080.0,86.0,1090.0,74.0), Q(1100.0,62.0,1120.0,62.0), Q(1157.0,62.0,1183.0,106.0), Q(1209.0,149.0,1209.0,196.0), Q(1209.0,218.0,1199.0,232.0), Q(1189.0,246.0,1170.0,246.0), Z , M(1162.0,402.0), L(1111.0,232.0), Q(1131.0,255.0,1148.0,265.0), Q(1165.0,275.0,1186.0,275.0), Q(1218.0,274.0,1237.0,252.0), Q(1256.0,230.0,1256.0,194.0), Q(1256.0,127.0,1208.0,81.0), Q(1170.0,44.0,1117.0,44.0), Q(1080.0,44.0,1060.0,62.0), Q(1040.0,80.0,1039.0,112.0), Q(1039.0,125.0,1046.0,150.0), L(1103.0,342.0), Q(1108.0,358.0,1107.0,362.0), Q(1107.0,378.0,1080.0,378.0), L(1068.0,378.0), L(1070.0,395.0), L(1162.0,402.0), Z , M(729.0,275.0), L(716.0,211.0), Q(740.0,246.0,759.0,260.0), Q(778.0,274.0,801.0,275.0), Q(820.0,275.0,833.5,262.5), Q(847.0,250.0,847.0,233.0), Q(847.0,222.0,844.0,208.0), Q(885.0,275.0,936.0,275.0), Q(956.0,275.0,968.0,263.0), Q(980.0,251.0,980.0,232.0), Q(980.0,214.0,974.0,196.0), L(944.0,104.0), Q(943.0,102.0,939.0,80.0), Q(938.0,76.0,942.0,72.0), Q(946.0,68.0,949.0,68.0), Q(966.0,68.0,1003.0,124.0), L(1018.0,114.0), Q(995.0,80.0,982.0,67.0), Q(958.0,44.0,935.0,44.0), Q(918.0,44.0,908.0,54.0), Q(898.0,64.0,898.0,77.0), Q(899.0,90.0,903.0,104.0), Q(906.0,115.0,911.0,131.0), L(933.0,198.0), Q(940.0,219.0,940.0,229.0), Q(940.0,246.0,921.0,246.0), Q(908.0,246.0,891.0,235.0), Q(874.0,224.0,862.0,207.0), Q(840.0,176.0,823.0,120.0), L(802.0,51.0), L(761.0,51.0), L(799.0,180.0), Q(808.0,211.0,808.0,228.0), Q(808.0,246.0,788.0,246.0), Q(760.0,246.0,732.0,209.0), Q(704.0,172.0,688.0,118.0), L(668.0,51.0), L(627.0,51.0), L(676.0,215.0), Q(680.0,228.0,679.0,235.0), Q(679.0,251.0,653.0,251.0), L(641.0,251.0), L(643.0,268.0), L(729.0,275.0), Z , M(334.0,91.0), L(391.0,293.0), L(392.0,297.0), L(396.0,296.0), Q(468.0,278.0,525.0,278.0), Q(540.0,278.0,596.0,294.0), Q(603.0,296.0,604.0,296.0), Q(613.0,296.0,613.0,285.0), Q(613.0,271.0,594.0,250.0), Q(561.0,214.0,496.0,214.0), Q(494.0,214.0,492.0,214.0), Q(459.0,214.0,411.0,223.0), L(379.0,115.0), Q(419.0,133.0,453.0,133.0), Q(507.0,133.0,541.0,101.0), Q(575.0,69.0,575.0,20.0), Q(575.0,-58.0,520.0,-113.0), Q(465.0,-168.0,385.0,-168.0), Q(332.0,-168.0,299.0,-140.0), Q(265.0,-111.0,265.0,-67.0), Q(265.0,-42.0,280.0,-25.5), Q(295.0,-9.0,318.0,-9.0), Q(336.0,-9.0,348.0,-20.0), Q(360.0,-31.0,360.0,-49.0), Q(360.0,-83.0,341.0,-85.0), Q(321.0,-87.0,321.0,-95.0), L(321.0,-96.0), L(319.0,-101.0), Q(319.0,-113.0,337.0,-123.0), Q(357.0,-134.0,383.0,-134.0), Q(430.0,-134.0,464.0,-92.0), Q(498.0,-49.0,498.0,12.0), Q(498.0,50.0,479.5,70.0), Q(461.0,90.0,428.0,90.0), Q(394.0,90.0,367.0,69.0), Q(363.0,32.0,335.0,32.0), Q(323.0,32.0,315.5,39.0), Q(308.0,46.0,308.0,57.0), Q(308.0,70.0,314.0,75.0), Q(321.0,81.0,334.0,91.0), Z , M(267.0,294.0), L(168.0,-77.0), Q(162.0,-99.0,162.0,-107.0), Q(162.0,-114.0,182.0,-117.0), Q(205.0,-120.0,208.0,-120.0), L(242.0,-120.0), L(246.0,-120.0), L(246.0,-124.0), L(243.0,-154.0), L(243.0,-158.0), L(239.0,-158.0), L(4.0,-158.0), L(0.0,-158.0), L(0.0,-154.0), L(3.0,-124.0), L(3.0,-120.0), L(7.0,-120.0), L(34.0,-120.0), Q(77.0,-120.0,85.0,-112.0), Q(89.0,-108.0,98.0,-75.0), L(166.0,179.0), L(173.0,206.0), Q(175.0,212.0,169.0,213.0), Q(162.0,215.0,133.0,215.0), L(90.0,215.0), L(85.0,215.0), L(86.0,220.0), L(93.0,248.0), L(94.0,251.0), L(97.0,251.0), Q(130.0,251.0,179.5,267.5), Q(229.0,284.0,243.0,298.0), L(244.0,299.0), L(245.0,299.0), L(263.0,299.0), L(268.0,299.0), L(267.0,294.0), Z ];
-
-// pub const CADENZA_63 : &'static [PathSegment] = &[ M(534.0,0.0), Q(534.0,157.0,465.0,243.0), Q(462.0,246.0,462.0,250.0), Q(462.0,263.0,477.0,263.0), Q(484.0,263.0,488.0,257.0), Q(592.0,127.0,592.0,0.0), Q(592.0,-127.0,488.0,-257.0), Q(484.0,-263.0,477.0,-263.0), Q(462.0,-263.0,462.0,-250.0), Q(462.0,-246.0,465.0,-243.0), Q(534.0,-157.0,534.0,0.0), Z , M(374.0,96.0), L(374.0,-26.0), Q(374.0,-34.0,379.0,-39.0), Q(384.0,-44.0,392.0,-44.0), Q(395.0,-44.0,398.0,-43.0), L(406.0,-40.0), Q(409.0,-39.0,413.0,-39.0), Q(421.0,-39.0,427.0,-45.0), Q(433.0,-51.0,433.0,-59.0), L(433.0,-119.0), Q(433.0,-132.0,420.0,-137.0), L(386.0,-150.0), Q(374.0,-155.0,374.0,-166.0), L(374.0,-312.0), Q(374.0,-320.0,368.5,-325.5), Q(363.0,-331.0,356.0,-331.0), Q(348.0,-331.0,342.5,-325.5), Q(337.0,-320.0,337.0,-312.0), L(337.0,-193.0), Q(337.0,-185.0,331.5,-180.0), Q(326.0,-175.0,319.0,-175.0), Q(315.0,-175.0,312.0,-176.0), L(266.0,-194.0), Q(254.0,-198.0,254.0,-210.0), L(254.0,-356.0), Q(254.0,-364.0,248.0,-369.5), Q(242.0,-375.0,235.0,-375.0), Q(228.0,-375.0,222.5,-369.5), Q(217.0,-364.0,217.0,-356.0), L(217.0,-237.0), Q(217.0,-229.0,211.5,-224.0), Q(206.0,-219.0,199.0,-219.0), Q(196.0,-219.0,193.0,-220.0), L(185.0,-223.0), Q(183.0,-224.0,178.0,-224.0), Q(170.0,-224.0,164.0,-218.0), Q(158.0,-212.0,158.0,-204.0), L(158.0,-144.0), Q(158.0,-130.0,171.0,-125.0), L(205.0,-113.0), Q(217.0,-110.0,217.0,-96.0), L(217.0,26.0), Q(217.0,34.0,211.5,39.0), Q(206.0,44.0,199.0,44.0), Q(196.0,44.0,194.0,43.0), L(185.0,40.0), Q(183.0,39.0,178.0,39.0), Q(170.0,39.0,164.0,45.0), Q(158.0,51.0,158.0,59.0), L(158.0,119.0), Q(158.0,132.0,171.0,137.0), L(206.0,150.0), Q(217.0,155.0,217.0,166.0), L(217.0,312.0), Q(217.0,320.0,222.5,325.5), Q(228.0,331.0,235.0,331.0), Q(242.0,331.0,248.0,325.5), Q(254.0,320.0,254.0,312.0), L(254.0,193.0), Q(254.0,185.0,259.5,180.0), Q(265.0,175.0,273.0,175.0), Q(276.0,175.0,279.0,176.0), L(325.0,194.0), Q(337.0,199.0,337.0,211.0), L(337.0,356.0), Q(337.0,364.0,342.5,369.5), Q(348.0,375.0,356.0,375.0), Q(363.0,375.0,368.5,369.5), Q(374.0,364.0,374.0,356.0), L(374.0,236.0), Q(374.0,229.0,379.0,224.0), Q(384.0,219.0,391.0,219.0), Q(394.0,219.0,398.0,220.0), L(406.0,223.0), Q(408.0,224.0,413.0,224.0), Q(421.0,224.0,427.0,218.0), Q(433.0,212.0,433.0,204.0), L(433.0,144.0), Q(433.0,130.0,420.0,125.0), L(386.0,113.0), Q(374.0,110.0,374.0,96.0), Z , M(337.0,-52.0), L(337.0,69.0), Q(337.0,76.0,331.5,81.5), Q(326.0,87.0,319.0,87.0), Q(316.0,87.0,313.0,86.0), L(266.0,69.0), Q(254.0,64.0,254.0,52.0), L(254.0,-69.0), Q(254.0,-77.0,259.0,-82.0), Q(264.0,-87.0,272.0,-87.0), Q(275.0,-87.0,278.0,-86.0), L(325.0,-69.0), Q(337.0,-64.0,337.0,-52.0), Z , M(57.0,0.0), Q(57.0,-157.0,126.0,-243.0), Q(129.0,-246.0,129.0,-250.0), Q(129.0,-263.0,115.0,-263.0), Q(107.0,-263.0,103.0,-257.0), Q(0.0,-129.0,0.0,0.0), Q(0.0,129.0,103.0,257.0), Q(107.0,263.0,115.0,263.0), Q(129.0,263.0,129.0,250.0), Q(129.0,246.0,126.0,243.0), Q(57.0,157.0,57.0,0.0), Z ];
-
-// pub const CADENZA_64 : &'static [PathSegment] = &[ M(46.0,-86.0), Q(46.0,-98.0,56.0,-102.0), Q(68.0,-107.0,76.0,-99.0), Q(142.0,-39.0,142.0,37.0), Q(142.0,64.0,130.5,84.0), Q(119.0,104.0,99.0,104.0), Q(77.0,104.0,63.0,85.0), Q(49.0,66.0,48.0,41.0), L(46.0,-86.0), Z , M(74.0,135.0), Q(116.0,156.0,143.0,156.0), Q(181.0,156.0,204.0,126.0), Q(227.0,96.0,227.0,54.0), Q(227.0,25.0,218.5,1.5), Q(210.0,-22.0,192.0,-41.5), Q(174.0,-61.0,158.5,-74.0), Q(143.0,-87.0,118.0,-105.5), Q(93.0,-124.0,80.0,-135.0), Q(75.0,-139.0,67.0,-150.0), Q(59.0,-161.0,51.5,-167.0), Q(44.0,-173.0,35.0,-173.0), Q(25.0,-173.0,18.5,-166.0), Q(12.0,-159.0,12.0,-148.0), L(0.0,457.0), Q(13.0,465.0,27.0,465.0), Q(41.0,465.0,54.0,457.0), L(48.0,151.0), Q(48.0,141.0,56.0,136.0), Q(65.0,130.0,74.0,135.0), Z ];
-
-// pub const CADENZA_65 : &'static [PathSegment] = &[ M(340.0,207.0), Q(301.0,237.0,250.0,237.0), Q(219.0,237.0,197.5,228.0), Q(176.0,219.0,164.5,205.0), Q(153.0,191.0,146.5,164.5), Q(140.0,138.0,138.5,114.0), Q(137.0,90.0,137.0,51.0), L(137.0,0.0), L(137.0,-51.0), Q(137.0,-90.0,138.5,-114.5), Q(140.0,-139.0,146.5,-165.0), Q(153.0,-191.0,164.5,-205.0), Q(176.0,-219.0,197.5,-228.5), Q(219.0,-238.0,250.0,-238.0), Q(308.0,-238.0,350.5,-194.5), Q(393.0,-151.0,408.0,-87.0), Q(411.0,-77.0,420.0,-77.0), Q(433.0,-77.0,433.0,-89.0), Q(433.0,-156.0,372.5,-209.5), Q(312.0,-263.0,250.0,-263.0), Q(133.0,-263.0,66.0,-193.0), Q(0.0,-124.0,0.0,0.0), Q(0.0,124.0,66.0,193.0), Q(132.0,262.0,250.0,262.0), Q(315.0,262.0,364.5,219.5), Q(414.0,177.0,425.0,112.0), Q(426.0,107.0,426.0,99.0), Q(426.0,69.0,406.5,48.0), Q(387.0,27.0,359.0,27.0), Q(325.0,27.0,304.5,50.5), Q(284.0,74.0,284.0,102.0), Q(284.0,127.0,298.0,148.0), Q(312.0,169.0,335.0,176.0), Q(345.0,179.0,347.0,190.0), Q(348.0,200.0,340.0,207.0), Z ];
-
-// pub const CADENZA_66 : &'static [PathSegment] = &[ M(0.0,91.0), Q(0.0,151.0,66.0,206.5), Q(132.0,262.0,201.0,262.0), Q(228.0,262.0,254.0,255.0), Q(275.0,249.0,294.0,232.0), L(294.0,1000.0), L(310.0,1000.0), L(326.0,1000.0), Q(326.0,946.0,347.5,889.0), Q(369.0,832.0,399.5,783.0), Q(430.0,734.0,461.0,684.5), Q(492.0,635.0,513.5,578.0), Q(535.0,521.0,535.0,467.0), Q(535.0,354.0,493.0,260.0), Q(486.0,248.0,474.0,248.0), Q(464.0,248.0,456.5,256.0), Q(449.0,264.0,451.0,275.0), Q(493.0,359.0,493.0,463.0), Q(493.0,577.0,357.0,717.0), Q(351.0,723.0,344.0,723.0), Q(337.0,723.0,331.5,717.5), Q(326.0,712.0,326.0,704.0), L(326.0,671.0), L(326.0,159.0), Q(326.0,104.0,259.0,46.0), Q(192.0,-12.0,125.0,-12.0), Q(97.0,-12.0,71.5,-5.0), Q(46.0,2.0,23.0,27.0), Q(0.0,52.0,0.0,91.0), Z ];
-
-// pub const CADENZA_67 : &'static [PathSegment] = &[ M(106.0,295.0), Q(135.0,368.0,192.5,421.0), Q(250.0,474.0,315.0,474.0), Q(350.0,474.0,376.0,454.5), Q(402.0,435.0,402.0,405.0), Q(402.0,373.0,388.5,350.5), Q(375.0,328.0,350.0,328.0), Q(332.0,328.0,319.0,340.5), Q(306.0,353.0,306.0,371.0), Q(306.0,380.0,310.5,385.5), Q(315.0,391.0,323.5,397.0), Q(332.0,403.0,335.0,405.0), Q(345.0,415.0,339.0,427.0), Q(335.0,437.0,322.0,437.0), L(315.0,437.0), Q(302.0,437.0,291.0,432.5), Q(280.0,428.0,272.0,416.0), Q(264.0,404.0,259.0,395.0), Q(254.0,386.0,248.5,366.0), Q(243.0,346.0,241.0,337.5), Q(239.0,329.0,234.0,305.0), Q(233.0,302.0,233.0,300.0), Q(233.0,292.0,238.5,287.5), Q(244.0,283.0,251.0,283.0), L(298.0,283.0), Q(317.0,283.0,317.0,264.0), Q(317.0,245.0,298.0,245.0), L(231.0,245.0), Q(217.0,245.0,214.0,232.0), L(168.0,82.0), Q(141.0,-7.0,78.5,-90.0), Q(16.0,-173.0,-50.0,-173.0), Q(-85.0,-173.0,-111.0,-153.5), Q(-137.0,-134.0,-137.0,-104.0), Q(-137.0,-72.0,-123.5,-49.5), Q(-110.0,-27.0,-85.0,-27.0), Q(-67.0,-27.0,-54.0,-40.0), Q(-41.0,-53.0,-41.0,-71.0), Q(-41.0,-78.0,-44.0,-83.5), Q(-47.0,-89.0,-49.5,-91.0), Q(-52.0,-93.0,-58.5,-97.5), Q(-65.0,-102.0,-67.0,-103.0), Q(-78.0,-111.0,-73.0,-124.0), Q(-69.0,-136.0,-56.0,-136.0), L(-50.0,-136.0), Q(-33.0,-136.0,-20.5,-127.5), Q(-8.0,-119.0,0.5,-99.5), Q(9.0,-80.0,13.0,-67.5), Q(17.0,-55.0,23.0,-28.0), L(85.0,223.0), Q(86.0,225.0,86.0,228.0), Q(86.0,235.0,81.0,240.0), Q(76.0,245.0,68.0,245.0), L(31.0,245.0), Q(12.0,245.0,12.0,264.0), Q(12.0,283.0,31.0,283.0), L(89.0,283.0), Q(101.0,283.0,106.0,295.0), Z ];
-
-// pub const CADENZA_68 : &'static [PathSegment] = &[ M(78.0,42.0), Q(78.0,21.0,102.0,-3.0), L(111.0,-12.0), Q(117.0,-18.0,117.0,-24.0), Q(117.0,-36.0,106.0,-36.0), Q(100.0,-36.0,96.0,-32.0), L(32.0,21.0), Q(0.0,48.0,0.0,82.0), Q(0.0,115.0,35.0,147.0), Q(76.0,186.0,76.0,204.0), Q(76.0,230.0,55.0,252.0), L(32.0,271.0), Q(0.0,298.0,0.0,332.0), Q(0.0,365.0,35.0,397.0), Q(76.0,436.0,76.0,454.0), Q(76.0,481.0,53.0,504.0), L(44.0,513.0), Q(38.0,519.0,38.0,525.0), Q(38.0,536.0,50.0,536.0), Q(56.0,536.0,60.0,532.0), L(126.0,477.0), Q(156.0,451.0,156.0,423.0), Q(156.0,382.0,119.0,351.0), Q(78.0,317.0,78.0,292.0), Q(78.0,271.0,102.0,247.0), L(126.0,227.0), Q(156.0,201.0,156.0,173.0), Q(156.0,132.0,119.0,101.0), Q(78.0,67.0,78.0,42.0), Z ];
-
-// pub const CADENZA_69 : &'static [PathSegment] = &[ M(310.0,1000.0), L(342.0,1000.0), L(342.0,163.0), Q(342.0,91.0,280.0,39.5), Q(218.0,-12.0,130.0,-12.0), Q(100.0,-12.0,74.0,-6.0), Q(48.0,0.0,24.0,24.0), Q(0.0,48.0,0.0,87.0), Q(0.0,159.0,62.0,210.5), Q(124.0,262.0,212.0,262.0), Q(242.0,262.0,268.0,256.0), Q(290.0,251.0,310.0,233.0), L(310.0,1000.0), Z , M(249.0,226.0), Q(220.0,226.0,126.5,160.5), Q(33.0,95.0,33.0,65.0), Q(33.0,44.0,51.0,34.0), Q(69.0,24.0,94.0,24.0), Q(123.0,24.0,216.5,89.5), Q(310.0,155.0,310.0,185.0), Q(310.0,206.0,292.0,216.0), Q(274.0,226.0,249.0,226.0), Z ];
-
-// pub const CADENZA_70 : &'static [PathSegment] = &[ M(31.0,-57.0), Q(31.0,-65.0,37.0,-68.0), Q(45.0,-71.0,51.0,-66.0), Q(95.0,-26.0,95.0,25.0), Q(95.0,43.0,87.5,56.0), Q(80.0,69.0,66.0,69.0), Q(51.0,69.0,42.0,56.5), Q(33.0,44.0,32.0,27.0), L(31.0,-57.0), Z , M(49.0,90.0), Q(77.0,104.0,95.0,104.0), Q(120.0,104.0,135.5,84.0), Q(151.0,64.0,152.0,36.0), Q(152.0,13.0,141.0,-7.5), Q(130.0,-28.0,119.0,-38.0), Q(108.0,-48.0,84.5,-65.5), Q(61.0,-83.0,53.0,-90.0), Q(50.0,-93.0,45.0,-100.0), Q(40.0,-107.0,35.0,-111.0), Q(30.0,-115.0,23.0,-115.0), Q(17.0,-115.0,12.5,-110.5), Q(8.0,-106.0,8.0,-99.0), L(0.0,305.0), Q(9.0,310.0,18.0,310.0), Q(27.0,310.0,36.0,305.0), L(32.0,101.0), Q(32.0,95.0,37.0,91.0), Q(43.0,87.0,49.0,90.0), Z ];
-
-// pub const CADENZA_71 : &'static [PathSegment] = &[ M(16.0,0.0), L(32.0,0.0), Q(32.0,-54.0,53.5,-111.0), Q(75.0,-168.0,105.5,-217.0), Q(136.0,-266.0,167.0,-315.5), Q(198.0,-365.0,219.5,-422.0), Q(241.0,-479.0,241.0,-533.0), Q(241.0,-646.0,199.0,-740.0), Q(192.0,-752.0,180.0,-752.0), Q(170.0,-752.0,162.5,-744.0), Q(155.0,-736.0,157.0,-725.0), Q(199.0,-641.0,199.0,-537.0), Q(199.0,-423.0,63.0,-283.0), Q(57.0,-277.0,50.0,-277.0), Q(43.0,-277.0,37.5,-282.5), Q(32.0,-288.0,32.0,-296.0), L(32.0,-329.0), L(16.0,-329.0), L(16.0,0.0), Z ];
-
-// pub const CADENZA_72 : &'static [PathSegment] = &[ M(0.0,62.0), Q(0.0,88.0,18.0,106.0), Q(36.0,124.0,62.0,124.0), Q(87.0,124.0,105.0,106.0), Q(123.0,88.0,123.0,62.0), Q(123.0,36.0,105.0,18.0), Q(87.0,0.0,62.0,0.0), Q(36.0,0.0,18.0,18.0), Q(0.0,36.0,0.0,62.0), Z ];
-
-// pub const CADENZA_73 : &'static [PathSegment] = &[ M(466.0,171.0), Q(472.0,180.0,482.0,180.0), Q(490.0,180.0,496.0,174.0), Q(502.0,168.0,502.0,160.0), Q(502.0,154.0,499.0,149.0), L(406.0,9.0), Q(400.0,0.0,389.0,0.0), Q(380.0,0.0,374.0,7.0), L(292.0,107.0), Q(286.0,114.0,278.0,114.0), Q(269.0,114.0,263.0,106.0), L(198.0,9.0), Q(192.0,0.0,181.0,0.0), Q(172.0,0.0,166.0,7.0), L(83.0,107.0), Q(77.0,114.0,68.0,114.0), Q(58.0,114.0,54.0,106.0), L(36.0,79.0), Q(30.0,70.0,20.0,70.0), Q(12.0,70.0,6.0,76.0), Q(0.0,82.0,0.0,90.0), Q(0.0,96.0,3.0,101.0), L(96.0,241.0), Q(102.0,250.0,113.0,250.0), Q(122.0,250.0,128.0,243.0), L(211.0,142.0), Q(216.0,136.0,225.0,136.0), Q(235.0,136.0,239.0,144.0), L(304.0,241.0), Q(310.0,250.0,321.0,250.0), Q(330.0,250.0,336.0,243.0), L(420.0,142.0), Q(425.0,136.0,434.0,136.0), Q(444.0,136.0,448.0,144.0), L(466.0,171.0), Z ];
-
-// pub const CADENZA_74 : &'static [PathSegment] = &[ M(69.0,180.0), L(177.0,202.0), L(180.0,202.0), Q(198.0,202.0,198.0,185.0), L(205.0,-375.0), Q(191.0,-382.0,180.0,-382.0), Q(169.0,-382.0,155.0,-375.0), L(158.0,-198.0), Q(158.0,-191.0,153.0,-185.5), Q(148.0,-180.0,140.0,-180.0), Q(138.0,-180.0,137.0,-180.0), L(29.0,-203.0), L(26.0,-203.0), Q(8.0,-203.0,8.0,-186.0), L(0.0,375.0), Q(14.0,382.0,26.0,382.0), Q(37.0,382.0,51.0,375.0), L(48.0,199.0), Q(48.0,191.0,53.0,185.5), Q(58.0,180.0,66.0,180.0), Q(67.0,180.0,69.0,180.0), Z , M(65.0,-100.0), L(144.0,-82.0), Q(159.0,-79.0,159.0,-64.0), L(162.0,81.0), Q(162.0,89.0,156.0,95.0), Q(150.0,101.0,142.0,99.0), L(62.0,82.0), Q(47.0,79.0,47.0,65.0), L(44.0,-83.0), Q(44.0,-91.0,50.5,-96.5), Q(57.0,-102.0,65.0,-100.0), Z ];
-
-// pub const CADENZA_75 : &'static [PathSegment] = &[ M(133.0,133.0), Q(188.0,133.0,227.0,94.5), Q(266.0,56.0,266.0,0.0), Q(266.0,-56.0,227.0,-94.5), Q(188.0,-133.0,133.0,-133.0), Q(78.0,-133.0,39.0,-94.5), Q(0.0,-56.0,0.0,0.0), Q(0.0,56.0,39.0,94.5), Q(78.0,133.0,133.0,133.0), Z , M(133.0,95.0), Q(95.0,95.0,66.5,66.5), Q(38.0,38.0,38.0,0.0), Q(38.0,-38.0,66.5,-66.5), Q(95.0,-95.0,133.0,-95.0), Q(171.0,-95.0,199.5,-66.5), Q(228.0,-38.0,228.0,0.0), Q(228.0,38.0,199.5,66.5), Q(171.0,95.0,133.0,95.0), Z ];
-
-// pub const CADENZA_76 : &'static [PathSegment] = &[ M(265.0,250.0), Q(226.0,250.0,194.5,187.5), Q(163.0,125.0,163.0,70.0), Q(163.0,32.0,193.0,32.0), Q(215.0,32.0,234.5,53.5), Q(254.0,75.0,265.5,105.5), Q(277.0,136.0,283.5,166.0), Q(290.0,196.0,290.0,217.0), Q(290.0,250.0,265.0,250.0), Z , M(17.0,153.0), Q(10.0,136.0,-7.0,136.0), Q(-21.0,136.0,-21.0,148.0), Q(-21.0,150.0,-19.0,156.0), Q(44.0,299.0,113.0,299.0), Q(153.0,299.0,171.0,268.0), Q(175.0,262.0,183.0,260.5), Q(191.0,259.0,197.0,264.0), Q(240.0,299.0,282.0,299.0), Q(332.0,299.0,360.5,267.0), Q(389.0,235.0,389.0,185.0), Q(389.0,152.0,377.0,118.0), Q(361.0,73.0,316.0,32.5), Q(271.0,-8.0,230.0,-8.0), Q(187.0,-8.0,157.0,9.0), Q(151.0,12.0,146.0,12.0), Q(133.0,12.0,130.0,3.0), L(101.0,-83.0), Q(100.0,-85.0,100.0,-90.0), Q(100.0,-114.0,144.0,-118.0), Q(157.0,-119.0,157.0,-132.0), Q(157.0,-146.0,144.0,-146.0), Q(125.0,-146.0,86.0,-143.5), Q(47.0,-141.0,28.0,-141.0), Q(8.0,-141.0,-30.5,-143.5), Q(-69.0,-146.0,-87.0,-146.0), Q(-101.0,-146.0,-101.0,-132.0), Q(-101.0,-127.0,-98.0,-123.5), Q(-95.0,-120.0,-88.5,-118.5), Q(-82.0,-117.0,-77.5,-116.5), Q(-73.0,-116.0,-64.0,-116.0), Q(-55.0,-116.0,-53.0,-116.0), Q(-7.0,-114.0,1.0,-91.0), L(103.0,216.0), Q(105.0,223.0,105.0,229.0), Q(105.0,236.0,101.0,240.5), Q(97.0,245.0,94.0,246.0), Q(91.0,247.0,88.0,247.0), Q(74.0,247.0,51.5,214.5), Q(29.0,182.0,17.0,153.0), Z ];
-
-// pub const CADENZA_77 : &'static [PathSegment] = &[ M(294.0,1000.0), L(326.0,1000.0), L(326.0,159.0), Q(326.0,104.0,259.0,46.0), Q(192.0,-12.0,125.0,-12.0), Q(97.0,-12.0,71.5,-5.0), Q(46.0,2.0,23.0,27.0), Q(0.0,52.0,0.0,91.0), Q(0.0,151.0,66.0,206.5), Q(132.0,262.0,201.0,262.0), Q(228.0,262.0,254.0,255.0), Q(275.0,249.0,294.0,232.0), L(294.0,1000.0), Z ];
-
-// pub const CADENZA_78 : &'static [PathSegment] = &[ M(216.0,-572.0), Q(241.0,-627.0,241.0,-682.0), Q(241.0,-771.0,199.0,-865.0), Q(192.0,-877.0,180.0,-877.0), Q(170.0,-877.0,162.5,-869.0), Q(155.0,-861.0,157.0,-850.0), Q(199.0,-766.0,199.0,-682.0), Q(199.0,-584.0,63.0,-454.0), Q(57.0,-448.0,50.0,-448.0), Q(43.0,-448.0,37.5,-453.5), Q(32.0,-459.0,32.0,-467.0), L(32.0,-500.0), L(16.0,-500.0), L(16.0,0.0), L(32.0,0.0), Q(32.0,-49.0,53.0,-99.0), Q(74.0,-149.0,103.5,-192.0), Q(133.0,-235.0,163.0,-278.0), Q(193.0,-321.0,214.0,-371.0), Q(235.0,-421.0,235.0,-470.0), Q(235.0,-511.0,215.0,-558.0), Q(212.0,-563.0,216.0,-572.0), Z , M(159.0,-479.0), Q(164.0,-487.0,173.0,-487.0), Q(180.0,-487.0,186.0,-482.0), Q(192.0,-477.0,192.0,-470.0), Q(192.0,-377.0,69.0,-250.0), Q(63.0,-244.0,55.0,-244.0), Q(48.0,-244.0,43.0,-249.0), Q(38.0,-254.0,38.0,-262.0), Q(38.0,-288.0,52.0,-320.5), Q(66.0,-353.0,81.0,-374.5), Q(96.0,-396.0,123.5,-432.0), Q(151.0,-468.0,159.0,-479.0), Z ];
-
-// pub const CADENZA_79 : &'static [PathSegment] = &[ M(180.0,216.0), Q(180.0,221.0,184.5,230.5), Q(189.0,240.0,189.0,245.0), Q(189.0,268.0,171.0,268.0), Q(151.0,268.0,134.5,255.0), Q(118.0,242.0,118.0,224.0), Q(118.0,206.0,149.0,187.0), Q(181.0,169.0,199.0,151.0), Q(217.0,133.0,217.0,107.0), Q(217.0,61.0,184.0,30.5), Q(151.0,0.0,103.0,0.0), Q(70.0,0.0,46.0,19.5), Q(22.0,39.0,22.0,68.0), Q(22.0,85.0,30.5,97.5), Q(39.0,110.0,53.0,110.0), Q(66.0,110.0,75.5,101.0), Q(85.0,92.0,85.0,78.0), Q(85.0,72.0,74.5,64.0), Q(64.0,56.0,64.0,49.0), Q(64.0,27.0,99.0,27.0), Q(124.0,27.0,140.5,40.0), Q(157.0,53.0,157.0,75.0), Q(157.0,91.0,141.5,105.0), Q(126.0,119.0,107.0,127.5), Q(88.0,136.0,72.5,153.0), Q(57.0,170.0,57.0,192.0), Q(57.0,232.0,89.0,262.0), Q(121.0,292.0,162.0,292.0), Q(201.0,292.0,221.0,273.0), Q(241.0,254.0,241.0,230.0), Q(241.0,215.0,233.0,204.5), Q(225.0,194.0,211.0,194.0), Q(198.0,194.0,189.0,200.5), Q(180.0,207.0,180.0,216.0), Z ];
-
-// pub const CADENZA_80 : &'static [PathSegment] = &[ M(570.0,-125.0), Q(570.0,-104.0,585.0,-89.0), Q(600.0,-74.0,621.0,-74.0), Q(642.0,-74.0,657.0,-89.0), Q(672.0,-104.0,672.0,-125.0), Q(672.0,-146.0,657.0,-161.0), Q(642.0,-176.0,621.0,-176.0), Q(600.0,-176.0,585.0,-161.0), Q(570.0,-146.0,570.0,-125.0), Z , M(570.0,125.0), Q(570.0,146.0,585.0,161.0), Q(600.0,176.0,621.0,176.0), Q(642.0,176.0,657.0,161.0), Q(672.0,146.0,672.0,125.0), Q(672.0,104.0,657.0,89.0), Q(642.0,74.0,621.0,74.0), Q(600.0,74.0,585.0,89.0), Q(570.0,104.0,570.0,125.0), Z , M(122.0,119.0), Q(167.0,119.0,196.0,90.0), Q(225.0,61.0,225.0,19.0), Q(225.0,-25.0,196.0,-56.0), Q(167.0,-87.0,125.0,-87.0), Q(80.0,-87.0,46.5,-56.0), Q(13.0,-25.0,13.0,19.0), Q(13.0,127.0,85.0,195.0), Q(157.0,263.0,264.0,263.0), Q(385.0,263.0,461.5,193.5), Q(538.0,124.0,538.0,12.0), Q(538.0,-342.0,24.0,-569.0), Q(17.0,-572.0,13.0,-572.0), Q(0.0,-572.0,0.0,-559.0), Q(0.0,-552.0,13.0,-544.0), Q(93.0,-497.0,145.5,-461.0), Q(198.0,-425.0,254.0,-374.0), Q(310.0,-323.0,342.0,-271.5), Q(374.0,-220.0,395.0,-150.0), Q(416.0,-80.0,416.0,2.0), Q(416.0,106.0,370.5,169.0), Q(325.0,232.0,246.0,232.0), Q(198.0,232.0,159.0,209.5), Q(120.0,187.0,105.0,148.0), Q(101.0,135.0,106.0,128.0), Q(113.0,119.0,122.0,119.0), Z , M(255.0,-674.0), Q(254.0,-675.0,252.0,-676.0), Q(250.0,-677.0,249.0,-676.0), L(248.0,-677.0), L(248.0,-677.0), Q(226.0,-687.0,213.0,-704.0), Q(200.0,-721.0,200.0,-741.0), Q(200.0,-764.0,215.0,-777.5), Q(230.0,-791.0,255.0,-791.0), Q(283.0,-791.0,302.0,-775.5), Q(321.0,-760.0,321.0,-738.0), Q(321.0,-711.0,290.0,-692.0), Q(280.0,-686.0,255.0,-674.0), Z , M(298.0,-640.0), Q(301.0,-639.0,304.0,-638.0), Q(322.0,-630.0,333.5,-613.0), Q(345.0,-596.0,345.0,-577.0), Q(345.0,-559.0,332.0,-547.0), Q(319.0,-535.0,299.0,-535.0), Q(276.0,-535.0,260.0,-550.0), Q(244.0,-564.0,244.0,-583.0), Q(244.0,-599.0,256.0,-612.0), Q(268.0,-625.0,298.0,-640.0), Z , M(321.0,-653.0), Q(339.0,-665.0,347.0,-675.0), Q(361.0,-695.0,362.0,-719.0), Q(362.0,-761.0,330.5,-787.5), Q(299.0,-814.0,250.0,-814.0), Q(210.0,-814.0,186.0,-796.0), Q(162.0,-778.0,162.0,-747.0), Q(162.0,-690.0,234.0,-660.0), Q(220.0,-649.0,213.0,-636.0), Q(206.0,-622.0,206.0,-603.0), Q(206.0,-565.0,234.0,-539.0), Q(262.0,-513.0,302.0,-512.0), Q(336.0,-512.0,359.0,-531.0), Q(381.0,-550.0,381.0,-579.0), Q(381.0,-613.0,353.0,-635.0), Q(343.0,-642.0,321.0,-653.0), Z ];
-
-// pub const CADENZA_81 : &'static [PathSegment] = &[ M(258.0,-53.0), Q(258.0,-22.0,279.5,-0.5), Q(301.0,21.0,332.0,21.0), Q(363.0,21.0,384.5,-0.5), Q(406.0,-22.0,406.0,-53.0), Q(406.0,-84.0,384.5,-105.0), Q(363.0,-126.0,332.0,-126.0), Q(301.0,-126.0,279.5,-105.0), Q(258.0,-84.0,258.0,-53.0), Z , M(332.0,-363.0), Q(274.0,-363.0,214.0,-332.0), Q(154.0,-301.0,107.0,-251.5), Q(60.0,-202.0,30.0,-134.0), Q(0.0,-66.0,0.0,3.0), Q(0.0,9.0,6.0,13.5), Q(12.0,18.0,19.0,18.0), Q(35.0,18.0,38.0,0.0), Q(58.0,-112.0,140.0,-187.0), Q(222.0,-262.0,332.0,-262.0), Q(442.0,-262.0,525.5,-187.0), Q(609.0,-112.0,626.0,0.0), Q(629.0,18.0,645.0,18.0), Q(652.0,18.0,658.0,13.5), Q(664.0,9.0,664.0,3.0), Q(664.0,-66.0,634.0,-134.0), Q(604.0,-202.0,557.0,-251.5), Q(510.0,-301.0,450.0,-332.0), Q(390.0,-363.0,332.0,-363.0), Z ];
-
-// pub const CADENZA_82 : &'static [PathSegment] = &[ M(176.0,155.0), L(226.0,268.0), Q(229.0,275.0,237.0,275.0), Q(250.0,275.0,250.0,263.0), Q(250.0,259.0,249.0,257.0), L(136.0,7.0), Q(133.0,0.0,125.0,0.0), Q(117.0,0.0,114.0,7.0), L(1.0,257.0), Q(0.0,259.0,0.0,263.0), Q(0.0,275.0,13.0,275.0), L(79.0,275.0), Q(88.0,275.0,91.0,268.0), L(143.0,154.0), Q(148.0,144.0,159.0,144.0), Q(171.0,144.0,176.0,155.0), Z ];
-
-// pub const CADENZA_83 : &'static [PathSegment] = &[ M(0.0,15.0), Q(0.0,59.0,48.0,98.0), Q(96.0,137.0,186.0,137.0), Q(247.0,137.0,297.0,122.5), Q(347.0,108.0,382.0,72.5), Q(417.0,37.0,417.0,-15.0), Q(417.0,-67.0,371.5,-102.0), Q(326.0,-137.0,245.0,-137.0), Q(197.0,-137.0,157.0,-130.0), Q(117.0,-123.0,80.0,-107.0), Q(43.0,-91.0,21.5,-60.0), Q(0.0,-29.0,0.0,15.0), Z , M(186.0,110.0), Q(98.0,110.0,98.0,79.0), Q(98.0,44.0,151.5,-33.0), Q(205.0,-110.0,241.0,-110.0), Q(327.0,-110.0,327.0,-79.0), Q(327.0,-41.0,271.5,34.5), Q(216.0,110.0,186.0,110.0), Z ];
-
-// pub const CADENZA_84 : &'static [PathSegment] = &[ M(0.0,91.0), Q(0.0,151.0,66.0,206.5), Q(132.0,262.0,201.0,262.0), Q(228.0,262.0,254.0,255.0), Q(275.0,249.0,294.0,232.0), L(294.0,1000.0), L(310.0,1000.0), L(326.0,1000.0), Q(326.0,951.0,347.0,901.0), Q(368.0,851.0,397.5,808.0), Q(427.0,765.0,457.0,722.0), Q(487.0,679.0,508.0,629.0), Q(529.0,579.0,529.0,530.0), Q(529.0,489.0,509.0,442.0), Q(506.0,437.0,510.0,428.0), Q(535.0,373.0,535.0,318.0), Q(535.0,229.0,493.0,135.0), Q(486.0,123.0,474.0,123.0), Q(464.0,123.0,456.5,131.0), Q(449.0,139.0,451.0,150.0), Q(493.0,234.0,493.0,318.0), Q(493.0,416.0,357.0,546.0), Q(351.0,552.0,344.0,552.0), Q(337.0,552.0,331.5,546.5), Q(326.0,541.0,326.0,533.0), L(326.0,500.0), L(326.0,159.0), Q(326.0,104.0,259.0,46.0), Q(192.0,-12.0,125.0,-12.0), Q(97.0,-12.0,71.5,-5.0), Q(46.0,2.0,23.0,27.0), Q(0.0,52.0,0.0,91.0), Z , M(453.0,521.0), Q(458.0,513.0,467.0,513.0), Q(474.0,513.0,480.0,518.0), Q(486.0,523.0,486.0,530.0), Q(486.0,623.0,363.0,750.0), Q(357.0,756.0,349.0,756.0), Q(342.0,756.0,337.0,751.0), Q(332.0,746.0,332.0,738.0), Q(332.0,712.0,346.0,679.5), Q(360.0,647.0,375.0,625.5), Q(390.0,604.0,417.5,568.0), Q(445.0,532.0,453.0,521.0), Z ];
-
-// pub const CADENZA_85 : &'static [PathSegment] = &[ M(74.0,12.0), Q(46.0,12.0,38.0,6.0), Q(30.0,0.0,28.0,0.0), Q(9.0,0.0,9.0,25.0), Q(9.0,31.0,13.0,35.0), L(160.0,174.0), Q(169.0,183.0,165.0,195.0), Q(160.0,208.0,147.0,206.0), Q(119.0,204.0,94.0,200.0), Q(89.0,199.0,74.5,196.0), Q(60.0,193.0,54.0,193.0), Q(37.0,193.0,37.0,208.0), Q(37.0,232.0,60.0,255.0), Q(83.0,278.0,108.0,278.0), Q(124.0,278.0,155.0,267.0), Q(186.0,256.0,202.0,256.0), Q(206.0,256.0,213.5,255.5), Q(221.0,255.0,225.0,255.0), Q(246.0,255.0,255.0,264.0), Q(259.0,268.0,265.0,268.0), Q(270.0,268.0,274.5,258.5), Q(279.0,249.0,279.0,243.0), Q(279.0,238.0,274.0,233.0), L(128.0,95.0), Q(120.0,88.0,123.0,76.0), Q(126.0,66.0,138.0,64.0), Q(148.0,63.0,166.0,60.5), Q(184.0,58.0,199.0,56.5), Q(214.0,55.0,227.0,55.0), Q(242.0,55.0,252.0,66.0), Q(262.0,77.0,262.0,93.0), Q(262.0,96.0,260.0,103.0), Q(258.0,110.0,258.0,114.0), Q(258.0,127.0,273.0,127.0), Q(295.0,127.0,295.0,66.0), Q(295.0,27.0,267.0,-0.5), Q(239.0,-28.0,199.0,-28.0), Q(169.0,-28.0,133.5,-8.0), Q(98.0,12.0,74.0,12.0), Z ];
-
-// pub const CADENZA_86 : &'static [PathSegment] = &[ M(330.0,0.0), Q(330.0,130.0,278.0,182.0), Q(276.0,184.0,276.0,188.0), Q(276.0,198.0,287.0,197.0), Q(292.0,197.0,296.0,193.0), Q(374.0,95.0,374.0,0.0), Q(374.0,-76.0,296.0,-193.0), Q(293.0,-197.0,287.0,-197.0), Q(276.0,-198.0,276.0,-188.0), Q(276.0,-185.0,278.0,-182.0), Q(330.0,-118.0,330.0,0.0), Z , M(153.0,-64.0), Q(152.0,-72.0,160.0,-76.0), Q(170.0,-80.0,176.0,-74.0), Q(226.0,-29.0,225.0,28.0), Q(225.0,48.0,216.5,63.0), Q(208.0,78.0,193.0,78.0), Q(177.0,78.0,166.0,64.0), Q(155.0,50.0,155.0,31.0), L(153.0,-64.0), Z , M(174.0,101.0), Q(206.0,117.0,226.0,117.0), Q(254.0,117.0,271.5,94.5), Q(289.0,72.0,289.0,40.0), Q(289.0,18.0,282.5,1.0), Q(276.0,-16.0,262.5,-31.0), Q(249.0,-46.0,237.5,-55.5), Q(226.0,-65.0,207.0,-79.0), Q(188.0,-93.0,178.0,-101.0), Q(174.0,-104.0,168.5,-112.5), Q(163.0,-121.0,157.5,-125.5), Q(152.0,-130.0,145.0,-130.0), Q(137.0,-130.0,132.5,-124.5), Q(128.0,-119.0,128.0,-111.0), L(118.0,343.0), Q(128.0,349.0,138.5,349.0), Q(149.0,349.0,158.0,343.0), L(155.0,113.0), Q(154.0,105.0,160.0,102.0), Q(166.0,97.0,174.0,101.0), Z , M(43.0,0.0), Q(42.0,-118.0,94.0,-182.0), Q(96.0,-184.0,97.0,-188.0), Q(97.0,-198.0,86.0,-197.0), Q(80.0,-197.0,77.0,-193.0), Q(0.0,-97.0,0.0,0.0), Q(0.0,97.0,77.0,193.0), Q(80.0,197.0,86.0,197.0), Q(96.0,198.0,97.0,188.0), Q(96.0,185.0,94.0,182.0), Q(42.0,118.0,43.0,0.0), Z ];
-
-// pub const CADENZA_87 : &'static [PathSegment] = &[ M(437.0,456.0), L(362.0,456.0), Q(352.0,456.0,347.0,448.0), L(133.0,97.0), Q(130.0,93.0,131.0,88.0), Q(131.0,81.0,136.0,75.5), Q(141.0,70.0,149.0,70.0), L(224.0,70.0), Q(234.0,70.0,239.0,79.0), L(453.0,429.0), Q(456.0,433.0,455.0,438.0), Q(455.0,445.0,450.0,450.5), Q(445.0,456.0,437.0,456.0), Z , M(10.0,0.0), Q(0.0,0.0,0.0,11.0), Q(0.0,14.0,1.0,16.0), L(310.0,521.0), Q(313.0,526.0,319.0,526.0), L(576.0,526.0), Q(586.0,526.0,586.0,515.0), Q(586.0,512.0,585.0,510.0), L(276.0,5.0), Q(273.0,0.0,267.0,0.0), L(10.0,0.0), Z ];
-
-// pub const CADENZA_88 : &'static [PathSegment] = &[ M(573.0,-94.0), Q(556.0,-139.0,534.0,-169.0), Q(528.0,-176.0,520.0,-172.0), Q(516.0,-170.0,513.0,-165.0), Q(510.0,-160.0,513.0,-155.0), Q(551.0,-89.0,551.0,-11.0), Q(551.0,110.0,516.0,157.0), Q(508.0,167.0,519.0,176.0), Q(523.0,180.0,529.0,180.0), Q(534.0,180.0,538.0,175.0), Q(558.0,147.0,573.5,95.5), Q(589.0,44.0,589.0,-3.0), Q(589.0,-50.0,573.0,-94.0), Z , M(0.0,12.0), Q(0.0,17.0,0.0,21.0), Q(0.0,62.0,16.0,105.0), Q(32.0,148.0,56.0,178.0), Q(64.0,188.0,77.0,181.0), Q(87.0,174.0,82.0,165.0), Q(41.0,101.0,41.0,27.0), Q(41.0,-23.0,55.5,-70.0), Q(70.0,-117.0,98.0,-145.0), Q(107.0,-154.0,98.0,-164.0), Q(91.0,-170.0,83.0,-170.0), Q(76.0,-170.0,72.0,-166.0), Q(55.0,-148.0,39.0,-119.0), Q(23.0,-90.0,12.0,-54.5), Q(1.0,-19.0,0.0,12.0), Z ];
-
-// pub const CADENZA_89 : &'static [PathSegment] = &[ M(42.0,78.0), Q(21.0,78.0,-3.0,54.0), L(-12.0,45.0), Q(-18.0,39.0,-24.0,39.0), Q(-36.0,39.0,-36.0,50.0), Q(-36.0,55.0,-32.0,60.0), L(21.0,124.0), Q(48.0,156.0,82.0,156.0), Q(114.0,156.0,147.0,121.0), Q(185.0,80.0,204.0,80.0), Q(231.0,80.0,254.0,103.0), L(263.0,112.0), Q(269.0,118.0,275.0,118.0), Q(286.0,118.0,286.0,106.0), Q(286.0,101.0,282.0,96.0), L(227.0,30.0), Q(202.0,0.0,173.0,0.0), Q(132.0,0.0,101.0,37.0), Q(67.0,78.0,42.0,78.0), Z ];
-
-// pub const CADENZA_90 : &'static [PathSegment] = &[ M(92.0,231.0), L(36.0,155.0), Q(32.0,149.0,26.0,149.0), Q(22.0,149.0,19.5,151.5), Q(17.0,154.0,18.0,158.0), Q(19.0,160.0,21.0,162.0), L(140.0,325.0), Q(141.0,327.0,144.0,327.0), Q(150.0,327.0,159.5,322.0), Q(169.0,317.0,176.0,317.0), Q(185.0,317.0,205.0,325.0), Q(206.0,326.0,208.0,326.0), Q(216.0,326.0,213.0,315.0), L(149.0,76.0), Q(146.0,65.0,146.0,56.0), Q(146.0,44.0,152.5,29.5), Q(159.0,15.0,180.0,15.0), Q(188.0,15.0,186.0,8.0), Q(184.0,0.0,176.0,0.0), Q(162.0,0.0,134.5,4.0), Q(107.0,8.0,93.0,8.0), Q(79.0,8.0,49.5,4.0), Q(20.0,0.0,6.0,0.0), Q(-2.0,0.0,0.0,8.0), Q(2.0,15.0,10.0,15.0), Q(31.0,15.0,49.0,33.0), Q(67.0,51.0,74.0,76.0), L(114.0,227.0), Q(117.0,239.0,105.0,238.0), Q(97.0,238.0,92.0,231.0), Z ];
-
-// pub const CADENZA_91 : &'static [PathSegment] = &[ M(199.0,68.0), Q(198.0,61.0,198.0,54.0), Q(198.0,40.0,205.0,27.5), Q(212.0,15.0,231.0,15.0), Q(239.0,15.0,237.0,8.0), Q(235.0,0.0,227.0,0.0), Q(213.0,0.0,186.0,4.0), Q(159.0,8.0,144.5,8.0), Q(130.0,8.0,100.5,4.0), Q(71.0,0.0,57.0,0.0), Q(49.0,0.0,51.0,8.0), Q(53.0,15.0,61.0,15.0), Q(81.0,15.0,98.0,31.0), Q(115.0,47.0,123.0,69.0), Q(125.0,73.0,122.0,77.0), Q(119.0,81.0,114.0,81.0), L(18.0,81.0), Q(8.0,81.0,4.0,86.0), Q(0.0,91.0,2.0,96.0), Q(3.0,99.0,4.0,100.0), Q(122.0,204.0,151.0,315.0), Q(154.0,325.0,162.0,325.0), L(164.0,325.0), Q(190.0,317.0,208.5,317.0), Q(227.0,317.0,257.0,325.0), Q(258.0,326.0,260.0,326.0), Q(265.0,326.0,267.0,322.0), Q(269.0,318.0,265.0,315.0), L(45.0,120.0), Q(41.0,117.0,40.0,112.0), Q(39.0,107.0,41.0,103.5), Q(43.0,100.0,48.0,100.0), L(120.0,100.0), Q(132.0,100.0,134.0,112.0), L(154.0,185.0), Q(157.0,196.0,163.0,200.0), Q(195.0,218.0,211.0,232.0), Q(220.0,241.0,237.0,265.0), Q(240.0,269.0,244.0,268.0), Q(252.0,268.0,250.0,259.0), L(210.0,112.0), Q(207.0,100.0,219.0,100.0), L(254.0,100.0), Q(264.0,100.0,261.0,91.0), Q(258.0,81.0,249.0,81.0), L(215.0,81.0), Q(209.0,81.0,204.5,77.0), Q(200.0,73.0,199.0,68.0), Z ];
-
-// pub const CADENZA_92 : &'static [PathSegment] = &[ M(173.0,310.0), Q(135.0,310.0,129.0,289.0), Q(127.0,282.0,136.5,273.5), Q(146.0,265.0,145.0,258.0), Q(141.0,242.0,126.5,231.0), Q(112.0,220.0,96.5,220.0), Q(81.0,220.0,73.0,231.0), Q(65.0,242.0,70.0,258.0), Q(78.0,288.0,109.5,306.5), Q(141.0,325.0,177.0,325.0), Q(225.0,325.0,247.5,309.0), Q(270.0,293.0,270.0,270.0), Q(270.0,261.0,267.0,251.0), Q(260.0,226.0,251.5,212.0), Q(243.0,198.0,224.0,190.0), Q(214.0,186.0,211.0,176.0), Q(208.0,166.0,216.0,162.0), Q(238.0,150.0,238.0,121.0), Q(238.0,108.0,234.0,91.0), Q(222.0,48.0,183.0,24.0), Q(144.0,0.0,93.0,0.0), Q(52.0,0.0,31.0,17.5), Q(10.0,35.0,10.0,57.0), Q(10.0,65.0,12.0,73.0), Q(17.0,91.0,33.0,103.5), Q(49.0,116.0,66.5,116.0), Q(84.0,116.0,91.5,105.5), Q(99.0,95.0,99.0,85.0), Q(99.0,80.0,98.0,73.0), Q(96.0,65.0,80.0,56.0), Q(64.0,47.0,62.0,39.0), Q(59.0,27.0,69.5,21.0), Q(80.0,15.0,97.0,15.0), Q(138.0,15.0,158.0,91.0), L(161.0,101.0), Q(169.0,123.0,169.0,141.0), Q(169.0,144.0,168.0,155.0), Q(167.0,166.0,146.0,166.0), L(94.0,166.0), Q(85.0,166.0,87.0,176.0), Q(89.0,185.0,99.0,185.0), L(151.0,185.0), Q(173.0,185.0,182.0,199.0), Q(191.0,213.0,200.0,246.0), L(201.0,251.0), Q(205.0,267.0,205.0,278.0), Q(205.0,310.0,173.0,310.0), Z ];
-
-// pub const CADENZA_93 : &'static [PathSegment] = &[ M(1192.0,234.0), Q(1183.0,244.0,1168.0,244.0), Q(1148.0,244.0,1133.0,229.0), Q(1115.0,211.0,1103.0,178.0), Q(1091.0,146.0,1090.0,115.0), Q(1090.0,95.0,1099.0,84.0), Q(1108.0,73.0,1124.0,72.0), Q(1155.0,72.0,1176.0,113.0), Q(1200.0,161.0,1200.0,206.0), Q(1200.0,224.0,1192.0,234.0), Z , M(1231.0,271.0), L(1272.0,271.0), L(1277.0,271.0), L(1276.0,266.0), L(1229.0,107.0), Q(1223.0,87.0,1223.0,82.0), Q(1223.0,79.0,1225.0,77.0), Q(1227.0,75.0,1229.0,74.0), Q(1246.0,75.0,1273.0,128.0), L(1275.0,132.0), L(1279.0,129.0), L(1293.0,121.0), L(1296.0,120.0), L(1295.0,116.0), Q(1287.0,98.0,1271.0,77.0), Q(1245.0,40.0,1212.0,40.0), Q(1197.0,40.0,1188.0,49.0), Q(1179.0,59.0,1179.0,74.0), Q(1166.0,58.0,1154.0,51.0), Q(1137.0,40.0,1113.0,40.0), Q(1079.0,40.0,1059.0,62.0), Q(1039.0,84.0,1039.0,121.0), Q(1039.0,182.0,1079.0,233.0), Q(1114.0,279.0,1160.0,279.0), Q(1185.0,279.0,1202.0,264.0), Q(1214.0,254.0,1218.0,240.0), L(1227.0,268.0), L(1228.0,271.0), L(1231.0,271.0), Z , M(733.0,274.0), L(723.0,228.0), Q(742.0,253.0,757.0,263.0), Q(777.0,278.0,800.0,279.0), Q(822.0,279.0,836.0,265.0), Q(850.0,251.0,851.0,230.0), Q(851.0,228.0,851.0,226.0), Q(890.0,279.0,936.0,279.0), Q(957.0,279.0,970.0,265.0), Q(983.0,252.0,984.0,231.0), Q(984.0,214.0,978.0,195.0), L(948.0,102.0), Q(942.0,84.0,942.0,80.0), Q(942.0,77.0,944.0,75.0), L(944.0,74.0), Q(946.0,72.0,948.0,72.0), Q(964.0,72.0,1000.0,125.0), L(1002.0,129.0), L(1005.0,126.0), L(1020.0,116.0), L(1024.0,114.0), L(1021.0,111.0), Q(998.0,77.0,985.0,64.0), Q(960.0,40.0,935.0,40.0), Q(918.0,40.0,905.0,50.0), Q(894.0,60.0,893.0,77.0), Q(893.0,87.0,895.0,96.0), Q(896.0,100.0,907.0,132.0), L(929.0,199.0), Q(936.0,219.0,935.0,228.0), Q(935.0,242.0,921.0,242.0), Q(908.0,242.0,893.0,232.0), Q(877.0,221.0,864.0,205.0), Q(842.0,177.0,826.0,119.0), L(806.0,50.0), L(805.0,47.0), L(802.0,47.0), L(760.0,47.0), L(755.0,47.0), L(756.0,52.0), L(795.0,181.0), Q(804.0,210.0,804.0,225.0), Q(804.0,243.0,788.0,242.0), Q(763.0,242.0,736.0,207.0), Q(708.0,171.0,692.0,117.0), L(672.0,50.0), L(671.0,47.0), L(668.0,47.0), L(627.0,47.0), L(622.0,47.0), L(623.0,52.0), L(671.0,216.0), Q(675.0,229.0,675.0,235.0), Q(675.0,246.0,652.0,246.0), L(641.0,246.0), L(637.0,246.0), L(637.0,250.0), L(638.0,267.0), L(638.0,271.0), L(642.0,271.0), L(729.0,279.0), L(734.0,279.0), L(733.0,274.0), Z , M(334.0,91.0), L(391.0,293.0), L(392.0,297.0), L(396.0,296.0), Q(468.0,278.0,525.0,278.0), Q(540.0,278.0,596.0,294.0), Q(603.0,296.0,604.0,296.0), Q(613.0,296.0,613.0,285.0), Q(613.0,271.0,594.0,250.0), Q(561.0,214.0,496.0,214.0), Q(494.0,214.0,492.0,214.0), Q(459.0,214.0,411.0,223.0), L(379.0,115.0), Q(419.0,133.0,453.0,133.0), Q(507.0,133.0,541.0,101.0), Q(575.0,69.0,575.0,20.0), Q(575.0,-58.0,520.0,-113.0), Q(465.0,-168.0,385.0,-168.0), Q(332.0,-168.0,299.0,-140.0), Q(265.0,-111.0,265.0,-67.0), Q(265.0,-42.0,280.0,-25.5), Q(295.0,-9.0,318.0,-9.0), Q(336.0,-9.0,348.0,-20.0), Q(360.0,-31.0,360.0,-49.0), Q(360.0,-83.0,341.0,-85.0), Q(321.0,-87.0,321.0,-95.0), L(321.0,-96.0), L(319.0,-101.0), Q(319.0,-113.0,337.0,-123.0), Q(357.0,-134.0,383.0,-134.0), Q(430.0,-134.0,464.0,-92.0), Q(498.0,-49.0,498.0,12.0), Q(498.0,50.0,479.5,70.0), Q(461.0,90.0,428.0,90.0), Q(394.0,90.0,367.0,69.0), Q(363.0,32.0,335.0,32.0), Q(323.0,32.0,315.5,39.0), Q(308.0,46.0,308.0,57.0), Q(308.0,70.0,314.0,75.0), Q(321.0,81.0,334.0,91.0), Z , M(267.0,294.0), L(168.0,-77.0), Q(162.0,-99.0,162.0,-107.0), Q(162.0,-114.0,182.0,-117.0), Q(205.0,-120.0,208.0,-120.0), L(242.0,-120.0), L(246.0,-120.0), L(246.0,-124.0), L(243.0,-154.0), L(243.0,-158.0), L(239.0,-158.0), L(4.0,-158.0), L(0.0,-158.0), L(0.0,-154.0), L(3.0,-124.0), L(3.0,-120.0), L(7.0,-120.0), L(34.0,-120.0), Q(77.0,-120.0,85.0,-112.0), Q(89.0,-108.0,98.0,-75.0), L(166.0,179.0), L(173.0,206.0), Q(175.0,212.0,169.0,213.0), Q(162.0,215.0,133.0,215.0), L(90.0,215.0), L(85.0,215.0), L(86.0,220.0), L(93.0,248.0), L(94.0,251.0), L(97.0,251.0), Q(130.0,251.0,179.5,267.5), Q(229.0,284.0,243.0,298.0), L(244.0,299.0), L(245.0,299.0), L(263.0,299.0), L(268.0,299.0), L(267.0,294.0), Z ];
-
-// pub const CADENZA_94 : &'static [PathSegment] = &[ M(133.0,166.0), Q(111.0,166.0,101.0,150.5), Q(91.0,135.0,81.0,100.0), L(79.0,91.0), L(76.0,81.0), Q(68.0,58.0,68.0,41.0), Q(68.0,37.0,69.0,26.0), Q(70.0,15.0,93.0,15.0), Q(118.0,15.0,130.5,33.5), Q(143.0,52.0,153.0,91.0), Q(162.0,117.0,162.0,136.0), Q(162.0,141.0,160.0,153.5), Q(158.0,166.0,133.0,166.0), Z , M(117.0,178.0), Q(129.0,181.0,137.0,181.0), Q(188.0,181.0,208.0,162.5), Q(228.0,144.0,228.0,119.0), Q(228.0,106.0,220.0,76.0), Q(212.0,46.0,176.0,23.0), Q(140.0,0.0,89.0,0.0), Q(54.0,0.0,34.5,24.0), Q(15.0,48.0,14.0,68.0), Q(13.0,88.0,13.0,92.0), Q(13.0,124.0,22.0,162.0), Q(40.0,228.0,87.0,276.5), Q(134.0,325.0,189.0,325.0), Q(225.0,325.0,244.0,309.0), Q(263.0,293.0,263.0,273.0), Q(263.0,266.0,261.0,258.0), Q(256.0,240.0,240.0,227.5), Q(224.0,215.0,206.5,215.0), Q(189.0,215.0,181.5,225.5), Q(174.0,236.0,174.0,247.0), Q(174.0,252.0,176.0,258.0), Q(178.0,266.0,192.5,274.0), Q(207.0,282.0,209.0,290.0), Q(211.0,299.0,204.0,304.5), Q(197.0,310.0,185.0,310.0), Q(156.0,310.0,140.0,286.5), Q(124.0,263.0,112.0,220.0), Q(107.0,200.0,104.0,189.0), Q(103.0,185.0,105.5,181.5), Q(108.0,178.0,113.0,178.0), Q(115.0,177.0,117.0,178.0), Z ];
-
-// pub const CADENZA_95 : &'static [PathSegment] = &[ M(70.0,55.0), Q(70.0,78.0,86.0,94.0), Q(102.0,110.0,125.0,110.0), Q(148.0,110.0,164.0,94.0), Q(180.0,78.0,180.0,55.0), Q(180.0,32.0,164.0,16.0), Q(148.0,0.0,125.0,0.0), Q(102.0,0.0,86.0,16.0), Q(70.0,32.0,70.0,55.0), Z , M(75.0,290.0), L(24.0,177.0), Q(21.0,170.0,13.0,170.0), Q(0.0,170.0,0.0,182.0), Q(0.0,186.0,1.0,188.0), L(114.0,438.0), Q(117.0,445.0,125.0,445.0), Q(133.0,445.0,136.0,438.0), L(249.0,188.0), Q(250.0,186.0,250.0,182.0), Q(250.0,170.0,237.0,170.0), L(171.0,170.0), Q(162.0,170.0,159.0,177.0), L(108.0,290.0), Q(103.0,301.0,91.0,301.0), Q(80.0,301.0,75.0,290.0), Z ];
-
-// pub const CADENZA_96 : &'static [PathSegment] = &[ M(0.0,-23.0), Q(0.0,17.0,44.0,54.5), Q(88.0,92.0,135.0,92.0), Q(154.0,92.0,171.0,87.0), Q(185.0,83.0,197.0,71.0), L(197.0,586.0), L(218.0,586.0), L(218.0,23.0), Q(218.0,-14.0,173.5,-53.0), Q(129.0,-92.0,84.0,-92.0), Q(65.0,-92.0,48.0,-87.5), Q(31.0,-83.0,15.5,-66.0), Q(0.0,-49.0,0.0,-23.0), Z ];
-
-// pub const CADENZA_97 : &'static [PathSegment] = &[ M(138.0,159.0), Q(160.0,159.0,170.5,174.5), Q(181.0,190.0,191.0,225.0), L(193.0,234.0), L(196.0,244.0), Q(204.0,267.0,204.0,284.0), Q(204.0,288.0,202.5,299.0), Q(201.0,310.0,179.0,310.0), Q(154.0,310.0,141.5,291.5), Q(129.0,273.0,119.0,234.0), Q(110.0,208.0,110.0,189.0), Q(110.0,184.0,111.5,171.5), Q(113.0,159.0,138.0,159.0), Z , M(155.0,147.0), Q(143.0,144.0,134.0,144.0), Q(83.0,144.0,63.5,162.5), Q(44.0,181.0,44.0,206.0), Q(44.0,219.0,52.0,249.0), Q(60.0,279.0,96.0,302.0), Q(132.0,325.0,183.0,325.0), Q(218.0,325.0,237.5,301.0), Q(257.0,277.0,258.0,257.0), Q(259.0,237.0,259.0,233.0), Q(259.0,200.0,250.0,162.0), Q(232.0,96.0,185.0,48.0), Q(138.0,0.0,83.0,0.0), Q(47.0,0.0,28.0,16.0), Q(9.0,32.0,9.0,52.0), Q(9.0,59.0,11.0,67.0), Q(16.0,85.0,31.5,97.5), Q(47.0,110.0,64.5,110.0), Q(82.0,110.0,90.0,99.5), Q(98.0,89.0,98.0,78.0), Q(98.0,73.0,96.0,67.0), Q(94.0,59.0,79.5,51.0), Q(65.0,43.0,62.0,35.0), Q(60.0,26.0,67.5,20.5), Q(75.0,15.0,87.0,15.0), Q(116.0,15.0,132.0,38.5), Q(148.0,62.0,160.0,105.0), Q(165.0,125.0,167.0,136.0), Q(168.0,141.0,165.5,144.0), Q(163.0,147.0,158.0,147.0), Q(156.0,147.0,155.0,147.0), Z ];
-
-// pub const CADENZA_98 : &'static [PathSegment] = &[ M(102.0,385.0), Q(102.0,412.0,121.0,431.0), Q(140.0,450.0,167.0,450.0), Q(194.0,450.0,213.0,431.0), Q(232.0,412.0,232.0,385.0), Q(232.0,358.0,213.0,339.0), Q(194.0,320.0,167.0,320.0), Q(140.0,320.0,121.0,339.0), Q(102.0,358.0,102.0,385.0), Z , M(611.0,122.0), Q(611.0,149.0,630.0,168.0), Q(649.0,187.0,676.0,187.0), Q(703.0,187.0,722.0,168.0), Q(741.0,149.0,741.0,122.0), Q(741.0,95.0,722.0,76.0), Q(703.0,57.0,676.0,57.0), Q(649.0,57.0,630.0,76.0), Q(611.0,95.0,611.0,122.0), Z , M(0.0,0.0), L(466.0,506.0), L(596.0,506.0), L(134.0,0.0), L(0.0,0.0), Z , M(249.0,0.0), L(715.0,506.0), L(845.0,506.0), L(383.0,0.0), L(249.0,0.0), Z ];
-
-// pub const CADENZA_99 : &'static [PathSegment] = &[ M(296.0,316.0), Q(255.0,304.0,224.0,304.0), Q(189.0,304.0,163.0,326.5), Q(137.0,349.0,137.0,383.0), Q(137.0,413.0,158.0,434.0), Q(179.0,455.0,209.0,455.0), Q(228.0,455.0,244.0,444.5), Q(260.0,434.0,266.0,416.0), Q(269.0,409.0,272.5,390.0), Q(276.0,371.0,281.5,364.0), Q(287.0,357.0,300.0,357.0), Q(310.0,357.0,328.0,381.0), Q(346.0,405.0,352.0,420.0), Q(356.0,429.0,366.0,429.0), Q(376.0,429.0,380.0,420.0), L(164.0,-500.0), Q(153.0,-510.0,136.0,-510.0), Q(120.0,-510.0,109.0,-500.0), L(186.0,-205.0), Q(187.0,-202.0,187.0,-200.0), Q(187.0,-193.0,182.0,-187.5), Q(177.0,-182.0,169.0,-182.0), Q(166.0,-182.0,164.0,-183.0), Q(118.0,-196.0,87.0,-196.0), Q(52.0,-196.0,26.0,-173.5), Q(0.0,-151.0,0.0,-117.0), Q(0.0,-87.0,21.0,-66.0), Q(42.0,-45.0,72.0,-45.0), Q(91.0,-45.0,107.0,-55.5), Q(123.0,-66.0,129.0,-84.0), Q(132.0,-91.0,135.5,-110.0), Q(139.0,-129.0,144.5,-136.0), Q(150.0,-143.0,163.0,-143.0), Q(175.0,-143.0,196.5,-115.0), Q(218.0,-87.0,222.0,-72.0), L(253.0,45.0), Q(254.0,48.0,254.0,50.0), Q(254.0,57.0,248.5,62.5), Q(243.0,68.0,235.0,68.0), Q(232.0,68.0,230.0,67.0), Q(185.0,54.0,155.0,54.0), Q(120.0,54.0,94.0,76.5), Q(68.0,99.0,68.0,133.0), Q(68.0,163.0,89.5,184.0), Q(111.0,205.0,141.0,205.0), Q(160.0,205.0,175.5,194.5), Q(191.0,184.0,197.0,166.0), Q(200.0,159.0,203.5,140.0), Q(207.0,121.0,212.5,114.0), Q(218.0,107.0,231.0,107.0), Q(242.0,107.0,263.0,134.0), Q(284.0,161.0,287.0,175.0), L(318.0,294.0), Q(319.0,297.0,319.0,299.0), Q(319.0,306.0,313.5,311.5), Q(308.0,317.0,301.0,317.0), Q(299.0,317.0,296.0,316.0), Z ];
-
-// pub const CADENZA_100 : &'static [PathSegment] = &[ M(194.0,44.0), Q(194.0,26.0,181.0,13.0), Q(168.0,0.0,150.0,0.0), Q(132.0,0.0,119.0,13.0), Q(106.0,26.0,106.0,44.0), Q(106.0,62.0,119.0,75.0), Q(132.0,88.0,150.0,88.0), Q(168.0,88.0,181.0,75.0), Q(194.0,62.0,194.0,44.0), Z , M(18.0,229.0), L(282.0,229.0), Q(289.0,229.0,294.5,223.0), Q(300.0,217.0,300.0,209.0), Q(300.0,201.0,294.5,195.0), Q(289.0,189.0,282.0,189.0), L(18.0,189.0), Q(11.0,189.0,5.5,195.0), Q(0.0,201.0,0.0,209.0), Q(0.0,217.0,5.5,223.0), Q(11.0,229.0,18.0,229.0), Z ];
-
-// pub const CADENZA_101 : &'static [PathSegment] = &[ M(801.0,38.0), Q(801.0,54.0,812.0,65.5), Q(823.0,77.0,839.0,77.0), Q(855.0,77.0,866.5,65.5), Q(878.0,54.0,878.0,38.0), Q(878.0,22.0,866.5,11.0), Q(855.0,0.0,839.0,0.0), Q(823.0,0.0,812.0,11.0), Q(801.0,22.0,801.0,38.0), Z , M(661.0,297.0), Q(578.0,400.0,466.0,420.0), Q(462.0,420.0,461.5,425.0), Q(461.0,430.0,463.0,434.0), Q(465.0,438.0,469.0,438.0), Q(542.0,425.0,612.0,388.0), Q(682.0,351.0,734.5,291.5), Q(787.0,232.0,798.0,166.0), Q(798.0,163.0,799.0,158.5), Q(800.0,154.0,800.5,150.0), Q(801.0,146.0,801.0,142.0), Q(801.0,88.0,767.5,44.0), Q(734.0,0.0,688.0,0.0), Q(662.0,0.0,638.5,17.0), Q(615.0,34.0,599.0,62.0), Q(594.0,71.0,583.0,71.0), Q(576.0,71.0,571.0,66.0), Q(515.0,16.0,504.0,10.0), Q(492.0,1.0,474.0,1.0), Q(438.0,1.0,419.0,61.0), Q(415.0,73.0,403.0,73.0), Q(396.0,73.0,391.0,69.0), Q(317.0,10.0,306.0,4.0), Q(296.0,-1.0,289.0,4.0), Q(281.0,11.0,268.5,43.5), Q(256.0,76.0,249.0,87.0), Q(244.0,95.0,235.0,95.0), Q(227.0,95.0,221.0,88.0), Q(186.0,46.0,176.0,36.0), Q(165.0,25.0,144.5,12.5), Q(124.0,0.0,112.0,0.0), Q(98.0,0.0,98.0,12.0), Q(98.0,29.0,128.0,59.0), Q(143.0,75.0,170.5,94.5), Q(198.0,114.0,218.0,119.0), Q(223.0,120.0,227.0,124.0), Q(229.0,126.0,234.5,132.0), Q(240.0,138.0,241.0,139.5), Q(242.0,141.0,245.5,145.5), Q(249.0,150.0,250.0,153.0), Q(251.0,156.0,252.0,160.0), Q(253.0,164.0,253.0,169.0), Q(253.0,194.0,215.5,247.0), Q(178.0,300.0,178.0,319.0), Q(178.0,358.0,212.0,452.0), Q(213.0,455.0,213.0,458.0), Q(213.0,465.0,207.5,470.5), Q(202.0,476.0,195.0,476.0), Q(193.0,476.0,191.0,476.0), Q(125.0,461.0,84.5,419.5), Q(44.0,378.0,44.0,339.0), Q(44.0,322.0,54.5,306.5), Q(65.0,291.0,83.0,291.0), Q(100.0,291.0,113.0,309.0), Q(126.0,327.0,126.0,350.0), Q(126.0,356.0,135.0,356.0), Q(144.0,356.0,144.0,350.0), Q(144.0,303.0,118.0,264.0), Q(92.0,225.0,61.0,225.0), Q(33.0,225.0,16.5,249.5), Q(0.0,274.0,0.0,303.0), Q(0.0,373.0,69.5,436.5), Q(139.0,500.0,251.0,500.0), Q(326.0,500.0,371.5,454.0), Q(417.0,408.0,417.0,356.0), Q(417.0,331.0,403.0,310.0), Q(389.0,289.0,365.0,289.0), Q(320.0,289.0,278.0,379.0), Q(277.0,382.0,280.5,385.5), Q(284.0,389.0,288.0,389.0), Q(292.0,389.0,294.0,386.0), Q(300.0,372.0,313.5,363.0), Q(327.0,354.0,340.0,354.0), Q(355.0,354.0,363.5,364.5), Q(372.0,375.0,372.0,390.0), Q(372.0,422.0,338.5,452.0), Q(305.0,482.0,256.0,482.0), Q(244.0,482.0,239.0,470.0), Q(218.0,411.0,218.0,374.0), Q(218.0,352.0,261.5,294.0), Q(305.0,236.0,305.0,210.0), Q(305.0,191.0,265.0,142.0), Q(261.0,137.0,261.0,130.0), Q(261.0,117.0,273.0,114.0), Q(324.0,98.0,349.0,76.0), Q(351.0,74.0,355.0,74.0), Q(367.0,74.0,398.0,100.0), Q(406.0,108.0,404.0,117.0), Q(400.0,134.0,400.0,155.0), Q(400.0,205.0,425.5,246.0), Q(451.0,287.0,496.0,287.0), Q(516.0,287.0,530.5,274.5), Q(545.0,262.0,545.0,242.0), Q(545.0,198.0,466.0,132.0), Q(455.0,123.0,462.0,110.0), Q(485.0,68.0,502.0,54.0), Q(509.0,47.0,520.0,54.0), Q(535.0,62.0,574.0,94.0), Q(582.0,100.0,580.0,111.0), Q(577.0,128.0,577.0,143.0), Q(577.0,179.0,599.5,213.5), Q(622.0,248.0,656.0,270.0), Q(666.0,276.0,666.0,285.0), Q(666.0,290.0,661.0,297.0), Z , M(467.0,157.0), Q(510.0,196.0,510.0,222.0), Q(510.0,236.0,500.0,245.0), Q(490.0,254.0,476.0,254.0), Q(455.0,254.0,444.0,238.0), Q(433.0,222.0,433.0,199.0), Q(433.0,183.0,438.0,165.0), Q(441.0,152.0,455.0,152.0), Q(462.0,152.0,467.0,157.0), Z , M(666.0,251.0), Q(629.0,213.0,629.0,143.0), Q(629.0,102.0,644.5,68.5), Q(660.0,35.0,688.0,35.0), Q(711.0,35.0,722.0,58.0), Q(733.0,81.0,733.0,116.0), Q(733.0,185.0,695.0,248.0), Q(690.0,257.0,680.0,257.0), Q(671.0,257.0,666.0,251.0), Z ];
-
-// pub const CADENZA_102 : &'static [PathSegment] = &[ M(37.0,79.0), Q(31.0,70.0,20.0,70.0), Q(12.0,70.0,6.0,76.0), Q(0.0,82.0,0.0,90.0), Q(0.0,95.0,4.0,101.0), L(97.0,241.0), Q(103.0,250.0,114.0,250.0), Q(123.0,250.0,129.0,243.0), L(212.0,143.0), Q(218.0,136.0,226.0,136.0), Q(235.0,136.0,240.0,144.0), L(305.0,241.0), Q(311.0,250.0,322.0,250.0), Q(331.0,250.0,337.0,243.0), L(421.0,143.0), Q(427.0,136.0,435.0,136.0), Q(444.0,136.0,449.0,144.0), L(514.0,241.0), Q(520.0,250.0,531.0,250.0), Q(540.0,250.0,546.0,243.0), L(629.0,143.0), Q(635.0,136.0,643.0,136.0), Q(652.0,136.0,657.0,144.0), L(675.0,171.0), Q(681.0,180.0,692.0,180.0), Q(700.0,180.0,706.0,174.0), Q(712.0,168.0,712.0,160.0), Q(712.0,155.0,708.0,149.0), L(615.0,9.0), Q(609.0,0.0,598.0,0.0), Q(589.0,0.0,583.0,7.0), L(500.0,107.0), Q(494.0,114.0,486.0,114.0), Q(477.0,114.0,472.0,106.0), L(407.0,9.0), Q(401.0,0.0,390.0,0.0), Q(381.0,0.0,375.0,7.0), L(291.0,107.0), Q(285.0,114.0,277.0,114.0), Q(268.0,114.0,263.0,106.0), L(198.0,9.0), Q(192.0,0.0,181.0,0.0), Q(172.0,0.0,166.0,7.0), L(83.0,107.0), Q(77.0,114.0,69.0,114.0), Q(60.0,114.0,55.0,106.0), L(37.0,79.0), Z ];
-
-// pub const CADENZA_103 : &'static [PathSegment] = &[ M(106.0,308.0), Q(128.0,325.0,150.0,324.0), Q(179.0,324.0,198.0,300.0), Q(206.0,289.0,223.0,289.0), Q(256.0,289.0,286.0,321.0), Q(289.0,325.0,295.0,324.0), Q(299.0,324.0,301.5,322.0), Q(304.0,320.0,303.0,316.0), Q(302.0,313.0,299.0,310.0), Q(157.0,164.0,129.0,58.0), Q(123.0,36.0,121.0,9.0), Q(121.0,5.0,117.5,1.5), Q(114.0,-2.0,110.0,-2.0), L(108.0,-2.0), L(107.0,-1.0), Q(76.0,9.0,62.0,8.0), Q(47.0,8.0,12.0,-1.0), L(10.0,-2.0), L(9.0,-2.0), Q(5.0,-2.0,3.0,1.5), Q(1.0,5.0,4.0,9.0), Q(62.0,94.0,179.0,212.0), Q(184.0,218.0,182.0,224.0), Q(180.0,231.0,172.0,230.0), Q(171.0,230.0,168.5,229.5), Q(166.0,229.0,165.0,229.0), Q(143.0,229.0,131.0,246.0), Q(116.0,266.0,99.0,266.0), Q(74.0,266.0,62.0,221.0), L(49.0,172.0), Q(46.0,162.0,37.0,162.0), Q(27.0,162.0,30.0,172.0), L(68.0,315.0), Q(71.0,325.0,81.0,324.0), Q(90.0,324.0,87.0,315.0), Q(85.0,310.0,93.0,306.0), Q(99.0,302.0,106.0,308.0), Z ];
-
-// pub const CADENZA_104 : &'static [PathSegment] = &[ M(87.0,46.0), Q(84.0,43.0,84.0,40.0), Q(84.0,37.0,87.0,34.0), L(196.0,-74.0), Q(199.0,-77.0,203.0,-77.0), Q(207.0,-77.0,210.0,-74.0), L(238.0,-46.0), Q(241.0,-43.0,241.0,-40.0), Q(241.0,-37.0,238.0,-34.0), L(129.0,74.0), Q(126.0,77.0,122.0,77.0), Q(118.0,77.0,115.0,74.0), L(87.0,46.0), Z , M(0.0,0.0), Q(0.0,3.0,3.0,6.0), Q(86.0,76.0,155.0,158.0), Q(158.0,161.0,163.0,161.0), Q(167.0,161.0,170.0,158.0), Q(239.0,76.0,322.0,6.0), Q(325.0,3.0,325.0,0.0), Q(325.0,-3.0,322.0,-6.0), Q(239.0,-76.0,170.0,-158.0), Q(167.0,-161.0,163.0,-161.0), Q(158.0,-161.0,155.0,-158.0), Q(86.0,-76.0,3.0,-6.0), Q(0.0,-3.0,0.0,0.0), Z ];
-
-// pub const CADENZA_105 : &'static [PathSegment] = &[ M(180.0,306.0), Q(153.0,306.0,136.5,276.5), Q(120.0,247.0,107.0,198.0), Q(105.0,192.0,102.5,180.0), Q(100.0,168.0,98.5,162.5), Q(97.0,157.0,93.5,145.0), Q(90.0,133.0,88.0,127.0), Q(82.0,104.0,79.0,88.0), Q(76.0,72.0,75.5,54.5), Q(75.0,37.0,81.5,28.0), Q(88.0,19.0,103.0,19.0), Q(130.0,19.0,146.0,48.5), Q(162.0,78.0,175.0,127.0), L(185.0,162.0), L(194.0,198.0), Q(200.0,221.0,203.0,237.0), Q(206.0,253.0,206.5,270.5), Q(207.0,288.0,200.5,297.0), Q(194.0,306.0,180.0,306.0), Z , M(185.0,325.0), Q(239.0,325.0,254.0,288.0), Q(269.0,251.0,269.0,220.0), Q(269.0,193.0,256.0,145.0), Q(243.0,97.0,197.5,48.5), Q(152.0,0.0,97.5,0.0), Q(43.0,0.0,28.5,37.0), Q(14.0,74.0,14.0,104.0), Q(14.0,131.0,27.0,179.5), Q(40.0,228.0,85.5,276.5), Q(131.0,325.0,185.0,325.0), Z ];
-
-// pub const CADENZA_106 : &'static [PathSegment] = &[ M(84.0,236.0), L(354.0,236.0), L(354.0,184.0), L(84.0,184.0), L(84.0,236.0), Z , M(84.0,120.0), L(354.0,120.0), L(354.0,68.0), L(84.0,68.0), L(84.0,120.0), Z ];
-
-// pub const CADENZA_107 : &'static [PathSegment] = &[ M(157.0,36.0), L(271.0,134.0), Q(277.0,138.0,279.0,138.0), Q(283.0,138.0,289.0,134.0), L(308.0,118.0), Q(313.0,113.0,313.0,105.0), Q(313.0,100.0,308.0,95.0), L(198.0,0.0), L(308.0,-95.0), Q(313.0,-100.0,313.0,-105.0), Q(313.0,-113.0,308.0,-118.0), L(289.0,-134.0), Q(283.0,-138.0,279.0,-138.0), Q(277.0,-138.0,271.0,-134.0), L(157.0,-36.0), L(42.0,-134.0), Q(36.0,-138.0,33.0,-138.0), Q(30.0,-138.0,24.0,-134.0), L(5.0,-118.0), Q(0.0,-113.0,0.0,-105.0), Q(0.0,-100.0,5.0,-95.0), L(115.0,0.0), L(5.0,95.0), Q(0.0,100.0,0.0,105.0), Q(0.0,113.0,5.0,118.0), L(24.0,134.0), Q(30.0,138.0,33.0,138.0), Q(36.0,138.0,42.0,134.0), L(157.0,36.0), Z ];
-
-// pub const CADENZA_108 : &'static [PathSegment] = &[ M(10.0,-263.0), Q(0.0,-263.0,0.0,-252.0), Q(0.0,-249.0,1.0,-247.0), L(310.0,258.0), Q(313.0,263.0,319.0,263.0), L(428.0,263.0), Q(438.0,263.0,438.0,252.0), Q(438.0,251.0,436.0,247.0), L(128.0,-258.0), Q(125.0,-263.0,119.0,-263.0), L(10.0,-263.0), Z ];
-
-// pub const CADENZA_109 : &'static [PathSegment] = &[ M(360.0,816.0), Q(321.0,804.0,289.0,804.0), Q(254.0,804.0,228.0,826.5), Q(202.0,849.0,202.0,883.0), Q(202.0,913.0,223.0,934.0), Q(244.0,955.0,274.0,955.0), Q(293.0,955.0,309.0,944.5), Q(325.0,934.0,331.0,916.0), Q(334.0,909.0,337.5,890.0), Q(341.0,871.0,346.5,864.0), Q(352.0,857.0,365.0,857.0), Q(373.0,857.0,380.5,868.5), Q(388.0,880.0,393.0,893.5), Q(398.0,907.0,406.0,918.0), Q(414.0,929.0,422.0,929.0), Q(423.0,929.0,425.0,929.0), Q(432.0,928.0,435.0,920.0), L(198.0,-500.0), Q(187.0,-510.0,170.0,-510.0), Q(154.0,-510.0,143.0,-500.0), L(198.0,-201.0), Q(200.0,-192.0,194.5,-186.0), Q(189.0,-180.0,181.0,-180.0), Q(178.0,-180.0,175.0,-181.0), Q(127.0,-196.0,87.0,-196.0), Q(52.0,-196.0,26.0,-173.5), Q(0.0,-151.0,0.0,-117.0), Q(0.0,-87.0,21.0,-66.0), Q(42.0,-45.0,72.0,-45.0), Q(91.0,-45.0,107.0,-55.5), Q(123.0,-66.0,129.0,-84.0), Q(132.0,-91.0,135.5,-110.0), Q(139.0,-129.0,144.5,-136.0), Q(150.0,-143.0,163.0,-143.0), Q(176.0,-143.0,198.5,-113.0), Q(221.0,-83.0,223.0,-67.0), L(244.0,48.0), Q(245.0,56.0,239.5,63.0), Q(234.0,70.0,226.0,70.0), Q(223.0,70.0,221.0,69.0), Q(168.0,54.0,137.0,54.0), Q(102.0,54.0,76.0,76.5), Q(50.0,99.0,50.0,133.0), Q(50.0,163.0,71.0,184.0), Q(92.0,205.0,122.0,205.0), Q(141.0,205.0,157.0,194.5), Q(173.0,184.0,179.0,166.0), Q(182.0,159.0,185.5,140.0), Q(189.0,121.0,194.5,114.0), Q(200.0,107.0,213.0,107.0), Q(225.0,107.0,245.5,136.0), Q(266.0,165.0,269.0,180.0), L(290.0,296.0), Q(291.0,305.0,286.0,311.5), Q(281.0,318.0,273.0,318.0), Q(270.0,318.0,267.0,317.0), Q(219.0,304.0,188.0,304.0), Q(153.0,304.0,127.0,326.5), Q(101.0,349.0,101.0,383.0), Q(101.0,413.0,122.0,434.0), Q(143.0,455.0,173.0,455.0), Q(192.0,455.0,208.0,444.5), Q(224.0,434.0,230.0,416.0), Q(233.0,409.0,236.5,390.0), Q(240.0,371.0,245.5,364.0), Q(251.0,357.0,264.0,357.0), Q(275.0,357.0,293.5,385.0), Q(312.0,413.0,315.0,428.0), L(337.0,546.0), Q(339.0,555.0,333.5,561.5), Q(328.0,568.0,319.0,568.0), Q(316.0,568.0,314.0,567.0), Q(268.0,554.0,238.0,554.0), Q(203.0,554.0,177.0,576.5), Q(151.0,599.0,151.0,633.0), Q(151.0,663.0,172.0,684.0), Q(193.0,705.0,223.0,705.0), Q(242.0,705.0,258.0,694.5), Q(274.0,684.0,280.0,666.0), Q(283.0,659.0,286.5,640.0), Q(290.0,621.0,295.5,614.0), Q(301.0,607.0,314.0,607.0), Q(324.0,607.0,341.0,634.0), Q(358.0,661.0,361.0,675.0), L(383.0,796.0), Q(385.0,805.0,379.5,811.0), Q(374.0,817.0,366.0,817.0), Q(363.0,817.0,360.0,816.0), Z ];
-
-// pub const CADENZA_110 : &'static [PathSegment] = &[ M(469.0,8.5), Q(469.0,174.0,400.0,243.0), Q(397.0,246.0,397.0,250.0), Q(397.0,263.0,412.0,263.0), Q(418.0,263.0,423.0,257.0), Q(527.0,127.0,527.0,-2.5), Q(527.0,-132.0,423.0,-257.0), Q(418.0,-263.0,412.0,-263.0), Q(396.0,-263.0,397.0,-250.0), Q(397.0,-246.0,400.0,-243.0), Q(469.0,-157.0,469.0,8.5), Z , M(309.0,0.0), Q(309.0,-17.0,335.0,-27.0), Q(361.0,-37.0,390.0,-37.0), Q(399.0,-37.0,400.0,-47.0), L(404.0,-125.0), Q(405.0,-135.0,395.0,-135.0), L(394.0,-135.0), L(316.0,-131.0), Q(306.0,-130.0,306.0,-121.0), Q(306.0,-93.0,296.0,-66.5), Q(286.0,-40.0,269.0,-40.0), Q(252.0,-40.0,242.0,-66.0), Q(232.0,-92.0,232.0,-121.0), Q(232.0,-131.0,223.0,-131.0), L(144.0,-135.0), L(143.0,-135.0), Q(133.0,-135.0,134.0,-125.0), L(138.0,-47.0), Q(139.0,-37.0,148.0,-37.0), Q(176.0,-37.0,202.5,-27.0), Q(229.0,-17.0,229.0,0.0), Q(229.0,17.0,203.0,27.0), Q(177.0,37.0,148.0,37.0), Q(139.0,37.0,138.0,47.0), L(134.0,125.0), Q(133.0,135.0,143.0,135.0), L(144.0,135.0), L(223.0,131.0), Q(232.0,131.0,232.0,121.0), Q(232.0,93.0,242.0,66.5), Q(252.0,40.0,269.0,40.0), Q(286.0,40.0,296.0,66.5), Q(306.0,93.0,306.0,121.0), Q(306.0,130.0,316.0,131.0), L(394.0,135.0), L(395.0,135.0), Q(405.0,135.0,404.0,125.0), L(400.0,47.0), Q(399.0,37.0,390.0,37.0), Q(362.0,37.0,335.5,27.0), Q(309.0,17.0,309.0,0.0), Z , M(57.0,-8.5), Q(57.0,-174.0,126.0,-243.0), Q(129.0,-246.0,129.0,-250.0), Q(129.0,-263.0,115.0,-263.0), Q(108.0,-263.0,103.0,-257.0), Q(0.0,-129.0,0.0,2.0), Q(0.0,133.0,103.0,257.0), Q(108.0,263.0,115.0,263.0), Q(130.0,263.0,129.0,250.0), Q(129.0,246.0,126.0,243.0), Q(57.0,157.0,57.0,-8.5), Z ];
-
-// pub const CADENZA_111 : &'static [PathSegment] = &[ M(42.0,14.0), L(1.0,234.0), Q(1.0,235.0,0.5,238.0), Q(0.0,241.0,0.0,242.0), Q(0.0,257.0,16.5,267.5), Q(33.0,278.0,56.0,278.0), Q(79.0,278.0,95.5,267.5), Q(112.0,257.0,112.0,242.0), Q(112.0,241.0,111.5,238.0), Q(111.0,235.0,111.0,234.0), L(70.0,14.0), Q(68.0,0.0,56.0,0.0), Q(44.0,0.0,42.0,14.0), Z ];
-
-// pub const CADENZA_112 : &'static [PathSegment] = &[ M(781.0,250.0), Q(742.0,250.0,710.5,187.5), Q(679.0,125.0,679.0,70.0), Q(679.0,32.0,709.0,32.0), Q(731.0,32.0,750.5,53.5), Q(770.0,75.0,781.5,105.5), Q(793.0,136.0,799.5,166.0), Q(806.0,196.0,806.0,217.0), Q(806.0,250.0,781.0,250.0), Z , M(533.0,153.0), Q(526.0,136.0,509.0,136.0), Q(495.0,136.0,495.0,148.0), Q(495.0,150.0,497.0,156.0), Q(522.0,212.0,547.0,246.0), Q(543.0,245.0,539.0,245.0), L(472.0,245.0), Q(458.0,245.0,455.0,232.0), L(409.0,82.0), Q(382.0,-7.0,319.5,-90.0), Q(257.0,-173.0,191.0,-173.0), Q(156.0,-173.0,130.0,-153.5), Q(104.0,-134.0,104.0,-104.0), Q(104.0,-72.0,117.5,-49.5), Q(131.0,-27.0,156.0,-27.0), Q(174.0,-27.0,187.0,-40.0), Q(200.0,-53.0,200.0,-71.0), Q(200.0,-78.0,197.0,-83.5), Q(194.0,-89.0,191.5,-91.0), Q(189.0,-93.0,182.5,-97.5), Q(176.0,-102.0,174.0,-103.0), Q(163.0,-111.0,168.0,-124.0), Q(172.0,-136.0,185.0,-136.0), L(191.0,-136.0), Q(208.0,-136.0,220.5,-127.5), Q(233.0,-119.0,241.5,-99.5), Q(250.0,-80.0,254.0,-67.5), Q(258.0,-55.0,264.0,-28.0), L(326.0,223.0), Q(327.0,225.0,327.0,228.0), Q(327.0,235.0,322.0,240.0), Q(317.0,245.0,309.0,245.0), L(272.0,245.0), Q(253.0,245.0,253.0,264.0), Q(253.0,283.0,272.0,283.0), L(330.0,283.0), Q(342.0,283.0,347.0,295.0), Q(376.0,368.0,433.5,421.0), Q(491.0,474.0,556.0,474.0), Q(591.0,474.0,617.0,454.5), Q(643.0,435.0,643.0,405.0), Q(643.0,373.0,629.5,350.5), Q(616.0,328.0,591.0,328.0), Q(573.0,328.0,560.0,340.5), Q(547.0,353.0,547.0,371.0), Q(547.0,380.0,551.5,385.5), Q(556.0,391.0,564.5,397.0), Q(573.0,403.0,576.0,405.0), Q(586.0,415.0,580.0,427.0), Q(576.0,437.0,563.0,437.0), L(556.0,437.0), Q(543.0,437.0,532.0,432.5), Q(521.0,428.0,513.0,416.0), Q(505.0,404.0,500.0,395.0), Q(495.0,386.0,489.5,366.0), Q(484.0,346.0,482.0,337.5), Q(480.0,329.0,475.0,305.0), Q(474.0,302.0,474.0,300.0), Q(474.0,292.0,479.5,287.5), Q(485.0,283.0,492.0,283.0), L(539.0,283.0), Q(558.0,283.0,558.0,264.0), Q(558.0,261.0,558.0,259.0), Q(593.0,299.0,629.0,299.0), Q(669.0,299.0,687.0,268.0), Q(691.0,262.0,699.0,260.5), Q(707.0,259.0,713.0,264.0), Q(756.0,299.0,798.0,299.0), Q(848.0,299.0,876.5,267.0), Q(905.0,235.0,905.0,185.0), Q(905.0,152.0,893.0,118.0), Q(877.0,73.0,832.0,32.5), Q(787.0,-8.0,746.0,-8.0), Q(703.0,-8.0,673.0,9.0), Q(667.0,12.0,662.0,12.0), Q(649.0,12.0,646.0,3.0), L(617.0,-83.0), Q(616.0,-85.0,616.0,-90.0), Q(616.0,-114.0,660.0,-118.0), Q(673.0,-119.0,673.0,-132.0), Q(673.0,-146.0,660.0,-146.0), Q(641.0,-146.0,602.0,-143.5), Q(563.0,-141.0,544.0,-141.0), Q(524.0,-141.0,485.5,-143.5), Q(447.0,-146.0,429.0,-146.0), Q(415.0,-146.0,415.0,-132.0), Q(415.0,-127.0,418.0,-123.5), Q(421.0,-120.0,427.5,-118.5), Q(434.0,-117.0,438.5,-116.5), Q(443.0,-116.0,452.0,-116.0), Q(461.0,-116.0,463.0,-116.0), Q(509.0,-114.0,517.0,-91.0), L(619.0,216.0), Q(621.0,223.0,621.0,229.0), Q(621.0,236.0,617.0,240.5), Q(613.0,245.0,610.0,246.0), Q(607.0,247.0,604.0,247.0), Q(590.0,247.0,567.5,214.5), Q(545.0,182.0,533.0,153.0), Z , M(180.0,216.0), Q(180.0,221.0,184.5,230.5), Q(189.0,240.0,189.0,245.0), Q(189.0,268.0,171.0,268.0), Q(151.0,268.0,134.5,255.0), Q(118.0,242.0,118.0,224.0), Q(118.0,206.0,149.0,187.0), Q(181.0,169.0,199.0,151.0), Q(217.0,133.0,217.0,107.0), Q(217.0,61.0,184.0,30.5), Q(151.0,0.0,103.0,0.0), Q(70.0,0.0,46.0,19.5), Q(22.0,39.0,22.0,68.0), Q(22.0,85.0,30.5,97.5), Q(39.0,110.0,53.0,110.0), Q(66.0,110.0,75.5,101.0), Q(85.0,92.0,85.0,78.0), Q(85.0,72.0,74.5,64.0), Q(64.0,56.0,64.0,49.0), Q(64.0,27.0,99.0,27.0), Q(124.0,27.0,140.5,40.0), Q(157.0,53.0,157.0,75.0), Q(157.0,91.0,141.5,105.0), Q(126.0,119.0,107.0,127.5), Q(88.0,136.0,72.5,153.0), Q(57.0,170.0,57.0,192.0), Q(57.0,232.0,89.0,262.0), Q(121.0,292.0,162.0,292.0), Q(201.0,292.0,221.0,273.0), Q(241.0,254.0,241.0,230.0), Q(241.0,215.0,233.0,204.5), Q(225.0,194.0,211.0,194.0), Q(198.0,194.0,189.0,200.5), Q(180.0,207.0,180.0,216.0), Z ];
-
-// pub const CADENZA_113 : &'static [PathSegment] = &[ M(41.0,120.0), L(113.0,135.0), L(115.0,135.0), Q(127.0,135.0,127.0,123.0), L(131.0,-250.0), Q(122.0,-255.0,114.5,-255.0), Q(107.0,-255.0,98.0,-250.0), L(100.0,-132.0), Q(100.0,-127.0,96.5,-123.5), Q(93.0,-120.0,88.0,-120.0), Q(87.0,-120.0,86.0,-120.0), L(14.0,-135.0), L(12.0,-135.0), Q(0.0,-135.0,0.0,-124.0), L(-5.0,250.0), Q(4.0,255.0,12.0,255.0), Q(19.0,255.0,29.0,250.0), L(27.0,133.0), Q(27.0,128.0,30.5,124.0), Q(34.0,120.0,38.0,120.0), Q(40.0,120.0,41.0,120.0), Z , M(38.0,-67.0), L(91.0,-55.0), Q(101.0,-53.0,101.0,-43.0), L(103.0,54.0), Q(103.0,59.0,99.0,63.0), Q(95.0,67.0,89.0,66.0), L(36.0,55.0), Q(26.0,53.0,26.0,43.0), L(24.0,-55.0), Q(24.0,-60.0,28.5,-64.0), Q(33.0,-68.0,38.0,-67.0), Z ];
-
-// pub const CADENZA_114 : &'static [PathSegment] = &[ M(143.0,47.0), Q(141.0,46.0,139.0,45.0), Q(136.0,44.0,134.0,44.0), L(133.0,43.0), L(132.0,43.0), Q(98.0,28.0,78.5,1.5), Q(59.0,-25.0,59.0,-56.0), Q(59.0,-91.0,81.5,-111.5), Q(104.0,-132.0,143.0,-132.0), Q(186.0,-132.0,215.0,-108.0), Q(244.0,-84.0,244.0,-50.0), Q(244.0,-9.0,197.0,20.0), Q(182.0,29.0,143.0,47.0), Z , M(210.0,100.0), Q(214.0,102.0,219.0,103.0), Q(247.0,116.0,264.0,142.0), Q(281.0,168.0,282.0,197.0), Q(282.0,225.0,262.0,243.0), Q(242.0,261.0,211.0,261.0), Q(175.0,261.0,151.0,239.0), Q(126.0,217.0,126.0,187.0), Q(126.0,162.0,145.0,142.5), Q(164.0,123.0,210.0,100.0), Z , M(245.0,80.0), Q(273.0,61.0,285.0,46.0), Q(307.0,16.0,307.0,-21.0), Q(307.0,-85.0,259.0,-126.0), Q(211.0,-167.0,136.0,-168.0), Q(74.0,-168.0,37.0,-140.0), Q(0.0,-112.0,0.0,-64.0), Q(0.0,24.0,111.0,70.0), Q(89.0,87.0,79.0,106.0), Q(68.0,127.0,67.0,157.0), Q(67.0,216.0,110.5,256.0), Q(154.0,296.0,216.0,296.0), Q(268.0,296.0,303.0,268.0), Q(337.0,239.0,337.0,194.0), Q(337.0,142.0,294.0,108.0), Q(279.0,97.0,245.0,80.0), Z ];
-
-// pub const CADENZA_115 : &'static [PathSegment] = &[ M(570.0,-125.0), Q(570.0,-104.0,585.0,-89.0), Q(600.0,-74.0,621.0,-74.0), Q(642.0,-74.0,657.0,-89.0), Q(672.0,-104.0,672.0,-125.0), Q(672.0,-146.0,657.0,-161.0), Q(642.0,-176.0,621.0,-176.0), Q(600.0,-176.0,585.0,-161.0), Q(570.0,-146.0,570.0,-125.0), Z , M(570.0,125.0), Q(570.0,146.0,585.0,161.0), Q(600.0,176.0,621.0,176.0), Q(642.0,176.0,657.0,161.0), Q(672.0,146.0,672.0,125.0), Q(672.0,104.0,657.0,89.0), Q(642.0,74.0,621.0,74.0), Q(600.0,74.0,585.0,89.0), Q(570.0,104.0,570.0,125.0), Z , M(122.0,119.0), Q(167.0,119.0,196.0,90.0), Q(225.0,61.0,225.0,19.0), Q(225.0,-25.0,196.0,-56.0), Q(167.0,-87.0,125.0,-87.0), Q(80.0,-87.0,46.5,-56.0), Q(13.0,-25.0,13.0,19.0), Q(13.0,127.0,85.0,195.0), Q(145.0,251.0,229.0,261.0), Q(216.0,265.0,206.0,273.0), Q(182.0,291.0,182.0,322.0), Q(182.0,380.0,254.0,410.0), Q(240.0,421.0,233.0,433.0), Q(226.0,447.0,226.0,466.0), Q(226.0,504.0,254.0,530.0), Q(282.0,556.0,322.0,557.0), Q(356.0,557.0,379.0,538.0), Q(401.0,519.0,401.0,490.0), Q(401.0,456.0,373.0,434.0), Q(363.0,427.0,341.0,416.0), Q(359.0,404.0,367.0,394.0), Q(381.0,374.0,382.0,350.0), Q(382.0,308.0,350.0,282.0), Q(332.0,267.0,310.0,260.0), Q(400.0,250.0,462.0,194.0), Q(538.0,124.0,538.0,12.0), Q(538.0,-342.0,24.0,-569.0), Q(17.0,-572.0,13.0,-572.0), Q(0.0,-572.0,0.0,-559.0), Q(0.0,-552.0,13.0,-544.0), Q(93.0,-497.0,145.5,-461.0), Q(198.0,-425.0,254.0,-374.0), Q(310.0,-323.0,342.0,-271.5), Q(374.0,-220.0,395.0,-150.0), Q(416.0,-80.0,416.0,2.0), Q(416.0,106.0,370.5,169.0), Q(325.0,232.0,246.0,232.0), Q(198.0,232.0,159.0,209.5), Q(120.0,187.0,105.0,148.0), Q(101.0,135.0,106.0,128.0), Q(113.0,119.0,122.0,119.0), Z , M(275.0,395.0), Q(274.0,394.0,272.0,393.0), Q(270.0,392.0,269.0,393.0), L(268.0,392.0), L(268.0,392.0), Q(246.0,382.0,233.0,365.0), Q(220.0,348.0,220.0,328.0), Q(220.0,305.0,235.0,291.5), Q(250.0,278.0,275.0,278.0), Q(303.0,278.0,322.0,294.0), Q(341.0,310.0,341.0,332.0), Q(341.0,359.0,310.0,377.0), Q(300.0,383.0,275.0,395.0), Z , M(318.0,429.0), Q(321.0,430.0,324.0,431.0), Q(342.0,439.0,353.5,456.0), Q(365.0,473.0,365.0,492.0), Q(365.0,510.0,352.0,522.0), Q(339.0,534.0,319.0,534.0), Q(296.0,534.0,280.0,519.0), Q(264.0,505.0,264.0,486.0), Q(264.0,470.0,276.0,457.0), Q(288.0,444.0,318.0,429.0), Z ];
-
-// pub const CADENZA_116 : &'static [PathSegment] = &[ M(182.0,394.0), Q(182.0,371.0,166.0,355.0), Q(150.0,339.0,127.0,339.0), Q(104.0,339.0,88.0,355.0), Q(72.0,371.0,72.0,394.0), Q(72.0,417.0,88.0,433.0), Q(104.0,449.0,127.0,449.0), Q(150.0,449.0,166.0,433.0), Q(182.0,417.0,182.0,394.0), Z , M(175.0,155.0), L(226.0,268.0), Q(229.0,275.0,237.0,275.0), Q(250.0,275.0,250.0,263.0), Q(250.0,259.0,249.0,257.0), L(136.0,7.0), Q(133.0,0.0,125.0,0.0), Q(117.0,0.0,114.0,7.0), L(1.0,257.0), Q(0.0,259.0,0.0,263.0), Q(0.0,275.0,13.0,275.0), L(79.0,275.0), Q(88.0,275.0,91.0,268.0), L(142.0,155.0), Q(147.0,144.0,159.0,144.0), Q(170.0,144.0,175.0,155.0), Z ];
-
-// pub const CADENZA_117 : &'static [PathSegment] = &[ M(21.0,-72.0), L(21.0,-586.0), L(0.0,-586.0), L(0.0,-23.0), Q(0.0,17.0,44.0,54.5), Q(88.0,92.0,135.0,92.0), Q(154.0,92.0,171.0,87.0), Q(188.0,82.0,203.0,65.5), Q(218.0,49.0,218.0,23.0), Q(218.0,-14.0,173.5,-53.0), Q(129.0,-92.0,84.0,-92.0), Q(65.0,-92.0,48.0,-87.0), Q(34.0,-84.0,21.0,-72.0), Z ];
-
-// pub const CADENZA_118 : &'static [PathSegment] = &[ M(540.0,250.0), Q(501.0,250.0,469.5,187.5), Q(438.0,125.0,438.0,70.0), Q(438.0,32.0,468.0,32.0), Q(490.0,32.0,509.5,53.5), Q(529.0,75.0,540.5,105.5), Q(552.0,136.0,558.5,166.0), Q(565.0,196.0,565.0,217.0), Q(565.0,250.0,540.0,250.0), Z , M(292.0,153.0), Q(285.0,136.0,268.0,136.0), Q(254.0,136.0,254.0,148.0), Q(254.0,150.0,256.0,156.0), Q(281.0,212.0,306.0,246.0), Q(302.0,245.0,298.0,245.0), L(231.0,245.0), Q(217.0,245.0,214.0,232.0), L(168.0,82.0), Q(141.0,-7.0,78.5,-90.0), Q(16.0,-173.0,-50.0,-173.0), Q(-85.0,-173.0,-111.0,-153.5), Q(-137.0,-134.0,-137.0,-104.0), Q(-137.0,-72.0,-123.5,-49.5), Q(-110.0,-27.0,-85.0,-27.0), Q(-67.0,-27.0,-54.0,-40.0), Q(-41.0,-53.0,-41.0,-71.0), Q(-41.0,-78.0,-44.0,-83.5), Q(-47.0,-89.0,-49.5,-91.0), Q(-52.0,-93.0,-58.5,-97.5), Q(-65.0,-102.0,-67.0,-103.0), Q(-78.0,-111.0,-73.0,-124.0), Q(-69.0,-136.0,-56.0,-136.0), L(-50.0,-136.0), Q(-33.0,-136.0,-20.5,-127.5), Q(-8.0,-119.0,0.5,-99.5), Q(9.0,-80.0,13.0,-67.5), Q(17.0,-55.0,23.0,-28.0), L(85.0,223.0), Q(86.0,225.0,86.0,228.0), Q(86.0,235.0,81.0,240.0), Q(76.0,245.0,68.0,245.0), L(31.0,245.0), Q(12.0,245.0,12.0,264.0), Q(12.0,283.0,31.0,283.0), L(89.0,283.0), Q(101.0,283.0,106.0,295.0), Q(135.0,368.0,192.5,421.0), Q(250.0,474.0,315.0,474.0), Q(350.0,474.0,376.0,454.5), Q(402.0,435.0,402.0,405.0), Q(402.0,373.0,388.5,350.5), Q(375.0,328.0,350.0,328.0), Q(332.0,328.0,319.0,340.5), Q(306.0,353.0,306.0,371.0), Q(306.0,380.0,310.5,385.5), Q(315.0,391.0,323.5,397.0), Q(332.0,403.0,335.0,405.0), Q(345.0,415.0,339.0,427.0), Q(335.0,437.0,322.0,437.0), L(315.0,437.0), Q(302.0,437.0,291.0,432.5), Q(280.0,428.0,272.0,416.0), Q(264.0,404.0,259.0,395.0), Q(254.0,386.0,248.5,366.0), Q(243.0,346.0,241.0,337.5), Q(239.0,329.0,234.0,305.0), Q(233.0,302.0,233.0,300.0), Q(233.0,292.0,238.5,287.5), Q(244.0,283.0,251.0,283.0), L(298.0,283.0), Q(317.0,283.0,317.0,264.0), Q(317.0,261.0,317.0,259.0), Q(352.0,299.0,388.0,299.0), Q(428.0,299.0,446.0,268.0), Q(450.0,262.0,458.0,260.5), Q(466.0,259.0,472.0,264.0), Q(515.0,299.0,557.0,299.0), Q(607.0,299.0,635.5,267.0), Q(664.0,235.0,664.0,185.0), Q(664.0,152.0,652.0,118.0), Q(636.0,73.0,591.0,32.5), Q(546.0,-8.0,505.0,-8.0), Q(462.0,-8.0,432.0,9.0), Q(426.0,12.0,421.0,12.0), Q(408.0,12.0,405.0,3.0), L(376.0,-83.0), Q(375.0,-85.0,375.0,-90.0), Q(375.0,-114.0,419.0,-118.0), Q(432.0,-119.0,432.0,-132.0), Q(432.0,-146.0,419.0,-146.0), Q(400.0,-146.0,361.0,-143.5), Q(322.0,-141.0,303.0,-141.0), Q(283.0,-141.0,244.5,-143.5), Q(206.0,-146.0,188.0,-146.0), Q(174.0,-146.0,174.0,-132.0), Q(174.0,-127.0,177.0,-123.5), Q(180.0,-120.0,186.5,-118.5), Q(193.0,-117.0,197.5,-116.5), Q(202.0,-116.0,211.0,-116.0), Q(220.0,-116.0,222.0,-116.0), Q(268.0,-114.0,276.0,-91.0), L(378.0,216.0), Q(380.0,223.0,380.0,229.0), Q(380.0,236.0,376.0,240.5), Q(372.0,245.0,369.0,246.0), Q(366.0,247.0,363.0,247.0), Q(349.0,247.0,326.5,214.5), Q(304.0,182.0,292.0,153.0), Z ];
-
-// pub const CADENZA_119 : &'static [PathSegment] = &[ M(931.0,295.0), Q(960.0,368.0,1017.5,421.0), Q(1075.0,474.0,1140.0,474.0), Q(1175.0,474.0,1201.0,454.5), Q(1227.0,435.0,1227.0,405.0), Q(1227.0,373.0,1213.5,350.5), Q(1200.0,328.0,1175.0,328.0), Q(1157.0,328.0,1144.0,340.5), Q(1131.0,353.0,1131.0,371.0), Q(1131.0,380.0,1135.5,385.5), Q(1140.0,391.0,1148.5,397.0), Q(1157.0,403.0,1160.0,405.0), Q(1170.0,415.0,1164.0,427.0), Q(1160.0,437.0,1147.0,437.0), L(1140.0,437.0), Q(1127.0,437.0,1116.0,432.5), Q(1105.0,428.0,1097.0,416.0), Q(1089.0,404.0,1084.0,395.0), Q(1079.0,386.0,1073.5,366.0), Q(1068.0,346.0,1066.0,337.5), Q(1064.0,329.0,1059.0,305.0), Q(1058.0,302.0,1058.0,300.0), Q(1058.0,292.0,1063.5,287.5), Q(1069.0,283.0,1076.0,283.0), L(1123.0,283.0), Q(1142.0,283.0,1142.0,264.0), Q(1142.0,245.0,1123.0,245.0), L(1056.0,245.0), Q(1042.0,245.0,1039.0,232.0), L(993.0,82.0), Q(966.0,-7.0,903.5,-90.0), Q(841.0,-173.0,775.0,-173.0), Q(740.0,-173.0,714.0,-153.5), Q(688.0,-134.0,688.0,-104.0), Q(688.0,-72.0,701.5,-49.5), Q(715.0,-27.0,740.0,-27.0), Q(758.0,-27.0,771.0,-40.0), Q(784.0,-53.0,784.0,-71.0), Q(784.0,-78.0,781.0,-83.5), Q(778.0,-89.0,775.5,-91.0), Q(773.0,-93.0,766.5,-97.5), Q(760.0,-102.0,758.0,-103.0), Q(747.0,-111.0,752.0,-124.0), Q(756.0,-136.0,769.0,-136.0), L(775.0,-136.0), Q(792.0,-136.0,804.5,-127.5), Q(817.0,-119.0,825.5,-99.5), Q(834.0,-80.0,838.0,-67.5), Q(842.0,-55.0,848.0,-28.0), L(910.0,223.0), Q(911.0,225.0,911.0,228.0), Q(911.0,235.0,906.0,240.0), Q(901.0,245.0,893.0,245.0), L(856.0,245.0), Q(854.0,245.0,852.0,245.0), Q(850.0,245.0,848.0,245.0), L(781.0,245.0), Q(767.0,245.0,764.0,232.0), L(718.0,82.0), Q(691.0,-7.0,628.5,-90.0), Q(566.0,-173.0,500.0,-173.0), Q(465.0,-173.0,439.0,-153.5), Q(413.0,-134.0,413.0,-104.0), Q(413.0,-72.0,426.5,-49.5), Q(440.0,-27.0,465.0,-27.0), Q(483.0,-27.0,496.0,-40.0), Q(509.0,-53.0,509.0,-71.0), Q(509.0,-78.0,506.0,-83.5), Q(503.0,-89.0,500.5,-91.0), Q(498.0,-93.0,491.5,-97.5), Q(485.0,-102.0,483.0,-103.0), Q(472.0,-111.0,477.0,-124.0), Q(481.0,-136.0,494.0,-136.0), L(500.0,-136.0), Q(517.0,-136.0,529.5,-127.5), Q(542.0,-119.0,550.5,-99.5), Q(559.0,-80.0,563.0,-67.5), Q(567.0,-55.0,573.0,-28.0), L(635.0,223.0), Q(636.0,225.0,636.0,228.0), Q(636.0,235.0,631.0,240.0), Q(626.0,245.0,618.0,245.0), L(581.0,245.0), Q(579.0,245.0,577.0,245.0), Q(575.0,245.0,573.0,245.0), L(506.0,245.0), Q(492.0,245.0,489.0,232.0), L(443.0,82.0), Q(416.0,-7.0,353.5,-90.0), Q(291.0,-173.0,225.0,-173.0), Q(190.0,-173.0,164.0,-153.5), Q(138.0,-134.0,138.0,-104.0), Q(138.0,-72.0,151.5,-49.5), Q(165.0,-27.0,190.0,-27.0), Q(208.0,-27.0,221.0,-40.0), Q(234.0,-53.0,234.0,-71.0), Q(234.0,-78.0,231.0,-83.5), Q(228.0,-89.0,225.5,-91.0), Q(223.0,-93.0,216.5,-97.5), Q(210.0,-102.0,208.0,-103.0), Q(197.0,-111.0,202.0,-124.0), Q(206.0,-136.0,219.0,-136.0), L(225.0,-136.0), Q(242.0,-136.0,254.5,-127.5), Q(267.0,-119.0,275.5,-99.5), Q(284.0,-80.0,288.0,-67.5), Q(292.0,-55.0,298.0,-28.0), L(360.0,223.0), Q(361.0,225.0,361.0,228.0), Q(361.0,235.0,356.0,240.0), Q(351.0,245.0,343.0,245.0), L(306.0,245.0), Q(304.0,245.0,302.0,245.0), Q(300.0,245.0,298.0,245.0), L(231.0,245.0), Q(217.0,245.0,214.0,232.0), L(168.0,82.0), Q(141.0,-7.0,78.5,-90.0), Q(16.0,-173.0,-50.0,-173.0), Q(-85.0,-173.0,-111.0,-153.5), Q(-137.0,-134.0,-137.0,-104.0), Q(-137.0,-72.0,-123.5,-49.5), Q(-110.0,-27.0,-85.0,-27.0), Q(-67.0,-27.0,-54.0,-40.0), Q(-41.0,-53.0,-41.0,-71.0), Q(-41.0,-78.0,-44.0,-83.5), Q(-47.0,-89.0,-49.5,-91.0), Q(-52.0,-93.0,-58.5,-97.5), Q(-65.0,-102.0,-67.0,-103.0), Q(-78.0,-111.0,-73.0,-124.0), Q(-69.0,-136.0,-56.0,-136.0), L(-50.0,-136.0), Q(-33.0,-136.0,-20.5,-127.5), Q(-8.0,-119.0,0.5,-99.5), Q(9.0,-80.0,13.0,-67.5), Q(17.0,-55.0,23.0,-28.0), L(85.0,223.0), Q(86.0,225.0,86.0,228.0), Q(86.0,235.0,81.0,240.0), Q(76.0,245.0,68.0,245.0), L(31.0,245.0), Q(12.0,245.0,12.0,264.0), Q(12.0,283.0,31.0,283.0), L(89.0,283.0), Q(101.0,283.0,106.0,295.0), Q(135.0,368.0,192.5,421.0), Q(250.0,474.0,315.0,474.0), Q(350.0,474.0,376.0,454.5), Q(402.0,435.0,402.0,405.0), Q(402.0,373.0,388.5,350.5), Q(375.0,328.0,350.0,328.0), Q(332.0,328.0,319.0,340.5), Q(306.0,353.0,306.0,371.0), Q(306.0,380.0,310.5,385.5), Q(315.0,391.0,323.5,397.0), Q(332.0,403.0,335.0,405.0), Q(345.0,415.0,339.0,427.0), Q(335.0,437.0,322.0,437.0), L(315.0,437.0), Q(302.0,437.0,291.0,432.5), Q(280.0,428.0,272.0,416.0), Q(264.0,404.0,259.0,395.0), Q(254.0,386.0,248.5,366.0), Q(243.0,346.0,241.0,337.5), Q(239.0,329.0,234.0,305.0), Q(233.0,302.0,233.0,300.0), Q(233.0,292.0,238.5,287.5), Q(244.0,283.0,251.0,283.0), L(298.0,283.0), Q(300.0,283.0,302.0,283.0), Q(304.0,283.0,306.0,283.0), L(364.0,283.0), Q(376.0,283.0,381.0,295.0), Q(410.0,368.0,467.5,421.0), Q(525.0,474.0,590.0,474.0), Q(625.0,474.0,651.0,454.5), Q(677.0,435.0,677.0,405.0), Q(677.0,373.0,663.5,350.5), Q(650.0,328.0,625.0,328.0), Q(607.0,328.0,594.0,340.5), Q(581.0,353.0,581.0,371.0), Q(581.0,380.0,585.5,385.5), Q(590.0,391.0,598.5,397.0), Q(607.0,403.0,610.0,405.0), Q(620.0,415.0,614.0,427.0), Q(610.0,437.0,597.0,437.0), L(590.0,437.0), Q(577.0,437.0,566.0,432.5), Q(555.0,428.0,547.0,416.0), Q(539.0,404.0,534.0,395.0), Q(529.0,386.0,523.5,366.0), Q(518.0,346.0,516.0,337.5), Q(514.0,329.0,509.0,305.0), Q(508.0,302.0,508.0,300.0), Q(508.0,292.0,513.5,287.5), Q(519.0,283.0,526.0,283.0), L(573.0,283.0), Q(575.0,283.0,577.0,283.0), Q(579.0,283.0,581.0,283.0), L(639.0,283.0), Q(651.0,283.0,656.0,295.0), Q(685.0,368.0,742.5,421.0), Q(800.0,474.0,865.0,474.0), Q(900.0,474.0,926.0,454.5), Q(952.0,435.0,952.0,405.0), Q(952.0,373.0,938.5,350.5), Q(925.0,328.0,900.0,328.0), Q(882.0,328.0,869.0,340.5), Q(856.0,353.0,856.0,371.0), Q(856.0,380.0,860.5,385.5), Q(865.0,391.0,873.5,397.0), Q(882.0,403.0,885.0,405.0), Q(895.0,415.0,889.0,427.0), Q(885.0,437.0,872.0,437.0), L(865.0,437.0), Q(852.0,437.0,841.0,432.5), Q(830.0,428.0,822.0,416.0), Q(814.0,404.0,809.0,395.0), Q(804.0,386.0,798.5,366.0), Q(793.0,346.0,791.0,337.5), Q(789.0,329.0,784.0,305.0), Q(783.0,302.0,783.0,300.0), Q(783.0,292.0,788.5,287.5), Q(794.0,283.0,801.0,283.0), L(848.0,283.0), Q(850.0,283.0,852.0,283.0), Q(854.0,283.0,856.0,283.0), L(914.0,283.0), Q(926.0,283.0,931.0,295.0), Z ];
-
-// pub const CADENZA_120 : &'static [PathSegment] = &[ M(656.0,295.0), Q(685.0,368.0,742.5,421.0), Q(800.0,474.0,865.0,474.0), Q(900.0,474.0,926.0,454.5), Q(952.0,435.0,952.0,405.0), Q(952.0,373.0,938.5,350.5), Q(925.0,328.0,900.0,328.0), Q(882.0,328.0,869.0,340.5), Q(856.0,353.0,856.0,371.0), Q(856.0,380.0,860.5,385.5), Q(865.0,391.0,873.5,397.0), Q(882.0,403.0,885.0,405.0), Q(895.0,415.0,889.0,427.0), Q(885.0,437.0,872.0,437.0), L(865.0,437.0), Q(852.0,437.0,841.0,432.5), Q(830.0,428.0,822.0,416.0), Q(814.0,404.0,809.0,395.0), Q(804.0,386.0,798.5,366.0), Q(793.0,346.0,791.0,337.5), Q(789.0,329.0,784.0,305.0), Q(783.0,302.0,783.0,300.0), Q(783.0,292.0,788.5,287.5), Q(794.0,283.0,801.0,283.0), L(848.0,283.0), Q(867.0,283.0,867.0,264.0), Q(867.0,245.0,848.0,245.0), L(781.0,245.0), Q(767.0,245.0,764.0,232.0), L(718.0,82.0), Q(691.0,-7.0,628.5,-90.0), Q(566.0,-173.0,500.0,-173.0), Q(465.0,-173.0,439.0,-153.5), Q(413.0,-134.0,413.0,-104.0), Q(413.0,-72.0,426.5,-49.5), Q(440.0,-27.0,465.0,-27.0), Q(483.0,-27.0,496.0,-40.0), Q(509.0,-53.0,509.0,-71.0), Q(509.0,-78.0,506.0,-83.5), Q(503.0,-89.0,500.5,-91.0), Q(498.0,-93.0,491.5,-97.5), Q(485.0,-102.0,483.0,-103.0), Q(472.0,-111.0,477.0,-124.0), Q(481.0,-136.0,494.0,-136.0), L(500.0,-136.0), Q(517.0,-136.0,529.5,-127.5), Q(542.0,-119.0,550.5,-99.5), Q(559.0,-80.0,563.0,-67.5), Q(567.0,-55.0,573.0,-28.0), L(635.0,223.0), Q(636.0,225.0,636.0,228.0), Q(636.0,235.0,631.0,240.0), Q(626.0,245.0,618.0,245.0), L(581.0,245.0), Q(579.0,245.0,577.0,245.0), Q(575.0,245.0,573.0,245.0), L(506.0,245.0), Q(492.0,245.0,489.0,232.0), L(443.0,82.0), Q(416.0,-7.0,353.5,-90.0), Q(291.0,-173.0,225.0,-173.0), Q(190.0,-173.0,164.0,-153.5), Q(138.0,-134.0,138.0,-104.0), Q(138.0,-72.0,151.5,-49.5), Q(165.0,-27.0,190.0,-27.0), Q(208.0,-27.0,221.0,-40.0), Q(234.0,-53.0,234.0,-71.0), Q(234.0,-78.0,231.0,-83.5), Q(228.0,-89.0,225.5,-91.0), Q(223.0,-93.0,216.5,-97.5), Q(210.0,-102.0,208.0,-103.0), Q(197.0,-111.0,202.0,-124.0), Q(206.0,-136.0,219.0,-136.0), L(225.0,-136.0), Q(242.0,-136.0,254.5,-127.5), Q(267.0,-119.0,275.5,-99.5), Q(284.0,-80.0,288.0,-67.5), Q(292.0,-55.0,298.0,-28.0), L(360.0,223.0), Q(361.0,225.0,361.0,228.0), Q(361.0,235.0,356.0,240.0), Q(351.0,245.0,343.0,245.0), L(306.0,245.0), Q(304.0,245.0,302.0,245.0), Q(300.0,245.0,298.0,245.0), L(231.0,245.0), Q(217.0,245.0,214.0,232.0), L(168.0,82.0), Q(141.0,-7.0,78.5,-90.0), Q(16.0,-173.0,-50.0,-173.0), Q(-85.0,-173.0,-111.0,-153.5), Q(-137.0,-134.0,-137.0,-104.0), Q(-137.0,-72.0,-123.5,-49.5), Q(-110.0,-27.0,-85.0,-27.0), Q(-67.0,-27.0,-54.0,-40.0), Q(-41.0,-53.0,-41.0,-71.0), Q(-41.0,-78.0,-44.0,-83.5), Q(-47.0,-89.0,-49.5,-91.0), Q(-52.0,-93.0,-58.5,-97.5), Q(-65.0,-102.0,-67.0,-103.0), Q(-78.0,-111.0,-73.0,-124.0), Q(-69.0,-136.0,-56.0,-136.0), L(-50.0,-136.0), Q(-33.0,-136.0,-20.5,-127.5), Q(-8.0,-119.0,0.5,-99.5), Q(9.0,-80.0,13.0,-67.5), Q(17.0,-55.0,23.0,-28.0), L(85.0,223.0), Q(86.0,225.0,86.0,228.0), Q(86.0,235.0,81.0,240.0), Q(76.0,245.0,68.0,245.0), L(31.0,245.0), Q(12.0,245.0,12.0,264.0), Q(12.0,283.0,31.0,283.0), L(89.0,283.0), Q(101.0,283.0,106.0,295.0), Q(135.0,368.0,192.5,421.0), Q(250.0,474.0,315.0,474.0), Q(350.0,474.0,376.0,454.5), Q(402.0,435.0,402.0,405.0), Q(402.0,373.0,388.5,350.5), Q(375.0,328.0,350.0,328.0), Q(332.0,328.0,319.0,340.5), Q(306.0,353.0,306.0,371.0), Q(306.0,380.0,310.5,385.5), Q(315.0,391.0,323.5,397.0), Q(332.0,403.0,335.0,405.0), Q(345.0,415.0,339.0,427.0), Q(335.0,437.0,322.0,437.0), L(315.0,437.0), Q(302.0,437.0,291.0,432.5), Q(280.0,428.0,272.0,416.0), Q(264.0,404.0,259.0,395.0), Q(254.0,386.0,248.5,366.0), Q(243.0,346.0,241.0,337.5), Q(239.0,329.0,234.0,305.0), Q(233.0,302.0,233.0,300.0), Q(233.0,292.0,238.5,287.5), Q(244.0,283.0,251.0,283.0), L(298.0,283.0), Q(300.0,283.0,302.0,283.0), Q(304.0,283.0,306.0,283.0), L(364.0,283.0), Q(376.0,283.0,381.0,295.0), Q(410.0,368.0,467.5,421.0), Q(525.0,474.0,590.0,474.0), Q(625.0,474.0,651.0,454.5), Q(677.0,435.0,677.0,405.0), Q(677.0,373.0,663.5,350.5), Q(650.0,328.0,625.0,328.0), Q(607.0,328.0,594.0,340.5), Q(581.0,353.0,581.0,371.0), Q(581.0,380.0,585.5,385.5), Q(590.0,391.0,598.5,397.0), Q(607.0,403.0,610.0,405.0), Q(620.0,415.0,614.0,427.0), Q(610.0,437.0,597.0,437.0), L(590.0,437.0), Q(577.0,437.0,566.0,432.5), Q(555.0,428.0,547.0,416.0), Q(539.0,404.0,534.0,395.0), Q(529.0,386.0,523.5,366.0), Q(518.0,346.0,516.0,337.5), Q(514.0,329.0,509.0,305.0), Q(508.0,302.0,508.0,300.0), Q(508.0,292.0,513.5,287.5), Q(519.0,283.0,526.0,283.0), L(573.0,283.0), Q(575.0,283.0,577.0,283.0), Q(579.0,283.0,581.0,283.0), L(639.0,283.0), Q(651.0,283.0,656.0,295.0), Z ];
-
-// pub const CADENZA_121 : &'static [PathSegment] = &[ M(138.0,138.0), Q(148.0,138.0,155.5,130.5), Q(163.0,123.0,163.0,112.0), L(163.0,43.0), Q(163.0,25.0,181.0,25.0), L(250.0,25.0), Q(261.0,25.0,268.5,17.5), Q(276.0,10.0,276.0,0.0), Q(276.0,-10.0,268.5,-17.5), Q(261.0,-25.0,250.0,-25.0), L(181.0,-25.0), Q(163.0,-25.0,163.0,-43.0), L(163.0,-112.0), Q(163.0,-123.0,155.5,-130.5), Q(148.0,-138.0,138.0,-138.0), Q(128.0,-138.0,120.5,-130.5), Q(113.0,-123.0,113.0,-112.0), L(113.0,-43.0), Q(113.0,-25.0,95.0,-25.0), L(26.0,-25.0), Q(15.0,-25.0,7.5,-17.5), Q(0.0,-10.0,0.0,0.0), Q(0.0,10.0,7.5,17.5), Q(15.0,25.0,26.0,25.0), L(95.0,25.0), Q(113.0,25.0,113.0,43.0), L(113.0,112.0), Q(113.0,123.0,120.5,130.5), Q(128.0,138.0,138.0,138.0), Z ];
-
-// pub const CADENZA_122 : &'static [PathSegment] = &[ M(365.0,0.0), L(10.0,0.0), Q(0.0,0.0,0.0,10.0), L(0.0,136.0), Q(0.0,146.0,10.0,146.0), L(365.0,146.0), Q(375.0,146.0,375.0,136.0), L(375.0,10.0), Q(375.0,0.0,365.0,0.0), Z ];
-
-// pub const CADENZA_123 : &'static [PathSegment] = &[ M(16.0,0.0), L(16.0,280.0), L(32.0,280.0), L(32.0,240.0), Q(32.0,230.0,37.0,224.0), Q(42.0,218.0,50.0,218.0), Q(55.0,218.0,60.0,221.0), Q(246.0,330.0,246.0,438.0), Q(246.0,504.0,226.0,534.0), Q(222.0,539.0,222.0,545.0), Q(222.0,553.0,228.5,559.5), Q(235.0,566.0,243.0,566.0), Q(263.0,566.0,275.5,520.0), Q(288.0,474.0,288.0,438.0), Q(288.0,388.0,261.5,341.0), Q(235.0,294.0,197.5,256.5), Q(160.0,219.0,122.5,181.5), Q(85.0,144.0,58.5,97.0), Q(32.0,50.0,32.0,0.0), L(16.0,0.0), Z ];
-
-// pub const CADENZA_124 : &'static [PathSegment] = &[ M(163.0,120.0), Q(246.0,358.0,416.0,425.0), L(419.0,425.0), Q(425.0,425.0,425.0,419.0), Q(425.0,415.0,421.0,413.0), Q(394.0,402.0,371.0,387.5), Q(348.0,373.0,328.0,350.5), Q(308.0,328.0,293.5,311.0), Q(279.0,294.0,263.0,261.0), Q(247.0,228.0,238.5,211.5), Q(230.0,195.0,216.0,154.0), Q(202.0,113.0,198.0,99.5), Q(194.0,86.0,180.0,41.0), Q(177.0,30.0,175.0,25.0), Q(167.0,0.0,151.0,0.0), Q(136.0,0.0,125.0,25.0), Q(85.0,113.0,2.0,196.0), Q(0.0,198.0,0.0,200.0), Q(0.0,203.0,2.0,204.5), Q(4.0,206.0,6.5,205.5), Q(9.0,205.0,11.0,204.0), Q(98.0,146.0,132.0,112.0), Q(137.0,107.0,144.0,107.0), Q(159.0,107.0,163.0,120.0), Z ];
-
-// pub const CADENZA_125 : &'static [PathSegment] = &[ M(1357.0,250.0), Q(1318.0,250.0,1286.5,187.5), Q(1255.0,125.0,1255.0,70.0), Q(1255.0,32.0,1285.0,32.0), Q(1307.0,32.0,1326.5,53.5), Q(1346.0,75.0,1357.5,105.5), Q(1369.0,136.0,1375.5,166.0), Q(1382.0,196.0,1382.0,217.0), Q(1382.0,250.0,1357.0,250.0), Z , M(993.0,250.0), Q(954.0,250.0,922.5,187.5), Q(891.0,125.0,891.0,70.0), Q(891.0,32.0,921.0,32.0), Q(943.0,32.0,962.5,53.5), Q(982.0,75.0,993.5,105.5), Q(1005.0,136.0,1011.5,166.0), Q(1018.0,196.0,1018.0,217.0), Q(1018.0,250.0,993.0,250.0), Z , M(629.0,250.0), Q(590.0,250.0,558.5,187.5), Q(527.0,125.0,527.0,70.0), Q(527.0,32.0,557.0,32.0), Q(579.0,32.0,598.5,53.5), Q(618.0,75.0,629.5,105.5), Q(641.0,136.0,647.5,166.0), Q(654.0,196.0,654.0,217.0), Q(654.0,250.0,629.0,250.0), Z , M(265.0,250.0), Q(226.0,250.0,194.5,187.5), Q(163.0,125.0,163.0,70.0), Q(163.0,32.0,193.0,32.0), Q(215.0,32.0,234.5,53.5), Q(254.0,75.0,265.5,105.5), Q(277.0,136.0,283.5,166.0), Q(290.0,196.0,290.0,217.0), Q(290.0,250.0,265.0,250.0), Z , M(17.0,153.0), Q(10.0,136.0,-7.0,136.0), Q(-21.0,136.0,-21.0,148.0), Q(-21.0,150.0,-19.0,156.0), Q(44.0,299.0,113.0,299.0), Q(153.0,299.0,171.0,268.0), Q(175.0,262.0,183.0,260.5), Q(191.0,259.0,197.0,264.0), Q(240.0,299.0,282.0,299.0), Q(332.0,299.0,360.0,267.0), Q(375.0,250.0,383.0,228.0), Q(429.0,299.0,477.0,299.0), Q(517.0,299.0,535.0,268.0), Q(539.0,262.0,547.0,260.5), Q(555.0,259.0,561.0,264.0), Q(604.0,299.0,646.0,299.0), Q(696.0,299.0,724.0,267.0), Q(739.0,250.0,747.0,228.0), Q(793.0,299.0,841.0,299.0), Q(881.0,299.0,899.0,268.0), Q(903.0,262.0,911.0,260.5), Q(919.0,259.0,925.0,264.0), Q(968.0,299.0,1010.0,299.0), Q(1060.0,299.0,1088.0,267.0), Q(1103.0,250.0,1111.0,228.0), Q(1157.0,299.0,1205.0,299.0), Q(1245.0,299.0,1263.0,268.0), Q(1267.0,262.0,1275.0,260.5), Q(1283.0,259.0,1289.0,264.0), Q(1332.0,299.0,1374.0,299.0), Q(1424.0,299.0,1452.5,267.0), Q(1481.0,235.0,1481.0,185.0), Q(1481.0,152.0,1469.0,118.0), Q(1453.0,73.0,1408.0,32.5), Q(1363.0,-8.0,1322.0,-8.0), Q(1279.0,-8.0,1249.0,9.0), Q(1243.0,12.0,1238.0,12.0), Q(1225.0,12.0,1222.0,3.0), L(1193.0,-83.0), Q(1192.0,-85.0,1192.0,-90.0), Q(1192.0,-114.0,1236.0,-118.0), Q(1249.0,-119.0,1249.0,-132.0), Q(1249.0,-146.0,1236.0,-146.0), Q(1217.0,-146.0,1178.0,-143.5), Q(1139.0,-141.0,1120.0,-141.0), Q(1100.0,-141.0,1061.5,-143.5), Q(1023.0,-146.0,1005.0,-146.0), Q(991.0,-146.0,991.0,-132.0), Q(991.0,-127.0,994.0,-123.5), Q(997.0,-120.0,1003.5,-118.5), Q(1010.0,-117.0,1014.5,-116.5), Q(1019.0,-116.0,1028.0,-116.0), Q(1037.0,-116.0,1039.0,-116.0), Q(1085.0,-114.0,1093.0,-91.0), L(1195.0,216.0), Q(1197.0,223.0,1197.0,229.0), Q(1197.0,236.0,1193.0,240.5), Q(1189.0,245.0,1186.0,246.0), Q(1183.0,247.0,1180.0,247.0), Q(1166.0,246.0,1144.0,214.0), Q(1128.0,190.0,1116.0,169.0), Q(1114.0,144.0,1105.0,118.0), Q(1089.0,73.0,1044.0,32.5), Q(999.0,-8.0,958.0,-8.0), Q(915.0,-8.0,885.0,9.0), Q(879.0,12.0,874.0,12.0), Q(861.0,12.0,858.0,3.0), L(829.0,-83.0), Q(828.0,-85.0,828.0,-90.0), Q(828.0,-114.0,872.0,-118.0), Q(885.0,-119.0,885.0,-132.0), Q(885.0,-146.0,872.0,-146.0), Q(853.0,-146.0,814.0,-143.5), Q(775.0,-141.0,756.0,-141.0), Q(736.0,-141.0,697.5,-143.5), Q(659.0,-146.0,641.0,-146.0), Q(627.0,-146.0,627.0,-132.0), Q(627.0,-127.0,630.0,-123.5), Q(633.0,-120.0,639.5,-118.5), Q(646.0,-117.0,650.5,-116.5), Q(655.0,-116.0,664.0,-116.0), Q(673.0,-116.0,675.0,-116.0), Q(721.0,-114.0,729.0,-91.0), L(831.0,216.0), Q(833.0,223.0,833.0,229.0), Q(833.0,236.0,829.0,240.5), Q(825.0,245.0,822.0,246.0), Q(819.0,247.0,816.0,247.0), Q(802.0,246.0,780.0,214.0), Q(764.0,190.0,752.0,169.0), Q(750.0,144.0,741.0,118.0), Q(725.0,73.0,680.0,32.5), Q(635.0,-8.0,594.0,-8.0), Q(551.0,-8.0,521.0,9.0), Q(515.0,12.0,510.0,12.0), Q(497.0,12.0,494.0,3.0), L(465.0,-83.0), Q(464.0,-85.0,464.0,-90.0), Q(464.0,-114.0,508.0,-118.0), Q(521.0,-119.0,521.0,-132.0), Q(521.0,-146.0,508.0,-146.0), Q(489.0,-146.0,450.0,-143.5), Q(411.0,-141.0,392.0,-141.0), Q(372.0,-141.0,333.5,-143.5), Q(295.0,-146.0,277.0,-146.0), Q(263.0,-146.0,263.0,-132.0), Q(263.0,-127.0,266.0,-123.5), Q(269.0,-120.0,275.5,-118.5), Q(282.0,-117.0,286.5,-116.5), Q(291.0,-116.0,300.0,-116.0), Q(309.0,-116.0,311.0,-116.0), Q(357.0,-114.0,365.0,-91.0), L(467.0,216.0), Q(469.0,223.0,469.0,229.0), Q(469.0,236.0,465.0,240.5), Q(461.0,245.0,458.0,246.0), Q(455.0,247.0,452.0,247.0), Q(438.0,246.0,416.0,214.0), Q(400.0,190.0,388.0,169.0), Q(386.0,144.0,377.0,118.0), Q(361.0,73.0,316.0,32.5), Q(271.0,-8.0,230.0,-8.0), Q(187.0,-8.0,157.0,9.0), Q(151.0,12.0,146.0,12.0), Q(133.0,12.0,130.0,3.0), L(101.0,-83.0), Q(100.0,-85.0,100.0,-90.0), Q(100.0,-114.0,144.0,-118.0), Q(157.0,-119.0,157.0,-132.0), Q(157.0,-146.0,144.0,-146.0), Q(125.0,-146.0,86.0,-143.5), Q(47.0,-141.0,28.0,-141.0), Q(8.0,-141.0,-30.5,-143.5), Q(-69.0,-146.0,-87.0,-146.0), Q(-101.0,-146.0,-101.0,-132.0), Q(-101.0,-127.0,-98.0,-123.5), Q(-95.0,-120.0,-88.5,-118.5), Q(-82.0,-117.0,-77.5,-116.5), Q(-73.0,-116.0,-64.0,-116.0), Q(-55.0,-116.0,-53.0,-116.0), Q(-7.0,-114.0,1.0,-91.0), L(103.0,216.0), Q(105.0,223.0,105.0,229.0), Q(105.0,236.0,101.0,240.5), Q(97.0,245.0,94.0,246.0), Q(91.0,247.0,88.0,247.0), Q(74.0,247.0,51.5,214.5), Q(29.0,182.0,17.0,153.0), Z ];
-
-// pub const CADENZA_126 : &'static [PathSegment] = &[ M(170.0,-182.0), Q(123.0,-196.0,87.0,-196.0), Q(52.0,-196.0,26.0,-173.5), Q(0.0,-151.0,0.0,-117.0), Q(0.0,-87.0,21.5,-66.0), Q(43.0,-45.0,73.0,-45.0), Q(92.0,-45.0,107.5,-55.5), Q(123.0,-66.0,129.0,-84.0), Q(132.0,-91.0,135.5,-110.0), Q(139.0,-129.0,144.5,-136.0), Q(150.0,-143.0,163.0,-143.0), Q(175.0,-143.0,197.5,-114.0), Q(220.0,-85.0,223.0,-70.0), L(249.0,46.0), Q(251.0,54.0,245.5,61.0), Q(240.0,68.0,231.0,68.0), Q(228.0,68.0,226.0,67.0), Q(184.0,54.0,147.0,54.0), Q(112.0,54.0,86.0,76.5), Q(60.0,99.0,60.0,133.0), Q(60.0,163.0,81.0,184.0), Q(102.0,205.0,132.0,205.0), Q(151.0,205.0,167.0,194.5), Q(183.0,184.0,189.0,166.0), Q(192.0,159.0,195.5,140.0), Q(199.0,121.0,204.5,114.0), Q(210.0,107.0,223.0,107.0), Q(234.0,107.0,254.5,135.0), Q(275.0,163.0,278.0,178.0), L(305.0,296.0), Q(307.0,305.0,301.5,311.5), Q(296.0,318.0,287.0,318.0), Q(284.0,318.0,282.0,317.0), Q(241.0,304.0,206.0,304.0), Q(171.0,304.0,145.0,326.5), Q(119.0,349.0,119.0,383.0), Q(119.0,413.0,140.5,434.0), Q(162.0,455.0,192.0,455.0), Q(211.0,455.0,226.5,444.5), Q(242.0,434.0,248.0,416.0), Q(251.0,409.0,254.5,390.0), Q(258.0,371.0,263.5,364.0), Q(269.0,357.0,282.0,357.0), Q(292.0,357.0,311.0,384.0), Q(330.0,411.0,334.0,425.0), L(361.0,545.0), Q(363.0,553.0,357.5,560.0), Q(352.0,567.0,343.0,567.0), Q(340.0,567.0,338.0,566.0), Q(297.0,554.0,266.0,554.0), Q(231.0,554.0,205.0,576.5), Q(179.0,599.0,179.0,633.0), Q(179.0,663.0,200.0,684.0), Q(221.0,705.0,251.0,705.0), Q(270.0,705.0,286.0,694.5), Q(302.0,684.0,308.0,666.0), Q(311.0,659.0,314.5,640.0), Q(318.0,621.0,323.5,614.0), Q(329.0,607.0,342.0,607.0), Q(351.0,607.0,366.5,630.5), Q(382.0,654.0,389.0,670.0), Q(393.0,679.0,403.0,679.0), Q(413.0,679.0,417.0,670.0), L(182.0,-500.0), Q(171.0,-510.0,154.0,-510.0), Q(138.0,-510.0,127.0,-500.0), L(193.0,-203.0), Q(195.0,-195.0,189.5,-188.0), Q(184.0,-181.0,175.0,-181.0), Q(173.0,-181.0,170.0,-182.0), Z ];
-
-// pub const CADENZA_127 : &'static [PathSegment] = &[ M(0.0,42.0), Q(0.0,69.0,29.5,93.5), Q(59.0,118.0,90.0,118.0), Q(103.0,118.0,114.0,115.0), Q(123.0,112.0,132.0,105.0), L(132.0,450.0), L(146.0,450.0), L(146.0,72.0), Q(146.0,47.0,116.0,21.0), Q(86.0,-5.0,56.0,-5.0), Q(43.0,-5.0,32.0,-1.5), Q(21.0,2.0,10.5,13.0), Q(0.0,24.0,0.0,42.0), Z , M(208.0,111.0), L(348.0,111.0), L(348.0,84.0), L(208.0,84.0), L(208.0,111.0), Z , M(208.0,51.0), L(348.0,51.0), L(348.0,24.0), L(208.0,24.0), L(208.0,51.0), Z ];
-
-// pub const CADENZA_128 : &'static [PathSegment] = &[ M(334.0,91.0), L(391.0,293.0), L(392.0,297.0), L(396.0,296.0), Q(468.0,278.0,525.0,278.0), Q(540.0,278.0,596.0,294.0), Q(603.0,296.0,604.0,296.0), Q(613.0,296.0,613.0,285.0), Q(613.0,271.0,594.0,250.0), Q(561.0,214.0,496.0,214.0), Q(494.0,214.0,492.0,214.0), Q(459.0,214.0,411.0,223.0), L(379.0,115.0), Q(419.0,133.0,453.0,133.0), Q(507.0,133.0,541.0,101.0), Q(575.0,69.0,575.0,20.0), Q(575.0,-58.0,520.0,-113.0), Q(465.0,-168.0,385.0,-168.0), Q(332.0,-168.0,299.0,-140.0), Q(265.0,-111.0,265.0,-67.0), Q(265.0,-42.0,280.0,-25.5), Q(295.0,-9.0,318.0,-9.0), Q(336.0,-9.0,348.0,-20.0), Q(360.0,-31.0,360.0,-49.0), Q(360.0,-83.0,341.0,-85.0), Q(321.0,-87.0,321.0,-95.0), L(321.0,-96.0), L(319.0,-101.0), Q(319.0,-113.0,337.0,-123.0), Q(357.0,-134.0,383.0,-134.0), Q(430.0,-134.0,464.0,-92.0), Q(498.0,-49.0,498.0,12.0), Q(498.0,50.0,479.5,70.0), Q(461.0,90.0,428.0,90.0), Q(394.0,90.0,367.0,69.0), Q(363.0,32.0,335.0,32.0), Q(323.0,32.0,315.5,39.0), Q(308.0,46.0,308.0,57.0), Q(308.0,70.0,314.0,75.0), Q(321.0,81.0,334.0,91.0), Z , M(267.0,294.0), L(168.0,-77.0), Q(162.0,-99.0,162.0,-107.0), Q(162.0,-114.0,182.0,-117.0), Q(205.0,-120.0,208.0,-120.0), L(242.0,-120.0), L(246.0,-120.0), L(246.0,-124.0), L(243.0,-154.0), L(243.0,-158.0), L(239.0,-158.0), L(4.0,-158.0), L(0.0,-158.0), L(0.0,-154.0), L(3.0,-124.0), L(3.0,-120.0), L(7.0,-120.0), L(34.0,-120.0), Q(77.0,-120.0,85.0,-112.0), Q(89.0,-108.0,98.0,-75.0), L(166.0,179.0), L(173.0,206.0), Q(175.0,212.0,169.0,213.0), Q(162.0,215.0,133.0,215.0), L(90.0,215.0), L(85.0,215.0), L(86.0,220.0), L(93.0,248.0), L(94.0,251.0), L(97.0,251.0), Q(130.0,251.0,179.5,267.5), Q(229.0,284.0,243.0,298.0), L(244.0,299.0), L(245.0,299.0), L(263.0,299.0), L(268.0,299.0), L(267.0,294.0), Z ];
-
-// pub const CADENZA_129 : &'static [PathSegment] = &[ M(590.0,12.0), Q(562.0,12.0,554.0,6.0), Q(546.0,0.0,544.0,0.0), Q(525.0,0.0,525.0,25.0), Q(525.0,31.0,529.0,35.0), L(676.0,174.0), Q(685.0,183.0,681.0,195.0), Q(676.0,208.0,663.0,206.0), Q(635.0,204.0,610.0,200.0), Q(605.0,199.0,590.5,196.0), Q(576.0,193.0,570.0,193.0), Q(553.0,193.0,553.0,208.0), Q(553.0,232.0,576.0,255.0), Q(599.0,278.0,624.0,278.0), Q(640.0,278.0,671.0,267.0), Q(702.0,256.0,718.0,256.0), Q(722.0,256.0,729.5,255.5), Q(737.0,255.0,741.0,255.0), Q(762.0,255.0,771.0,264.0), Q(775.0,268.0,781.0,268.0), Q(786.0,268.0,790.5,258.5), Q(795.0,249.0,795.0,243.0), Q(795.0,238.0,790.0,233.0), L(644.0,95.0), Q(636.0,88.0,639.0,76.0), Q(642.0,66.0,654.0,64.0), Q(664.0,63.0,682.0,60.5), Q(700.0,58.0,715.0,56.5), Q(730.0,55.0,743.0,55.0), Q(758.0,55.0,768.0,66.0), Q(778.0,77.0,778.0,93.0), Q(778.0,96.0,776.0,103.0), Q(774.0,110.0,774.0,114.0), Q(774.0,127.0,789.0,127.0), Q(811.0,127.0,811.0,66.0), Q(811.0,27.0,783.0,-0.5), Q(755.0,-28.0,715.0,-28.0), Q(685.0,-28.0,649.5,-8.0), Q(614.0,12.0,590.0,12.0), Z , M(347.0,295.0), Q(376.0,368.0,433.5,421.0), Q(491.0,474.0,556.0,474.0), Q(591.0,474.0,617.0,454.5), Q(643.0,435.0,643.0,405.0), Q(643.0,373.0,629.5,350.5), Q(616.0,328.0,591.0,328.0), Q(573.0,328.0,560.0,340.5), Q(547.0,353.0,547.0,371.0), Q(547.0,380.0,551.5,385.5), Q(556.0,391.0,564.5,397.0), Q(573.0,403.0,576.0,405.0), Q(586.0,415.0,580.0,427.0), Q(576.0,437.0,563.0,437.0), L(556.0,437.0), Q(543.0,437.0,532.0,432.5), Q(521.0,428.0,513.0,416.0), Q(505.0,404.0,500.0,395.0), Q(495.0,386.0,489.5,366.0), Q(484.0,346.0,482.0,337.5), Q(480.0,329.0,475.0,305.0), Q(474.0,302.0,474.0,300.0), Q(474.0,292.0,479.5,287.5), Q(485.0,283.0,492.0,283.0), L(539.0,283.0), Q(558.0,283.0,558.0,264.0), Q(558.0,245.0,539.0,245.0), L(472.0,245.0), Q(458.0,245.0,455.0,232.0), L(409.0,82.0), Q(382.0,-7.0,319.5,-90.0), Q(257.0,-173.0,191.0,-173.0), Q(156.0,-173.0,130.0,-153.5), Q(104.0,-134.0,104.0,-104.0), Q(104.0,-72.0,117.5,-49.5), Q(131.0,-27.0,156.0,-27.0), Q(174.0,-27.0,187.0,-40.0), Q(200.0,-53.0,200.0,-71.0), Q(200.0,-78.0,197.0,-83.5), Q(194.0,-89.0,191.5,-91.0), Q(189.0,-93.0,182.5,-97.5), Q(176.0,-102.0,174.0,-103.0), Q(163.0,-111.0,168.0,-124.0), Q(172.0,-136.0,185.0,-136.0), L(191.0,-136.0), Q(208.0,-136.0,220.5,-127.5), Q(233.0,-119.0,241.5,-99.5), Q(250.0,-80.0,254.0,-67.5), Q(258.0,-55.0,264.0,-28.0), L(326.0,223.0), Q(327.0,225.0,327.0,228.0), Q(327.0,235.0,322.0,240.0), Q(317.0,245.0,309.0,245.0), L(272.0,245.0), Q(253.0,245.0,253.0,264.0), Q(253.0,283.0,272.0,283.0), L(330.0,283.0), Q(342.0,283.0,347.0,295.0), Z , M(180.0,216.0), Q(180.0,221.0,184.5,230.5), Q(189.0,240.0,189.0,245.0), Q(189.0,268.0,171.0,268.0), Q(151.0,268.0,134.5,255.0), Q(118.0,242.0,118.0,224.0), Q(118.0,206.0,149.0,187.0), Q(181.0,169.0,199.0,151.0), Q(217.0,133.0,217.0,107.0), Q(217.0,61.0,184.0,30.5), Q(151.0,0.0,103.0,0.0), Q(70.0,0.0,46.0,19.5), Q(22.0,39.0,22.0,68.0), Q(22.0,85.0,30.5,97.5), Q(39.0,110.0,53.0,110.0), Q(66.0,110.0,75.5,101.0), Q(85.0,92.0,85.0,78.0), Q(85.0,72.0,74.5,64.0), Q(64.0,56.0,64.0,49.0), Q(64.0,27.0,99.0,27.0), Q(124.0,27.0,140.5,40.0), Q(157.0,53.0,157.0,75.0), Q(157.0,91.0,141.5,105.0), Q(126.0,119.0,107.0,127.5), Q(88.0,136.0,72.5,153.0), Q(57.0,170.0,57.0,192.0), Q(57.0,232.0,89.0,262.0), Q(121.0,292.0,162.0,292.0), Q(201.0,292.0,221.0,273.0), Q(241.0,254.0,241.0,230.0), Q(241.0,215.0,233.0,204.5), Q(225.0,194.0,211.0,194.0), Q(198.0,194.0,189.0,200.5), Q(180.0,207.0,180.0,216.0), Z ];
-
-// pub const CADENZA_130 : &'static [PathSegment] = &[ M(114.0,77.0), Q(114.0,60.0,102.5,48.5), Q(91.0,37.0,74.0,37.0), Q(56.0,37.0,44.5,48.5), Q(33.0,60.0,33.0,77.0), Q(33.0,94.0,44.5,105.5), Q(56.0,117.0,74.0,117.0), Q(91.0,117.0,102.5,105.5), Q(114.0,94.0,114.0,77.0), Z , M(150.0,226.0), Q(150.0,210.0,138.5,198.0), Q(127.0,186.0,109.0,186.0), Q(93.0,186.0,80.5,198.0), Q(68.0,210.0,68.0,226.0), Q(68.0,241.0,80.0,253.0), Q(92.0,265.0,109.0,265.0), Q(127.0,265.0,138.5,253.0), Q(150.0,241.0,150.0,226.0), Z ];
-
-// pub const CADENZA_131 : &'static [PathSegment] = &[ M(223.0,110.0), Q(135.0,110.0,135.0,79.0), Q(135.0,44.0,188.5,-33.0), Q(242.0,-110.0,278.0,-110.0), Q(364.0,-110.0,364.0,-79.0), Q(364.0,-41.0,308.5,34.5), Q(253.0,110.0,223.0,110.0), Z , M(421.0,-91.0), Q(374.0,-137.0,282.0,-137.0), Q(130.0,-137.0,71.0,-75.0), Q(66.0,-70.0,58.5,-70.0), Q(51.0,-70.0,45.5,-75.0), Q(40.0,-80.0,40.0,-88.0), L(40.0,-160.0), Q(40.0,-168.0,34.0,-174.0), Q(28.0,-180.0,20.0,-180.0), Q(12.0,-180.0,6.0,-174.0), Q(0.0,-168.0,0.0,-160.0), L(0.0,160.0), Q(0.0,168.0,6.0,174.0), Q(12.0,180.0,20.0,180.0), Q(28.0,180.0,34.0,174.0), Q(40.0,168.0,40.0,160.0), L(40.0,99.0), Q(40.0,91.0,45.5,86.0), Q(51.0,81.0,59.0,81.0), Q(66.0,81.0,71.0,86.0), Q(124.0,137.0,223.0,137.0), Q(284.0,137.0,336.5,121.0), Q(389.0,105.0,420.0,73.0), Q(425.0,68.0,432.5,68.0), Q(440.0,68.0,445.5,73.0), Q(451.0,78.0,451.0,86.0), L(451.0,160.0), Q(451.0,168.0,457.0,174.0), Q(463.0,180.0,471.0,180.0), Q(479.0,180.0,485.0,174.0), Q(491.0,168.0,491.0,160.0), L(491.0,-160.0), Q(491.0,-168.0,485.0,-174.0), Q(479.0,-180.0,471.0,-180.0), Q(463.0,-180.0,457.0,-174.0), Q(451.0,-168.0,451.0,-160.0), L(451.0,-105.0), Q(451.0,-97.0,445.5,-92.0), Q(440.0,-87.0,432.0,-87.0), Q(426.0,-87.0,421.0,-91.0), Z ];
-
-// pub const CADENZA_132 : &'static [PathSegment] = &[ M(340.0,298.0), L(80.0,298.0), Q(72.0,298.0,66.0,304.5), Q(60.0,311.0,60.0,320.0), Q(60.0,329.0,66.0,335.5), Q(72.0,342.0,80.0,342.0), L(340.0,342.0), Q(348.0,342.0,354.0,335.5), Q(360.0,329.0,360.0,320.0), Q(360.0,311.0,354.0,304.5), Q(348.0,298.0,340.0,298.0), Z , M(285.0,142.0), L(14.0,213.0), Q(0.0,216.0,0.0,231.0), Q(0.0,238.0,6.0,244.0), Q(12.0,250.0,19.0,250.0), Q(20.0,250.0,22.0,250.0), L(23.0,249.0), L(431.0,144.0), Q(450.0,144.0,450.0,125.0), Q(450.0,106.0,431.0,106.0), L(23.0,1.0), L(22.0,0.0), Q(20.0,0.0,19.0,0.0), Q(12.0,0.0,6.0,6.0), Q(0.0,12.0,0.0,19.0), Q(0.0,34.0,14.0,37.0), L(285.0,108.0), Q(299.0,113.0,299.0,125.0), Q(299.0,137.0,285.0,142.0), Z ];
-
-// pub const CADENZA_133 : &'static [PathSegment] = &[ M(338.0,0.0), L(231.0,0.0), Q(221.0,0.0,221.0,10.0), L(221.0,490.0), Q(221.0,500.0,231.0,500.0), L(338.0,500.0), Q(348.0,500.0,348.0,490.0), L(348.0,10.0), Q(348.0,0.0,338.0,0.0), Z , M(118.0,0.0), L(10.0,0.0), Q(0.0,0.0,0.0,10.0), L(0.0,490.0), Q(0.0,500.0,10.0,500.0), L(118.0,500.0), Q(128.0,500.0,128.0,490.0), L(128.0,10.0), Q(128.0,0.0,118.0,0.0), Z ];
-
-// pub const CADENZA_134 : &'static [PathSegment] = &[ M(340.0,11.0), L(80.0,11.0), Q(72.0,11.0,66.0,17.5), Q(60.0,24.0,60.0,33.0), Q(60.0,42.0,66.0,48.5), Q(72.0,55.0,80.0,55.0), L(340.0,55.0), Q(348.0,55.0,354.0,48.5), Q(360.0,42.0,360.0,33.0), Q(360.0,24.0,354.0,17.5), Q(348.0,11.0,340.0,11.0), Z , M(285.0,237.0), L(14.0,308.0), Q(0.0,311.0,0.0,326.0), Q(0.0,333.0,6.0,339.0), Q(12.0,345.0,19.0,345.0), Q(20.0,344.0,22.0,344.0), L(23.0,344.0), L(431.0,239.0), Q(450.0,239.0,450.0,220.0), Q(450.0,201.0,431.0,201.0), L(23.0,96.0), L(22.0,96.0), Q(20.0,96.0,19.0,95.0), Q(12.0,95.0,6.0,101.0), Q(0.0,107.0,0.0,114.0), Q(0.0,129.0,14.0,132.0), L(285.0,203.0), Q(299.0,208.0,299.0,220.0), Q(299.0,232.0,285.0,237.0), Z ];
-
-// pub const CADENZA_135 : &'static [PathSegment] = &[ M(625.0,0.0), Q(625.0,174.0,556.0,243.0), Q(553.0,246.0,553.0,250.0), Q(553.0,263.0,568.0,263.0), Q(574.0,263.0,579.0,257.0), Q(683.0,127.0,683.0,0.0), Q(683.0,-132.0,579.0,-257.0), Q(574.0,-263.0,568.0,-263.0), Q(553.0,-263.0,553.0,-250.0), Q(553.0,-246.0,556.0,-243.0), Q(625.0,-157.0,625.0,0.0), Z , M(342.0,172.0), L(336.0,457.0), Q(349.0,465.0,363.0,465.0), Q(377.0,465.0,390.0,457.0), L(385.0,151.0), Q(385.0,141.0,393.0,136.0), Q(402.0,130.0,411.0,135.0), Q(453.0,156.0,480.0,156.0), Q(518.0,156.0,541.0,126.0), Q(564.0,96.0,564.0,54.0), Q(564.0,25.0,555.5,1.5), Q(547.0,-22.0,529.0,-41.5), Q(511.0,-61.0,495.5,-74.0), Q(480.0,-87.0,455.0,-105.5), Q(430.0,-124.0,417.0,-135.0), Q(412.0,-139.0,404.0,-150.0), Q(396.0,-161.0,388.5,-167.0), Q(381.0,-173.0,372.0,-173.0), Q(362.0,-173.0,355.5,-166.0), Q(349.0,-159.0,349.0,-148.0), L(348.0,-87.0), Q(348.0,-79.0,342.5,-74.5), Q(337.0,-70.0,329.0,-70.0), Q(322.0,-70.0,317.0,-74.0), Q(306.0,-83.0,279.0,-103.5), Q(252.0,-124.0,238.0,-135.0), Q(233.0,-139.0,225.0,-150.0), Q(217.0,-161.0,209.5,-167.0), Q(202.0,-173.0,193.0,-173.0), Q(183.0,-173.0,176.5,-166.0), Q(170.0,-159.0,170.0,-148.0), L(158.0,457.0), Q(171.0,465.0,185.0,465.0), Q(199.0,465.0,212.0,457.0), L(206.0,151.0), Q(206.0,141.0,214.0,136.0), Q(223.0,130.0,232.0,135.0), Q(274.0,156.0,301.0,156.0), Q(307.0,156.0,319.0,154.0), Q(321.0,153.0,324.0,153.0), Q(332.0,153.0,337.0,158.5), Q(342.0,164.0,342.0,172.0), Z , M(383.0,-86.0), Q(383.0,-98.0,393.0,-102.0), Q(405.0,-107.0,413.0,-99.0), Q(479.0,-39.0,479.0,37.0), Q(479.0,64.0,467.5,84.0), Q(456.0,104.0,436.0,104.0), Q(414.0,104.0,400.0,85.0), Q(386.0,66.0,385.0,41.0), L(383.0,-86.0), Z , M(204.0,-86.0), Q(204.0,-98.0,214.0,-102.0), Q(226.0,-107.0,234.0,-99.0), Q(300.0,-39.0,300.0,37.0), Q(300.0,64.0,288.5,84.0), Q(277.0,104.0,257.0,104.0), Q(235.0,104.0,221.0,85.0), Q(207.0,66.0,206.0,41.0), L(204.0,-86.0), Z , M(57.0,0.0), Q(57.0,-157.0,126.0,-243.0), Q(129.0,-246.0,129.0,-250.0), Q(129.0,-263.0,115.0,-263.0), Q(107.0,-263.0,103.0,-257.0), Q(0.0,-129.0,0.0,0.0), Q(0.0,129.0,103.0,257.0), Q(107.0,263.0,115.0,263.0), Q(129.0,263.0,129.0,250.0), Q(129.0,246.0,126.0,243.0), Q(57.0,157.0,57.0,0.0), Z ];
-
-// pub const CADENZA_136 : &'static [PathSegment] = &[ M(301.0,281.0), L(301.0,160.0), L(0.0,-1.0), L(0.0,124.0), L(301.0,281.0), Z , M(301.0,461.0), L(301.0,340.0), L(0.0,179.0), L(0.0,304.0), L(301.0,461.0), Z , M(301.0,641.0), L(301.0,520.0), L(0.0,359.0), L(0.0,484.0), L(301.0,641.0), Z ];
-
-// pub const CADENZA_137 : &'static [PathSegment] = &[ M(865.0,12.0), Q(837.0,12.0,829.0,6.0), Q(821.0,0.0,819.0,0.0), Q(800.0,0.0,800.0,25.0), Q(800.0,31.0,804.0,35.0), L(951.0,174.0), Q(960.0,183.0,956.0,195.0), Q(951.0,208.0,938.0,206.0), Q(910.0,204.0,885.0,200.0), Q(880.0,199.0,865.5,196.0), Q(851.0,193.0,845.0,193.0), Q(828.0,193.0,828.0,208.0), Q(828.0,232.0,851.0,255.0), Q(874.0,278.0,899.0,278.0), Q(915.0,278.0,946.0,267.0), Q(977.0,256.0,993.0,256.0), Q(997.0,256.0,1004.5,255.5), Q(1012.0,255.0,1016.0,255.0), Q(1037.0,255.0,1046.0,264.0), Q(1050.0,268.0,1056.0,268.0), Q(1061.0,268.0,1065.5,258.5), Q(1070.0,249.0,1070.0,243.0), Q(1070.0,238.0,1065.0,233.0), L(919.0,95.0), Q(911.0,88.0,914.0,76.0), Q(917.0,66.0,929.0,64.0), Q(939.0,63.0,957.0,60.5), Q(975.0,58.0,990.0,56.5), Q(1005.0,55.0,1018.0,55.0), Q(1033.0,55.0,1043.0,66.0), Q(1053.0,77.0,1053.0,93.0), Q(1053.0,96.0,1051.0,103.0), Q(1049.0,110.0,1049.0,114.0), Q(1049.0,127.0,1064.0,127.0), Q(1086.0,127.0,1086.0,66.0), Q(1086.0,27.0,1058.0,-0.5), Q(1030.0,-28.0,990.0,-28.0), Q(960.0,-28.0,924.5,-8.0), Q(889.0,12.0,865.0,12.0), Z , M(622.0,295.0), Q(651.0,368.0,708.5,421.0), Q(766.0,474.0,831.0,474.0), Q(866.0,474.0,892.0,454.5), Q(918.0,435.0,918.0,405.0), Q(918.0,373.0,904.5,350.5), Q(891.0,328.0,866.0,328.0), Q(848.0,328.0,835.0,340.5), Q(822.0,353.0,822.0,371.0), Q(822.0,380.0,826.5,385.5), Q(831.0,391.0,839.5,397.0), Q(848.0,403.0,851.0,405.0), Q(861.0,415.0,855.0,427.0), Q(851.0,437.0,838.0,437.0), L(831.0,437.0), Q(818.0,437.0,807.0,432.5), Q(796.0,428.0,788.0,416.0), Q(780.0,404.0,775.0,395.0), Q(770.0,386.0,764.5,366.0), Q(759.0,346.0,757.0,337.5), Q(755.0,329.0,750.0,305.0), Q(749.0,302.0,749.0,300.0), Q(749.0,292.0,754.5,287.5), Q(760.0,283.0,767.0,283.0), L(814.0,283.0), Q(833.0,283.0,833.0,264.0), Q(833.0,245.0,814.0,245.0), L(747.0,245.0), Q(733.0,245.0,730.0,232.0), L(684.0,82.0), Q(657.0,-7.0,594.5,-90.0), Q(532.0,-173.0,466.0,-173.0), Q(431.0,-173.0,405.0,-153.5), Q(379.0,-134.0,379.0,-104.0), Q(379.0,-72.0,392.5,-49.5), Q(406.0,-27.0,431.0,-27.0), Q(449.0,-27.0,462.0,-40.0), Q(475.0,-53.0,475.0,-71.0), Q(475.0,-78.0,472.0,-83.5), Q(469.0,-89.0,466.5,-91.0), Q(464.0,-93.0,457.5,-97.5), Q(451.0,-102.0,449.0,-103.0), Q(438.0,-111.0,443.0,-124.0), Q(447.0,-136.0,460.0,-136.0), L(466.0,-136.0), Q(483.0,-136.0,495.5,-127.5), Q(508.0,-119.0,516.5,-99.5), Q(525.0,-80.0,529.0,-67.5), Q(533.0,-55.0,539.0,-28.0), L(601.0,223.0), Q(602.0,225.0,602.0,228.0), Q(602.0,235.0,597.0,240.0), Q(592.0,245.0,584.0,245.0), L(547.0,245.0), Q(545.0,245.0,543.0,245.0), Q(541.0,245.0,539.0,245.0), L(472.0,245.0), Q(458.0,245.0,455.0,232.0), L(409.0,82.0), Q(382.0,-7.0,319.5,-90.0), Q(257.0,-173.0,191.0,-173.0), Q(156.0,-173.0,130.0,-153.5), Q(104.0,-134.0,104.0,-104.0), Q(104.0,-72.0,117.5,-49.5), Q(131.0,-27.0,156.0,-27.0), Q(174.0,-27.0,187.0,-40.0), Q(200.0,-53.0,200.0,-71.0), Q(200.0,-78.0,197.0,-83.5), Q(194.0,-89.0,191.5,-91.0), Q(189.0,-93.0,182.5,-97.5), Q(176.0,-102.0,174.0,-103.0), Q(163.0,-111.0,168.0,-124.0), Q(172.0,-136.0,185.0,-136.0), L(191.0,-136.0), Q(208.0,-136.0,220.5,-127.5), Q(233.0,-119.0,241.5,-99.5), Q(250.0,-80.0,254.0,-67.5), Q(258.0,-55.0,264.0,-28.0), L(326.0,223.0), Q(327.0,225.0,327.0,228.0), Q(327.0,235.0,322.0,240.0), Q(317.0,245.0,309.0,245.0), L(272.0,245.0), Q(253.0,245.0,253.0,264.0), Q(253.0,283.0,272.0,283.0), L(330.0,283.0), Q(342.0,283.0,347.0,295.0), Q(376.0,368.0,433.5,421.0), Q(491.0,474.0,556.0,474.0), Q(591.0,474.0,617.0,454.5), Q(643.0,435.0,643.0,405.0), Q(643.0,373.0,629.5,350.5), Q(616.0,328.0,591.0,328.0), Q(573.0,328.0,560.0,340.5), Q(547.0,353.0,547.0,371.0), Q(547.0,380.0,551.5,385.5), Q(556.0,391.0,564.5,397.0), Q(573.0,403.0,576.0,405.0), Q(586.0,415.0,580.0,427.0), Q(576.0,437.0,563.0,437.0), L(556.0,437.0), Q(543.0,437.0,532.0,432.5), Q(521.0,428.0,513.0,416.0), Q(505.0,404.0,500.0,395.0), Q(495.0,386.0,489.5,366.0), Q(484.0,346.0,482.0,337.5), Q(480.0,329.0,475.0,305.0), Q(474.0,302.0,474.0,300.0), Q(474.0,292.0,479.5,287.5), Q(485.0,283.0,492.0,283.0), L(539.0,283.0), Q(541.0,283.0,543.0,283.0), Q(545.0,283.0,547.0,283.0), L(605.0,283.0), Q(617.0,283.0,622.0,295.0), Z , M(180.0,216.0), Q(180.0,221.0,184.5,230.5), Q(189.0,240.0,189.0,245.0), Q(189.0,268.0,171.0,268.0), Q(151.0,268.0,134.5,255.0), Q(118.0,242.0,118.0,224.0), Q(118.0,206.0,149.0,187.0), Q(181.0,169.0,199.0,151.0), Q(217.0,133.0,217.0,107.0), Q(217.0,61.0,184.0,30.5), Q(151.0,0.0,103.0,0.0), Q(70.0,0.0,46.0,19.5), Q(22.0,39.0,22.0,68.0), Q(22.0,85.0,30.5,97.5), Q(39.0,110.0,53.0,110.0), Q(66.0,110.0,75.5,101.0), Q(85.0,92.0,85.0,78.0), Q(85.0,72.0,74.5,64.0), Q(64.0,56.0,64.0,49.0), Q(64.0,27.0,99.0,27.0), Q(124.0,27.0,140.5,40.0), Q(157.0,53.0,157.0,75.0), Q(157.0,91.0,141.5,105.0), Q(126.0,119.0,107.0,127.5), Q(88.0,136.0,72.5,153.0), Q(57.0,170.0,57.0,192.0), Q(57.0,232.0,89.0,262.0), Q(121.0,292.0,162.0,292.0), Q(201.0,292.0,221.0,273.0), Q(241.0,254.0,241.0,230.0), Q(241.0,215.0,233.0,204.5), Q(225.0,194.0,211.0,194.0), Q(198.0,194.0,189.0,200.5), Q(180.0,207.0,180.0,216.0), Z ];
-
-// pub const CADENZA_138 : &'static [PathSegment] = &[ M(39.0,271.0), Q(26.0,265.0,14.0,249.0), Q(2.0,233.0,-2.0,219.0), Q(-8.0,209.0,-18.0,209.0), Q(-30.0,209.0,-30.0,221.0), Q(-30.0,225.0,-27.0,230.0), Q(-18.0,263.0,9.0,284.0), Q(36.0,305.0,70.0,305.0), Q(105.0,305.0,125.0,283.0), Q(135.0,272.0,148.0,280.0), Q(186.0,306.0,227.0,306.0), Q(253.0,306.0,270.0,295.5), Q(287.0,285.0,287.0,265.0), Q(287.0,247.0,273.5,232.5), Q(260.0,218.0,244.0,218.0), Q(222.0,218.0,215.0,233.0), Q(213.0,236.0,211.0,242.5), Q(209.0,249.0,207.5,253.0), Q(206.0,257.0,201.5,260.0), Q(197.0,263.0,190.0,263.0), Q(174.0,263.0,155.0,249.0), Q(136.0,235.0,132.0,219.0), L(80.0,18.0), Q(75.0,0.0,58.0,0.0), L(18.0,0.0), Q(10.0,0.0,6.5,5.5), Q(3.0,11.0,5.0,18.0), L(62.0,241.0), Q(64.0,249.0,64.0,253.0), Q(64.0,261.0,58.5,266.5), Q(53.0,272.0,46.0,272.0), Q(42.0,272.0,39.0,271.0), Z ];
-
-// pub const CADENZA_139 : &'static [PathSegment] = &[ M(216.0,-572.0), Q(241.0,-627.0,241.0,-682.0), Q(241.0,-771.0,199.0,-865.0), Q(192.0,-877.0,180.0,-877.0), Q(170.0,-877.0,162.5,-869.0), Q(155.0,-861.0,157.0,-850.0), Q(199.0,-766.0,199.0,-682.0), Q(199.0,-584.0,63.0,-454.0), Q(57.0,-448.0,50.0,-448.0), Q(43.0,-448.0,37.5,-453.5), Q(32.0,-459.0,32.0,-467.0), L(32.0,-500.0), L(16.0,-500.0), L(16.0,0.0), L(32.0,0.0), Q(32.0,-49.0,53.0,-99.0), Q(74.0,-149.0,103.5,-192.0), Q(133.0,-235.0,163.0,-278.0), Q(193.0,-321.0,214.0,-371.0), Q(235.0,-421.0,235.0,-470.0), Q(235.0,-511.0,215.0,-558.0), Q(212.0,-563.0,216.0,-572.0), Z , M(159.0,-479.0), Q(164.0,-487.0,173.0,-487.0), Q(180.0,-487.0,186.0,-482.0), Q(192.0,-477.0,192.0,-470.0), Q(192.0,-377.0,69.0,-250.0), Q(63.0,-244.0,55.0,-244.0), Q(48.0,-244.0,43.0,-249.0), Q(38.0,-254.0,38.0,-262.0), Q(38.0,-288.0,52.0,-320.5), Q(66.0,-353.0,81.0,-374.5), Q(96.0,-396.0,123.5,-432.0), Q(151.0,-468.0,159.0,-479.0), Z ];
-
-// pub const CADENZA_140 : &'static [PathSegment] = &[ M(192.0,-470.0), Q(192.0,-373.0,69.0,-250.0), Q(59.0,-240.0,50.0,-240.0), Q(32.0,-240.0,32.0,-265.0), L(32.0,-298.0), L(16.0,-298.0), L(16.0,0.0), L(32.0,0.0), Q(32.0,-49.0,53.0,-99.0), Q(74.0,-149.0,103.5,-192.0), Q(133.0,-235.0,163.0,-278.0), Q(193.0,-321.0,214.0,-371.0), Q(235.0,-421.0,235.0,-470.0), Q(235.0,-520.0,202.0,-564.0), Q(193.0,-576.0,180.0,-576.0), Q(173.0,-576.0,168.5,-570.5), Q(164.0,-565.0,164.0,-557.0), Q(164.0,-551.0,167.0,-547.0), Q(192.0,-506.0,192.0,-470.0), Z ];
-
-// pub const CADENZA_141 : &'static [PathSegment] = &[ M(59.0,267.0), Q(259.0,392.0,259.0,509.0), Q(259.0,588.0,218.0,672.0), Q(216.0,683.0,223.5,691.0), Q(231.0,699.0,241.0,699.0), Q(254.0,699.0,261.0,688.0), Q(301.0,598.0,301.0,509.0), Q(301.0,468.0,286.0,428.0), Q(271.0,388.0,247.5,354.5), Q(224.0,321.0,195.5,288.0), Q(167.0,255.0,138.0,221.5), Q(109.0,188.0,85.5,154.5), Q(62.0,121.0,47.0,81.0), Q(32.0,41.0,32.0,0.0), L(16.0,0.0), L(16.0,320.0), L(32.0,320.0), L(32.0,283.0), Q(32.0,275.0,37.5,270.0), Q(43.0,265.0,50.0,265.0), Q(55.0,265.0,59.0,267.0), Z ];
-
-// pub const CADENZA_142 : &'static [PathSegment] = &[ M(211.0,486.0), Q(197.0,472.0,166.0,443.5), Q(135.0,415.0,116.0,395.5), Q(97.0,376.0,75.5,349.0), Q(54.0,322.0,43.0,294.0), Q(32.0,266.0,32.0,237.0), Q(32.0,229.0,38.0,224.0), Q(44.0,219.0,51.0,219.0), Q(56.0,219.0,60.0,221.0), Q(246.0,330.0,246.0,438.0), Q(246.0,461.0,242.0,478.0), Q(239.0,492.0,225.0,492.0), Q(217.0,492.0,211.0,486.0), Z , M(267.0,538.0), Q(288.0,490.0,288.0,438.0), Q(288.0,388.0,261.5,341.0), Q(235.0,294.0,197.5,256.5), Q(160.0,219.0,122.5,181.5), Q(85.0,144.0,58.5,97.0), Q(32.0,50.0,32.0,0.0), L(16.0,0.0), L(16.0,500.0), L(32.0,500.0), L(32.0,460.0), Q(32.0,452.0,37.5,447.0), Q(43.0,442.0,50.0,442.0), Q(54.0,442.0,58.0,444.0), Q(259.0,550.0,259.0,663.0), Q(259.0,674.0,252.0,696.0), Q(245.0,718.0,245.0,730.0), Q(245.0,740.0,252.0,746.0), Q(259.0,752.0,268.0,752.0), Q(301.0,752.0,301.0,663.0), Q(301.0,608.0,268.0,555.0), Q(263.0,547.0,267.0,538.0), Z ];
-
-// pub const CADENZA_143 : &'static [PathSegment] = &[ M(44.0,339.0), L(44.0,-345.0), L(224.0,-345.0), L(224.0,339.0), L(44.0,339.0), Z , M(0.0,444.0), L(265.0,444.0), L(265.0,-447.0), L(0.0,-447.0), L(0.0,444.0), Z ];
-
-// pub const CADENZA_144 : &'static [PathSegment] = &[ M(141.0,82.0), Q(106.0,82.0,82.0,58.5), Q(58.0,35.0,58.0,0.0), Q(58.0,-12.0,62.0,-26.0), Q(66.0,-37.0,72.0,-46.0), L(73.0,-48.0), L(189.0,68.0), L(187.0,69.0), Q(178.0,75.0,167.0,78.0), Q(153.0,82.0,141.0,82.0), Z , M(95.0,-69.0), Q(118.0,-82.0,141.0,-82.0), Q(175.0,-82.0,199.0,-58.5), Q(223.0,-35.0,223.0,0.0), Q(223.0,12.0,219.0,27.0), Q(216.0,38.0,209.0,47.0), L(208.0,49.0), L(93.0,-68.0), L(95.0,-69.0), Z , M(230.0,66.0), Q(239.0,53.0,245.0,39.0), Q(252.0,22.0,252.0,0.0), Q(252.0,-24.0,243.0,-44.0), Q(234.0,-64.0,219.0,-78.5), Q(204.0,-93.0,184.0,-102.0), Q(164.0,-111.0,141.0,-111.0), Q(120.0,-111.0,103.0,-104.0), Q(89.0,-98.0,76.0,-89.0), L(74.0,-88.0), L(0.0,-161.0), L(-19.0,-141.0), L(53.0,-68.0), L(52.0,-67.0), Q(43.0,-55.0,37.0,-40.0), Q(30.0,-22.0,30.0,0.0), Q(30.0,23.0,39.0,43.5), Q(48.0,64.0,62.5,78.5), Q(77.0,93.0,97.0,102.0), Q(117.0,111.0,141.0,111.0), Q(162.0,111.0,180.0,104.0), Q(196.0,97.0,207.0,89.0), L(208.0,88.0), L(280.0,160.0), L(300.0,140.0), L(229.0,67.0), L(230.0,66.0), Z ];
-
-// pub const CADENZA_145 : &'static [PathSegment] = &[ M(554.0,567.0), Q(628.0,488.0,628.0,399.0), Q(628.0,357.0,608.0,320.0), Q(588.0,283.0,556.0,260.0), Q(549.0,255.0,549.0,246.5), Q(549.0,238.0,554.0,233.0), Q(628.0,154.0,628.0,66.0), Q(628.0,24.0,608.0,-13.5), Q(588.0,-51.0,556.0,-74.0), Q(540.0,-85.0,553.0,-100.0), Q(621.0,-176.0,621.0,-273.0), Q(621.0,-345.0,582.0,-401.0), Q(547.0,-452.0,489.0,-477.5), Q(431.0,-503.0,363.0,-503.0), Q(338.0,-503.0,314.0,-500.0), Q(230.0,-489.0,157.5,-444.0), Q(85.0,-399.0,37.0,-328.0), Q(27.0,-311.0,27.0,-291.0), Q(27.0,-263.0,45.5,-243.5), Q(64.0,-224.0,94.0,-224.0), Q(128.0,-224.0,148.0,-246.5), Q(168.0,-269.0,168.0,-299.0), Q(168.0,-323.0,154.0,-343.0), Q(144.0,-357.0,157.0,-368.0), Q(257.0,-451.0,381.0,-451.0), Q(449.0,-451.0,499.5,-418.0), Q(550.0,-385.0,550.0,-322.0), Q(550.0,-242.0,432.0,-159.0), L(125.0,56.0), Q(68.0,95.0,34.0,152.0), Q(0.0,209.0,0.0,267.0), Q(0.0,309.0,20.5,346.5), Q(41.0,384.0,72.0,407.0), Q(79.0,411.0,79.0,419.0), Q(79.0,427.0,74.0,433.0), Q(0.0,512.0,0.0,601.0), Q(0.0,643.0,20.0,680.0), Q(40.0,717.0,72.0,740.0), Q(79.0,745.0,79.5,753.5), Q(80.0,762.0,75.0,766.0), Q(0.0,846.0,0.0,934.0), Q(0.0,976.0,20.5,1013.5), Q(41.0,1051.0,72.0,1074.0), Q(78.0,1079.0,79.0,1086.5), Q(80.0,1094.0,75.0,1100.0), Q(7.0,1176.0,7.0,1273.0), Q(7.0,1345.0,46.0,1401.0), Q(81.0,1452.0,139.0,1477.5), Q(197.0,1503.0,265.0,1503.0), Q(290.0,1503.0,314.0,1500.0), Q(398.0,1489.0,470.5,1444.0), Q(543.0,1399.0,591.0,1328.0), Q(601.0,1311.0,601.0,1291.0), Q(601.0,1263.0,582.5,1243.5), Q(564.0,1224.0,534.0,1224.0), Q(500.0,1224.0,480.0,1246.5), Q(460.0,1269.0,460.0,1299.0), Q(460.0,1323.0,474.0,1343.0), Q(484.0,1355.0,471.0,1367.0), Q(370.0,1451.0,247.0,1451.0), Q(179.0,1451.0,128.5,1418.0), Q(78.0,1385.0,78.0,1322.0), Q(78.0,1242.0,196.0,1159.0), L(503.0,944.0), Q(560.0,905.0,594.0,848.0), Q(628.0,791.0,628.0,733.0), Q(628.0,691.0,608.0,654.5), Q(588.0,618.0,556.0,594.0), Q(549.0,588.0,549.0,580.0), Q(549.0,572.0,554.0,567.0), Z , M(531.0,626.0), Q(553.0,626.0,570.0,641.0), Q(587.0,656.0,587.0,679.0), Q(587.0,704.0,560.5,735.0), Q(534.0,766.0,507.0,787.0), Q(480.0,808.0,432.0,841.0), L(278.0,949.0), Q(237.0,978.0,213.0,993.5), Q(189.0,1009.0,154.5,1025.0), Q(120.0,1041.0,97.0,1041.0), Q(75.0,1041.0,58.0,1025.5), Q(41.0,1010.0,41.0,987.0), Q(41.0,962.0,67.5,931.0), Q(94.0,900.0,121.0,879.0), Q(148.0,858.0,196.0,825.0), L(349.0,719.0), Q(392.0,689.0,414.5,674.0), Q(437.0,659.0,472.0,642.5), Q(507.0,626.0,531.0,626.0), Z , M(531.0,-41.0), Q(553.0,-41.0,570.0,-25.5), Q(587.0,-10.0,587.0,13.0), Q(587.0,38.0,560.5,69.0), Q(534.0,100.0,507.0,121.0), Q(480.0,142.0,432.0,175.0), L(279.0,281.0), Q(237.0,310.0,213.5,325.5), Q(190.0,341.0,155.0,357.5), Q(120.0,374.0,97.0,374.0), Q(75.0,374.0,58.0,359.0), Q(41.0,344.0,41.0,321.0), Q(41.0,296.0,67.5,265.0), Q(94.0,234.0,121.0,213.0), Q(148.0,192.0,196.0,159.0), L(350.0,51.0), Q(391.0,22.0,415.0,6.5), Q(439.0,-9.0,473.5,-25.0), Q(508.0,-41.0,531.0,-41.0), Z , M(97.0,707.0), Q(75.0,707.0,58.0,691.5), Q(41.0,676.0,41.0,654.0), Q(41.0,629.0,67.5,598.0), Q(94.0,567.0,121.0,546.0), Q(148.0,525.0,196.0,492.0), L(360.0,377.0), Q(479.0,293.0,531.0,293.0), Q(553.0,293.0,570.0,308.5), Q(587.0,324.0,587.0,346.0), Q(587.0,371.0,560.5,402.0), Q(534.0,433.0,507.0,454.0), Q(480.0,475.0,432.0,508.0), L(268.0,623.0), Q(149.0,707.0,97.0,707.0), Z ];
-
-// pub const CADENZA_146 : &'static [PathSegment] = &[ M(150.0,-86.0), Q(150.0,-98.0,160.0,-102.0), Q(172.0,-107.0,180.0,-99.0), Q(246.0,-39.0,246.0,37.0), Q(246.0,64.0,234.5,84.0), Q(223.0,104.0,203.0,104.0), Q(181.0,104.0,167.0,85.0), Q(153.0,66.0,152.0,41.0), L(150.0,-86.0), Z , M(152.0,151.0), Q(152.0,141.0,160.0,136.0), Q(169.0,130.0,178.0,135.0), Q(220.0,156.0,247.0,156.0), Q(285.0,156.0,308.0,126.0), Q(331.0,96.0,331.0,54.0), Q(331.0,25.0,322.5,1.5), Q(314.0,-22.0,296.0,-41.5), Q(278.0,-61.0,262.5,-74.0), Q(247.0,-87.0,222.0,-105.5), Q(197.0,-124.0,184.0,-135.0), Q(179.0,-139.0,171.0,-150.0), Q(163.0,-161.0,155.5,-167.0), Q(148.0,-173.0,139.0,-173.0), Q(129.0,-173.0,122.5,-166.0), Q(116.0,-159.0,116.0,-148.0), Q(106.0,236.0,106.0,423.0), Q(106.0,441.0,93.0,441.0), Q(79.0,441.0,17.0,402.0), Q(11.0,398.0,5.5,402.0), Q(0.0,406.0,0.0,412.0), Q(0.0,415.0,2.0,418.0), Q(24.0,450.0,43.5,487.5), Q(63.0,525.0,85.5,575.0), Q(108.0,625.0,120.0,649.0), Q(123.0,655.0,129.0,655.0), Q(136.0,655.0,138.0,649.0), Q(196.0,503.0,256.0,418.0), Q(261.0,410.0,255.0,403.5), Q(249.0,397.0,241.0,402.0), Q(235.0,405.0,215.0,417.5), Q(195.0,430.0,184.0,435.5), Q(173.0,441.0,165.0,441.0), Q(152.0,441.0,152.0,423.0), Q(152.0,382.0,152.0,311.0), L(152.0,151.0), Z ];
-
-// pub const CADENZA_147 : &'static [PathSegment] = &[ M(105.0,-79.0), Q(112.0,-79.0,116.5,-74.5), Q(121.0,-70.0,121.0,-64.0), Q(121.0,-58.0,117.0,-54.0), L(45.0,27.0), Q(15.0,61.0,15.0,90.0), Q(15.0,115.0,86.0,177.0), Q(123.0,210.0,123.0,260.0), Q(123.0,299.0,98.0,329.0), L(63.0,371.0), Q(59.0,376.0,59.0,381.0), Q(59.0,396.0,73.0,396.0), Q(82.0,396.0,88.0,389.0), L(238.0,211.0), Q(248.0,199.0,248.0,186.0), Q(248.0,175.0,240.0,163.0), Q(232.0,151.0,223.0,142.5), Q(214.0,134.0,196.5,119.5), Q(179.0,105.0,172.0,98.0), Q(130.0,59.0,130.0,12.0), Q(130.0,-24.0,171.0,-68.0), L(255.0,-159.0), Q(259.0,-163.0,259.0,-169.0), Q(259.0,-175.0,254.5,-179.5), Q(250.0,-184.0,245.0,-184.0), Q(238.0,-184.0,224.0,-173.0), Q(187.0,-145.0,145.0,-145.0), Q(116.0,-145.0,94.5,-167.5), Q(73.0,-190.0,73.0,-219.0), Q(73.0,-232.0,75.0,-242.5), Q(77.0,-253.0,82.5,-264.0), Q(88.0,-275.0,90.0,-279.0), Q(92.0,-283.0,101.0,-295.5), Q(110.0,-308.0,111.0,-309.0), Q(114.0,-313.0,113.0,-318.0), Q(112.0,-323.0,107.5,-325.5), Q(103.0,-328.0,98.0,-327.0), Q(93.0,-326.0,85.0,-318.0), Q(51.0,-283.0,25.5,-239.5), Q(0.0,-196.0,0.0,-164.0), Q(0.0,-125.0,27.5,-102.0), Q(55.0,-79.0,105.0,-79.0), Z ];
-
-// pub const CADENZA_148 : &'static [PathSegment] = &[ M(0.0,-34.0), Q(0.0,26.0,66.0,81.5), Q(132.0,137.0,201.0,137.0), Q(229.0,137.0,254.5,130.0), Q(280.0,123.0,303.0,98.0), Q(326.0,73.0,326.0,34.0), Q(326.0,-21.0,259.0,-79.0), Q(192.0,-137.0,125.0,-137.0), Q(97.0,-137.0,71.5,-130.0), Q(46.0,-123.0,23.0,-98.0), Q(0.0,-73.0,0.0,-34.0), Z ];
-
-// pub const CADENZA_149 : &'static [PathSegment] = &[ M(161.0,131.0), Q(127.0,153.0,91.0,153.0), Q(64.0,153.0,44.5,140.0), Q(25.0,127.0,25.0,102.0), Q(25.0,72.0,64.0,72.0), Q(77.0,72.0,91.0,74.5), Q(105.0,77.0,113.0,80.0), Q(121.0,83.0,137.0,88.5), Q(153.0,94.0,159.0,96.0), Q(165.0,98.0,169.0,109.0), L(170.0,112.0), Q(174.0,123.0,161.0,131.0), Z , M(333.0,-122.0), L(385.0,137.0), L(385.0,144.0), Q(385.0,170.0,356.0,170.0), Q(345.0,170.0,332.0,163.0), L(260.0,123.0), Q(253.0,119.0,251.0,111.0), L(248.0,97.0), Q(246.0,88.0,252.0,81.0), Q(296.0,28.0,296.0,-21.0), Q(296.0,-67.0,266.0,-101.5), Q(236.0,-136.0,198.0,-136.0), Q(170.0,-136.0,156.0,-109.5), Q(142.0,-83.0,142.0,-45.0), Q(142.0,-24.0,146.0,-4.0), L(155.0,40.0), Q(157.0,50.0,148.0,57.0), Q(140.0,63.0,131.0,60.0), Q(95.0,47.0,60.0,47.0), Q(33.0,47.0,16.5,60.5), Q(0.0,74.0,0.0,98.0), Q(0.0,134.0,29.0,156.0), Q(58.0,178.0,96.0,178.0), Q(129.0,178.0,157.0,164.0), Q(161.0,162.0,165.0,162.0), Q(180.0,162.0,183.0,177.0), L(211.0,321.0), Q(212.0,324.0,213.5,325.5), Q(215.0,327.0,218.0,327.5), Q(221.0,328.0,222.5,328.0), Q(224.0,328.0,228.0,328.0), Q(232.0,328.0,233.0,328.0), Q(258.0,328.0,286.0,339.0), Q(289.0,340.0,292.5,337.5), Q(296.0,335.0,295.0,331.0), L(267.0,188.0), Q(265.0,179.0,270.5,172.5), Q(276.0,166.0,285.0,166.0), Q(289.0,166.0,293.0,168.0), L(323.0,185.0), Q(358.0,205.0,397.0,205.0), Q(432.0,205.0,451.0,189.0), Q(462.0,180.0,473.0,188.0), Q(500.0,207.0,530.0,207.0), Q(559.0,207.0,579.0,189.0), Q(599.0,171.0,599.0,138.0), Q(599.0,103.0,580.5,75.5), Q(562.0,48.0,534.0,48.0), Q(516.0,48.0,506.0,61.5), Q(496.0,75.0,496.0,96.0), Q(496.0,132.0,524.0,146.0), Q(537.0,152.0,532.0,167.0), Q(527.0,181.0,512.0,179.0), Q(473.0,173.0,466.0,137.0), L(414.0,-122.0), Q(413.0,-126.0,409.0,-127.5), Q(405.0,-129.0,402.0,-128.0), Q(389.0,-122.0,374.0,-122.0), Q(358.0,-122.0,340.0,-129.0), Q(338.0,-130.0,335.0,-128.0), Q(332.0,-126.0,333.0,-122.0), Z , M(230.0,8.0), Q(221.0,-35.0,221.0,-57.0), Q(221.0,-86.0,236.0,-86.0), Q(252.0,-86.0,261.5,-67.0), Q(271.0,-48.0,271.0,-25.0), Q(271.0,-9.0,264.0,10.0), Q(259.0,24.0,245.0,22.0), Q(233.0,20.0,230.0,8.0), Z ];
-
-// pub const CADENZA_150 : &'static [PathSegment] = &[ M(381.0,295.0), Q(410.0,368.0,467.5,421.0), Q(525.0,474.0,590.0,474.0), Q(625.0,474.0,651.0,454.5), Q(677.0,435.0,677.0,405.0), Q(677.0,373.0,663.5,350.5), Q(650.0,328.0,625.0,328.0), Q(607.0,328.0,594.0,340.5), Q(581.0,353.0,581.0,371.0), Q(581.0,380.0,585.5,385.5), Q(590.0,391.0,598.5,397.0), Q(607.0,403.0,610.0,405.0), Q(620.0,415.0,614.0,427.0), Q(610.0,437.0,597.0,437.0), L(590.0,437.0), Q(577.0,437.0,566.0,432.5), Q(555.0,428.0,547.0,416.0), Q(539.0,404.0,534.0,395.0), Q(529.0,386.0,523.5,366.0), Q(518.0,346.0,516.0,337.5), Q(514.0,329.0,509.0,305.0), Q(508.0,302.0,508.0,300.0), Q(508.0,292.0,513.5,287.5), Q(519.0,283.0,526.0,283.0), L(573.0,283.0), Q(592.0,283.0,592.0,264.0), Q(592.0,245.0,573.0,245.0), L(506.0,245.0), Q(492.0,245.0,489.0,232.0), L(443.0,82.0), Q(416.0,-7.0,353.5,-90.0), Q(291.0,-173.0,225.0,-173.0), Q(190.0,-173.0,164.0,-153.5), Q(138.0,-134.0,138.0,-104.0), Q(138.0,-72.0,151.5,-49.5), Q(165.0,-27.0,190.0,-27.0), Q(208.0,-27.0,221.0,-40.0), Q(234.0,-53.0,234.0,-71.0), Q(234.0,-78.0,231.0,-83.5), Q(228.0,-89.0,225.5,-91.0), Q(223.0,-93.0,216.5,-97.5), Q(210.0,-102.0,208.0,-103.0), Q(197.0,-111.0,202.0,-124.0), Q(206.0,-136.0,219.0,-136.0), L(225.0,-136.0), Q(242.0,-136.0,254.5,-127.5), Q(267.0,-119.0,275.5,-99.5), Q(284.0,-80.0,288.0,-67.5), Q(292.0,-55.0,298.0,-28.0), L(360.0,223.0), Q(361.0,225.0,361.0,228.0), Q(361.0,235.0,356.0,240.0), Q(351.0,245.0,343.0,245.0), L(306.0,245.0), Q(304.0,245.0,302.0,245.0), Q(300.0,245.0,298.0,245.0), L(231.0,245.0), Q(217.0,245.0,214.0,232.0), L(168.0,82.0), Q(141.0,-7.0,78.5,-90.0), Q(16.0,-173.0,-50.0,-173.0), Q(-85.0,-173.0,-111.0,-153.5), Q(-137.0,-134.0,-137.0,-104.0), Q(-137.0,-72.0,-123.5,-49.5), Q(-110.0,-27.0,-85.0,-27.0), Q(-67.0,-27.0,-54.0,-40.0), Q(-41.0,-53.0,-41.0,-71.0), Q(-41.0,-78.0,-44.0,-83.5), Q(-47.0,-89.0,-49.5,-91.0), Q(-52.0,-93.0,-58.5,-97.5), Q(-65.0,-102.0,-67.0,-103.0), Q(-78.0,-111.0,-73.0,-124.0), Q(-69.0,-136.0,-56.0,-136.0), L(-50.0,-136.0), Q(-33.0,-136.0,-20.5,-127.5), Q(-8.0,-119.0,0.5,-99.5), Q(9.0,-80.0,13.0,-67.5), Q(17.0,-55.0,23.0,-28.0), L(85.0,223.0), Q(86.0,225.0,86.0,228.0), Q(86.0,235.0,81.0,240.0), Q(76.0,245.0,68.0,245.0), L(31.0,245.0), Q(12.0,245.0,12.0,264.0), Q(12.0,283.0,31.0,283.0), L(89.0,283.0), Q(101.0,283.0,106.0,295.0), Q(135.0,368.0,192.5,421.0), Q(250.0,474.0,315.0,474.0), Q(350.0,474.0,376.0,454.5), Q(402.0,435.0,402.0,405.0), Q(402.0,373.0,388.5,350.5), Q(375.0,328.0,350.0,328.0), Q(332.0,328.0,319.0,340.5), Q(306.0,353.0,306.0,371.0), Q(306.0,380.0,310.5,385.5), Q(315.0,391.0,323.5,397.0), Q(332.0,403.0,335.0,405.0), Q(345.0,415.0,339.0,427.0), Q(335.0,437.0,322.0,437.0), L(315.0,437.0), Q(302.0,437.0,291.0,432.5), Q(280.0,428.0,272.0,416.0), Q(264.0,404.0,259.0,395.0), Q(254.0,386.0,248.5,366.0), Q(243.0,346.0,241.0,337.5), Q(239.0,329.0,234.0,305.0), Q(233.0,302.0,233.0,300.0), Q(233.0,292.0,238.5,287.5), Q(244.0,283.0,251.0,283.0), L(298.0,283.0), Q(300.0,283.0,302.0,283.0), Q(304.0,283.0,306.0,283.0), L(364.0,283.0), Q(376.0,283.0,381.0,295.0), Z ];
-
-// pub const CADENZA_151 : &'static [PathSegment] = &[ M(138.0,138.0), Q(148.0,138.0,155.5,130.5), Q(163.0,123.0,163.0,112.0), L(163.0,43.0), Q(163.0,25.0,181.0,25.0), L(250.0,25.0), Q(261.0,25.0,268.5,17.5), Q(276.0,10.0,276.0,0.0), Q(276.0,-10.0,268.5,-17.5), Q(261.0,-25.0,250.0,-25.0), L(181.0,-25.0), Q(163.0,-25.0,163.0,-43.0), L(163.0,-112.0), Q(163.0,-123.0,155.5,-130.5), Q(148.0,-138.0,138.0,-138.0), Q(128.0,-138.0,120.5,-130.5), Q(113.0,-123.0,113.0,-112.0), L(113.0,-43.0), Q(113.0,-25.0,95.0,-25.0), L(26.0,-25.0), Q(15.0,-25.0,7.5,-17.5), Q(0.0,-10.0,0.0,0.0), Q(0.0,10.0,7.5,17.5), Q(15.0,25.0,26.0,25.0), L(95.0,25.0), Q(113.0,25.0,113.0,43.0), L(113.0,112.0), Q(113.0,123.0,120.5,130.5), Q(128.0,138.0,138.0,138.0), Z ];
-
-// pub const CADENZA_152 : &'static [PathSegment] = &[ M(115.0,1.0), Q(115.0,24.0,131.0,40.0), Q(147.0,56.0,170.0,56.0), Q(193.0,56.0,209.0,40.0), Q(225.0,24.0,225.0,1.0), Q(225.0,-22.0,209.0,-38.0), Q(193.0,-54.0,170.0,-54.0), Q(147.0,-54.0,131.0,-38.0), Q(115.0,-22.0,115.0,1.0), Z , M(285.0,230.0), L(14.0,301.0), Q(0.0,304.0,0.0,319.0), Q(0.0,326.0,6.0,332.0), Q(12.0,338.0,19.0,338.0), Q(20.0,338.0,22.0,338.0), L(23.0,337.0), L(431.0,232.0), Q(450.0,232.0,450.0,213.0), Q(450.0,194.0,431.0,194.0), L(23.0,89.0), L(22.0,88.0), Q(20.0,88.0,19.0,88.0), Q(12.0,88.0,6.0,94.0), Q(0.0,100.0,0.0,107.0), Q(0.0,122.0,14.0,125.0), L(285.0,196.0), Q(299.0,201.0,299.0,213.0), Q(299.0,225.0,285.0,230.0), Z ];
-
-// pub const CADENZA_153 : &'static [PathSegment] = &[ M(249.0,101.0), Q(220.0,101.0,126.5,35.5), Q(33.0,-30.0,33.0,-60.0), Q(33.0,-81.0,51.0,-91.0), Q(69.0,-101.0,94.0,-101.0), Q(123.0,-101.0,216.5,-35.5), Q(310.0,30.0,310.0,60.0), Q(310.0,81.0,292.0,91.0), Q(274.0,101.0,249.0,101.0), Z , M(0.0,-38.0), Q(0.0,34.0,62.0,85.5), Q(124.0,137.0,212.0,137.0), Q(242.0,137.0,268.0,131.0), Q(294.0,125.0,318.0,101.0), Q(342.0,77.0,342.0,38.0), Q(342.0,-34.0,280.0,-85.5), Q(218.0,-137.0,130.0,-137.0), Q(100.0,-137.0,74.0,-131.0), Q(48.0,-125.0,24.0,-101.0), Q(0.0,-77.0,0.0,-38.0), Z ];
-
-// pub const CADENZA_154 : &'static [PathSegment] = &[ M(429.0,66.0), Q(431.0,68.0,450.0,84.0), Q(456.0,90.0,463.0,90.0), Q(474.0,90.0,474.0,80.0), Q(474.0,75.0,467.0,68.0), Q(388.0,-7.0,336.0,-7.0), Q(321.0,-7.0,312.5,2.0), Q(304.0,11.0,304.0,25.0), Q(304.0,32.0,305.0,36.0), L(344.0,201.0), Q(346.0,211.0,346.0,215.0), Q(346.0,222.0,338.0,227.5), Q(330.0,233.0,322.0,233.0), Q(310.0,233.0,299.0,223.5), Q(288.0,214.0,283.0,201.0), L(230.0,18.0), Q(225.0,0.0,208.0,0.0), L(167.0,0.0), Q(159.0,0.0,155.5,5.5), Q(152.0,11.0,154.0,18.0), L(206.0,201.0), Q(209.0,210.0,209.0,217.0), Q(209.0,223.0,201.0,228.0), Q(193.0,233.0,186.0,233.0), Q(174.0,233.0,162.0,223.5), Q(150.0,214.0,145.0,201.0), L(83.0,18.0), Q(78.0,0.0,59.0,0.0), L(18.0,0.0), Q(1.0,0.0,6.0,18.0), L(69.0,201.0), Q(71.0,206.0,71.0,216.0), Q(71.0,233.0,58.0,233.0), Q(23.0,233.0,-8.0,146.0), Q(-14.0,129.0,-31.0,129.0), Q(-43.0,129.0,-43.0,140.0), Q(-43.0,142.0,-41.0,146.0), Q(-20.0,209.0,20.5,254.0), Q(61.0,299.0,108.0,299.0), Q(130.0,299.0,141.0,278.0), Q(146.0,269.0,157.0,269.0), Q(163.0,269.0,168.0,273.0), Q(199.0,299.0,230.0,299.0), Q(242.0,299.0,255.5,292.0), Q(269.0,285.0,275.0,276.0), Q(280.0,267.0,290.0,267.0), Q(297.0,267.0,302.0,272.0), Q(332.0,299.0,364.0,299.0), Q(389.0,299.0,411.0,277.0), Q(433.0,255.0,433.0,230.0), Q(433.0,225.0,431.0,215.0), L(400.0,84.0), Q(396.0,72.0,408.0,65.0), Q(418.0,59.0,429.0,66.0), Z ];
-
-// pub const CADENZA_155 : &'static [PathSegment] = &[ M(629.0,250.0), Q(590.0,250.0,558.5,187.5), Q(527.0,125.0,527.0,70.0), Q(527.0,32.0,557.0,32.0), Q(579.0,32.0,598.5,53.5), Q(618.0,75.0,629.5,105.5), Q(641.0,136.0,647.5,166.0), Q(654.0,196.0,654.0,217.0), Q(654.0,250.0,629.0,250.0), Z , M(265.0,250.0), Q(226.0,250.0,194.5,187.5), Q(163.0,125.0,163.0,70.0), Q(163.0,32.0,193.0,32.0), Q(215.0,32.0,234.5,53.5), Q(254.0,75.0,265.5,105.5), Q(277.0,136.0,283.5,166.0), Q(290.0,196.0,290.0,217.0), Q(290.0,250.0,265.0,250.0), Z , M(17.0,153.0), Q(10.0,136.0,-7.0,136.0), Q(-21.0,136.0,-21.0,148.0), Q(-21.0,150.0,-19.0,156.0), Q(44.0,299.0,113.0,299.0), Q(153.0,299.0,171.0,268.0), Q(175.0,262.0,183.0,260.5), Q(191.0,259.0,197.0,264.0), Q(240.0,299.0,282.0,299.0), Q(332.0,299.0,360.0,267.0), Q(375.0,250.0,383.0,228.0), Q(429.0,299.0,477.0,299.0), Q(517.0,299.0,535.0,268.0), Q(539.0,262.0,547.0,260.5), Q(555.0,259.0,561.0,264.0), Q(604.0,299.0,646.0,299.0), Q(696.0,299.0,724.5,267.0), Q(753.0,235.0,753.0,185.0), Q(753.0,152.0,741.0,118.0), Q(725.0,73.0,680.0,32.5), Q(635.0,-8.0,594.0,-8.0), Q(551.0,-8.0,521.0,9.0), Q(515.0,12.0,510.0,12.0), Q(497.0,12.0,494.0,3.0), L(465.0,-83.0), Q(464.0,-85.0,464.0,-90.0), Q(464.0,-114.0,508.0,-118.0), Q(521.0,-119.0,521.0,-132.0), Q(521.0,-146.0,508.0,-146.0), Q(489.0,-146.0,450.0,-143.5), Q(411.0,-141.0,392.0,-141.0), Q(372.0,-141.0,333.5,-143.5), Q(295.0,-146.0,277.0,-146.0), Q(263.0,-146.0,263.0,-132.0), Q(263.0,-127.0,266.0,-123.5), Q(269.0,-120.0,275.5,-118.5), Q(282.0,-117.0,286.5,-116.5), Q(291.0,-116.0,300.0,-116.0), Q(309.0,-116.0,311.0,-116.0), Q(357.0,-114.0,365.0,-91.0), L(467.0,216.0), Q(469.0,223.0,469.0,229.0), Q(469.0,236.0,465.0,240.5), Q(461.0,245.0,458.0,246.0), Q(455.0,247.0,452.0,247.0), Q(438.0,246.0,416.0,214.0), Q(400.0,190.0,388.0,169.0), Q(386.0,144.0,377.0,118.0), Q(361.0,73.0,316.0,32.5), Q(271.0,-8.0,230.0,-8.0), Q(187.0,-8.0,157.0,9.0), Q(151.0,12.0,146.0,12.0), Q(133.0,12.0,130.0,3.0), L(101.0,-83.0), Q(100.0,-85.0,100.0,-90.0), Q(100.0,-114.0,144.0,-118.0), Q(157.0,-119.0,157.0,-132.0), Q(157.0,-146.0,144.0,-146.0), Q(125.0,-146.0,86.0,-143.5), Q(47.0,-141.0,28.0,-141.0), Q(8.0,-141.0,-30.5,-143.5), Q(-69.0,-146.0,-87.0,-146.0), Q(-101.0,-146.0,-101.0,-132.0), Q(-101.0,-127.0,-98.0,-123.5), Q(-95.0,-120.0,-88.5,-118.5), Q(-82.0,-117.0,-77.5,-116.5), Q(-73.0,-116.0,-64.0,-116.0), Q(-55.0,-116.0,-53.0,-116.0), Q(-7.0,-114.0,1.0,-91.0), L(103.0,216.0), Q(105.0,223.0,105.0,229.0), Q(105.0,236.0,101.0,240.5), Q(97.0,245.0,94.0,246.0), Q(91.0,247.0,88.0,247.0), Q(74.0,247.0,51.5,214.5), Q(29.0,182.0,17.0,153.0), Z ];
-
-// pub const CADENZA_156 : &'static [PathSegment] = &[ M(102.0,385.0), Q(102.0,412.0,121.0,431.0), Q(140.0,450.0,167.0,450.0), Q(194.0,450.0,213.0,431.0), Q(232.0,412.0,232.0,385.0), Q(232.0,358.0,213.0,339.0), Q(194.0,320.0,167.0,320.0), Q(140.0,320.0,121.0,339.0), Q(102.0,358.0,102.0,385.0), Z , M(371.0,122.0), Q(371.0,149.0,390.0,168.0), Q(409.0,187.0,436.0,187.0), Q(463.0,187.0,482.0,168.0), Q(501.0,149.0,501.0,122.0), Q(501.0,95.0,482.0,76.0), Q(463.0,57.0,436.0,57.0), Q(409.0,57.0,390.0,76.0), Q(371.0,95.0,371.0,122.0), Z , M(0.0,0.0), L(466.0,506.0), L(596.0,506.0), L(134.0,0.0), L(0.0,0.0), Z ];
-
-// pub const CADENZA_157 : &'static [PathSegment] = &[ M(0.0,0.0), L(466.0,506.0), L(596.0,506.0), L(134.0,0.0), L(0.0,0.0), Z ];
-
-// pub const CADENZA_158 : &'static [PathSegment] = &[ M(0.0,0.0), Q(0.0,3.0,3.0,6.0), Q(86.0,76.0,155.0,158.0), Q(158.0,161.0,163.0,161.0), Q(167.0,161.0,170.0,158.0), Q(239.0,76.0,322.0,6.0), Q(325.0,3.0,325.0,0.0), Q(325.0,-3.0,322.0,-6.0), Q(239.0,-76.0,170.0,-158.0), Q(167.0,-161.0,163.0,-161.0), Q(158.0,-161.0,155.0,-158.0), Q(86.0,-76.0,3.0,-6.0), Q(0.0,-3.0,0.0,0.0), Z ];
-
-// pub const CADENZA_159 : &'static [PathSegment] = &[ M(338.0,0.0), Q(338.0,118.0,286.0,182.0), Q(284.0,184.0,284.0,188.0), Q(284.0,198.0,296.0,197.0), Q(300.0,197.0,304.0,193.0), Q(382.0,95.0,382.0,0.0), Q(382.0,-95.0,304.0,-193.0), Q(300.0,-197.0,296.0,-197.0), Q(285.0,-198.0,284.0,-188.0), Q(284.0,-185.0,286.0,-182.0), Q(338.0,-118.0,338.0,0.0), Z , M(164.0,135.0), L(245.0,152.0), L(248.0,152.0), Q(262.0,152.0,261.0,139.0), L(266.0,-281.0), Q(256.0,-286.0,247.5,-286.0), Q(239.0,-286.0,229.0,-281.0), L(231.0,-148.0), Q(231.0,-142.0,227.0,-138.5), Q(223.0,-135.0,218.0,-135.0), Q(217.0,-135.0,215.0,-135.0), L(134.0,-152.0), L(132.0,-152.0), Q(118.0,-152.0,118.0,-140.0), L(112.0,281.0), Q(122.0,286.0,132.0,286.0), Q(140.0,286.0,151.0,281.0), L(148.0,149.0), Q(148.0,143.0,152.0,139.0), Q(156.0,135.0,162.0,135.0), Q(163.0,135.0,164.0,135.0), Z , M(161.0,-75.0), L(220.0,-62.0), Q(231.0,-60.0,232.0,-48.0), L(234.0,61.0), Q(234.0,67.0,229.5,71.5), Q(225.0,76.0,219.0,74.0), L(159.0,62.0), Q(148.0,60.0,147.0,49.0), L(146.0,-62.0), Q(146.0,-68.0,150.5,-72.5), Q(155.0,-77.0,161.0,-75.0), Z , M(43.0,0.0), Q(42.0,-118.0,94.0,-182.0), Q(96.0,-184.0,97.0,-188.0), Q(97.0,-198.0,86.0,-197.0), Q(80.0,-197.0,77.0,-193.0), Q(0.0,-97.0,0.0,0.0), Q(0.0,97.0,77.0,193.0), Q(80.0,197.0,86.0,197.0), Q(96.0,198.0,97.0,188.0), Q(96.0,185.0,94.0,182.0), Q(42.0,118.0,43.0,0.0), Z ];
-
-// pub const CADENZA_160 : &'static [PathSegment] = &[ M(469.0,0.0), Q(469.0,130.0,417.0,182.0), Q(415.0,184.0,415.0,188.0), Q(415.0,198.0,426.0,197.0), Q(430.0,197.0,434.0,193.0), Q(512.0,95.0,513.0,0.0), Q(513.0,-99.0,434.0,-193.0), Q(430.0,-197.0,426.0,-197.0), Q(415.0,-198.0,415.0,-188.0), Q(415.0,-185.0,417.0,-182.0), Q(469.0,-118.0,469.0,0.0), Z , M(256.0,129.0), L(252.0,343.0), Q(262.0,349.0,272.0,349.0), Q(282.0,349.0,292.0,343.0), L(289.0,113.0), Q(289.0,105.0,295.0,102.0), Q(302.0,98.0,308.0,101.0), Q(340.0,117.0,360.0,117.0), Q(388.0,117.0,405.5,94.5), Q(423.0,72.0,423.0,40.0), Q(423.0,18.0,416.5,1.0), Q(410.0,-16.0,396.5,-31.0), Q(383.0,-46.0,371.5,-55.5), Q(360.0,-65.0,341.0,-79.0), Q(322.0,-93.0,313.0,-101.0), Q(309.0,-104.0,303.0,-112.5), Q(297.0,-121.0,291.5,-125.5), Q(286.0,-130.0,279.0,-130.0), Q(271.0,-130.0,266.5,-124.5), Q(262.0,-119.0,262.0,-111.0), L(261.0,-65.0), Q(261.0,-59.0,257.0,-55.5), Q(253.0,-52.0,247.0,-52.0), Q(242.0,-52.0,238.0,-56.0), Q(230.0,-63.0,210.0,-78.0), Q(188.0,-93.0,178.0,-101.0), Q(174.0,-104.0,168.5,-112.5), Q(163.0,-121.0,157.5,-125.5), Q(152.0,-130.0,145.0,-130.0), Q(137.0,-130.0,132.5,-124.5), Q(128.0,-119.0,128.0,-111.0), L(118.0,343.0), Q(128.0,349.0,138.5,349.0), Q(149.0,349.0,158.0,343.0), L(155.0,113.0), Q(154.0,105.0,160.0,102.0), Q(167.0,98.0,174.0,101.0), Q(206.0,117.0,226.0,117.0), Q(230.0,118.0,239.0,116.0), Q(241.0,116.0,243.0,115.0), Q(249.0,115.0,252.5,119.0), Q(256.0,123.0,256.0,129.0), Z , M(287.0,-64.0), Q(287.0,-72.0,295.0,-76.0), Q(304.0,-80.0,310.0,-74.0), Q(360.0,-29.0,359.0,28.0), Q(359.0,48.0,350.5,63.0), Q(342.0,78.0,327.0,78.0), Q(311.0,78.0,300.5,64.0), Q(290.0,50.0,289.0,31.0), L(287.0,-64.0), Z , M(153.0,-64.0), Q(152.0,-72.0,160.0,-76.0), Q(170.0,-80.0,176.0,-74.0), Q(226.0,-29.0,225.0,28.0), Q(225.0,48.0,216.5,63.0), Q(208.0,78.0,193.0,78.0), Q(177.0,78.0,166.0,64.0), Q(155.0,50.0,155.0,31.0), L(153.0,-64.0), Z , M(43.0,0.0), Q(42.0,-118.0,94.0,-182.0), Q(96.0,-184.0,97.0,-188.0), Q(97.0,-198.0,86.0,-197.0), Q(80.0,-197.0,77.0,-193.0), Q(0.0,-97.0,0.0,0.0), Q(0.0,97.0,77.0,193.0), Q(80.0,197.0,86.0,197.0), Q(96.0,198.0,97.0,188.0), Q(96.0,185.0,94.0,182.0), Q(42.0,118.0,43.0,0.0), Z ];
-
-// pub const CADENZA_161 : &'static [PathSegment] = &[ M(150.0,0.0), L(10.0,0.0), Q(0.0,0.0,0.0,10.0), L(0.0,240.0), Q(0.0,250.0,10.0,250.0), L(150.0,250.0), Q(160.0,250.0,160.0,240.0), L(160.0,10.0), Q(160.0,0.0,150.0,0.0), Z ];
-
-// pub const CADENZA_162 : &'static [PathSegment] = &[ M(248.0,565.0), Q(225.0,638.0,216.0,695.0), Q(208.0,743.0,208.0,807.0), Q(208.0,829.0,209.0,851.0), Q(213.0,946.0,248.0,1026.0), Q(276.0,1090.0,328.0,1130.0), Q(308.0,1134.0,293.0,1145.0), Q(269.0,1163.0,269.0,1194.0), Q(269.0,1252.0,341.0,1282.0), Q(327.0,1293.0,320.0,1305.0), Q(313.0,1319.0,313.0,1338.0), Q(313.0,1376.0,341.0,1402.0), Q(369.0,1428.0,409.0,1429.0), Q(443.0,1429.0,466.0,1410.0), Q(488.0,1391.0,488.0,1362.0), Q(488.0,1328.0,460.0,1306.0), Q(450.0,1299.0,428.0,1288.0), Q(446.0,1276.0,454.0,1266.0), Q(468.0,1246.0,469.0,1222.0), Q(469.0,1180.0,437.0,1154.0), Q(417.0,1137.0,391.0,1131.0), Q(437.0,1079.0,464.0,1002.0), Q(494.0,915.0,494.0,826.0), Q(494.0,723.0,450.0,624.0), Q(408.0,525.0,332.0,449.0), Q(326.0,443.0,328.0,433.0), L(368.0,273.0), Q(371.0,257.0,388.0,259.0), Q(404.0,261.0,419.0,261.0), Q(477.0,261.0,524.0,238.0), Q(571.0,215.0,600.0,178.0), Q(629.0,141.0,644.5,96.5), Q(660.0,52.0,660.0,5.0), Q(660.0,-81.0,612.0,-150.5), Q(564.0,-220.0,497.0,-242.0), Q(483.0,-247.0,486.0,-263.0), Q(511.0,-381.0,512.0,-462.0), Q(512.0,-493.0,508.0,-523.0), Q(500.0,-592.0,448.0,-644.0), Q(396.0,-696.0,324.0,-696.0), Q(236.0,-696.0,175.0,-643.0), Q(114.0,-590.0,114.0,-504.0), Q(114.0,-460.0,145.0,-428.0), Q(176.0,-396.0,218.0,-396.0), Q(261.0,-396.0,290.5,-426.5), Q(320.0,-457.0,320.0,-499.0), Q(320.0,-535.0,296.0,-562.0), Q(272.0,-590.0,238.0,-593.0), Q(226.0,-594.0,222.0,-605.0), Q(218.0,-616.0,230.0,-625.0), Q(266.0,-653.0,318.0,-653.0), Q(375.0,-653.0,416.5,-612.0), Q(458.0,-571.0,465.0,-519.0), Q(470.0,-483.0,470.0,-447.0), Q(470.0,-369.0,446.0,-274.0), Q(442.0,-257.0,424.0,-259.0), Q(384.0,-264.0,350.0,-264.0), Q(202.0,-264.0,101.0,-171.0), Q(0.0,-78.0,0.0,82.0), Q(0.0,131.0,11.5,178.5), Q(23.0,226.0,37.5,260.5), Q(52.0,295.0,82.5,340.5), Q(113.0,386.0,131.5,410.0), Q(150.0,434.0,190.0,481.5), Q(230.0,529.0,245.0,548.0), Q(251.0,555.0,248.0,565.0), Z , M(404.0,108.0), L(469.0,-187.0), Q(472.0,-200.0,487.0,-200.0), Q(491.0,-200.0,494.0,-199.0), Q(535.0,-182.0,562.0,-133.0), Q(589.0,-84.0,589.0,-36.0), Q(589.0,34.0,548.0,82.0), Q(506.0,131.0,422.0,131.0), Q(415.0,131.0,409.5,125.5), Q(404.0,120.0,404.0,113.0), Q(403.0,110.0,404.0,108.0), Z , M(266.0,386.0), Q(250.0,368.0,218.5,334.5), Q(187.0,301.0,171.5,283.5), Q(156.0,266.0,133.5,234.0), Q(111.0,202.0,100.0,175.0), Q(89.0,148.0,80.5,109.0), Q(72.0,70.0,72.0,26.0), Q(72.0,-35.0,109.5,-92.0), Q(147.0,-149.0,212.5,-185.0), Q(278.0,-221.0,353.0,-221.0), Q(380.0,-221.0,413.0,-218.0), Q(421.0,-217.0,426.0,-210.5), Q(431.0,-204.0,429.0,-196.0), L(364.0,103.0), Q(361.0,117.0,345.0,117.0), Q(342.0,117.0,338.0,116.0), Q(292.0,99.0,273.0,68.0), Q(254.0,37.0,254.0,-10.0), Q(254.0,-65.0,314.0,-105.0), Q(321.0,-110.0,321.0,-120.0), Q(321.0,-128.0,314.5,-135.0), Q(308.0,-142.0,299.0,-142.0), Q(294.0,-142.0,290.0,-140.0), Q(233.0,-110.0,205.5,-58.5), Q(178.0,-7.0,178.0,50.0), Q(178.0,105.0,217.5,163.5), Q(257.0,222.0,316.0,244.0), Q(331.0,250.0,328.0,265.0), L(298.0,378.0), Q(296.0,389.0,284.0,391.0), Q(272.0,393.0,266.0,386.0), Z , M(434.0,968.0), Q(426.0,994.0,407.0,994.0), Q(401.0,994.0,396.0,991.0), Q(332.0,953.0,294.5,884.0), Q(257.0,815.0,257.0,738.0), Q(257.0,688.0,278.0,615.0), Q(281.0,604.0,293.0,602.0), Q(303.0,601.0,310.0,609.0), Q(313.0,613.0,338.5,644.5), Q(364.0,676.0,372.0,686.5), Q(380.0,697.0,399.0,726.5), Q(418.0,756.0,425.5,775.5), Q(433.0,795.0,440.0,824.0), Q(447.0,853.0,447.0,882.0), Q(447.0,924.0,434.0,968.0), Z , M(362.0,1267.0), Q(361.0,1266.0,359.0,1265.0), Q(357.0,1264.0,356.0,1265.0), L(355.0,1264.0), L(355.0,1264.0), Q(333.0,1254.0,320.0,1237.0), Q(307.0,1220.0,307.0,1200.0), Q(307.0,1177.0,322.0,1163.5), Q(337.0,1150.0,362.0,1150.0), Q(390.0,1150.0,409.0,1166.0), Q(428.0,1182.0,428.0,1204.0), Q(428.0,1231.0,397.0,1249.0), Q(387.0,1255.0,362.0,1267.0), Z , M(406.0,1301.0), Q(409.0,1302.0,411.0,1303.0), Q(429.0,1311.0,440.5,1328.0), Q(452.0,1345.0,452.0,1364.0), Q(452.0,1382.0,439.0,1394.0), Q(426.0,1406.0,406.0,1406.0), Q(383.0,1406.0,367.0,1391.0), Q(351.0,1377.0,351.0,1358.0), Q(351.0,1342.0,363.5,1329.0), Q(376.0,1316.0,406.0,1301.0), Z ];
-
-// pub const CADENZA_163 : &'static [PathSegment] = &[ M(218.0,195.0), Q(251.0,224.0,259.0,254.0), Q(261.0,261.0,261.0,267.0), Q(261.0,284.0,245.0,297.0), Q(229.0,310.0,198.0,310.0), Q(176.0,310.0,159.0,296.0), Q(142.0,282.0,137.0,264.0), Q(135.0,258.0,135.0,253.0), Q(135.0,239.0,147.0,230.0), L(202.0,192.0), Q(210.0,186.0,218.0,195.0), Z , M(77.0,177.0), Q(61.0,196.0,61.0,218.0), Q(61.0,228.0,64.0,238.0), Q(74.0,274.0,111.5,299.5), Q(149.0,325.0,202.0,325.0), Q(244.0,325.0,267.0,308.5), Q(290.0,292.0,290.0,270.0), Q(290.0,262.0,287.0,254.0), Q(278.0,222.0,238.0,187.0), Q(227.0,177.0,235.0,169.0), Q(260.0,146.0,261.0,116.0), Q(261.0,105.0,257.0,94.0), Q(247.0,56.0,207.0,28.0), Q(167.0,0.0,110.0,0.0), Q(62.0,0.0,37.0,22.0), Q(12.0,44.0,12.0,70.0), Q(12.0,80.0,15.0,91.0), Q(23.0,121.0,73.0,160.0), Q(84.0,169.0,77.0,177.0), Z , M(94.0,151.0), Q(52.0,121.0,44.0,91.0), Q(41.0,81.0,41.0,73.0), Q(41.0,52.0,59.0,33.5), Q(77.0,15.0,114.0,15.0), Q(139.0,15.0,158.5,31.5), Q(178.0,48.0,184.0,69.0), Q(186.0,77.0,186.0,83.0), Q(186.0,100.0,172.0,109.0), L(108.0,153.0), Q(101.0,157.0,94.0,151.0), Z ];
-
-// pub const CADENZA_164 : &'static [PathSegment] = &[ M(0.0,-23.0), Q(0.0,17.0,44.0,54.5), Q(88.0,92.0,135.0,92.0), Q(154.0,92.0,171.0,87.0), Q(185.0,83.0,197.0,72.0), L(197.0,207.0), L(153.0,175.0), Q(148.0,171.0,143.0,171.0), Q(136.0,171.0,131.0,176.0), Q(126.0,180.0,126.0,186.0), Q(126.0,194.0,132.0,198.0), L(197.0,245.0), L(197.0,586.0), L(208.0,586.0), L(218.0,586.0), Q(218.0,550.0,232.5,512.0), Q(247.0,474.0,267.5,441.0), Q(288.0,408.0,309.0,375.0), Q(319.0,359.0,328.0,341.0), L(377.0,377.0), Q(381.0,380.0,388.0,381.0), Q(395.0,381.0,400.0,376.0), Q(405.0,372.0,404.0,366.0), Q(404.0,358.0,398.0,354.0), L(341.0,312.0), Q(343.0,308.0,344.0,304.0), Q(358.0,266.0,358.0,229.0), Q(358.0,153.0,330.0,90.0), Q(325.0,82.0,318.0,82.0), Q(311.0,82.0,306.0,88.0), Q(301.0,92.0,302.0,100.0), Q(330.0,156.0,330.0,226.0), Q(330.0,258.0,315.0,293.0), L(218.0,223.0), L(218.0,23.0), Q(218.0,-14.0,173.5,-53.0), Q(129.0,-92.0,84.0,-92.0), Q(65.0,-92.0,48.0,-87.0), Q(31.0,-82.0,15.5,-65.5), Q(0.0,-49.0,0.0,-23.0), Z , M(300.0,321.0), Q(278.0,357.0,239.0,397.0), Q(235.0,401.0,230.5,400.5), Q(226.0,400.0,222.0,396.5), Q(218.0,393.0,218.0,388.0), L(218.0,366.0), L(218.0,261.0), L(300.0,321.0), Z ];
-
-// pub const CADENZA_165 : &'static [PathSegment] = &[ M(157.0,65.0), Q(115.0,54.0,87.0,54.0), Q(52.0,54.0,26.0,76.5), Q(0.0,99.0,0.0,133.0), Q(0.0,163.0,21.5,184.0), Q(43.0,205.0,73.0,205.0), Q(92.0,205.0,107.5,194.5), Q(123.0,184.0,129.0,166.0), Q(131.0,160.0,135.0,140.5), Q(139.0,121.0,145.0,114.0), Q(151.0,107.0,164.0,107.0), Q(175.0,107.0,196.5,131.5), Q(218.0,156.0,225.0,170.0), Q(229.0,179.0,239.0,179.0), Q(249.0,179.0,253.0,170.0), L(130.0,-250.0), Q(119.0,-260.0,103.0,-260.0), Q(86.0,-260.0,75.0,-250.0), L(179.0,41.0), Q(183.0,52.0,176.0,59.0), Q(168.0,69.0,157.0,65.0), Z ];
-
-// pub const CADENZA_166 : &'static [PathSegment] = &[ M(175.0,0.0), Q(175.0,-17.0,201.0,-27.0), Q(227.0,-37.0,256.0,-37.0), Q(266.0,-37.0,266.0,-47.0), L(270.0,-125.0), Q(270.0,-135.0,261.0,-135.0), L(260.0,-135.0), L(182.0,-131.0), Q(172.0,-129.0,172.0,-121.0), Q(172.0,-93.0,162.0,-66.5), Q(152.0,-40.0,135.0,-40.0), Q(118.0,-40.0,108.0,-66.0), Q(98.0,-92.0,98.0,-121.0), Q(98.0,-129.0,89.0,-131.0), L(10.0,-135.0), L(9.0,-135.0), Q(0.0,-135.0,0.0,-125.0), L(4.0,-47.0), Q(4.0,-37.0,14.0,-37.0), Q(42.0,-37.0,68.5,-27.0), Q(95.0,-17.0,95.0,0.0), Q(95.0,17.0,69.0,27.0), Q(43.0,37.0,14.0,37.0), Q(4.0,37.0,4.0,47.0), L(0.0,125.0), Q(0.0,135.0,9.0,135.0), L(10.0,135.0), L(89.0,131.0), Q(98.0,129.0,98.0,121.0), Q(98.0,93.0,108.0,66.5), Q(118.0,40.0,135.0,40.0), Q(152.0,40.0,162.0,66.5), Q(172.0,93.0,172.0,121.0), Q(172.0,129.0,182.0,131.0), L(260.0,135.0), L(261.0,135.0), Q(270.0,135.0,270.0,125.0), L(266.0,47.0), Q(266.0,37.0,256.0,37.0), Q(228.0,37.0,201.5,27.0), Q(175.0,17.0,175.0,0.0), Z ];
-
-// pub const CADENZA_167 : &'static [PathSegment] = &[ M(0.0,251.0), L(42.0,251.0), L(42.0,133.0), L(473.0,133.0), L(473.0,251.0), L(515.0,251.0), L(515.0,-253.0), L(473.0,-253.0), L(473.0,-134.0), L(42.0,-134.0), L(42.0,-253.0), L(0.0,-253.0), L(0.0,251.0), Z , M(42.0,53.0), L(42.0,-54.0), L(473.0,-54.0), L(473.0,53.0), L(42.0,53.0), Z ];
-
-// pub const CADENZA_168 : &'static [PathSegment] = &[ M(407.0,79.0), L(620.0,429.0), Q(623.0,433.0,623.0,438.0), Q(623.0,445.0,618.0,450.5), Q(613.0,456.0,605.0,456.0), L(362.0,456.0), Q(352.0,456.0,347.0,447.0), L(133.0,97.0), Q(130.0,93.0,131.0,88.0), Q(131.0,81.0,136.0,75.5), Q(141.0,70.0,149.0,70.0), L(391.0,70.0), Q(401.0,70.0,407.0,79.0), Z , M(10.0,0.0), Q(0.0,0.0,0.0,11.0), Q(0.0,14.0,1.0,16.0), L(310.0,521.0), Q(313.0,526.0,319.0,526.0), L(744.0,526.0), Q(754.0,526.0,754.0,515.0), Q(754.0,514.0,752.0,510.0), L(444.0,5.0), Q(441.0,0.0,435.0,0.0), L(10.0,0.0), Z ];
-
-// pub const CADENZA_169 : &'static [PathSegment] = &[ M(131.0,86.0), Q(153.0,79.0,168.0,66.0), Q(185.0,51.0,207.0,51.0), Q(231.0,51.0,241.0,70.0), Q(245.0,76.0,249.0,76.0), Q(257.0,76.0,255.0,68.0), Q(247.0,38.0,211.0,19.0), Q(175.0,0.0,147.0,0.0), Q(108.0,0.0,92.0,29.0), Q(84.0,43.0,65.0,43.0), Q(50.0,43.0,35.5,33.5), Q(21.0,24.0,15.0,7.0), Q(12.0,0.0,6.0,0.0), Q(-2.0,0.0,0.0,7.0), Q(8.0,38.0,30.5,62.0), Q(53.0,86.0,79.5,102.0), Q(106.0,118.0,133.0,134.0), Q(160.0,150.0,182.0,174.5), Q(204.0,199.0,213.0,231.0), Q(219.0,250.0,219.0,267.0), Q(219.0,276.0,215.5,293.0), Q(212.0,310.0,189.0,310.0), Q(176.0,310.0,164.0,303.5), Q(152.0,297.0,149.0,286.0), Q(147.0,277.0,154.5,264.0), Q(162.0,251.0,160.0,242.0), Q(155.0,223.0,138.5,210.0), Q(122.0,197.0,103.5,197.0), Q(85.0,197.0,77.0,208.0), Q(69.0,219.0,69.0,230.0), Q(69.0,236.0,71.0,242.0), Q(81.0,278.0,116.5,301.5), Q(152.0,325.0,193.0,325.0), Q(242.0,325.0,267.0,302.5), Q(292.0,280.0,292.0,253.0), Q(292.0,243.0,289.0,231.0), Q(286.0,218.0,281.0,206.5), Q(276.0,195.0,268.0,186.0), Q(260.0,177.0,254.5,169.5), Q(249.0,162.0,237.5,155.0), Q(226.0,148.0,220.0,144.0), Q(214.0,140.0,199.5,133.5), Q(185.0,127.0,179.5,125.0), Q(174.0,123.0,157.5,116.5), Q(141.0,110.0,136.0,109.0), Q(127.0,105.0,125.0,96.0), Q(124.0,87.0,131.0,86.0), Z ];
-
-// pub const CADENZA_170 : &'static [PathSegment] = &[ M(1145.0,250.0), Q(1106.0,250.0,1074.5,187.5), Q(1043.0,125.0,1043.0,70.0), Q(1043.0,32.0,1073.0,32.0), Q(1095.0,32.0,1114.5,53.5), Q(1134.0,75.0,1145.5,105.5), Q(1157.0,136.0,1163.5,166.0), Q(1170.0,196.0,1170.0,217.0), Q(1170.0,250.0,1145.0,250.0), Z , M(781.0,250.0), Q(742.0,250.0,710.5,187.5), Q(679.0,125.0,679.0,70.0), Q(679.0,32.0,709.0,32.0), Q(731.0,32.0,750.5,53.5), Q(770.0,75.0,781.5,105.5), Q(793.0,136.0,799.5,166.0), Q(806.0,196.0,806.0,217.0), Q(806.0,250.0,781.0,250.0), Z , M(533.0,153.0), Q(526.0,136.0,509.0,136.0), Q(495.0,136.0,495.0,148.0), Q(495.0,150.0,497.0,156.0), Q(522.0,212.0,547.0,246.0), Q(543.0,245.0,539.0,245.0), L(472.0,245.0), Q(458.0,245.0,455.0,232.0), L(409.0,82.0), Q(382.0,-7.0,319.5,-90.0), Q(257.0,-173.0,191.0,-173.0), Q(156.0,-173.0,130.0,-153.5), Q(104.0,-134.0,104.0,-104.0), Q(104.0,-72.0,117.5,-49.5), Q(131.0,-27.0,156.0,-27.0), Q(174.0,-27.0,187.0,-40.0), Q(200.0,-53.0,200.0,-71.0), Q(200.0,-78.0,197.0,-83.5), Q(194.0,-89.0,191.5,-91.0), Q(189.0,-93.0,182.5,-97.5), Q(176.0,-102.0,174.0,-103.0), Q(163.0,-111.0,168.0,-124.0), Q(172.0,-136.0,185.0,-136.0), L(191.0,-136.0), Q(208.0,-136.0,220.5,-127.5), Q(233.0,-119.0,241.5,-99.5), Q(250.0,-80.0,254.0,-67.5), Q(258.0,-55.0,264.0,-28.0), L(326.0,223.0), Q(327.0,225.0,327.0,228.0), Q(327.0,235.0,322.0,240.0), Q(317.0,245.0,309.0,245.0), L(272.0,245.0), Q(253.0,245.0,253.0,264.0), Q(253.0,283.0,272.0,283.0), L(330.0,283.0), Q(342.0,283.0,347.0,295.0), Q(376.0,368.0,433.5,421.0), Q(491.0,474.0,556.0,474.0), Q(591.0,474.0,617.0,454.5), Q(643.0,435.0,643.0,405.0), Q(643.0,373.0,629.5,350.5), Q(616.0,328.0,591.0,328.0), Q(573.0,328.0,560.0,340.5), Q(547.0,353.0,547.0,371.0), Q(547.0,380.0,551.5,385.5), Q(556.0,391.0,564.5,397.0), Q(573.0,403.0,576.0,405.0), Q(586.0,415.0,580.0,427.0), Q(576.0,437.0,563.0,437.0), L(556.0,437.0), Q(543.0,437.0,532.0,432.5), Q(521.0,428.0,513.0,416.0), Q(505.0,404.0,500.0,395.0), Q(495.0,386.0,489.5,366.0), Q(484.0,346.0,482.0,337.5), Q(480.0,329.0,475.0,305.0), Q(474.0,302.0,474.0,300.0), Q(474.0,292.0,479.5,287.5), Q(485.0,283.0,492.0,283.0), L(539.0,283.0), Q(558.0,283.0,558.0,264.0), Q(558.0,261.0,558.0,259.0), Q(593.0,299.0,629.0,299.0), Q(669.0,299.0,687.0,268.0), Q(691.0,262.0,699.0,260.5), Q(707.0,259.0,713.0,264.0), Q(756.0,299.0,798.0,299.0), Q(848.0,299.0,876.0,267.0), Q(891.0,250.0,899.0,228.0), Q(945.0,299.0,993.0,299.0), Q(1033.0,299.0,1051.0,268.0), Q(1055.0,262.0,1063.0,260.5), Q(1071.0,259.0,1077.0,264.0), Q(1120.0,299.0,1162.0,299.0), Q(1212.0,299.0,1240.5,267.0), Q(1269.0,235.0,1269.0,185.0), Q(1269.0,152.0,1257.0,118.0), Q(1241.0,73.0,1196.0,32.5), Q(1151.0,-8.0,1110.0,-8.0), Q(1067.0,-8.0,1037.0,9.0), Q(1031.0,12.0,1026.0,12.0), Q(1013.0,12.0,1010.0,3.0), L(981.0,-83.0), Q(980.0,-85.0,980.0,-90.0), Q(980.0,-114.0,1024.0,-118.0), Q(1037.0,-119.0,1037.0,-132.0), Q(1037.0,-146.0,1024.0,-146.0), Q(1005.0,-146.0,966.0,-143.5), Q(927.0,-141.0,908.0,-141.0), Q(888.0,-141.0,849.5,-143.5), Q(811.0,-146.0,793.0,-146.0), Q(779.0,-146.0,779.0,-132.0), Q(779.0,-127.0,782.0,-123.5), Q(785.0,-120.0,791.5,-118.5), Q(798.0,-117.0,802.5,-116.5), Q(807.0,-116.0,816.0,-116.0), Q(825.0,-116.0,827.0,-116.0), Q(873.0,-114.0,881.0,-91.0), L(983.0,216.0), Q(985.0,223.0,985.0,229.0), Q(985.0,236.0,981.0,240.5), Q(977.0,245.0,974.0,246.0), Q(971.0,247.0,968.0,247.0), Q(954.0,246.0,932.0,214.0), Q(916.0,190.0,904.0,169.0), Q(902.0,144.0,893.0,118.0), Q(877.0,73.0,832.0,32.5), Q(787.0,-8.0,746.0,-8.0), Q(703.0,-8.0,673.0,9.0), Q(667.0,12.0,662.0,12.0), Q(649.0,12.0,646.0,3.0), L(617.0,-83.0), Q(616.0,-85.0,616.0,-90.0), Q(616.0,-114.0,660.0,-118.0), Q(673.0,-119.0,673.0,-132.0), Q(673.0,-146.0,660.0,-146.0), Q(641.0,-146.0,602.0,-143.5), Q(563.0,-141.0,544.0,-141.0), Q(524.0,-141.0,485.5,-143.5), Q(447.0,-146.0,429.0,-146.0), Q(415.0,-146.0,415.0,-132.0), Q(415.0,-127.0,418.0,-123.5), Q(421.0,-120.0,427.5,-118.5), Q(434.0,-117.0,438.5,-116.5), Q(443.0,-116.0,452.0,-116.0), Q(461.0,-116.0,463.0,-116.0), Q(509.0,-114.0,517.0,-91.0), L(619.0,216.0), Q(621.0,223.0,621.0,229.0), Q(621.0,236.0,617.0,240.5), Q(613.0,245.0,610.0,246.0), Q(607.0,247.0,604.0,247.0), Q(590.0,247.0,567.5,214.5), Q(545.0,182.0,533.0,153.0), Z , M(180.0,216.0), Q(180.0,221.0,184.5,230.5), Q(189.0,240.0,189.0,245.0), Q(189.0,268.0,171.0,268.0), Q(151.0,268.0,134.5,255.0), Q(118.0,242.0,118.0,224.0), Q(118.0,206.0,149.0,187.0), Q(181.0,169.0,199.0,151.0), Q(217.0,133.0,217.0,107.0), Q(217.0,61.0,184.0,30.5), Q(151.0,0.0,103.0,0.0), Q(70.0,0.0,46.0,19.5), Q(22.0,39.0,22.0,68.0), Q(22.0,85.0,30.5,97.5), Q(39.0,110.0,53.0,110.0), Q(66.0,110.0,75.5,101.0), Q(85.0,92.0,85.0,78.0), Q(85.0,72.0,74.5,64.0), Q(64.0,56.0,64.0,49.0), Q(64.0,27.0,99.0,27.0), Q(124.0,27.0,140.5,40.0), Q(157.0,53.0,157.0,75.0), Q(157.0,91.0,141.5,105.0), Q(126.0,119.0,107.0,127.5), Q(88.0,136.0,72.5,153.0), Q(57.0,170.0,57.0,192.0), Q(57.0,232.0,89.0,262.0), Q(121.0,292.0,162.0,292.0), Q(201.0,292.0,221.0,273.0), Q(241.0,254.0,241.0,230.0), Q(241.0,215.0,233.0,204.5), Q(225.0,194.0,211.0,194.0), Q(198.0,194.0,189.0,200.5), Q(180.0,207.0,180.0,216.0), Z ];
-
-// pub const CADENZA_171 : &'static [PathSegment] = &[ M(993.0,250.0), Q(954.0,250.0,922.5,187.5), Q(891.0,125.0,891.0,70.0), Q(891.0,32.0,921.0,32.0), Q(943.0,32.0,962.5,53.5), Q(982.0,75.0,993.5,105.5), Q(1005.0,136.0,1011.5,166.0), Q(1018.0,196.0,1018.0,217.0), Q(1018.0,250.0,993.0,250.0), Z , M(629.0,250.0), Q(590.0,250.0,558.5,187.5), Q(527.0,125.0,527.0,70.0), Q(527.0,32.0,557.0,32.0), Q(579.0,32.0,598.5,53.5), Q(618.0,75.0,629.5,105.5), Q(641.0,136.0,647.5,166.0), Q(654.0,196.0,654.0,217.0), Q(654.0,250.0,629.0,250.0), Z , M(265.0,250.0), Q(226.0,250.0,194.5,187.5), Q(163.0,125.0,163.0,70.0), Q(163.0,32.0,193.0,32.0), Q(215.0,32.0,234.5,53.5), Q(254.0,75.0,265.5,105.5), Q(277.0,136.0,283.5,166.0), Q(290.0,196.0,290.0,217.0), Q(290.0,250.0,265.0,250.0), Z , M(17.0,153.0), Q(10.0,136.0,-7.0,136.0), Q(-21.0,136.0,-21.0,148.0), Q(-21.0,150.0,-19.0,156.0), Q(44.0,299.0,113.0,299.0), Q(153.0,299.0,171.0,268.0), Q(175.0,262.0,183.0,260.5), Q(191.0,259.0,197.0,264.0), Q(240.0,299.0,282.0,299.0), Q(332.0,299.0,360.0,267.0), Q(375.0,250.0,383.0,228.0), Q(429.0,299.0,477.0,299.0), Q(517.0,299.0,535.0,268.0), Q(539.0,262.0,547.0,260.5), Q(555.0,259.0,561.0,264.0), Q(604.0,299.0,646.0,299.0), Q(696.0,299.0,724.0,267.0), Q(739.0,250.0,747.0,228.0), Q(793.0,299.0,841.0,299.0), Q(881.0,299.0,899.0,268.0), Q(903.0,262.0,911.0,260.5), Q(919.0,259.0,925.0,264.0), Q(968.0,299.0,1010.0,299.0), Q(1060.0,299.0,1088.5,267.0), Q(1117.0,235.0,1117.0,185.0), Q(1117.0,152.0,1105.0,118.0), Q(1089.0,73.0,1044.0,32.5), Q(999.0,-8.0,958.0,-8.0), Q(915.0,-8.0,885.0,9.0), Q(879.0,12.0,874.0,12.0), Q(861.0,12.0,858.0,3.0), L(829.0,-83.0), Q(828.0,-85.0,828.0,-90.0), Q(828.0,-114.0,872.0,-118.0), Q(885.0,-119.0,885.0,-132.0), Q(885.0,-146.0,872.0,-146.0), Q(853.0,-146.0,814.0,-143.5), Q(775.0,-141.0,756.0,-141.0), Q(736.0,-141.0,697.5,-143.5), Q(659.0,-146.0,641.0,-146.0), Q(627.0,-146.0,627.0,-132.0), Q(627.0,-127.0,630.0,-123.5), Q(633.0,-120.0,639.5,-118.5), Q(646.0,-117.0,650.5,-116.5), Q(655.0,-116.0,664.0,-116.0), Q(673.0,-116.0,675.0,-116.0), Q(721.0,-114.0,729.0,-91.0), L(831.0,216.0), Q(833.0,223.0,833.0,229.0), Q(833.0,236.0,829.0,240.5), Q(825.0,245.0,822.0,246.0), Q(819.0,247.0,816.0,247.0), Q(802.0,246.0,780.0,214.0), Q(764.0,190.0,752.0,169.0), Q(750.0,144.0,741.0,118.0), Q(725.0,73.0,680.0,32.5), Q(635.0,-8.0,594.0,-8.0), Q(551.0,-8.0,521.0,9.0), Q(515.0,12.0,510.0,12.0), Q(497.0,12.0,494.0,3.0), L(465.0,-83.0), Q(464.0,-85.0,464.0,-90.0), Q(464.0,-114.0,508.0,-118.0), Q(521.0,-119.0,521.0,-132.0), Q(521.0,-146.0,508.0,-146.0), Q(489.0,-146.0,450.0,-143.5), Q(411.0,-141.0,392.0,-141.0), Q(372.0,-141.0,333.5,-143.5), Q(295.0,-146.0,277.0,-146.0), Q(263.0,-146.0,263.0,-132.0), Q(263.0,-127.0,266.0,-123.5), Q(269.0,-120.0,275.5,-118.5), Q(282.0,-117.0,286.5,-116.5), Q(291.0,-116.0,300.0,-116.0), Q(309.0,-116.0,311.0,-116.0), Q(357.0,-114.0,365.0,-91.0), L(467.0,216.0), Q(469.0,223.0,469.0,229.0), Q(469.0,236.0,465.0,240.5), Q(461.0,245.0,458.0,246.0), Q(455.0,247.0,452.0,247.0), Q(438.0,246.0,416.0,214.0), Q(400.0,190.0,388.0,169.0), Q(386.0,144.0,377.0,118.0), Q(361.0,73.0,316.0,32.5), Q(271.0,-8.0,230.0,-8.0), Q(187.0,-8.0,157.0,9.0), Q(151.0,12.0,146.0,12.0), Q(133.0,12.0,130.0,3.0), L(101.0,-83.0), Q(100.0,-85.0,100.0,-90.0), Q(100.0,-114.0,144.0,-118.0), Q(157.0,-119.0,157.0,-132.0), Q(157.0,-146.0,144.0,-146.0), Q(125.0,-146.0,86.0,-143.5), Q(47.0,-141.0,28.0,-141.0), Q(8.0,-141.0,-30.5,-143.5), Q(-69.0,-146.0,-87.0,-146.0), Q(-101.0,-146.0,-101.0,-132.0), Q(-101.0,-127.0,-98.0,-123.5), Q(-95.0,-120.0,-88.5,-118.5), Q(-82.0,-117.0,-77.5,-116.5), Q(-73.0,-116.0,-64.0,-116.0), Q(-55.0,-116.0,-53.0,-116.0), Q(-7.0,-114.0,1.0,-91.0), L(103.0,216.0), Q(105.0,223.0,105.0,229.0), Q(105.0,236.0,101.0,240.5), Q(97.0,245.0,94.0,246.0), Q(91.0,247.0,88.0,247.0), Q(74.0,247.0,51.5,214.5), Q(29.0,182.0,17.0,153.0), Z ];
-
-// pub const CADENZA_172 : &'static [PathSegment] = &[ M(365.0,104.0), L(10.0,104.0), Q(0.0,104.0,0.0,114.0), L(0.0,240.0), Q(0.0,250.0,10.0,250.0), L(365.0,250.0), Q(375.0,250.0,375.0,240.0), L(375.0,114.0), Q(375.0,104.0,365.0,104.0), Z ];
-
-// pub const CADENZA_173 : &'static [PathSegment] = &[ M(718.0,235.0), Q(709.0,245.0,694.0,245.0), Q(674.0,245.0,659.0,230.0), Q(641.0,212.0,629.0,179.0), Q(617.0,147.0,616.0,116.0), Q(616.0,96.0,626.0,85.0), Q(635.0,74.0,651.0,73.0), Q(682.0,73.0,702.0,114.0), Q(726.0,162.0,726.0,207.0), Q(726.0,225.0,718.0,235.0), Z , M(757.0,272.0), L(798.0,272.0), L(803.0,272.0), L(802.0,267.0), L(755.0,108.0), Q(750.0,92.0,750.0,83.0), Q(750.0,80.0,752.0,78.0), Q(754.0,76.0,756.0,75.0), Q(773.0,76.0,799.0,129.0), L(801.0,133.0), L(805.0,130.0), L(819.0,122.0), L(822.0,121.0), L(821.0,117.0), Q(813.0,99.0,797.0,78.0), Q(771.0,41.0,739.0,41.0), Q(724.0,41.0,715.0,50.0), Q(706.0,60.0,706.0,76.0), Q(681.0,52.0,680.0,52.0), Q(663.0,41.0,640.0,41.0), Q(606.0,41.0,586.0,63.0), Q(566.0,85.0,565.0,122.0), Q(565.0,183.0,605.0,234.0), Q(640.0,280.0,686.0,280.0), Q(710.0,280.0,729.0,265.0), Q(740.0,255.0,745.0,242.0), L(753.0,269.0), L(754.0,272.0), L(757.0,272.0), Z , M(450.0,275.0), L(418.0,165.0), Q(407.0,128.0,407.0,107.0), Q(407.0,92.0,415.0,83.0), Q(423.0,74.0,434.0,74.0), Q(451.0,74.0,468.0,89.0), Q(486.0,105.0,500.0,134.0), Q(521.0,176.0,521.0,203.0), Q(521.0,210.0,519.0,213.0), L(519.0,214.0), Q(517.0,218.0,509.0,227.0), Q(498.0,239.0,498.0,252.0), Q(498.0,263.0,505.0,272.0), Q(512.0,280.0,523.0,280.0), Q(537.0,280.0,547.0,268.0), Q(557.0,256.0,557.0,238.0), Q(557.0,209.0,545.0,172.0), Q(533.0,134.0,514.0,104.0), Q(474.0,40.0,423.0,40.0), Q(395.0,40.0,377.0,59.0), Q(359.0,78.0,358.0,107.0), Q(358.0,131.0,367.0,162.0), L(383.0,217.0), Q(388.0,234.0,388.0,238.0), Q(388.0,247.0,365.0,247.0), L(357.0,247.0), L(353.0,247.0), L(353.0,251.0), L(354.0,268.0), L(354.0,272.0), L(358.0,272.0), L(446.0,280.0), L(451.0,281.0), L(450.0,275.0), Z , M(143.0,47.0), Q(141.0,46.0,139.0,45.0), Q(136.0,44.0,134.0,44.0), L(133.0,43.0), L(132.0,43.0), Q(98.0,28.0,78.5,1.5), Q(59.0,-25.0,59.0,-56.0), Q(59.0,-91.0,81.5,-111.5), Q(104.0,-132.0,143.0,-132.0), Q(186.0,-132.0,215.0,-108.0), Q(244.0,-84.0,244.0,-50.0), Q(244.0,-9.0,197.0,20.0), Q(182.0,29.0,143.0,47.0), Z , M(210.0,100.0), Q(214.0,102.0,219.0,103.0), Q(247.0,116.0,264.0,142.0), Q(281.0,168.0,282.0,197.0), Q(282.0,225.0,262.0,243.0), Q(242.0,261.0,211.0,261.0), Q(175.0,261.0,151.0,239.0), Q(126.0,217.0,126.0,187.0), Q(126.0,162.0,145.0,142.5), Q(164.0,123.0,210.0,100.0), Z , M(245.0,80.0), Q(273.0,61.0,285.0,46.0), Q(307.0,16.0,307.0,-21.0), Q(307.0,-85.0,259.0,-126.0), Q(211.0,-167.0,136.0,-168.0), Q(74.0,-168.0,37.0,-140.0), Q(0.0,-112.0,0.0,-64.0), Q(0.0,24.0,111.0,70.0), Q(89.0,87.0,79.0,106.0), Q(68.0,127.0,67.0,157.0), Q(67.0,216.0,110.5,256.0), Q(154.0,296.0,216.0,296.0), Q(268.0,296.0,303.0,268.0), Q(337.0,239.0,337.0,194.0), Q(337.0,142.0,294.0,108.0), Q(279.0,97.0,245.0,80.0), Z ];
-
-// pub const CADENZA_174 : &'static [PathSegment] = &[ M(101.0,195.0), Q(127.0,209.0,155.0,209.0), Q(213.0,209.0,233.0,187.5), Q(253.0,166.0,253.0,139.0), Q(253.0,123.0,248.0,104.0), Q(236.0,59.0,192.5,29.5), Q(149.0,0.0,99.0,0.0), Q(56.0,0.0,33.5,17.0), Q(11.0,34.0,11.0,57.0), Q(11.0,65.0,13.0,73.0), Q(18.0,91.0,34.0,103.5), Q(50.0,116.0,67.5,116.0), Q(85.0,116.0,92.5,105.5), Q(100.0,95.0,100.0,85.0), Q(100.0,80.0,98.0,73.0), Q(96.0,65.0,80.5,56.0), Q(65.0,47.0,63.0,39.0), Q(59.0,26.0,71.0,20.5), Q(83.0,15.0,103.0,15.0), Q(148.0,15.0,173.0,104.0), Q(181.0,133.0,180.0,152.0), Q(180.0,190.0,150.0,190.0), Q(102.0,190.0,73.0,161.0), Q(69.0,157.0,64.0,157.0), Q(54.0,157.0,57.0,166.0), L(96.0,315.0), Q(99.0,325.0,109.0,325.0), L(110.0,325.0), Q(156.0,317.0,197.0,317.0), Q(238.0,317.0,289.0,325.0), L(292.0,325.0), Q(298.0,325.0,299.5,321.5), Q(301.0,318.0,297.0,315.0), Q(220.0,255.0,110.0,254.0), Q(99.0,254.0,96.0,243.0), L(86.0,205.0), Q(85.0,200.0,87.5,196.5), Q(90.0,193.0,95.0,193.0), Q(97.0,193.0,101.0,195.0), Z ];
-
-// pub const CADENZA_175 : &'static [PathSegment] = &[ M(184.0,172.0), L(178.0,457.0), Q(191.0,465.0,205.0,465.0), Q(219.0,465.0,232.0,457.0), L(227.0,151.0), Q(227.0,141.0,235.0,136.0), Q(244.0,130.0,253.0,135.0), Q(295.0,156.0,322.0,156.0), Q(360.0,156.0,383.0,126.0), Q(406.0,96.0,406.0,54.0), Q(406.0,25.0,397.5,1.5), Q(389.0,-22.0,371.0,-41.5), Q(353.0,-61.0,337.5,-74.0), Q(322.0,-87.0,297.0,-105.5), Q(272.0,-124.0,259.0,-135.0), Q(254.0,-139.0,246.0,-150.0), Q(238.0,-161.0,230.5,-167.0), Q(223.0,-173.0,214.0,-173.0), Q(204.0,-173.0,197.5,-166.0), Q(191.0,-159.0,191.0,-148.0), L(190.0,-87.0), Q(190.0,-79.0,184.5,-74.5), Q(179.0,-70.0,171.0,-70.0), Q(164.0,-70.0,159.0,-74.0), Q(148.0,-83.0,121.0,-103.5), Q(94.0,-124.0,80.0,-135.0), Q(75.0,-139.0,67.0,-150.0), Q(59.0,-161.0,51.5,-167.0), Q(44.0,-173.0,35.0,-173.0), Q(25.0,-173.0,18.5,-166.0), Q(12.0,-159.0,12.0,-148.0), L(0.0,457.0), Q(13.0,465.0,27.0,465.0), Q(41.0,465.0,54.0,457.0), L(48.0,151.0), Q(48.0,141.0,56.0,136.0), Q(65.0,130.0,74.0,135.0), Q(116.0,156.0,143.0,156.0), Q(149.0,156.0,161.0,154.0), Q(163.0,153.0,166.0,153.0), Q(174.0,153.0,179.0,158.5), Q(184.0,164.0,184.0,172.0), Z , M(225.0,-86.0), Q(225.0,-98.0,235.0,-102.0), Q(247.0,-107.0,255.0,-99.0), Q(321.0,-39.0,321.0,37.0), Q(321.0,64.0,309.5,84.0), Q(298.0,104.0,278.0,104.0), Q(256.0,104.0,242.0,85.0), Q(228.0,66.0,227.0,41.0), L(225.0,-86.0), Z , M(46.0,-86.0), Q(46.0,-98.0,56.0,-102.0), Q(68.0,-107.0,76.0,-99.0), Q(142.0,-39.0,142.0,37.0), Q(142.0,64.0,130.5,84.0), Q(119.0,104.0,99.0,104.0), Q(77.0,104.0,63.0,85.0), Q(49.0,66.0,48.0,41.0), L(46.0,-86.0), Z ];
-
-// pub const CADENZA_176 : &'static [PathSegment] = &[ M(161.0,-184.0), Q(120.0,-196.0,87.0,-196.0), Q(52.0,-196.0,26.0,-173.5), Q(0.0,-151.0,0.0,-117.0), Q(0.0,-87.0,21.0,-66.0), Q(42.0,-45.0,72.0,-45.0), Q(91.0,-45.0,107.0,-55.5), Q(123.0,-66.0,129.0,-84.0), Q(132.0,-91.0,135.5,-110.0), Q(139.0,-129.0,144.5,-136.0), Q(150.0,-143.0,163.0,-143.0), Q(174.0,-143.0,196.5,-116.0), Q(219.0,-89.0,223.0,-75.0), L(259.0,43.0), Q(260.0,46.0,260.0,48.0), Q(260.0,55.0,254.5,60.5), Q(249.0,66.0,241.0,66.0), Q(238.0,66.0,236.0,65.0), Q(194.0,54.0,165.0,54.0), Q(130.0,54.0,104.0,76.5), Q(78.0,99.0,78.0,133.0), Q(78.0,163.0,99.5,184.0), Q(121.0,205.0,151.0,205.0), Q(170.0,205.0,185.5,194.5), Q(201.0,184.0,207.0,166.0), Q(210.0,159.0,213.5,140.0), Q(217.0,121.0,222.5,114.0), Q(228.0,107.0,241.0,107.0), Q(251.0,107.0,271.0,131.0), Q(291.0,155.0,298.0,170.0), Q(302.0,179.0,312.0,179.0), Q(322.0,179.0,326.0,170.0), L(147.0,-500.0), Q(136.0,-510.0,120.0,-510.0), Q(103.0,-510.0,92.0,-500.0), L(183.0,-206.0), Q(184.0,-203.0,184.0,-200.0), Q(184.0,-193.0,179.0,-188.0), Q(174.0,-183.0,166.0,-183.0), Q(164.0,-183.0,161.0,-184.0), Z ];
-
-// pub const CADENZA_177 : &'static [PathSegment] = &[ M(179.0,137.0), L(290.0,508.0), Q(294.0,520.0,307.0,520.0), Q(314.0,520.0,319.5,515.0), Q(325.0,510.0,325.0,502.0), Q(325.0,500.0,324.0,497.0), L(180.0,18.0), Q(175.0,0.0,162.0,0.0), Q(149.0,0.0,144.0,18.0), L(0.0,497.0), L(0.0,502.0), Q(0.0,520.0,17.0,520.0), Q(30.0,520.0,34.0,508.0), L(145.0,137.0), Q(149.0,125.0,162.0,125.0), Q(175.0,125.0,179.0,137.0), Z ];
-
-// pub const CADENZA_178 : &'static [PathSegment] = &[ M(49.0,200.0), Q(42.0,200.0,36.5,195.0), Q(31.0,190.0,31.0,182.0), L(31.0,15.0), Q(31.0,9.0,26.5,4.5), Q(22.0,0.0,16.0,0.0), Q(10.0,0.0,5.0,4.5), Q(0.0,9.0,0.0,15.0), L(0.0,323.0), Q(0.0,333.0,10.0,333.0), L(366.0,333.0), Q(376.0,333.0,376.0,323.0), L(376.0,15.0), Q(376.0,9.0,371.5,4.5), Q(367.0,0.0,361.0,0.0), Q(355.0,0.0,350.0,4.5), Q(345.0,9.0,345.0,15.0), L(345.0,182.0), Q(345.0,190.0,339.5,195.0), Q(334.0,200.0,327.0,200.0), L(49.0,200.0), Z ];
-
-// pub const CADENZA_179 : &'static [PathSegment] = &[ M(619.0,77.0), Q(629.0,66.0,647.0,66.0), Q(681.0,66.0,706.0,108.0), Q(731.0,150.0,731.0,196.0), Q(731.0,220.0,722.0,230.0), Q(712.0,242.0,696.0,242.0), Q(663.0,242.0,637.0,200.0), Q(610.0,154.0,610.0,108.0), Q(610.0,88.0,619.0,77.0), Z , M(692.0,401.0), L(646.0,247.0), Q(660.0,261.0,672.0,268.0), Q(690.0,279.0,712.0,279.0), Q(746.0,279.0,766.0,256.0), Q(786.0,233.0,786.0,194.0), Q(786.0,125.0,737.0,78.0), Q(698.0,40.0,643.0,40.0), Q(607.0,40.0,583.0,60.0), Q(561.0,79.0,561.0,112.0), Q(561.0,127.0,568.0,151.0), L(625.0,343.0), Q(629.0,356.0,629.0,362.0), Q(629.0,373.0,606.0,373.0), L(595.0,373.0), L(591.0,373.0), L(591.0,377.0), L(593.0,395.0), L(593.0,399.0), L(597.0,399.0), L(688.0,406.0), L(693.0,406.0), L(692.0,401.0), Z , M(451.0,274.0), L(418.0,164.0), Q(407.0,128.0,407.0,106.0), Q(407.0,91.0,415.0,82.0), Q(423.0,73.0,434.0,73.0), L(434.0,73.0), Q(452.0,73.0,469.0,88.0), Q(487.0,104.0,500.0,133.0), Q(521.0,175.0,521.0,202.0), Q(521.0,209.0,519.5,213.5), Q(518.0,218.0,509.0,226.0), L(509.0,226.0), Q(498.0,238.0,498.0,251.0), Q(498.0,262.0,505.0,271.0), Q(512.0,279.0,523.0,279.0), Q(537.0,279.0,547.0,267.0), Q(557.0,255.0,557.0,237.0), Q(557.0,208.0,545.0,171.0), Q(533.0,133.0,514.0,103.0), Q(474.0,40.0,425.0,40.0), Q(424.0,40.0,423.0,40.0), Q(395.0,40.0,377.0,58.0), Q(359.0,77.0,358.0,106.0), Q(358.0,127.0,368.0,161.0), L(384.0,216.0), Q(389.0,232.0,388.0,236.0), L(388.0,237.0), Q(388.0,246.0,365.0,246.0), L(357.0,246.0), L(353.0,246.0), L(353.0,250.0), L(355.0,268.0), L(355.0,272.0), L(359.0,272.0), L(447.0,279.0), L(453.0,279.0), L(451.0,274.0), Z , M(143.0,47.0), Q(141.0,46.0,139.0,45.0), Q(136.0,44.0,134.0,44.0), L(133.0,43.0), L(132.0,43.0), Q(98.0,28.0,78.5,1.5), Q(59.0,-25.0,59.0,-56.0), Q(59.0,-91.0,81.5,-111.5), Q(104.0,-132.0,143.0,-132.0), Q(186.0,-132.0,215.0,-108.0), Q(244.0,-84.0,244.0,-50.0), Q(244.0,-9.0,197.0,20.0), Q(182.0,29.0,143.0,47.0), Z , M(210.0,100.0), Q(214.0,102.0,219.0,103.0), Q(247.0,116.0,264.0,142.0), Q(281.0,168.0,282.0,197.0), Q(282.0,225.0,262.0,243.0), Q(242.0,261.0,211.0,261.0), Q(175.0,261.0,151.0,239.0), Q(126.0,217.0,126.0,187.0), Q(126.0,162.0,145.0,142.5), Q(164.0,123.0,210.0,100.0), Z , M(245.0,80.0), Q(273.0,61.0,285.0,46.0), Q(307.0,16.0,307.0,-21.0), Q(307.0,-85.0,259.0,-126.0), Q(211.0,-167.0,136.0,-168.0), Q(74.0,-168.0,37.0,-140.0), Q(0.0,-112.0,0.0,-64.0), Q(0.0,24.0,111.0,70.0), Q(89.0,87.0,79.0,106.0), Q(68.0,127.0,67.0,157.0), Q(67.0,216.0,110.5,256.0), Q(154.0,296.0,216.0,296.0), Q(268.0,296.0,303.0,268.0), Q(337.0,239.0,337.0,194.0), Q(337.0,142.0,294.0,108.0), Q(279.0,97.0,245.0,80.0), Z ];
-
-// pub const CADENZA_180 : &'static [PathSegment] = &[ M(284.0,617.0), Q(284.0,603.0,298.0,600.0), Q(357.0,587.0,391.5,527.5), Q(426.0,468.0,434.0,385.0), Q(436.0,368.0,452.0,368.0), L(523.0,368.0), Q(529.0,368.0,533.5,363.5), Q(538.0,359.0,538.0,353.0), Q(538.0,347.0,533.5,342.5), Q(529.0,338.0,523.0,338.0), L(452.0,338.0), Q(435.0,338.0,434.0,321.0), Q(428.0,240.0,392.5,180.5), Q(357.0,121.0,298.0,106.0), Q(284.0,102.0,284.0,89.0), L(284.0,15.0), Q(284.0,9.0,279.5,4.5), Q(275.0,0.0,269.0,0.0), Q(263.0,0.0,258.5,4.5), Q(254.0,9.0,254.0,15.0), L(254.0,89.0), Q(254.0,103.0,240.0,106.0), Q(181.0,119.0,146.5,178.5), Q(112.0,238.0,104.0,321.0), Q(102.0,338.0,86.0,338.0), L(15.0,338.0), Q(9.0,338.0,4.5,342.5), Q(0.0,347.0,0.0,353.0), Q(0.0,359.0,4.5,363.5), Q(9.0,368.0,15.0,368.0), L(86.0,368.0), Q(103.0,368.0,104.0,385.0), Q(110.0,466.0,145.5,525.5), Q(181.0,585.0,240.0,600.0), Q(254.0,604.0,254.0,617.0), L(254.0,691.0), Q(254.0,697.0,258.5,701.5), Q(263.0,706.0,269.0,706.0), Q(275.0,706.0,279.5,701.5), Q(284.0,697.0,284.0,691.0), L(284.0,617.0), Z , M(344.0,338.0), L(302.0,338.0), Q(294.0,338.0,289.0,333.0), Q(284.0,328.0,284.0,320.0), L(284.0,161.0), Q(284.0,153.0,289.5,147.5), Q(295.0,142.0,302.0,142.0), Q(308.0,142.0,313.0,146.0), Q(362.0,182.0,362.0,319.0), Q(362.0,338.0,344.0,338.0), Z , M(194.0,338.0), Q(186.0,338.0,181.0,332.5), Q(176.0,327.0,176.0,319.0), Q(180.0,180.0,225.0,146.0), Q(230.0,142.0,236.0,142.0), Q(243.0,142.0,248.5,147.5), Q(254.0,153.0,254.0,161.0), L(254.0,320.0), Q(254.0,328.0,249.0,333.0), Q(244.0,338.0,236.0,338.0), L(194.0,338.0), Z , M(344.0,368.0), Q(352.0,368.0,357.0,373.5), Q(362.0,379.0,362.0,387.0), Q(358.0,526.0,313.0,560.0), Q(308.0,564.0,302.0,564.0), Q(295.0,564.0,289.5,558.5), Q(284.0,553.0,284.0,545.0), L(284.0,386.0), Q(284.0,378.0,289.0,373.0), Q(294.0,368.0,302.0,368.0), L(344.0,368.0), Z , M(194.0,368.0), L(236.0,368.0), Q(244.0,368.0,249.0,373.0), Q(254.0,378.0,254.0,386.0), L(254.0,545.0), Q(254.0,553.0,248.5,558.5), Q(243.0,564.0,236.0,564.0), Q(230.0,564.0,225.0,560.0), Q(176.0,524.0,176.0,387.0), Q(176.0,368.0,194.0,368.0), Z ];
-
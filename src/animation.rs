@@ -0,0 +1,336 @@
+//! Keyframe animation of groups. A group's `Animation` holds one track per animated
+//! property; `GraphicItems::sample` resolves the tracks at a point in time into plain items,
+//! so that any builder can render an animation frame by frame.
+
+use serde::{Deserialize, Serialize};
+
+use crate::item::{Color, Fill, GraphicItem, GraphicItems, ItemAttributes, Stroke, Trim};
+use crate::prelude::*;
+
+/// How a value moves from a keyframe to the next one. The curves are CSS timing functions:
+/// cubic Béziers from (0, 0) to (1, 1) mapping the fraction of time to the fraction of change.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum Easing {
+    #[default]
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    CubicBezier(f32, f32, f32, f32),
+    /// Keeps the value until the next keyframe.
+    Hold,
+}
+
+impl Easing {
+    /// Control points (x1, y1, x2, y2) of the timing curve, None for `Hold`.
+    pub fn control_points(&self) -> Option<(f32, f32, f32, f32)> {
+        match *self {
+            Easing::Linear => Some((0., 0., 1., 1.)),
+            Easing::EaseIn => Some((0.42, 0., 1., 1.)),
+            Easing::EaseOut => Some((0., 0., 0.58, 1.)),
+            Easing::EaseInOut => Some((0.42, 0., 0.58, 1.)),
+            Easing::CubicBezier(x1, y1, x2, y2) => Some((x1, y1, x2, y2)),
+            Easing::Hold => None,
+        }
+    }
+
+    /// Fraction of the change reached after the fraction `x` of the time.
+    pub fn apply(&self, x: f32) -> f32 {
+        let x = x.clamp(0., 1.);
+        let Some((x1, y1, x2, y2)) = self.control_points() else { return 0. };
+        let bezier = |a: f32, b: f32, t: f32| 3. * a * t * (1. - t) * (1. - t) + 3. * b * t * t * (1. - t) + t * t * t;
+        // x of the curve is monotonic in t; bisection always converges
+        let (mut lo, mut hi) = (0., 1.);
+        for _ in 0..30 {
+            let mid = (lo + hi) / 2.;
+            if bezier(x1, x2, mid) < x {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        bezier(y1, y2, (lo + hi) / 2.)
+    }
+}
+
+/// Values that can be tweened between keyframes.
+pub trait Interpolate: Clone {
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &f32, t: f32) -> f32 {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for (f32, f32) {
+    fn interpolate(&self, other: &(f32, f32), t: f32) -> (f32, f32) {
+        (self.0.interpolate(&other.0, t), self.1.interpolate(&other.1, t))
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, other: &Color, t: f32) -> Color {
+        self.lerp(other, t)
+    }
+}
+
+/// A value at a time in seconds, and how it changes towards the next keyframe.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Keyframe<T> {
+    pub time: f32,
+    pub value: T,
+    pub easing: Easing,
+}
+
+/// Keyframes ordered by time. Before the first and after the last keyframe the value holds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Track<T>(pub Vec<Keyframe<T>>);
+
+impl<T: Interpolate> Track<T> {
+    pub fn new() -> Self {
+        Track(vec![])
+    }
+
+    /// Adds a keyframe, keeping the track ordered.
+    pub fn key(mut self, time: f32, value: T, easing: Easing) -> Self {
+        let idx = self.0.partition_point(|keyframe| keyframe.time <= time);
+        self.0.insert(idx, Keyframe { time, value, easing });
+        self
+    }
+
+    pub fn start(&self) -> f32 {
+        self.0.first().map_or(0., |keyframe| keyframe.time)
+    }
+
+    pub fn end(&self) -> f32 {
+        self.0.last().map_or(0., |keyframe| keyframe.time)
+    }
+
+    pub fn sample(&self, time: f32) -> Option<T> {
+        let idx = self.0.partition_point(|keyframe| keyframe.time <= time);
+        match (idx.checked_sub(1).map(|i| &self.0[i]), self.0.get(idx)) {
+            (Some(a), Some(b)) => Some(a.value.interpolate(&b.value, a.easing.apply((time - a.time) / (b.time - a.time)))),
+            (Some(a), None) => Some(a.value.clone()),
+            (None, b) => b.map(|b| b.value.clone()),
+        }
+    }
+}
+
+impl<T: Interpolate> Default for Track<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Animated properties of a group. The group is scaled about `pivot` and then translated;
+/// opacity and trim replace those of the group, colors replace the fills and strokes of
+/// the items in it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Animation {
+    pub translate: Option<Track<(f32, f32)>>,
+    pub scale: Option<Track<(f32, f32)>>,
+    pub pivot: Point,
+    pub opacity: Option<Track<f32>>,
+    pub fill: Option<Track<Color>>,
+    pub stroke: Option<Track<Color>>,
+    pub trim: Option<Track<(f32, f32)>>,
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Self { translate: None, scale: None, pivot: Point(0., 0.), opacity: None, fill: None, stroke: None, trim: None }
+    }
+}
+
+impl Animation {
+    /// Time of the last keyframe of any track.
+    pub fn duration(&self) -> f32 {
+        [
+            self.translate.as_ref().map(Track::end),
+            self.scale.as_ref().map(Track::end),
+            self.opacity.as_ref().map(Track::end),
+            self.fill.as_ref().map(Track::end),
+            self.stroke.as_ref().map(Track::end),
+            self.trim.as_ref().map(Track::end),
+        ]
+        .into_iter()
+        .flatten()
+        .fold(0., f32::max)
+    }
+
+    pub fn move_animation(&self, move_x: f32, move_y: f32) -> Animation {
+        Animation { pivot: Point(self.pivot.0 + move_x, self.pivot.1 + move_y), ..self.clone() }
+    }
+
+    // Scaling the plane scales the offsets and the pivot; scaling about the pivot commutes
+    // with it.
+    pub fn scale_animation(&self, scale_x: f32, scale_y: f32) -> Animation {
+        let translate = self.translate.as_ref().map(|track| {
+            Track(track.0.iter().map(|keyframe| Keyframe { value: (keyframe.value.0 * scale_x, keyframe.value.1 * scale_y), ..keyframe.clone() }).collect())
+        });
+        Animation { translate, pivot: Point(self.pivot.0 * scale_x, self.pivot.1 * scale_y), ..self.clone() }
+    }
+}
+
+impl GraphicItem {
+    /// Animates the item, wrapping it in a group unless it already is an unanimated one.
+    pub fn with_animation(self, animation: Animation) -> GraphicItem {
+        self.with_attributes(|attrs| attrs.animation.is_none(), |attrs| attrs.animation = Some(animation))
+    }
+
+    pub fn is_animated(&self) -> bool {
+        match self {
+            GraphicItem::Group(items, attrs) => attrs.animation.is_some() || items.is_animated() || attrs.mask.as_ref().is_some_and(|mask| mask.0.is_animated()),
+            _ => false,
+        }
+    }
+}
+
+impl GraphicItems {
+    pub fn is_animated(&self) -> bool {
+        self.0.iter().any(GraphicItem::is_animated)
+    }
+
+    /// Time of the last keyframe in the items.
+    pub fn duration(&self) -> f32 {
+        self.0
+            .iter()
+            .map(|item| match item {
+                GraphicItem::Group(items, attrs) => attrs.animation.as_ref().map_or(0., Animation::duration).max(items.duration()),
+                _ => 0.,
+            })
+            .fold(0., f32::max)
+    }
+
+    /// The items as they are at `time`, with all animations resolved.
+    pub fn sample(&self, time: f32) -> GraphicItems {
+        GraphicItems(self.0.iter().map(|item| sample_item(item, time)).collect())
+    }
+
+    /// Frames at `fps` frames per second, from 0 to the end of the animation.
+    pub fn frames(&self, fps: f32) -> impl Iterator<Item = (f32, GraphicItems)> + '_ {
+        let count = (self.duration() * fps).ceil() as usize + 1;
+        (0..count).map(move |frame| {
+            let time = frame as f32 / fps;
+            (time, self.sample(time))
+        })
+    }
+
+    /// Union of the bounding boxes at every keyframe.
+    pub fn animated_bbox(&self) -> Rectangle {
+        let mut times = vec![0.];
+        collect_times(self, &mut times);
+        times.iter().fold(self.bbox(), |bbox, time| bbox.union(&self.sample(*time).bbox()))
+    }
+
+    fn recolor(&self, fill: Option<&Color>, stroke: Option<&Color>) -> GraphicItems {
+        GraphicItems(self.0.iter().map(|item| recolor_item(item, fill, stroke)).collect())
+    }
+}
+
+fn sample_item(item: &GraphicItem, time: f32) -> GraphicItem {
+    let GraphicItem::Group(items, attrs) = item else { return item.clone() };
+    let mut items = items.sample(time);
+    let mut attrs = ItemAttributes { mask: attrs.mask.as_ref().map(|mask| Mask(mask.0.sample(time), mask.1.clone())), ..attrs.clone() };
+    let Some(animation) = attrs.animation.take() else { return GraphicItem::Group(items, attrs) };
+
+    let fill = animation.fill.as_ref().and_then(|track| track.sample(time));
+    let stroke = animation.stroke.as_ref().and_then(|track| track.sample(time));
+    if fill.is_some() || stroke.is_some() {
+        items = items.recolor(fill.as_ref(), stroke.as_ref());
+    }
+    if let Some(opacity) = animation.opacity.as_ref().and_then(|track| track.sample(time)) {
+        attrs.opacity = opacity.clamp(0., 1.);
+    }
+    if let Some((start, end)) = animation.trim.as_ref().and_then(|track| track.sample(time)) {
+        attrs.trim = Some(Trim(start.clamp(0., 1.), end.clamp(0., 1.)));
+    }
+
+    let mut group = GraphicItems(vec![GraphicItem::Group(items, attrs)]);
+    if let Some((sx, sy)) = animation.scale.as_ref().and_then(|track| track.sample(time)) {
+        let Point(px, py) = animation.pivot;
+        group = group.move_items(-px, -py).scale_items(sx, sy, (sx * sy).abs().sqrt()).move_items(px, py);
+    }
+    if let Some((dx, dy)) = animation.translate.as_ref().and_then(|track| track.sample(time)) {
+        group = group.move_items(dx, dy);
+    }
+    group.0.remove(0)
+}
+
+fn recolor_item(item: &GraphicItem, fill: Option<&Color>, stroke: Option<&Color>) -> GraphicItem {
+    let fill_of = |f: &Fill| match (f, fill) {
+        (Fill::Fillstyle(_), Some(color)) => Fill::Fillstyle(color.clone()),
+        (f, _) => f.clone(),
+    };
+    let stroke_of = |s: &Stroke| match (s, stroke) {
+        (Stroke::Strokestyle(width, _), Some(color)) => Stroke::Strokestyle(*width, color.clone()),
+        (s, _) => s.clone(),
+    };
+    match item {
+        GraphicItem::Line(x1, y1, x2, y2, s) => GraphicItem::Line(*x1, *y1, *x2, *y2, stroke_of(s)),
+        GraphicItem::Rect(x, y, w, h, s, f) => GraphicItem::Rect(*x, *y, *w, *h, stroke_of(s), fill_of(f)),
+        GraphicItem::RoundedRect(x, y, w, h, radii, s, f) => GraphicItem::RoundedRect(*x, *y, *w, *h, *radii, stroke_of(s), fill_of(f)),
+        GraphicItem::Ellipse(x, y, w, h, s, f) => GraphicItem::Ellipse(*x, *y, *w, *h, stroke_of(s), fill_of(f)),
+        GraphicItem::Polyline(points, s) => GraphicItem::Polyline(points.clone(), stroke_of(s)),
+        GraphicItem::Polygon(points, s, f) => GraphicItem::Polygon(points.clone(), stroke_of(s), fill_of(f)),
+        GraphicItem::Path(path, s, f, cache) => GraphicItem::Path(path.clone(), stroke_of(s), fill_of(f), cache.clone()),
+        GraphicItem::Group(items, attrs) => GraphicItem::Group(items.recolor(fill, stroke), attrs.clone()),
+    }
+}
+
+fn collect_times(items: &GraphicItems, times: &mut Vec<f32>) {
+    for item in items.0.iter() {
+        if let GraphicItem::Group(items, attrs) = item {
+            if let Some(animation) = &attrs.animation {
+                for track in [&animation.translate, &animation.scale].into_iter().flatten() {
+                    times.extend(track.0.iter().map(|keyframe| keyframe.time));
+                }
+            }
+            collect_times(items, times);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Animation, Easing, Track};
+    use crate::prelude::*;
+
+    #[test]
+    fn sample_animation() {
+        assert_eq!(Easing::Linear.apply(0.25), 0.25);
+        assert!((Easing::EaseInOut.apply(0.5) - 0.5).abs() < 1e-4);
+        assert!(Easing::EaseIn.apply(0.25) < 0.25 && Easing::EaseOut.apply(0.25) > 0.25);
+        assert_eq!(Easing::Hold.apply(0.99), 0.);
+
+        let track = Track::new().key(1., 10., Easing::Linear).key(0., 0., Easing::Hold).key(2., 0., Easing::Linear);
+        assert_eq!([-1., 0.5, 1., 1.5, 3.].map(|t| track.sample(t).unwrap()), [0., 0., 10., 5., 0.]);
+
+        let animation = Animation {
+            translate: Some(Track::new().key(0., (0., 0.), Easing::Linear).key(2., (100., 0.), Easing::Linear)),
+            scale: Some(Track::new().key(0., (1., 1.), Easing::Linear).key(1., (2., 2.), Easing::Linear)),
+            pivot: Point(5., 5.),
+            fill: Some(Track::new().key(0., Black, Easing::Linear).key(2., White, Easing::Linear)),
+            opacity: Some(Track::new().key(0., 0., Easing::Linear).key(1., 1., Easing::Linear)),
+            ..Default::default()
+        };
+        let items = GraphicItems(vec![Rect(0., 0., 10., 10., NoStroke, Fillstyle(Red)).with_animation(animation)]);
+        assert!(items.is_animated());
+        assert_eq!(items.duration(), 2.);
+
+        let frame = items.sample(1.);
+        assert!(!frame.is_animated());
+        assert_eq!(frame.bbox(), Rectangle(45., -5., 65., 15.));
+        let Group(inner, attrs) = &frame.0[0] else { panic!() };
+        assert_eq!(attrs.opacity, 1.);
+        assert!(matches!(&inner.0[0], Rect(_, _, _, _, _, Fillstyle(color)) if color.to_rgba8() == (128, 128, 128, 255)));
+
+        let frames: Vec<_> = items.frames(2.).collect();
+        assert_eq!(frames.len(), 5);
+        assert_eq!(frames[4].0, 2.);
+        assert_eq!(items.animated_bbox(), Rectangle(0., -5., 115., 15.));
+    }
+}
@@ -6,15 +6,17 @@ use crate::item::{
     GraphicItem, GraphicItems, Stroke,
     Stroke::{NoStroke, Strokestyle},
 };
+use crate::animation::{Animation, Interpolate, Track};
 use crate::prelude::*;
 
 use crate::path::{
-    PathSegment::{C, L, M, Q, Z},
+    PathSegment::{A, C, L, M, Q, Z},
     PathSegments,
 };
 
 use super::{BuilderOptions, GraphicBuilder};
 
+#[derive(Default)]
 pub struct FuseBuilder;
 
 impl FuseBuilder {
@@ -25,143 +27,365 @@ impl FuseBuilder {
 
 impl GraphicBuilder for FuseBuilder {
     fn build(&mut self, mut items: GraphicItems, options: Option<BuilderOptions>) -> Result<String> {
-        let items_bbox = items.bbox();
+        items.check_geometry()?;
+        if has_trim_track(&items) {
+            return Err(GraphicsError::Unsupported { backend: "fuse", what: "trim animation".to_string() });
+        }
+        let viewport = options.and_then(|options| options.viewport);
+        if let Some(ref viewport) = viewport {
+            items = items.cull(viewport);
+        }
+        items = items.apply_trims();
+
+        let items_bbox = viewport.unwrap_or_else(|| if items.is_animated() { items.animated_bbox() } else { items.bbox() });
         // println!("items_bbox:{:?}", items_bbox);
         if items_bbox.0 != 0. || items_bbox.1 != 0. {
             // println!("MOVE {} {}", items_bbox.0, items_bbox.1);
             items = items.move_items(-items_bbox.0, -items_bbox.1);
         }
+        // Fusion shapes are in fractions of the image width. A viewport is the image, so its
+        // width is scaled to 1; without one, items are drawn as they are.
+        let scale = match viewport {
+            Some(viewport) if viewport.2 > viewport.0 => 1. / (viewport.2 - viewport.0),
+            _ => 1.,
+        };
+        if scale != 1. {
+            items = items.scale_items(scale, scale, scale);
+        }
+        // Fusion is y up
+        items = items.scale_items(1., -1., 1.).move_items(0., (items_bbox.3 - items_bbox.1) * scale);
 
         let before = include_str!("./fuse_before.txt");
         let after = include_str!("./fuse_after.txt");
         let mut buffer = "\n\n-- dynamically added items:\n\n".to_string();
+        if items.is_animated() {
+            // Keyframe times are in seconds
+            buffer.push_str("\n\tlocal time = req.Time / self.Comp:GetPrefs(\"Comp.FrameFormat.Rate\")");
+        }
 
-        fn get_fill_color(color: &Color) -> &str {
-            match color {
-                Blue => "{R = 0, G = 0, B = 1, A = 1}",
-                Red => "{R = 1, G = 0, B = 0, A = 1}",
-                Lime => "{R = 0, G = 1, B = 0, A = 1}",
-                Purple => "{R = .5, G = 0, B = .5, A = 1}",
-                Black => "{R = 0, G = 0, B = 0, A = 1}",
-                White => "{R = 1, G = 1, B = 1, A = 1}",
-                _ => "{R = .5, G = .5, B = .5, A = 1}",
+        buffer = add_items(buffer, &items, "out", false, &Animated::default(), &mut 0);
+
+        let contents = format!("{}\n\n{}\n\n{}", before, buffer, after);
+        Ok(contents)
+    }
+}
+
+fn get_fill_color(color: &Color, luminance: bool) -> String {
+    let (r, g, b, a) = color.to_rgba();
+    if luminance {
+        // Drawn into a luminance mask: store the luminance as a gray level, so that any
+        // color channel of the premultiplied result holds luminance * alpha.
+        let l = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+        return format!("{{R = {}, G = {}, B = {}, A = {}}}", l, l, l, a);
+    }
+    format!("{{R = {}, G = {}, B = {}, A = {}}}", r, g, b, a)
+}
+
+// The color of a shape, sampled from the animated color in `track` if there is one
+fn pixel_color(color: &Color, track: Option<&String>, luminance: bool) -> String {
+    match track {
+        Some(track) => format!("ColorPixel({}, {})", track, luminance),
+        None => get_fill_color(color, luminance),
+    }
+}
+
+fn add_after_line(mut buffer: String, color_str: &str, target: &str, transform: Option<&String>) -> String {
+    if let Some(transform) = transform {
+        buffer.push_str(format!("\n\tline = TransformShape(line, {})", transform).as_str());
+    }
+    buffer.push_str(format!("\n\tic = ImageChannel({}, 8)", target).as_str());
+    buffer.push_str("\n\tic:ShapeFill(line)	");
+    buffer.push_str("\n\tcs = ChannelStyle()");
+    buffer.push_str(format!("\n\tcs.Color = Pixel({})", color_str).as_str());
+    buffer.push_str("\n\tif self.Status == \"OK\" then");
+    buffer.push_str("\n\t    ic:PutToImage(\"CM_Merge\", cs)");
+    buffer.push_str("\n\tend");
+    buffer.push_str("\n\t");
+    buffer
+}
+
+fn add_path(mut buffer: String, path: &PathSegments) -> String {
+    let mut prev_x: f32 = 0.;
+    let mut prev_y: f32 = 0.;
+    buffer.push_str("\n\tline = Shape()");
+    // Fusion shapes have no arcs
+    let path = path.arcs_to_cubics();
+    for segment in path.0.iter() {
+        match segment {
+            M(x, y) => {
+                buffer.push_str(format!("\n\tline:MoveTo({}, {})", x, y).as_str());
+                prev_x = *x;
+                prev_y = *y;
+            }
+            L(x, y) => {
+                buffer.push_str(format!("\n\tline:LineTo({}, {})", x, y).as_str());
+                prev_x = *x;
+                prev_y = *y;
+            }
+            Q(x1, y1, x, y) => {
+                let c1x = prev_x + (2. / 3.) * (x1 - prev_x);
+                let c1y = prev_y + (2. / 3.) * (y1 - prev_y);
+                let c2x = x + (2. / 3.) * (x1 - x);
+                let c2y = y + (2. / 3.) * (y1 - y);
+
+                buffer.push_str(
+                    format!(
+                        "\n\tline = BezierTo2(line, {{X={}, Y={}}}, {{X={}, Y={}}}, {{X={}, Y={}}}, {{X={}, Y={}}}, 20)",
+                        prev_x, prev_y, c1x, c1y, c2x, c2y, x, y
+                    )
+                    .as_str(),
+                );
+
+                prev_x = *x;
+                prev_y = *y;
             }
+            C(x1, y1, x2, y2, x, y) => {
+                // addLine('line = BezierTo2(line, {X=${cubic.sx}, Y=${cubic.sy}}, {X=${cubic.c1x}, Y=${cubic.c1y}},  {X=${cubic.c2x}, Y=${cubic.c2y}}, {X=${cubic.ex}, Y=${cubic.ey}}, 20)');
+                buffer.push_str(
+                    format!(
+                        "\n\tline = BezierTo2(line, {{X={}, Y={}}}, {{X={}, Y={}}}, {{X={}, Y={}}}, {{X={}, Y={}}}, 20)",
+                        prev_x, prev_y, x1, y1, x2, y2, x, y
+                    )
+                    .as_str(),
+                );
+                prev_x = *x;
+                prev_y = *y;
+            }
+            A(..) | Z => {}
         }
+    }
+    buffer
+}
+
+fn add_layer(mut buffer: String, name: &str) -> String {
+    buffer.push_str(format!("\n\tlocal {} = Image(imgattrs)", name).as_str());
+    buffer.push_str(format!("\n\t{}:Fill(Pixel({{R=0,G=0,B=0,A=0}}))", name).as_str());
+    buffer
+}
+
+// Multiplies all channels of the (premultiplied) layer with one channel of the matte.
+fn apply_matte(mut buffer: String, layer: &str, matte: &str, channel: &str) -> String {
+    buffer.push_str(
+        format!(
+            "\n\t{} = {}:ChannelOpOf(\"Multiply\", {}, {{R = \"fg.{}\", G = \"fg.{}\", B = \"fg.{}\", A = \"fg.{}\"}})",
+            layer, layer, matte, channel, channel, channel, channel
+        )
+        .as_str(),
+    );
+    buffer
+}
 
-        fn add_after_line(mut buffer: String, color: &Color) -> String {
-            let color_str = get_fill_color(color);
-            buffer.push_str("\n\tic = ImageChannel(out, 8)");
-            buffer.push_str("\n\tic:ShapeFill(line)	");
-            buffer.push_str("\n\tcs = ChannelStyle()");
-            buffer.push_str(format!("\n\tcs.Color = Pixel({})", color_str).as_str());
-            buffer.push_str("\n\tif self.Status == \"OK\" then");
-            buffer.push_str("\n\t    ic:PutToImage(\"CM_Merge\", cs)");
-            buffer.push_str("\n\tend");
-            buffer.push_str("\n\t");
-            buffer
+// Merge tool apply modes. Fusion has no exclusion mode, difference is the closest.
+fn blend_mode_fusion(blend: BlendMode) -> &'static str {
+    match blend {
+        BlendMode::Normal => "Normal",
+        BlendMode::Multiply => "Multiply",
+        BlendMode::Screen => "Screen",
+        BlendMode::Overlay => "Overlay",
+        BlendMode::Darken => "Darken",
+        BlendMode::Lighten => "Lighten",
+        BlendMode::ColorDodge => "ColorDodge",
+        BlendMode::ColorBurn => "ColorBurn",
+        BlendMode::HardLight => "HardLight",
+        BlendMode::SoftLight => "SoftLight",
+        BlendMode::Difference | BlendMode::Exclusion => "Difference",
+        BlendMode::Hue => "Hue",
+        BlendMode::Saturation => "Saturation",
+        BlendMode::Color => "Color",
+        BlendMode::Luminosity => "Luminosity",
+    }
+}
+
+// Lua variables holding the animated transform and colors of the enclosing groups, sampled
+// at the current time
+#[derive(Debug, Clone, Default)]
+struct Animated {
+    transform: Option<String>,
+    fill: Option<String>,
+    stroke: Option<String>,
+}
+
+// A keyframe table `track_<name>` of {time, values, easing} for each keyframe, the easing
+// being the control points of the timing curve or false to hold the value, and the values
+// `<name>` sampled from it at the current time.
+fn add_sampled_track<T: Interpolate>(mut buffer: String, name: &str, track: &Track<T>, values: impl Fn(&T) -> Vec<f32>) -> String {
+    buffer.push_str(format!("\n\tlocal track_{} = {{", name).as_str());
+    for keyframe in track.0.iter() {
+        // Adding zero turns the negative zeros left by the y flip into zeros
+        let values = values(&keyframe.value).iter().map(|v| (v + 0.).to_string()).collect::<Vec<_>>().join(", ");
+        let easing = match keyframe.easing.control_points() {
+            Some((x1, y1, x2, y2)) => format!("{{{}, {}, {}, {}}}", x1, y1, x2, y2),
+            None => "false".to_string(),
+        };
+        buffer.push_str(format!("\n\t\t{{{}, {{{}}}, {}}},", keyframe.time, values, easing).as_str());
+    }
+    buffer.push_str("\n\t}");
+    buffer.push_str(format!("\n\tlocal {} = SampleTrack(track_{}, time)", name, name).as_str());
+    buffer
+}
+
+// Trims are cut into the geometry, which then cannot follow a trim track
+fn has_trim_track(items: &GraphicItems) -> bool {
+    items.0.iter().any(|item| match item {
+        GraphicItem::Group(items, attrs) => {
+            attrs.animation.as_ref().is_some_and(|animation| animation.trim.is_some()) || has_trim_track(items) || attrs.mask.as_ref().is_some_and(|mask| has_trim_track(&mask.0))
         }
+        _ => false,
+    })
+}
 
-        for item in items.0.iter() {
-            match item {
-                GraphicItem::Line(x1, y1, x2, y2, stroke) => {
-                    if let Strokestyle(width, color) = stroke {
-                        buffer.push_str("\n\tline = Shape()");
-                        buffer.push_str(format!("\n\tline:MoveTo({}, {})", *x1, *y1).as_str());
-                        buffer.push_str(format!("\n\tline:LineTo({}, {})", *x2, *y2).as_str());
-                        buffer.push_str(format!("\n\tline = line:OutlineOfShape({},\"OLT_Solid\")", width).as_str());
-                        buffer = add_after_line(buffer, color);
-                    }
+// Samples the tracks of the group `id`. Colors of enclosing groups win over the group's own,
+// as when sampling in Rust.
+fn add_animation(mut buffer: String, animation: &Animation, outer: &Animated, id: usize) -> (String, Animated) {
+    let mut animated = outer.clone();
+    buffer.push_str(format!("\n\n\t-- animation {}", id).as_str());
+
+    let scale = animation.scale.as_ref().filter(|track| !track.0.is_empty());
+    let translate = animation.translate.as_ref().filter(|track| !track.0.is_empty());
+    if scale.is_some() || translate.is_some() {
+        let mut args = vec![];
+        for (track, kind, still) in [(scale, "scale", "{1, 1}"), (translate, "translate", "{0, 0}")] {
+            match track {
+                Some(track) => {
+                    let name = format!("{}_{}", kind, id);
+                    buffer = add_sampled_track(buffer, &name, track, |(x, y)| vec![*x, *y]);
+                    args.push(name);
                 }
-                GraphicItem::Rect(x, y, w, h, stroke, fill) => {
-                    if let Fillstyle(color) = fill {
-                        buffer.push_str("\n\tline = Shape()");
-                        buffer.push_str(format!("\n\tline:MoveTo({}, {})", *x, *y).as_str());
-                        buffer.push_str(format!("\n\tline:LineTo({}, {})", *x + *w, *y).as_str());
-                        buffer.push_str(format!("\n\tline:LineTo({}, {})", *x + *w, *y + *h).as_str());
-                        buffer.push_str(format!("\n\tline:LineTo({}, {})", *x, *y + *h).as_str());
-                        buffer.push_str("\n\tline:Close()");
-                        buffer = add_after_line(buffer, color);
-                    }
+                None => args.push(still.to_string()),
+            }
+        }
+        let name = format!("transform_{}", id);
+        let outer_transform = outer.transform.as_deref().unwrap_or("IDENTITY");
+        buffer.push_str(format!("\n\tlocal {} = GroupTransform({}, {}, {}, {}, {})", name, outer_transform, args[0], args[1], animation.pivot.0, animation.pivot.1).as_str());
+        animated.transform = Some(name);
+    }
 
-                    if let Strokestyle(width, color) = stroke {
-                        buffer.push_str("\n\tline = Shape()");
-                        buffer.push_str(format!("\n\tline:MoveTo({}, {})", *x, *y).as_str());
-                        buffer.push_str(format!("\n\tline:LineTo({}, {})", *x + *w, *y).as_str());
-                        buffer.push_str(format!("\n\tline:LineTo({}, {})", *x + *w, *y + *h).as_str());
-                        buffer.push_str(format!("\n\tline:LineTo({}, {})", *x, *y + *h).as_str());
-                        buffer.push_str(format!("\n\tline:LineTo({}, {})", *x, *y).as_str());
-                        buffer.push_str(format!("\n\tline = line:OutlineOfShape({},\"OLT_Solid\")", width).as_str());
-                        buffer = add_after_line(buffer, color);
-                    }
+    for (track, kind) in [(&animation.fill, "fill"), (&animation.stroke, "stroke")] {
+        let Some(track) = track.as_ref().filter(|track| !track.0.is_empty()) else { continue };
+        let name = format!("{}_{}", kind, id);
+        buffer = add_sampled_track(buffer, &name, track, |color| {
+            let (r, g, b, a) = color.to_rgba();
+            vec![r, g, b, a]
+        });
+        let color = if kind == "fill" { &mut animated.fill } else { &mut animated.stroke };
+        color.get_or_insert(name);
+    }
+
+    if let Some(track) = animation.opacity.as_ref().filter(|track| !track.0.is_empty()) {
+        buffer = add_sampled_track(buffer, &format!("opacity_{}", id), track, |opacity| vec![*opacity]);
+    }
+    (buffer, animated)
+}
+
+// Items are drawn onto the Lua image named `target`. Isolated groups (clip, mask, opacity
+// or blend mode) are rendered into a layer of their own, matted, and then merged onto the
+// target with the group's apply mode and opacity.
+fn add_items(mut buffer: String, items: &GraphicItems, target: &str, luminance: bool, animated: &Animated, layer_count: &mut usize) -> String {
+    for item in items.0.iter() {
+        match item {
+            GraphicItem::Line(x1, y1, x2, y2, Strokestyle(width, color)) => {
+                buffer.push_str("\n\tline = Shape()");
+                buffer.push_str(format!("\n\tline:MoveTo({}, {})", *x1, *y1).as_str());
+                buffer.push_str(format!("\n\tline:LineTo({}, {})", *x2, *y2).as_str());
+                buffer.push_str(format!("\n\tline = line:OutlineOfShape({},\"OLT_Solid\")", width).as_str());
+                buffer = add_after_line(buffer, &pixel_color(color, animated.stroke.as_ref(), luminance), target, animated.transform.as_ref());
+            }
+            GraphicItem::Rect(x, y, w, h, stroke, fill) => {
+                if let Fillstyle(color) = fill {
+                    buffer.push_str("\n\tline = Shape()");
+                    buffer.push_str(format!("\n\tline:MoveTo({}, {})", *x, *y).as_str());
+                    buffer.push_str(format!("\n\tline:LineTo({}, {})", *x + *w, *y).as_str());
+                    buffer.push_str(format!("\n\tline:LineTo({}, {})", *x + *w, *y + *h).as_str());
+                    buffer.push_str(format!("\n\tline:LineTo({}, {})", *x, *y + *h).as_str());
+                    buffer.push_str("\n\tline:Close()");
+                    buffer = add_after_line(buffer, &pixel_color(color, animated.fill.as_ref(), luminance), target, animated.transform.as_ref());
                 }
-                GraphicItem::Path(path, stroke, fill, cache) => {
-                    fn add_path(mut buffer: String, path: &PathSegments) -> String {
-                        let mut prev_x: f32 = 0.;
-                        let mut prev_y: f32 = 0.;
-                        buffer.push_str("\n\tline = Shape()");
-                        for segment in path.0.iter() {
-                            match segment {
-                                M(x, y) => {
-                                    buffer.push_str(format!("\n\tline:MoveTo({}, {})", x, y).as_str());
-                                    prev_x = *x;
-                                    prev_y = *y;
-                                }
-                                L(x, y) => {
-                                    buffer.push_str(format!("\n\tline:LineTo({}, {})", x, y).as_str());
-                                    prev_x = *x;
-                                    prev_y = *y;
-                                }
-                                Q(x1, y1, x, y) => {
-                                    let c1x = prev_x + (2. / 3.) * (x1 - prev_x);
-                                    let c1y = prev_y + (2. / 3.) * (y1 - prev_y);
-                                    let c2x = x + (2. / 3.) * (x1 - x);
-                                    let c2y = y + (2. / 3.) * (y1 - y);
-
-                                    buffer.push_str(
-                                        format!(
-                                            "\n\tline = BezierTo2(line, {{X={}, Y={}}}, {{X={}, Y={}}}, {{X={}, Y={}}}, {{X={}, Y={}}}, 20)",
-                                            prev_x, prev_y, c1x, c1y, c2x, c2y, x, y
-                                        )
-                                        .as_str(),
-                                    );
-
-                                    prev_x = *x;
-                                    prev_y = *y;
-                                }
-                                C(x1, y1, x2, y2, x, y) => {
-                                    // addLine('line = BezierTo2(line, {X=${cubic.sx}, Y=${cubic.sy}}, {X=${cubic.c1x}, Y=${cubic.c1y}},  {X=${cubic.c2x}, Y=${cubic.c2y}}, {X=${cubic.ex}, Y=${cubic.ey}}, 20)');
-                                    buffer.push_str(
-                                        format!(
-                                            "\n\tline = BezierTo2(line, {{X={}, Y={}}}, {{X={}, Y={}}}, {{X={}, Y={}}}, {{X={}, Y={}}}, 20)",
-                                            prev_x, prev_y, x1, y1, x2, y2, x, y
-                                        )
-                                        .as_str(),
-                                    );
-                                    prev_x = *x;
-                                    prev_y = *y;
-                                }
-                                Z => {}
-                            }
-                        }
-                        buffer
-                    }
 
-                    if let Fillstyle(color) = fill {
-                        buffer = add_path(buffer, &path);
-                        buffer = add_after_line(buffer, color);
+                if let Strokestyle(width, color) = stroke {
+                    buffer.push_str("\n\tline = Shape()");
+                    buffer.push_str(format!("\n\tline:MoveTo({}, {})", *x, *y).as_str());
+                    buffer.push_str(format!("\n\tline:LineTo({}, {})", *x + *w, *y).as_str());
+                    buffer.push_str(format!("\n\tline:LineTo({}, {})", *x + *w, *y + *h).as_str());
+                    buffer.push_str(format!("\n\tline:LineTo({}, {})", *x, *y + *h).as_str());
+                    buffer.push_str(format!("\n\tline:LineTo({}, {})", *x, *y).as_str());
+                    buffer.push_str(format!("\n\tline = line:OutlineOfShape({},\"OLT_Solid\")", width).as_str());
+                    buffer = add_after_line(buffer, &pixel_color(color, animated.stroke.as_ref(), luminance), target, animated.transform.as_ref());
+                }
+            }
+            GraphicItem::RoundedRect(_, _, _, _, _, stroke, fill) | GraphicItem::Ellipse(_, _, _, _, stroke, fill) | GraphicItem::Polygon(_, stroke, fill) => {
+                let path = GraphicItem::Path(item.to_path().unwrap(), stroke.clone(), fill.clone(), PathCacheInfo::NoCache);
+                buffer = add_items(buffer, &GraphicItems(vec![path]), target, luminance, animated, layer_count);
+            }
+            GraphicItem::Polyline(_, stroke) => {
+                let path = GraphicItem::Path(item.to_path().unwrap(), stroke.clone(), NoFill, PathCacheInfo::NoCache);
+                buffer = add_items(buffer, &GraphicItems(vec![path]), target, luminance, animated, layer_count);
+            }
+            GraphicItem::Path(path, stroke, fill, cache) => {
+                // Fusion has no shared shapes, so cached paths are drawn in place
+                let (x, y) = cache.offset();
+                let path = &path.move_path(x, y);
+                if let Fillstyle(color) = fill {
+                    buffer = add_path(buffer, path);
+                    buffer = add_after_line(buffer, &pixel_color(color, animated.fill.as_ref(), luminance), target, animated.transform.as_ref());
+                }
+
+                if let Strokestyle(width, color) = stroke {
+                    buffer = add_path(buffer, path);
+                    buffer.push_str(format!("\n\tline = line:OutlineOfShape({},\"OLT_Solid\")", width).as_str());
+                    buffer = add_after_line(buffer, &pixel_color(color, animated.stroke.as_ref(), luminance), target, animated.transform.as_ref());
+                }
+            }
+            GraphicItem::Group(group_items, attrs) => {
+                let (animated, opacity) = match &attrs.animation {
+                    Some(animation) => {
+                        *layer_count += 1;
+                        let id = *layer_count;
+                        let (next, animated) = add_animation(buffer, animation, animated, id);
+                        buffer = next;
+                        let opacity = animation.opacity.as_ref().filter(|track| !track.0.is_empty()).map(|_| format!("math.min(math.max(opacity_{}[1], 0), 1)", id));
+                        (animated, opacity.unwrap_or_else(|| attrs.opacity.to_string()))
                     }
+                    None => (animated.clone(), attrs.opacity.to_string()),
+                };
+                if !attrs.is_isolated() {
+                    buffer = add_items(buffer, group_items, target, luminance, &animated, layer_count);
+                    continue;
+                }
 
-                    if let Strokestyle(width, color) = stroke {
-                        buffer = add_path(buffer, &path);
-                        buffer.push_str(format!("\n\tline = line:OutlineOfShape({},\"OLT_Solid\")", width).as_str());
-                        buffer = add_after_line(buffer, color);
+                *layer_count += 1;
+                let layer = format!("layer_{}", layer_count);
+                buffer.push_str(format!("\n\n\t-- group {}", layer_count).as_str());
+                buffer = add_layer(buffer, &layer);
+                buffer = add_items(buffer, group_items, &layer, luminance, &animated, layer_count);
+
+                if let Some(clip) = &attrs.clip {
+                    let matte = format!("{}_clip", layer);
+                    buffer = add_layer(buffer, &matte);
+                    buffer = add_path(buffer, &clip.0);
+                    buffer = add_after_line(buffer, &get_fill_color(&Color::White, false), &matte, animated.transform.as_ref());
+                    buffer = apply_matte(buffer, &layer, &matte, "A");
+                }
+
+                if let Some(mask) = &attrs.mask {
+                    let matte = format!("{}_mask", layer);
+                    buffer = add_layer(buffer, &matte);
+                    match mask.1 {
+                        MaskType::Luminance => {
+                            buffer = add_items(buffer, &mask.0, &matte, true, &animated, layer_count);
+                            buffer = apply_matte(buffer, &layer, &matte, "R");
+                        }
+                        MaskType::Alpha => {
+                            buffer = add_items(buffer, &mask.0, &matte, false, &animated, layer_count);
+                            buffer = apply_matte(buffer, &layer, &matte, "A");
+                        }
                     }
                 }
-                _ => {}
+
+                buffer.push_str(format!("\n\tMergeLayer({}, {}, \"{}\", {})", target, layer, blend_mode_fusion(attrs.blend), opacity).as_str());
+                buffer.push_str("\n\t");
             }
+            _ => {}
         }
-
-        let contents = format!("{}\n\n{}\n\n{}", before, buffer, after);
-        Ok(contents)
     }
+    buffer
 }
@@ -17,16 +17,76 @@ use crate::path::{
     PathSegments,
 };
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SizeUnit {
     Pixel,
     Rem,
+    Millimeter,
+    Point,
+    Inch,
+}
+
+impl SizeUnit {
+    /// Pixels in one unit at `dpi` pixels per inch. A rem is taken as 16 pixels.
+    pub fn pixels(&self, dpi: f32) -> f32 {
+        match self {
+            SizeUnit::Pixel => 1.,
+            SizeUnit::Rem => 16.,
+            SizeUnit::Millimeter => dpi / 25.4,
+            SizeUnit::Point => dpi / 72.,
+            SizeUnit::Inch => dpi,
+        }
+    }
+
+    /// The CSS suffix of the unit, empty for pixels.
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            SizeUnit::Pixel => "",
+            SizeUnit::Rem => "rem",
+            SizeUnit::Millimeter => "mm",
+            SizeUnit::Point => "pt",
+            SizeUnit::Inch => "in",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
 pub struct BuilderOptions {
     pub size_unit: SizeUnit,
     pub size_scaling: f32,
+    /// Region to render instead of the bounding box of all items. Items entirely
+    /// outside of it are culled using a spatial index.
+    pub viewport: Option<Rectangle>,
+    /// Pixels per inch, for converting physical size units to pixels.
+    pub dpi: f32,
+}
+
+impl Default for BuilderOptions {
+    fn default() -> Self {
+        Self { size_unit: SizeUnit::Pixel, size_scaling: 1.0, viewport: None, dpi: 96. }
+    }
+}
+
+impl BuilderOptions {
+    /// Pixels per item unit.
+    pub fn pixel_scale(&self) -> f32 {
+        self.size_scaling * self.size_unit.pixels(self.dpi)
+    }
+}
+
+/// Space around the content: top, right, bottom and left.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Padding(pub f32, pub f32, pub f32, pub f32);
+
+impl Padding {
+    pub fn uniform(padding: f32) -> Padding {
+        Padding(padding, padding, padding, padding)
+    }
+
+    /// The rectangle grown by the padding.
+    pub fn around(&self, rect: &Rectangle) -> Rectangle {
+        Rectangle(rect.0 - self.3, rect.1 - self.0, rect.2 + self.1, rect.3 + self.2)
+    }
 }
 
 pub trait GraphicBuilder {
@@ -35,6 +95,7 @@ pub trait GraphicBuilder {
 
 //----------------------------------------------------------------------
 
+#[derive(Default)]
 pub struct TestBuilder;
 
 impl TestBuilder {
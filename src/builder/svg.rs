@@ -1,5 +1,6 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
+use crate::animation::{Interpolate, Track};
 use crate::item::{
     Color,
     Color::{Blue, Lime, Purple, Red, RGBA},
@@ -11,38 +12,80 @@ use crate::item::{
 use crate::prelude::*;
 
 use crate::path::{
-    PathSegment::{C, L, M, Q, Z},
+    PathSegment::{A, C, L, M, Q, Z},
     PathSegments,
 };
 
 pub use super::GraphicBuilder;
-use super::{BuilderOptions, SizeUnit};
+use super::{BuilderOptions, Padding};
 
-pub struct SvgBuilder {}
+pub struct SvgBuilder {
+    /// Express trimmed shapes with `stroke-dasharray` on the full shape instead of cutting
+    /// the geometry. Keeps the output animatable, but only trims strokes, not fills.
+    pub dash_trim: bool,
+    /// Decimal places of coordinates, lengths and path data, None for full precision.
+    pub precision: Option<usize>,
+    /// Keep the coordinates of the items, with the viewBox where they are, instead of moving
+    /// the content to 0, 0.
+    pub keep_origin: bool,
+    /// Space around the content, in item units.
+    pub padding: Padding,
+    /// Region to show instead of the bounding box of the items. Unlike the viewport of
+    /// `BuilderOptions` it culls nothing.
+    pub view_box: Option<Rectangle>,
+    /// Fill of the whole canvas, padding included.
+    pub background: Option<Color>,
+    /// Indented output with a line per element; false writes it all on one line.
+    pub pretty: bool,
+    /// Ends the document with an invisible rectangle of the canvas size, for viewers that
+    /// size drawings by their content.
+    pub bounding_rect: bool,
+    /// Write paths that repeat with only their position changed once in defs, and `use`
+    /// them, see `GraphicItems::share_paths`.
+    pub share_paths: bool,
+}
+
+impl Default for SvgBuilder {
+    fn default() -> Self {
+        Self { dash_trim: false, precision: None, keep_origin: false, padding: Padding::default(), view_box: None, background: None, pretty: true, bounding_rect: true, share_paths: false }
+    }
+}
 
 impl SvgBuilder {
     pub fn new() -> Self {
-        Self {}
+        Self::default()
     }
 }
 
 impl GraphicBuilder for SvgBuilder {
-    fn build(
-        &mut self,
-        mut items: GraphicItems,
-        options: Option<BuilderOptions>,
-    ) -> Result<String> {
+    fn build(&mut self, mut items: GraphicItems, options: Option<BuilderOptions>) -> Result<String> {
         // println!("items1:{:?}", items);
+        items.check_geometry()?;
         let mut use_cache: HashMap<String, PathSegments> = HashMap::new();
 
-        let items_bbox = items.bbox();
+        let viewport = options.and_then(|options| options.viewport);
+        if let Some(ref viewport) = viewport {
+            items = items.cull(viewport);
+        }
+        if !self.dash_trim {
+            items = items.apply_trims();
+        }
+        if self.share_paths {
+            items = items.share_paths();
+        }
+
+        // Animated items are sized to hold every keyframe
+        let items_bbox = self.view_box.or(viewport).unwrap_or_else(|| if items.is_animated() { items.animated_bbox() } else { items.bbox() });
+        let items_bbox = self.padding.around(&items_bbox);
         // println!("items_bbox:{:?}", items_bbox);
-        if items_bbox.0 != 0. || items_bbox.1 != 0. {
+        if !self.keep_origin && (items_bbox.0 != 0. || items_bbox.1 != 0.) {
             items = items.move_items(-items_bbox.0, -items_bbox.1);
         }
+        let (origin_x, origin_y) = if self.keep_origin { (items_bbox.0, items_bbox.1) } else { (0., 0.) };
         // println!("items2:{:?}", items);
 
-        let mut svg = xmlwriter::XmlWriter::new(xmlwriter::Options::default());
+        let indent = if self.pretty { xmlwriter::Indent::Spaces(4) } else { xmlwriter::Indent::None };
+        let mut svg = xmlwriter::XmlWriter::new(xmlwriter::Options { indent, attributes_indent: xmlwriter::Indent::None, ..Default::default() });
         svg.start_element("svg");
         svg.write_attribute("xmlns", "http://www.w3.org/2000/svg");
         svg.write_attribute("xmlns:xlink", "http://www.w3.org/1999/xlink");
@@ -50,24 +93,13 @@ impl GraphicBuilder for SvgBuilder {
         let svg_width_value = items_bbox.2 + (-items_bbox.0);
         let svg_height_value = items_bbox.3 + (-items_bbox.1);
 
-        let svg_width = match options {
-            Some(ref options) => match options.size_unit {
-                SizeUnit::Pixel => (round2(svg_width_value * options.size_scaling)).to_string(),
-                SizeUnit::Rem => {
-                    (round2(svg_width_value * options.size_scaling)).to_string() + "rem"
-                }
-            },
-            None => svg_width_value.to_string(),
-        };
-        let svg_height = match options {
-            Some(ref options) => match options.size_unit {
-                SizeUnit::Pixel => (round2(svg_height_value * options.size_scaling)).to_string(),
-                SizeUnit::Rem => {
-                    (round2(svg_height_value * options.size_scaling)).to_string() + "rem"
-                }
-            },
-            None => svg_height_value.to_string(),
+        let precision = Precision(self.precision);
+        let size = |value: f32| match options {
+            Some(ref options) => format!("{}{}", precision.number(round2(value * options.size_scaling)), options.size_unit.suffix()),
+            None => precision.number(value),
         };
+        let svg_width = size(svg_width_value);
+        let svg_height = size(svg_height_value);
 
         // dbg!(&options, &svg_width_value, &svg_width, &svg_height);
 
@@ -76,150 +108,501 @@ impl GraphicBuilder for SvgBuilder {
 
         svg.write_attribute_fmt(
             "viewBox",
-            format_args!(
-                "{} {} {} {}",
-                0,
-                0,
-                items_bbox.2 + (-items_bbox.0),
-                items_bbox.3 + (-items_bbox.1)
-            ),
+            format_args!("{} {} {} {}", precision.number(origin_x), precision.number(origin_y), precision.number(items_bbox.2 + (-items_bbox.0)), precision.number(items_bbox.3 + (-items_bbox.1))),
         );
 
-        // Cache elements -----------------------------------------
+        // Cache elements, clip paths and masks -----------------------
         svg.start_element("defs");
         svg.write_attribute("visibility", "hidden");
-        for item in items.0.iter() {
-            match item {
-                GraphicItem::Path(path, stroke, fill, cache) => match cache {
-                    PathCacheInfo::Cache(ref tag, x, y) => {
-                        dbg!("Use cache");
-                        if !use_cache.contains_key(tag) {
-                            use_cache.insert(tag.to_string(), path.clone());
-                            svg.start_element("path");
-                            svg.write_attribute("id", tag);
-                            svg.write_attribute("d", path.to_string().as_str());
-                            svg.end_element();
-                        }
-                    }
-                    PathCacheInfo::NoCache => {}
-                },
-                _ => {}
+        let root = key_root(&items);
+        write_defs(&mut svg, &items, &root, &mut use_cache, precision);
+        svg.end_element();
+
+        let canvas = |svg: &mut xmlwriter::XmlWriter| {
+            svg.start_element("rect");
+            precision.write(svg, "x", origin_x);
+            precision.write(svg, "y", origin_y);
+            precision.write(svg, "width", items_bbox.2 + (-items_bbox.0));
+            precision.write(svg, "height", items_bbox.3 + (-items_bbox.1));
+        };
+        if let Some(background) = &self.background {
+            canvas(&mut svg);
+            svg.write_attribute("fill", background.to_string().as_str());
+            svg.end_element();
+        }
+
+        write_items(&mut svg, &items, &root, Inherited::default(), precision);
+
+        // Bounding rectangle -----------------------------------------
+        if self.bounding_rect {
+            canvas(&mut svg);
+            svg.write_attribute("stroke", "transparent");
+            svg.write_attribute("stroke-width", "1");
+            svg.write_attribute("fill", "none");
+            svg.end_element();
+        }
+        // ------------------------------------------------------
+
+        Ok(svg.end_document())
+    }
+}
+
+// Coordinates and lengths with at most the given number of decimal places, None for full
+// precision. Timings, fractions, names, user data and colors are written as they are.
+#[derive(Clone, Copy)]
+struct Precision(Option<usize>);
+
+impl Precision {
+    fn number(&self, value: f32) -> String {
+        let Some(precision) = self.0 else { return value.to_string() };
+        let rounded = format!("{:.*}", precision, value);
+        let rounded = if rounded.contains('.') { rounded.trim_end_matches('0').trim_end_matches('.') } else { rounded.as_str() };
+        match rounded {
+            "-0" => "0".to_string(),
+            rounded => rounded.to_string(),
+        }
+    }
+
+    fn write(&self, svg: &mut xmlwriter::XmlWriter, name: &str, value: f32) {
+        svg.write_attribute(name, &self.number(value));
+    }
+
+    // Path data as written by `PathSegments`' Display, with rounded numbers
+    fn path(&self, path: &PathSegments) -> String {
+        if self.0.is_none() {
+            return path.to_string();
+        }
+        let n = |value: &f32| self.number(*value);
+        path.0
+            .iter()
+            .map(|segment| match segment {
+                M(x, y) => format!("M {} {} ", n(x), n(y)),
+                L(x, y) => format!("L {} {} ", n(x), n(y)),
+                Q(x1, y1, x, y) => format!("Q {} {} {} {} ", n(x1), n(y1), n(x), n(y)),
+                C(x1, y1, x2, y2, x, y) => format!("C {} {} {} {} {} {} ", n(x1), n(y1), n(x2), n(y2), n(x), n(y)),
+                A(rx, ry, rotation, large_arc, sweep, x, y) => format!("A {} {} {} {} {} {} {} ", n(rx), n(ry), n(rotation), *large_arc as u8, *sweep as u8, n(x), n(y)),
+                Z => "Z ".to_string(),
+            })
+            .collect()
+    }
+
+    fn points(&self, points: &[Point]) -> String {
+        points.iter().map(|p| format!("{},{}", self.number(p.0), self.number(p.1))).collect::<Vec<_>>().join(" ")
+    }
+}
+
+// Clip paths and masks get ids derived from the position of their group in the
+// item tree, so that defs and items can be written in separate passes.
+fn item_key(prefix: &str, idx: usize) -> String {
+    format!("{}{}", prefix, idx)
+}
+
+// Keys start with a root that keeps the clip and mask ids apart from the ids of items and cached paths
+fn key_root(items: &GraphicItems) -> String {
+    let mut keys = Vec::new();
+    let mut taken = HashSet::new();
+    collect_ids(items, "", &mut keys, &mut taken);
+    let clashes = |root: &str| keys.iter().any(|key| taken.contains(&format!("clip-{}{}", root, key)) || taken.contains(&format!("mask-{}{}", root, key)));
+    (0..).map(|n| if n == 0 { String::new() } else { format!("r{}-", n) }).find(|root| !clashes(root)).unwrap()
+}
+
+fn collect_ids(items: &GraphicItems, prefix: &str, keys: &mut Vec<String>, taken: &mut HashSet<String>) {
+    for (idx, item) in items.0.iter().enumerate() {
+        match item {
+            GraphicItem::Path(_, _, _, PathCacheInfo::Cache(tag, _, _)) => {
+                taken.insert(tag.clone());
             }
+            GraphicItem::Group(group_items, attrs) => {
+                let key = item_key(prefix, idx);
+                if let Some(id) = &attrs.metadata.id {
+                    taken.insert(id.clone());
+                }
+                if let Some(mask) = &attrs.mask {
+                    collect_ids(&mask.0, &format!("{}-m", key), keys, taken);
+                }
+                collect_ids(group_items, &format!("{}-", key), keys, taken);
+                if attrs.clip.is_some() || attrs.mask.is_some() {
+                    keys.push(key);
+                }
+            }
+            _ => {}
         }
-        svg.end_element();
+    }
+}
 
-        for item in items.0.iter() {
-            match item {
-                GraphicItem::Line(x1, y1, x2, y2, stroke) => {
-                    // println!("- Line:{:?}", item);
-
-                    svg.start_element("line");
-                    svg.write_attribute("x1", x1);
-                    svg.write_attribute("y1", y1);
-                    svg.write_attribute("x2", x2);
-                    svg.write_attribute("y2", y2);
-                    if let Stroke::Strokestyle(w, color) = stroke {
-                        svg.write_attribute("stroke", color.to_string().as_str());
-                        svg.write_attribute("stroke-width", w);
-                    }
+fn write_defs(svg: &mut xmlwriter::XmlWriter, items: &GraphicItems, prefix: &str, use_cache: &mut HashMap<String, PathSegments>, precision: Precision) {
+    for (idx, item) in items.0.iter().enumerate() {
+        match item {
+            GraphicItem::Path(path, _, _, PathCacheInfo::Cache(ref tag, _, _)) if !use_cache.contains_key(tag) => {
+                use_cache.insert(tag.to_string(), path.clone());
+                svg.start_element("path");
+                svg.write_attribute("id", tag);
+                svg.write_attribute("d", &precision.path(path));
+                svg.end_element();
+            }
+            GraphicItem::Group(group_items, attrs) => {
+                let key = item_key(prefix, idx);
+                if let Some(clip) = &attrs.clip {
+                    svg.start_element("clipPath");
+                    svg.write_attribute("id", &format!("clip-{}", key));
+                    svg.write_attribute("visibility", "visible");
+                    svg.start_element("path");
+                    svg.write_attribute("d", &precision.path(&clip.0));
+                    svg.end_element();
                     svg.end_element();
                 }
-                GraphicItem::Rect(x, y, w, h, stroke, fill) => {
-                    // println!("- Rect:{:?}", item);
-                    svg.start_element("rect");
-                    svg.write_attribute("x", x);
-                    svg.write_attribute("y", y);
-                    svg.write_attribute("width", w);
-                    svg.write_attribute("height", h);
-                    if let Stroke::Strokestyle(w, color) = stroke {
-                        svg.write_attribute("stroke", color.to_string().as_str());
-                        svg.write_attribute("stroke-width", w);
+                if let Some(mask) = &attrs.mask {
+                    let mask_prefix = format!("{}-m", key);
+                    write_defs(svg, &mask.0, &mask_prefix, use_cache, precision);
+                    svg.start_element("mask");
+                    svg.write_attribute("id", &format!("mask-{}", key));
+                    svg.write_attribute("maskUnits", "userSpaceOnUse");
+                    svg.write_attribute("visibility", "visible");
+                    if let MaskType::Alpha = mask.1 {
+                        svg.write_attribute("mask-type", "alpha");
                     }
-                    if let Fill::Fillstyle(color) = fill {
-                        svg.write_attribute("fill", color.to_string().as_str());
-                    } else {
-                        svg.write_attribute("fill", "none");
-                    }
-
+                    write_items(svg, &mask.0, &mask_prefix, Inherited::default(), precision);
                     svg.end_element();
                 }
+                write_defs(svg, group_items, &format!("{}-", key), use_cache, precision);
+            }
+            _ => {}
+        }
+    }
+}
 
-                GraphicItem::Ellipse(x, y, w, h, stroke, fill) => {
-                    // println!("- Rect:{:?}", item);
-                    svg.start_element("ellipse");
-                    svg.write_attribute("cx", &(x + w / 2.));
-                    svg.write_attribute("cy", &(y + h / 2.));
-                    svg.write_attribute("rx", &(w / 2.0));
-                    svg.write_attribute("ry", &(h / 2.0));
-                    if let Stroke::Strokestyle(w, color) = stroke {
-                        svg.write_attribute("stroke", color.to_string().as_str());
-                        svg.write_attribute("stroke-width", w);
-                    }
-                    if let Fill::Fillstyle(color) = fill {
-                        svg.write_attribute("fill", color.to_string().as_str());
-                    } else {
-                        svg.write_attribute("fill", "none");
-                    }
+// Trims and animation tracks of the enclosing groups, applied to the shapes in them
+#[derive(Default, Clone, Copy)]
+struct Inherited<'a> {
+    trim: Option<Trim>,
+    trim_track: Option<&'a Track<(f32, f32)>>,
+    fill: Option<&'a Track<Color>>,
+    stroke: Option<&'a Track<Color>>,
+}
 
-                    svg.end_element();
-                }
+impl Inherited<'_> {
+    fn is_trimmed(&self) -> bool {
+        self.trim.is_some() || self.trim_track.is_some()
+    }
+}
 
-                GraphicItem::Path(path, stroke, fill, cache) => match cache {
-                    PathCacheInfo::Cache(ref tag, x, y) => {
-                        let path = use_cache.get(tag).unwrap();
-                        svg.start_element("use");
-                        svg.write_attribute("href", &format!("#{}", tag));
-                        svg.write_attribute("x", x);
-                        svg.write_attribute("y", y);
-                        if let Stroke::Strokestyle(w, color) = stroke {
-                            svg.write_attribute("stroke", color.to_string().as_str());
-                            svg.write_attribute("stroke-width", w);
-                        }
-                        if let Fill::Fillstyle(color) = fill {
-                            svg.write_attribute("fill", color.to_string().as_str());
-                        } else {
-                            svg.write_attribute("fill", "none");
-                        }
-                        svg.end_element();
-                    }
-                    PathCacheInfo::NoCache => {
-                        svg.start_element("path");
-                        svg.write_attribute("d", path.to_string().as_str());
-                        if let Stroke::Strokestyle(w, color) = stroke {
-                            svg.write_attribute("stroke", color.to_string().as_str());
-                            svg.write_attribute("stroke-width", w);
-                        }
-                        if let Fill::Fillstyle(color) = fill {
-                            svg.write_attribute("fill", color.to_string().as_str());
-                        } else {
-                            svg.write_attribute("fill", "none");
-                        }
-                        svg.end_element();
-                    }
+fn write_items<'a>(svg: &mut xmlwriter::XmlWriter, items: &'a GraphicItems, prefix: &str, inherited: Inherited<'a>, precision: Precision) {
+    for (idx, item) in items.0.iter().enumerate() {
+        write_item(svg, item, &item_key(prefix, idx), inherited, precision, None);
+    }
+}
+
+// Groups that carry nothing but metadata around a single shape are written as the shape,
+// with the metadata on its element
+fn write_item<'a>(svg: &mut xmlwriter::XmlWriter, item: &'a GraphicItem, key: &str, inherited: Inherited<'a>, precision: Precision, metadata: Option<&Metadata>) {
+    match item {
+        GraphicItem::Line(x1, y1, x2, y2, stroke) => {
+            svg.start_element("line");
+            precision.write(svg, "x1", *x1);
+            precision.write(svg, "y1", *y1);
+            precision.write(svg, "x2", *x2);
+            precision.write(svg, "y2", *y2);
+            write_metadata(svg, metadata);
+            write_stroke(svg, stroke, precision);
+            write_trim(svg, &inherited);
+            write_title(svg, metadata);
+            write_shape_animations(svg, &inherited, stroke, &Fill::NoFill);
+            svg.end_element();
+        }
+        GraphicItem::Rect(x, y, w, h, stroke, fill) => {
+            svg.start_element("rect");
+            precision.write(svg, "x", *x);
+            precision.write(svg, "y", *y);
+            precision.write(svg, "width", *w);
+            precision.write(svg, "height", *h);
+            write_metadata(svg, metadata);
+            write_stroke(svg, stroke, precision);
+            write_fill(svg, fill);
+            write_trim(svg, &inherited);
+            write_title(svg, metadata);
+            write_shape_animations(svg, &inherited, stroke, fill);
+            svg.end_element();
+        }
+        GraphicItem::RoundedRect(x, y, w, h, radii, stroke, fill) => {
+            let radii = radii.fitted(*w, *h);
+            if radii.is_uniform() {
+                svg.start_element("rect");
+                precision.write(svg, "x", *x);
+                precision.write(svg, "y", *y);
+                precision.write(svg, "width", *w);
+                precision.write(svg, "height", *h);
+                precision.write(svg, "rx", radii.0);
+                precision.write(svg, "ry", radii.0);
+            } else {
+                svg.start_element("path");
+                svg.write_attribute("d", &precision.path(&PathSegments::rounded_rect(*x, *y, *w, *h, &radii)));
+            }
+            write_metadata(svg, metadata);
+            write_stroke(svg, stroke, precision);
+            write_fill(svg, fill);
+            write_trim(svg, &inherited);
+            write_title(svg, metadata);
+            write_shape_animations(svg, &inherited, stroke, fill);
+            svg.end_element();
+        }
+        GraphicItem::Polyline(points, stroke) => {
+            svg.start_element("polyline");
+            svg.write_attribute("points", &precision.points(points));
+            write_metadata(svg, metadata);
+            write_stroke(svg, stroke, precision);
+            write_fill(svg, &Fill::NoFill);
+            write_trim(svg, &inherited);
+            write_title(svg, metadata);
+            write_shape_animations(svg, &inherited, stroke, &Fill::NoFill);
+            svg.end_element();
+        }
+        GraphicItem::Polygon(points, stroke, fill) => {
+            svg.start_element("polygon");
+            svg.write_attribute("points", &precision.points(points));
+            write_metadata(svg, metadata);
+            write_stroke(svg, stroke, precision);
+            write_fill(svg, fill);
+            write_trim(svg, &inherited);
+            write_title(svg, metadata);
+            write_shape_animations(svg, &inherited, stroke, fill);
+            svg.end_element();
+        }
+        GraphicItem::Ellipse(x, y, w, h, stroke, fill) => {
+            svg.start_element("ellipse");
+            precision.write(svg, "cx", x + w / 2.);
+            precision.write(svg, "cy", y + h / 2.);
+            precision.write(svg, "rx", w / 2.0);
+            precision.write(svg, "ry", h / 2.0);
+            write_metadata(svg, metadata);
+            write_stroke(svg, stroke, precision);
+            write_fill(svg, fill);
+            write_trim(svg, &inherited);
+            write_title(svg, metadata);
+            write_shape_animations(svg, &inherited, stroke, fill);
+            svg.end_element();
+        }
+        GraphicItem::Path(path, stroke, fill, cache) => {
+            match cache {
+                // pathLength does not carry over to the referenced path
+                PathCacheInfo::Cache(_, x, y) if inherited.is_trimmed() => {
+                    svg.start_element("path");
+                    svg.write_attribute("d", &precision.path(&path.move_path(*x, *y)));
+                }
+                PathCacheInfo::Cache(ref tag, x, y) => {
+                    svg.start_element("use");
+                    svg.write_attribute("href", &format!("#{}", tag));
+                    precision.write(svg, "x", *x);
+                    precision.write(svg, "y", *y);
+                }
+                PathCacheInfo::NoCache => {
+                    svg.start_element("path");
+                    svg.write_attribute("d", &precision.path(path));
+                }
+            }
+            write_metadata(svg, metadata);
+            write_stroke(svg, stroke, precision);
+            write_fill(svg, fill);
+            write_trim(svg, &inherited);
+            write_title(svg, metadata);
+            write_shape_animations(svg, &inherited, stroke, fill);
+            svg.end_element();
+        }
+        GraphicItem::Group(group_items, attrs) => {
+            if let [single] = group_items.0.as_slice() {
+                if !matches!(single, GraphicItem::Group(..)) && has_only_metadata(attrs) {
+                    write_item(svg, single, &item_key(&format!("{}-", key), 0), inherited, precision, Some(&attrs.metadata));
+                    return;
+                }
+            }
+            svg.start_element("g");
+            write_metadata(svg, Some(&attrs.metadata));
+            if attrs.clip.is_some() {
+                svg.write_attribute("clip-path", &format!("url(#clip-{})", key));
+            }
+            if attrs.mask.is_some() {
+                svg.write_attribute("mask", &format!("url(#mask-{})", key));
+            }
+            if attrs.opacity < 1.0 {
+                svg.write_attribute("opacity", &attrs.opacity);
+            }
+            if attrs.blend != BlendMode::Normal {
+                svg.write_attribute_fmt("style", format_args!("mix-blend-mode:{}", blend_mode_css(attrs.blend)));
+            }
+            write_title(svg, Some(&attrs.metadata));
+            let animation = attrs.animation.as_ref();
+            if let Some(track) = animation.and_then(|animation| animation.opacity.as_ref()) {
+                write_track(svg, "animate", "opacity", track, |opacity| opacity.clamp(0., 1.).to_string(), &[]);
+            }
+            if let Some(track) = animation.and_then(|animation| animation.translate.as_ref()) {
+                write_track(svg, "animateTransform", "transform", track, |(x, y)| format!("{} {}", precision.number(*x), precision.number(*y)), &[("type", "translate")]);
+            }
+            // Scaling about the pivot: moved to the origin, scaled and moved back
+            let scale = animation.and_then(|animation| animation.scale.as_ref().map(|track| (track, animation.pivot)));
+            if let Some((track, Point(px, py))) = scale {
+                svg.start_element("g");
+                svg.write_attribute_fmt("transform", format_args!("translate({} {})", precision.number(px), precision.number(py)));
+                write_track(svg, "animateTransform", "transform", track, |(x, y)| format!("{} {}", x, y), &[("type", "scale"), ("additive", "sum")]);
+                svg.start_element("g");
+                svg.write_attribute_fmt("transform", format_args!("translate({} {})", precision.number(-px), precision.number(-py)));
+            }
+            // Outer colors win, as in sampling; the nearest trim track wins
+            let inherited = Inherited {
+                trim: match (attrs.trim, inherited.trim) {
+                    (Some(inner), Some(outer)) => Some(inner.then(&outer)),
+                    (inner, outer) => inner.or(outer),
                 },
+                trim_track: animation.and_then(|animation| animation.trim.as_ref()).or(inherited.trim_track),
+                fill: inherited.fill.or(animation.and_then(|animation| animation.fill.as_ref())),
+                stroke: inherited.stroke.or(animation.and_then(|animation| animation.stroke.as_ref())),
+            };
+            write_items(svg, group_items, &format!("{}-", key), inherited, precision);
+            if scale.is_some() {
+                svg.end_element();
+                svg.end_element();
             }
+            svg.end_element();
         }
+    }
+}
 
-        // Bounding rectangle -----------------------------------------
-        svg.start_element("rect");
-        svg.write_attribute("x", "0");
-        svg.write_attribute("y", "0");
-        svg.write_attribute(
-            "width",
-            (items_bbox.2 + (-items_bbox.0)).to_string().as_str(),
-        );
-        svg.write_attribute(
-            "height",
-            (items_bbox.3 + (-items_bbox.1)).to_string().as_str(),
-        );
-        svg.write_attribute("stroke", "transparent");
-        svg.write_attribute("stroke-width", "1");
-        svg.write_attribute("fill", "none");
+fn has_only_metadata(attrs: &ItemAttributes) -> bool {
+    *attrs.metadata != Metadata::default()
+        && attrs.clip.is_none()
+        && attrs.mask.is_none()
+        && attrs.opacity >= 1.0
+        && attrs.blend == BlendMode::Normal
+        && attrs.trim.is_none()
+        && attrs.animation.is_none()
+}
+
+fn write_metadata(svg: &mut xmlwriter::XmlWriter, metadata: Option<&Metadata>) {
+    let Some(metadata) = metadata else { return };
+    if let Some(id) = &metadata.id {
+        svg.write_attribute("id", id);
+    }
+    if !metadata.classes.is_empty() {
+        svg.write_attribute("class", &metadata.classes.join(" "));
+    }
+    for (name, value) in metadata.data.iter() {
+        svg.write_attribute(&format!("data-{}", name), value);
+    }
+}
+
+fn write_title(svg: &mut xmlwriter::XmlWriter, metadata: Option<&Metadata>) {
+    if let Some(title) = metadata.and_then(|metadata| metadata.title.as_ref()) {
+        // No indentation inside, it would be part of the tooltip
+        svg.set_preserve_whitespaces(true);
+        svg.start_element("title");
+        svg.write_text(title);
         svg.end_element();
-        // ------------------------------------------------------
+        svg.set_preserve_whitespaces(false);
+    }
+}
+
+fn blend_mode_css(blend: BlendMode) -> &'static str {
+    match blend {
+        BlendMode::Normal => "normal",
+        BlendMode::Multiply => "multiply",
+        BlendMode::Screen => "screen",
+        BlendMode::Overlay => "overlay",
+        BlendMode::Darken => "darken",
+        BlendMode::Lighten => "lighten",
+        BlendMode::ColorDodge => "color-dodge",
+        BlendMode::ColorBurn => "color-burn",
+        BlendMode::HardLight => "hard-light",
+        BlendMode::SoftLight => "soft-light",
+        BlendMode::Difference => "difference",
+        BlendMode::Exclusion => "exclusion",
+        BlendMode::Hue => "hue",
+        BlendMode::Saturation => "saturation",
+        BlendMode::Color => "color",
+        BlendMode::Luminosity => "luminosity",
+    }
+}
+
+// A single dash covering the trimmed part of the normalized length, followed by a gap
+// longer than the rest.
+fn write_trim(svg: &mut xmlwriter::XmlWriter, inherited: &Inherited) {
+    if !inherited.is_trimmed() {
+        return;
+    }
+    let Trim(start, end) = inherited.trim.unwrap_or(Trim(0., 1.));
+    svg.write_attribute("pathLength", "1");
+    svg.write_attribute_fmt("stroke-dasharray", format_args!("{} 1", (end - start).max(0.)));
+    svg.write_attribute("stroke-dashoffset", &(-start));
+}
+
+// Color tracks only recolor the fills and strokes a shape has; an animated trim always
+// animates the dashes, whether or not static trims were cut.
+fn write_shape_animations(svg: &mut xmlwriter::XmlWriter, inherited: &Inherited, stroke: &Stroke, fill: &Fill) {
+    if let (Some(track), Fill::Fillstyle(_)) = (inherited.fill, fill) {
+        write_track(svg, "animate", "fill", track, Color::to_string, &[]);
+    }
+    if let (Some(track), Stroke::Strokestyle(..)) = (inherited.stroke, stroke) {
+        write_track(svg, "animate", "stroke", track, Color::to_string, &[]);
+    }
+    if let Some(track) = inherited.trim_track {
+        write_track(svg, "animate", "stroke-dasharray", track, |(start, end)| format!("{} 1", (end.clamp(0., 1.) - start.clamp(0., 1.)).max(0.)), &[]);
+        write_track(svg, "animate", "stroke-dashoffset", track, |(start, _)| (0. - start.clamp(0., 1.)).to_string(), &[]);
+    }
+}
+
+// A SMIL animation running once from the start of the document and keeping its last value.
+// Easings become key splines; a held value is repeated at the next key time, where it jumps.
+fn write_track<T: Interpolate>(svg: &mut xmlwriter::XmlWriter, element: &str, attribute: &str, track: &Track<T>, value: impl Fn(&T) -> String, extra: &[(&str, &str)]) {
+    let Some(first) = track.0.first() else { return };
+    let linear = (0., 0., 1., 1.);
+    let duration = track.end().max(0.001);
+    let mut keys = vec![];
+    let mut splines = vec![];
+    if first.time > 0. {
+        keys.push((0., value(&first.value)));
+        splines.push(linear);
+    }
+    for (idx, keyframe) in track.0.iter().enumerate() {
+        keys.push((keyframe.time, value(&keyframe.value)));
+        let Some(next) = track.0.get(idx + 1) else { break };
+        match keyframe.easing.control_points() {
+            Some(points) => splines.push(points),
+            None => {
+                keys.push((next.time, value(&keyframe.value)));
+                splines.extend([linear, linear]);
+            }
+        }
+    }
+    if let Some((_, last)) = keys.last().cloned().filter(|(time, _)| *time < duration) {
+        keys.push((duration, last));
+        splines.push(linear);
+    }
+
+    svg.start_element(element);
+    svg.write_attribute("attributeName", attribute);
+    for (name, v) in extra {
+        svg.write_attribute(name, v);
+    }
+    svg.write_attribute("begin", "0s");
+    svg.write_attribute_fmt("dur", format_args!("{}s", duration));
+    svg.write_attribute("values", &keys.iter().map(|(_, v)| v.as_str()).collect::<Vec<_>>().join(";"));
+    svg.write_attribute("keyTimes", &keys.iter().map(|(time, _)| (time / duration).to_string()).collect::<Vec<_>>().join(";"));
+    svg.write_attribute("calcMode", "spline");
+    let splines = splines.iter().map(|(x1, y1, x2, y2)| [x1, y1, x2, y2].map(|v| v.to_string()).join(" "));
+    svg.write_attribute("keySplines", &splines.collect::<Vec<_>>().join(";"));
+    svg.write_attribute("fill", "freeze");
+    svg.end_element();
+}
 
-        let str = svg.end_document();
-        Ok(str)
+fn write_stroke(svg: &mut xmlwriter::XmlWriter, stroke: &Stroke, precision: Precision) {
+    if let Stroke::Strokestyle(w, color) = stroke {
+        svg.write_attribute("stroke", color.to_string().as_str());
+        precision.write(svg, "stroke-width", *w);
+    }
+}
+
+fn write_fill(svg: &mut xmlwriter::XmlWriter, fill: &Fill) {
+    if let Fill::Fillstyle(color) = fill {
+        svg.write_attribute("fill", color.to_string().as_str());
+    } else {
+        svg.write_attribute("fill", "none");
     }
 }
 
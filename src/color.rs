@@ -0,0 +1,472 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::GraphicsError;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Color {
+    RGBA(u8, u8, u8, u8),
+    Blue,
+    Dodgerblue,
+    Red,
+    Tomato,
+    Orange,
+    Purple,
+    Lime,
+    Gray,
+    LightGray,
+    Green,
+    Black,
+    White,
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Color::RGBA(r, g, b, a) => write!(f, "rgba({},{},{},{})", r, g, b, round3(*a as f32 / 255.)),
+            Color::Blue => write!(f, "blue"),
+            Color::Dodgerblue => write!(f, "dodgerblue"),
+            Color::LightGray => write!(f, "lightgray"),
+            Color::Gray => write!(f, "gray"),
+            Color::Red => write!(f, "red"),
+            Color::Tomato => write!(f, "tomato"),
+            Color::Orange => write!(f, "orange"),
+            Color::Purple => write!(f, "purple"),
+            Color::Lime => write!(f, "lime"),
+            Color::Green => write!(f, "green"),
+            Color::Black => write!(f, "black"),
+            Color::White => write!(f, "white"),
+        }
+    }
+}
+
+/// Parses CSS color syntax: named colors, `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`,
+/// `rgb()`, `rgba()`, `hsl()` and `hsla()`. Errors give the byte position of the bad
+/// digit or argument.
+impl FromStr for Color {
+    type Err = GraphicsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let start = s.len() - s.trim_start().len();
+        parse_color(&s.trim().to_ascii_lowercase()).map_err(|(position, message)| GraphicsError::Parse { what: "color", position: start + position, message })
+    }
+}
+
+impl Color {
+    /// Color components as bytes, alpha 255 being opaque.
+    pub fn to_rgba8(&self) -> (u8, u8, u8, u8) {
+        match self {
+            Color::RGBA(r, g, b, a) => (*r, *g, *b, *a),
+            Color::Blue => (0, 0, 255, 255),
+            Color::Dodgerblue => (30, 144, 255, 255),
+            Color::Red => (255, 0, 0, 255),
+            Color::Tomato => (255, 99, 71, 255),
+            Color::Orange => (255, 165, 0, 255),
+            Color::Purple => (128, 0, 128, 255),
+            Color::Lime => (0, 255, 0, 255),
+            Color::Gray => (128, 128, 128, 255),
+            Color::LightGray => (211, 211, 211, 255),
+            Color::Green => (0, 128, 0, 255),
+            Color::Black => (0, 0, 0, 255),
+            Color::White => (255, 255, 255, 255),
+        }
+    }
+
+    /// Color components in the range 0.0..=1.0. This is what the builders use
+    /// to translate a color into backend specific syntax.
+    pub fn to_rgba(&self) -> (f32, f32, f32, f32) {
+        let (r, g, b, a) = self.to_rgba8();
+        (r as f32 / 255., g as f32 / 255., b as f32 / 255., a as f32 / 255.)
+    }
+
+    pub fn from_rgba(r: f32, g: f32, b: f32, a: f32) -> Color {
+        Color::RGBA(to_byte(r), to_byte(g), to_byte(b), to_byte(a))
+    }
+
+    /// Hue in degrees (0.0..360.0), saturation and lightness in 0.0..=1.0, plus alpha.
+    pub fn to_hsla(&self) -> (f32, f32, f32, f32) {
+        let (r, g, b, a) = self.to_rgba();
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.;
+        let d = max - min;
+        if d == 0. {
+            return (0., 0., l, a);
+        }
+        let s = d / (1. - (2. * l - 1.).abs());
+        let h = if max == r {
+            60. * ((g - b) / d).rem_euclid(6.)
+        } else if max == g {
+            60. * ((b - r) / d + 2.)
+        } else {
+            60. * ((r - g) / d + 4.)
+        };
+        (h, s, l, a)
+    }
+
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let (h, s, l, _) = self.to_hsla();
+        (h, s, l)
+    }
+
+    pub fn from_hsla(h: f32, s: f32, l: f32, a: f32) -> Color {
+        let h = h.rem_euclid(360.);
+        let s = s.clamp(0., 1.);
+        let l = l.clamp(0., 1.);
+        let c = (1. - (2. * l - 1.).abs()) * s;
+        let x = c * (1. - ((h / 60.).rem_euclid(2.) - 1.).abs());
+        let m = l - c / 2.;
+        let (r, g, b) = match (h / 60.) as u32 {
+            0 => (c, x, 0.),
+            1 => (x, c, 0.),
+            2 => (0., c, x),
+            3 => (0., x, c),
+            4 => (x, 0., c),
+            _ => (c, 0., x),
+        };
+        Color::from_rgba(r + m, g + m, b + m, a)
+    }
+
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Color {
+        Color::from_hsla(h, s, l, 1.)
+    }
+
+    /// Linear interpolation in RGBA space, `t` = 0.0 giving `self` and 1.0 giving `other`.
+    pub fn lerp(&self, other: &Color, t: f32) -> Color {
+        let (r1, g1, b1, a1) = self.to_rgba();
+        let (r2, g2, b2, a2) = other.to_rgba();
+        let t = t.clamp(0., 1.);
+        Color::from_rgba(r1 + (r2 - r1) * t, g1 + (g2 - g1) * t, b1 + (b2 - b1) * t, a1 + (a2 - a1) * t)
+    }
+
+    /// Raises HSL lightness by `amount` (0.0..=1.0).
+    pub fn lighten(&self, amount: f32) -> Color {
+        let (h, s, l, a) = self.to_hsla();
+        Color::from_hsla(h, s, l + amount, a)
+    }
+
+    /// Lowers HSL lightness by `amount` (0.0..=1.0).
+    pub fn darken(&self, amount: f32) -> Color {
+        self.lighten(-amount)
+    }
+
+    pub fn to_hex(&self) -> String {
+        match self.to_rgba8() {
+            (r, g, b, 255) => format!("#{:02x}{:02x}{:02x}", r, g, b),
+            (r, g, b, a) => format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a),
+        }
+    }
+}
+
+// Position and message of the first problem
+type ColorError = (usize, String);
+
+fn parse_color(s: &str) -> Result<Color, ColorError> {
+    if let Some(hex) = s.strip_prefix('#') {
+        return parse_hex(hex).map_err(|(position, message)| (position + 1, message));
+    }
+
+    if let Some((name, args)) = s.strip_suffix(')').and_then(|s| s.split_once('(')) {
+        let parse_args = match name.trim() {
+            "rgb" | "rgba" => parse_rgb_args,
+            "hsl" | "hsla" => parse_hsl_args,
+            name => return Err((0, format!("unknown color function '{}'", name))),
+        };
+        let args = split_args(args);
+        if args.len() != 3 && args.len() != 4 {
+            return Err((s.len() - 1, format!("expected 3 or 4 arguments, found {}", args.len())));
+        }
+        return parse_args(&args).map_err(|(position, message)| (name.len() + 1 + position, message));
+    }
+
+    match s {
+        "blue" => Ok(Color::Blue),
+        "dodgerblue" => Ok(Color::Dodgerblue),
+        "red" => Ok(Color::Red),
+        "tomato" => Ok(Color::Tomato),
+        "orange" => Ok(Color::Orange),
+        "purple" => Ok(Color::Purple),
+        "lime" => Ok(Color::Lime),
+        "gray" => Ok(Color::Gray),
+        "lightgray" => Ok(Color::LightGray),
+        "green" => Ok(Color::Green),
+        "black" => Ok(Color::Black),
+        "white" => Ok(Color::White),
+        "transparent" => Ok(Color::RGBA(0, 0, 0, 0)),
+        name => CSS_COLORS.iter().find(|(n, _, _, _)| *n == name).map(|(_, r, g, b)| Color::RGBA(*r, *g, *b, 255)).ok_or_else(|| (0, format!("'{}' is not a CSS color", name))),
+    }
+}
+
+fn parse_hex(hex: &str) -> Result<Color, ColorError> {
+    if let Some((position, c)) = hex.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+        return Err((position, format!("'{}' is not a hex digit", c)));
+    }
+    let nibble = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).unwrap() * 17;
+    let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+    match hex.len() {
+        3 => Ok(Color::RGBA(nibble(0), nibble(1), nibble(2), 255)),
+        4 => Ok(Color::RGBA(nibble(0), nibble(1), nibble(2), nibble(3))),
+        6 => Ok(Color::RGBA(byte(0), byte(2), byte(4), 255)),
+        8 => Ok(Color::RGBA(byte(0), byte(2), byte(4), byte(6))),
+        len => Err((hex.len(), format!("expected 3, 4, 6 or 8 hex digits, found {}", len))),
+    }
+}
+
+// The arguments of a color function with their positions, separated by commas, slashes or spaces
+fn split_args(args: &str) -> Vec<(usize, &str)> {
+    let mut split = vec![];
+    let mut start = None;
+    for (idx, c) in args.char_indices().chain([(args.len(), ',')]) {
+        match (c == ',' || c == '/' || c.is_whitespace(), start) {
+            (true, Some(from)) => {
+                split.push((from, &args[from..idx]));
+                start = None;
+            }
+            (false, None) => start = Some(idx),
+            _ => {}
+        }
+    }
+    split
+}
+
+// A number, or a percentage of `full`
+fn parse_component((position, s): (usize, &str), full: f32) -> Result<f32, ColorError> {
+    let value = match s.strip_suffix('%') {
+        Some(p) => p.parse::<f32>().ok().map(|v| v / 100. * full),
+        None => s.parse::<f32>().ok(),
+    };
+    value.ok_or_else(|| (position, format!("'{}' is not a number or percentage", s)))
+}
+
+fn parse_alpha(args: &[(usize, &str)]) -> Result<f32, ColorError> {
+    match args.get(3) {
+        Some(a) => parse_component(*a, 1.),
+        None => Ok(1.),
+    }
+}
+
+fn parse_rgb_args(args: &[(usize, &str)]) -> Result<Color, ColorError> {
+    let r = parse_component(args[0], 255.)?;
+    let g = parse_component(args[1], 255.)?;
+    let b = parse_component(args[2], 255.)?;
+    Ok(Color::from_rgba(r / 255., g / 255., b / 255., parse_alpha(args)?))
+}
+
+fn parse_hsl_args(args: &[(usize, &str)]) -> Result<Color, ColorError> {
+    let (position, hue) = args[0];
+    let h = hue.strip_suffix("deg").unwrap_or(hue).parse::<f32>().map_err(|_| (position, format!("'{}' is not a hue", hue)))?;
+    let s = parse_component(args[1], 1.)?;
+    let l = parse_component(args[2], 1.)?;
+    Ok(Color::from_hsla(h, s, l, parse_alpha(args)?))
+}
+
+fn to_byte(v: f32) -> u8 {
+    (v.clamp(0., 1.) * 255.).round() as u8
+}
+
+fn round3(v: f32) -> f32 {
+    (v * 1000.).round() / 1000.
+}
+
+pub const CSS_COLORS: &[(&str, u8, u8, u8)] = &[
+    ("aliceblue", 240, 248, 255),
+    ("antiquewhite", 250, 235, 215),
+    ("aqua", 0, 255, 255),
+    ("aquamarine", 127, 255, 212),
+    ("azure", 240, 255, 255),
+    ("beige", 245, 245, 220),
+    ("bisque", 255, 228, 196),
+    ("black", 0, 0, 0),
+    ("blanchedalmond", 255, 235, 205),
+    ("blue", 0, 0, 255),
+    ("blueviolet", 138, 43, 226),
+    ("brown", 165, 42, 42),
+    ("burlywood", 222, 184, 135),
+    ("cadetblue", 95, 158, 160),
+    ("chartreuse", 127, 255, 0),
+    ("chocolate", 210, 105, 30),
+    ("coral", 255, 127, 80),
+    ("cornflowerblue", 100, 149, 237),
+    ("cornsilk", 255, 248, 220),
+    ("crimson", 220, 20, 60),
+    ("cyan", 0, 255, 255),
+    ("darkblue", 0, 0, 139),
+    ("darkcyan", 0, 139, 139),
+    ("darkgoldenrod", 184, 134, 11),
+    ("darkgray", 169, 169, 169),
+    ("darkgreen", 0, 100, 0),
+    ("darkgrey", 169, 169, 169),
+    ("darkkhaki", 189, 183, 107),
+    ("darkmagenta", 139, 0, 139),
+    ("darkolivegreen", 85, 107, 47),
+    ("darkorange", 255, 140, 0),
+    ("darkorchid", 153, 50, 204),
+    ("darkred", 139, 0, 0),
+    ("darksalmon", 233, 150, 122),
+    ("darkseagreen", 143, 188, 143),
+    ("darkslateblue", 72, 61, 139),
+    ("darkslategray", 47, 79, 79),
+    ("darkslategrey", 47, 79, 79),
+    ("darkturquoise", 0, 206, 209),
+    ("darkviolet", 148, 0, 211),
+    ("deeppink", 255, 20, 147),
+    ("deepskyblue", 0, 191, 255),
+    ("dimgray", 105, 105, 105),
+    ("dimgrey", 105, 105, 105),
+    ("dodgerblue", 30, 144, 255),
+    ("firebrick", 178, 34, 34),
+    ("floralwhite", 255, 250, 240),
+    ("forestgreen", 34, 139, 34),
+    ("fuchsia", 255, 0, 255),
+    ("gainsboro", 220, 220, 220),
+    ("ghostwhite", 248, 248, 255),
+    ("gold", 255, 215, 0),
+    ("goldenrod", 218, 165, 32),
+    ("gray", 128, 128, 128),
+    ("green", 0, 128, 0),
+    ("greenyellow", 173, 255, 47),
+    ("grey", 128, 128, 128),
+    ("honeydew", 240, 255, 240),
+    ("hotpink", 255, 105, 180),
+    ("indianred", 205, 92, 92),
+    ("indigo", 75, 0, 130),
+    ("ivory", 255, 255, 240),
+    ("khaki", 240, 230, 140),
+    ("lavender", 230, 230, 250),
+    ("lavenderblush", 255, 240, 245),
+    ("lawngreen", 124, 252, 0),
+    ("lemonchiffon", 255, 250, 205),
+    ("lightblue", 173, 216, 230),
+    ("lightcoral", 240, 128, 128),
+    ("lightcyan", 224, 255, 255),
+    ("lightgoldenrodyellow", 250, 250, 210),
+    ("lightgray", 211, 211, 211),
+    ("lightgreen", 144, 238, 144),
+    ("lightgrey", 211, 211, 211),
+    ("lightpink", 255, 182, 193),
+    ("lightsalmon", 255, 160, 122),
+    ("lightseagreen", 32, 178, 170),
+    ("lightskyblue", 135, 206, 250),
+    ("lightslategray", 119, 136, 153),
+    ("lightslategrey", 119, 136, 153),
+    ("lightsteelblue", 176, 196, 222),
+    ("lightyellow", 255, 255, 224),
+    ("lime", 0, 255, 0),
+    ("limegreen", 50, 205, 50),
+    ("linen", 250, 240, 230),
+    ("magenta", 255, 0, 255),
+    ("maroon", 128, 0, 0),
+    ("mediumaquamarine", 102, 205, 170),
+    ("mediumblue", 0, 0, 205),
+    ("mediumorchid", 186, 85, 211),
+    ("mediumpurple", 147, 112, 219),
+    ("mediumseagreen", 60, 179, 113),
+    ("mediumslateblue", 123, 104, 238),
+    ("mediumspringgreen", 0, 250, 154),
+    ("mediumturquoise", 72, 209, 204),
+    ("mediumvioletred", 199, 21, 133),
+    ("midnightblue", 25, 25, 112),
+    ("mintcream", 245, 255, 250),
+    ("mistyrose", 255, 228, 225),
+    ("moccasin", 255, 228, 181),
+    ("navajowhite", 255, 222, 173),
+    ("navy", 0, 0, 128),
+    ("oldlace", 253, 245, 230),
+    ("olive", 128, 128, 0),
+    ("olivedrab", 107, 142, 35),
+    ("orange", 255, 165, 0),
+    ("orangered", 255, 69, 0),
+    ("orchid", 218, 112, 214),
+    ("palegoldenrod", 238, 232, 170),
+    ("palegreen", 152, 251, 152),
+    ("paleturquoise", 175, 238, 238),
+    ("palevioletred", 219, 112, 147),
+    ("papayawhip", 255, 239, 213),
+    ("peachpuff", 255, 218, 185),
+    ("peru", 205, 133, 63),
+    ("pink", 255, 192, 203),
+    ("plum", 221, 160, 221),
+    ("powderblue", 176, 224, 230),
+    ("purple", 128, 0, 128),
+    ("rebeccapurple", 102, 51, 153),
+    ("red", 255, 0, 0),
+    ("rosybrown", 188, 143, 143),
+    ("royalblue", 65, 105, 225),
+    ("saddlebrown", 139, 69, 19),
+    ("salmon", 250, 128, 114),
+    ("sandybrown", 244, 164, 96),
+    ("seagreen", 46, 139, 87),
+    ("seashell", 255, 245, 238),
+    ("sienna", 160, 82, 45),
+    ("silver", 192, 192, 192),
+    ("skyblue", 135, 206, 235),
+    ("slateblue", 106, 90, 205),
+    ("slategray", 112, 128, 144),
+    ("slategrey", 112, 128, 144),
+    ("snow", 255, 250, 250),
+    ("springgreen", 0, 255, 127),
+    ("steelblue", 70, 130, 180),
+    ("tan", 210, 180, 140),
+    ("teal", 0, 128, 128),
+    ("thistle", 216, 191, 216),
+    ("tomato", 255, 99, 71),
+    ("turquoise", 64, 224, 208),
+    ("violet", 238, 130, 238),
+    ("wheat", 245, 222, 179),
+    ("white", 255, 255, 255),
+    ("whitesmoke", 245, 245, 245),
+    ("yellow", 255, 255, 0),
+    ("yellowgreen", 154, 205, 50),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_named() {
+        assert_eq!("Tomato".parse::<Color>().unwrap(), Color::Tomato);
+        assert_eq!("dodgerblue".parse::<Color>().unwrap(), Color::Dodgerblue);
+        assert_eq!("aliceblue".parse::<Color>().unwrap(), Color::RGBA(240, 248, 255, 255));
+        assert!("nosuchcolor".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn parse_hex_and_functions() {
+        assert_eq!("#f00".parse::<Color>().unwrap().to_rgba8(), (255, 0, 0, 255));
+        assert_eq!("#1e90ff".parse::<Color>().unwrap().to_rgba8(), Color::Dodgerblue.to_rgba8());
+        assert_eq!("#ff000080".parse::<Color>().unwrap(), Color::RGBA(255, 0, 0, 128));
+        assert_eq!("rgb(255, 99, 71)".parse::<Color>().unwrap().to_rgba8(), Color::Tomato.to_rgba8());
+        assert_eq!("rgba(0,0,255,0.5)".parse::<Color>().unwrap(), Color::RGBA(0, 0, 255, 128));
+        assert_eq!("rgb(100% 0% 0% / 50%)".parse::<Color>().unwrap(), Color::RGBA(255, 0, 0, 128));
+        assert_eq!("hsl(120, 100%, 50%)".parse::<Color>().unwrap().to_rgba8(), Color::Lime.to_rgba8());
+        assert!("#12345".parse::<Color>().is_err());
+        assert!("rgb(1,2)".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn parse_error_positions() {
+        let position = |s: &str| match s.parse::<Color>() {
+            Err(GraphicsError::Parse { what: "color", position, .. }) => position,
+            other => panic!("{:?}", other),
+        };
+        assert_eq!(position("rgb(1,2,x)"), 8);
+        assert_eq!(position("  hsl(120, 100%, 5o%)"), 17);
+        assert_eq!(position("#12g"), 3);
+        assert_eq!(position("#12345"), 6);
+        assert_eq!(position("rgb(1,2)"), 7);
+        assert_eq!(position("nosuchcolor"), 0);
+    }
+
+    #[test]
+    fn hsl_roundtrip_and_helpers() {
+        let (h, s, l) = Color::Tomato.to_hsl();
+        assert_eq!(Color::from_hsl(h, s, l).to_rgba8(), Color::Tomato.to_rgba8());
+        assert_eq!(Color::Black.lerp(&Color::White, 0.5).to_rgba8(), (128, 128, 128, 255));
+        assert_eq!(Color::Red.lighten(1.0).to_rgba8(), (255, 255, 255, 255));
+        assert_eq!(Color::Red.darken(1.0).to_rgba8(), (0, 0, 0, 255));
+        assert_eq!(Color::RGBA(255, 0, 0, 128).to_string(), "rgba(255,0,0,0.502)");
+    }
+}
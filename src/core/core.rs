@@ -1,17 +1,156 @@
+use std::ops::{Add, Mul, Sub};
+
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-#[derive(Debug, Clone, Serialize, Deserialize)]
+
+use crate::core::spatial::SpatialIndex;
+use crate::path::PathSegments;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Point(pub f32, pub f32);
+
+impl Point {
+    pub fn dot(&self, other: Point) -> f32 {
+        self.0 * other.0 + self.1 * other.1
+    }
+
+    pub fn cross(&self, other: Point) -> f32 {
+        self.0 * other.1 - self.1 * other.0
+    }
+
+    pub fn length(&self) -> f32 {
+        self.0.hypot(self.1)
+    }
+
+    pub fn distance(&self, other: Point) -> f32 {
+        (*self - other).length()
+    }
+
+    pub fn lerp(&self, other: Point, t: f32) -> Point {
+        *self + (other - *self) * t
+    }
+
+    /// Unit vector in the same direction, or the zero vector.
+    pub fn normalize(&self) -> Point {
+        let len = self.length();
+        if len > 0. {
+            *self * (1. / len)
+        } else {
+            Point(0., 0.)
+        }
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+    fn add(self, other: Point) -> Point {
+        Point(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+    fn sub(self, other: Point) -> Point {
+        Point(self.0 - other.0, self.1 - other.1)
+    }
+}
+
+impl Mul<f32> for Point {
+    type Output = Point;
+    fn mul(self, s: f32) -> Point {
+        Point(self.0 * s, self.1 * s)
+    }
+}
+
+/// Which way the y axis points. Items are y down, like SVG; font outlines and Fusion are y up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum YAxis {
+    #[default]
+    Down,
+    Up,
+}
+
+/// Coordinates of a source such as a font, relative to items: how many of its units make
+/// one item unit, and which way its y axis points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Units {
+    pub per_item_unit: f32,
+    pub y_axis: YAxis,
+}
+
+impl Units {
+    pub const fn new(per_item_unit: f32, y_axis: YAxis) -> Units {
+        Units { per_item_unit, y_axis }
+    }
+
+    /// Horizontal and vertical factors that take coordinates in these units to item coordinates.
+    pub fn scale(&self) -> (f32, f32) {
+        let scale = 1. / self.per_item_unit;
+        match self.y_axis {
+            YAxis::Down => (scale, scale),
+            YAxis::Up => (scale, -scale),
+        }
+    }
+
+    pub fn to_items(&self, path: &PathSegments) -> PathSegments {
+        let (scale_x, scale_y) = self.scale();
+        path.scale_path(scale_x, scale_y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Rectangle(pub f32, pub f32, pub f32, pub f32);
 
 impl Rectangle {
-    pub fn overlap_x(&self, right: &Rectangle) -> f32 {
-        if self.1 + self.3 <= right.1 {
-            return 0.;
-        } else if (self.1 >= right.1 + right.3) {
-            return 0.;
-        } else if (right.0 >= self.0 + self.2) {
+    // Bounding boxes are stored as (x_min, y_min, x_max, y_max). The empty box has
+    // inverted extremes so that it is the identity for `union`.
+    pub fn empty() -> Rectangle {
+        Rectangle(f32::MAX, f32::MAX, f32::MIN, f32::MIN)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 > self.2 || self.1 > self.3
+    }
+
+    pub fn union(&self, other: &Rectangle) -> Rectangle {
+        Rectangle(self.0.min(other.0), self.1.min(other.1), self.2.max(other.2), self.3.max(other.3))
+    }
+
+    pub fn intersection(&self, other: &Rectangle) -> Rectangle {
+        Rectangle(self.0.max(other.0), self.1.max(other.1), self.2.min(other.2), self.3.min(other.3))
+    }
+
+    pub fn contains_point(&self, x: f32, y: f32) -> bool {
+        x >= self.0 && x <= self.2 && y >= self.1 && y <= self.3
+    }
+
+    pub fn inflate(&self, d: f32) -> Rectangle {
+        Rectangle(self.0 - d, self.1 - d, self.2 + d, self.3 + d)
+    }
+
+    pub fn area(&self) -> f32 {
+        if self.is_empty() {
             return 0.;
+        }
+        (self.2 - self.0) * (self.3 - self.1)
+    }
+
+    pub fn intersects(&self, other: &Rectangle) -> bool {
+        self.0 <= other.2 && other.0 <= self.2 && self.1 <= other.3 && other.1 <= self.3
+    }
+
+    pub fn distance_to_point(&self, x: f32, y: f32) -> f32 {
+        let dx = (self.0 - x).max(x - self.2).max(0.);
+        let dy = (self.1 - y).max(y - self.3).max(0.);
+        dx.hypot(dy)
+    }
+
+    pub fn overlap_x(&self, right: &Rectangle) -> f32 {
+        if self.1 + self.3 <= right.1 || self.1 >= right.1 + right.3 || right.0 >= self.0 + self.2 {
+            0.
         } else {
-            return self.0 + self.3 - right.0;
+            self.0 + self.3 - right.0
         }
     }
 }
@@ -34,12 +173,24 @@ impl Rectangles {
     }
 
     fn overlap_x(&self, right: &Rectangles) -> f32 {
+        // Only right rectangles that overlap vertically and start left of the end of a left
+        // rectangle can give a non-zero overlap, all other pairs give 0.
+        let mut index = SpatialIndex::new();
+        for (idx, r) in right.iter().enumerate() {
+            index.insert(idx, Rectangle(r.0, r.1, r.0 + r.2.max(0.), r.1 + r.3.max(0.)));
+        }
         let mut max_overlap = f32::MIN;
+        let mut candidate_pairs = 0;
         for left_r in self {
-            for right_r in right {
-                max_overlap = max_overlap.max(left_r.overlap_x(right_r));
+            let region = Rectangle(f32::MIN, left_r.1, left_r.0 + left_r.2, left_r.1 + left_r.3);
+            for idx in index.query(&region) {
+                max_overlap = max_overlap.max(left_r.overlap_x(&right.items[idx]));
+                candidate_pairs += 1;
             }
         }
+        if self.items.len() * right.items.len() > candidate_pairs {
+            max_overlap = max_overlap.max(0.);
+        }
         max_overlap
     }
 
@@ -101,4 +252,17 @@ mod tests {
         let overlap_x = left.overlap_x(&right);
         println!("overlap_x:{:?}", overlap_x);
     }
+
+    #[test]
+    fn overlap_x_matches_pairwise() {
+        let left = Rectangles::new((0..40).map(|i| Rectangle((i % 7) as f32 * 3., i as f32 * 2., 4., 3.)).collect());
+        let right = Rectangles::new((0..40).map(|i| Rectangle((i % 5) as f32 * 4. + 10., i as f32 * 2.5, 4., 2.)).collect());
+        let mut pairwise = f32::MIN;
+        for l in left.iter() {
+            for r in right.iter() {
+                pairwise = pairwise.max(l.overlap_x(r));
+            }
+        }
+        assert_eq!(left.overlap_x(&right), pairwise);
+    }
 }
@@ -1 +1,3 @@
+#[allow(clippy::module_inception)]
 pub mod core;
+pub mod spatial;
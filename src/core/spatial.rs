@@ -0,0 +1,321 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::core::core::Rectangle;
+use crate::item::GraphicItems;
+
+const MAX_ENTRIES: usize = 16;
+const MIN_ENTRIES: usize = 6;
+
+#[derive(Debug, Clone)]
+enum Node {
+    Leaf(Vec<(Rectangle, usize)>),
+    Branch(Vec<(Rectangle, Node)>),
+}
+
+impl Node {
+    fn len(&self) -> usize {
+        match self {
+            Node::Leaf(entries) => entries.len(),
+            Node::Branch(children) => children.len(),
+        }
+    }
+
+    fn bbox(&self) -> Rectangle {
+        match self {
+            Node::Leaf(entries) => entries.iter().fold(Rectangle::empty(), |bbox, (r, _)| bbox.union(r)),
+            Node::Branch(children) => children.iter().fold(Rectangle::empty(), |bbox, (r, _)| bbox.union(r)),
+        }
+    }
+
+    fn collect(self, out: &mut Vec<(Rectangle, usize)>) {
+        match self {
+            Node::Leaf(entries) => out.extend(entries),
+            Node::Branch(children) => children.into_iter().for_each(|(_, child)| child.collect(out)),
+        }
+    }
+
+    // Inserts the entry and returns the new sibling if this node had to be split.
+    fn insert(&mut self, bbox: Rectangle, id: usize) -> Option<Node> {
+        match self {
+            Node::Leaf(entries) => {
+                entries.push((bbox, id));
+                if entries.len() > MAX_ENTRIES {
+                    let (a, b) = split(std::mem::take(entries));
+                    *entries = a;
+                    return Some(Node::Leaf(b));
+                }
+                None
+            }
+            Node::Branch(children) => {
+                let idx = choose_subtree(children, &bbox);
+                let (child_bbox, child) = &mut children[idx];
+                let sibling = child.insert(bbox, id);
+                *child_bbox = child_bbox.union(&bbox);
+                if let Some(sibling) = sibling {
+                    children[idx].0 = children[idx].1.bbox();
+                    children.push((sibling.bbox(), sibling));
+                    if children.len() > MAX_ENTRIES {
+                        let (a, b) = split(std::mem::take(children));
+                        *children = a;
+                        return Some(Node::Branch(b));
+                    }
+                }
+                None
+            }
+        }
+    }
+
+    // Removes the entry. Children left with too few entries are dissolved and their
+    // entries handed back in `orphans` for reinsertion.
+    fn remove(&mut self, bbox: &Rectangle, id: usize, orphans: &mut Vec<(Rectangle, usize)>) -> bool {
+        match self {
+            Node::Leaf(entries) => match entries.iter().position(|(_, entry_id)| *entry_id == id) {
+                Some(pos) => {
+                    entries.remove(pos);
+                    true
+                }
+                None => false,
+            },
+            Node::Branch(children) => {
+                for idx in 0..children.len() {
+                    if !children[idx].0.intersects(bbox) || !children[idx].1.remove(bbox, id, orphans) {
+                        continue;
+                    }
+                    if children[idx].1.len() < MIN_ENTRIES {
+                        children.remove(idx).1.collect(orphans);
+                    } else {
+                        children[idx].0 = children[idx].1.bbox();
+                    }
+                    return true;
+                }
+                false
+            }
+        }
+    }
+
+    fn query(&self, rect: &Rectangle, out: &mut Vec<usize>) {
+        match self {
+            Node::Leaf(entries) => out.extend(entries.iter().filter(|(r, _)| r.intersects(rect)).map(|(_, id)| *id)),
+            Node::Branch(children) => children.iter().filter(|(r, _)| r.intersects(rect)).for_each(|(_, child)| child.query(rect, out)),
+        }
+    }
+}
+
+fn enlargement(r: &Rectangle, bbox: &Rectangle) -> f32 {
+    r.union(bbox).area() - r.area()
+}
+
+fn choose_subtree(children: &[(Rectangle, Node)], bbox: &Rectangle) -> usize {
+    let mut best = 0;
+    let mut best_cost = (f32::MAX, f32::MAX);
+    for (idx, (r, _)) in children.iter().enumerate() {
+        let cost = (enlargement(r, bbox), r.area());
+        if cost < best_cost {
+            best_cost = cost;
+            best = idx;
+        }
+    }
+    best
+}
+
+type Entries<T> = Vec<(Rectangle, T)>;
+
+// Guttman's quadratic split.
+fn split<T>(mut entries: Entries<T>) -> (Entries<T>, Entries<T>) {
+    let (mut seed_a, mut seed_b) = (0, 1);
+    let mut worst = f32::MIN;
+    for i in 0..entries.len() {
+        for j in i + 1..entries.len() {
+            let waste = entries[i].0.union(&entries[j].0).area() - entries[i].0.area() - entries[j].0.area();
+            if waste > worst {
+                worst = waste;
+                seed_a = i;
+                seed_b = j;
+            }
+        }
+    }
+    // Remove the higher index first so that the lower one stays valid
+    let b = entries.remove(seed_b);
+    let a = entries.remove(seed_a);
+    let (mut bbox_a, mut bbox_b) = (a.0, b.0);
+    let (mut group_a, mut group_b) = (vec![a], vec![b]);
+
+    while !entries.is_empty() {
+        if group_a.len() + entries.len() <= MIN_ENTRIES {
+            group_a.append(&mut entries);
+            break;
+        }
+        if group_b.len() + entries.len() <= MIN_ENTRIES {
+            group_b.append(&mut entries);
+            break;
+        }
+        let (idx, _) = entries
+            .iter()
+            .enumerate()
+            .map(|(idx, (r, _))| (idx, (enlargement(&bbox_a, r) - enlargement(&bbox_b, r)).abs()))
+            .fold((0, f32::MIN), |best, cur| if cur.1 > best.1 { cur } else { best });
+        let entry = entries.remove(idx);
+        let (ea, eb) = (enlargement(&bbox_a, &entry.0), enlargement(&bbox_b, &entry.0));
+        if ea < eb || (ea == eb && group_a.len() <= group_b.len()) {
+            bbox_a = bbox_a.union(&entry.0);
+            group_a.push(entry);
+        } else {
+            bbox_b = bbox_b.union(&entry.0);
+            group_b.push(entry);
+        }
+    }
+    (group_a, group_b)
+}
+
+/// R-tree over bounding boxes, each entry carrying an id - typically the index of an item
+/// in `GraphicItems`.
+#[derive(Debug, Clone)]
+pub struct SpatialIndex {
+    root: Node,
+    len: usize,
+}
+
+impl Default for SpatialIndex {
+    fn default() -> Self {
+        Self { root: Node::Leaf(vec![]), len: 0 }
+    }
+}
+
+impl SpatialIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Adds an entry. Empty boxes, as from an empty group, are not indexed.
+    pub fn insert(&mut self, id: usize, bbox: Rectangle) {
+        if bbox.is_empty() {
+            return;
+        }
+        if let Some(sibling) = self.root.insert(bbox, id) {
+            let old_root = std::mem::replace(&mut self.root, Node::Leaf(vec![]));
+            self.root = Node::Branch(vec![(old_root.bbox(), old_root), (sibling.bbox(), sibling)]);
+        }
+        self.len += 1;
+    }
+
+    /// Removes the entry with `id`, which must have been inserted with a box intersecting `bbox`.
+    pub fn remove(&mut self, id: usize, bbox: &Rectangle) -> bool {
+        let mut orphans = vec![];
+        if !self.root.remove(bbox, id, &mut orphans) {
+            return false;
+        }
+        self.len -= 1 + orphans.len();
+        // Shrink the tree while the root has a single child
+        while let Node::Branch(children) = &mut self.root {
+            if children.len() != 1 {
+                break;
+            }
+            self.root = children.pop().unwrap().1;
+        }
+        for (bbox, id) in orphans {
+            self.insert(id, bbox);
+        }
+        true
+    }
+
+    /// Ids of all entries whose box intersects `rect`.
+    pub fn query(&self, rect: &Rectangle) -> Vec<usize> {
+        let mut out = vec![];
+        self.root.query(rect, &mut out);
+        out
+    }
+
+    /// Id of the entry whose box is closest to the point, boxes containing it being at distance 0.
+    pub fn nearest(&self, x: f32, y: f32) -> Option<usize> {
+        struct Candidate<'a>(f32, Option<&'a Node>, usize);
+        impl PartialEq for Candidate<'_> {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl Eq for Candidate<'_> {}
+        impl PartialOrd for Candidate<'_> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Candidate<'_> {
+            // Reversed, so that the binary heap pops the closest candidate first
+            fn cmp(&self, other: &Self) -> Ordering {
+                other.0.total_cmp(&self.0)
+            }
+        }
+
+        let mut heap = BinaryHeap::new();
+        heap.push(Candidate(0., Some(&self.root), 0));
+        while let Some(Candidate(_, node, id)) = heap.pop() {
+            match node {
+                None => return Some(id),
+                Some(Node::Leaf(entries)) => entries.iter().for_each(|(r, id)| heap.push(Candidate(r.distance_to_point(x, y), None, *id))),
+                Some(Node::Branch(children)) => children.iter().for_each(|(r, child)| heap.push(Candidate(r.distance_to_point(x, y), Some(child), 0))),
+            }
+        }
+        None
+    }
+}
+
+impl GraphicItems {
+    /// Spatial index over the bounding boxes of the items, ids being item indices. Animated
+    /// items are indexed by the union of their boxes at every keyframe.
+    pub fn spatial_index(&self) -> SpatialIndex {
+        let mut index = SpatialIndex::new();
+        for (idx, item) in self.0.iter().enumerate() {
+            let bbox = if item.is_animated() { GraphicItems(vec![item.clone()]).animated_bbox() } else { item.bbox() };
+            index.insert(idx, bbox);
+        }
+        index
+    }
+
+    /// The items whose bounding box intersects `viewport`, in their original order.
+    pub fn cull(&self, viewport: &Rectangle) -> GraphicItems {
+        let mut visible = self.spatial_index().query(viewport);
+        visible.sort_unstable();
+        GraphicItems(visible.into_iter().map(|idx| self.0[idx].clone()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_query_remove_nearest() {
+        let mut index = SpatialIndex::new();
+        let rect = |i: usize| {
+            let (x, y) = ((i % 50) as f32 * 10., (i / 50) as f32 * 10.);
+            Rectangle(x, y, x + 5., y + 5.)
+        };
+        for i in 0..2500 {
+            index.insert(i, rect(i));
+        }
+        assert_eq!(index.len(), 2500);
+
+        let mut hits = index.query(&Rectangle(12., 12., 28., 18.));
+        hits.sort_unstable();
+        assert_eq!(hits, vec![51, 52]);
+        assert_eq!(index.nearest(37., 31.), Some(153));
+        assert_eq!(index.nearest(-100., -100.), Some(0));
+
+        for i in 0..2400 {
+            assert!(index.remove(i, &rect(i)));
+        }
+        assert!(!index.remove(0, &rect(0)));
+        assert_eq!(index.len(), 100);
+        assert_eq!(index.query(&Rectangle(0., 0., 500., 500.)).len(), 100);
+        assert_eq!(index.nearest(0., 0.), Some(2400));
+    }
+}
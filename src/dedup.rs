@@ -0,0 +1,146 @@
+//! Sharing of repeated path geometry.
+//!
+//! Paths that differ only by where they are drawn, like the noteheads of a score, are turned
+//! into cached paths with a common tag, so that builders write the geometry once.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+use crate::item::{GraphicItem, GraphicItems, Mask, PathCacheInfo};
+use crate::path::{PathSegment, PathSegments};
+
+/// Coordinates are compared in thousandths when looking for repeated geometry.
+const KEY_SCALE: f32 = 1000.;
+
+fn key_number(value: f32) -> i64 {
+    (value * KEY_SCALE).round() as i64
+}
+
+impl GraphicItems {
+    /// The items with every uncached path that occurs more than once, up to translation,
+    /// turned into a cached one. Shared paths start at 0, 0 and are offset to their place,
+    /// and get tags `shared-1`, `shared-2` and so on that are not already in use as tags or
+    /// item ids.
+    pub fn share_paths(&self) -> GraphicItems {
+        let mut counts = HashMap::new();
+        let mut tags = HashSet::new();
+        count_paths(self, &mut counts, &mut tags);
+        let mut shared = Shared { counts, tags, paths: HashMap::new(), next: 1 };
+        shared.items(self)
+    }
+}
+
+fn count_paths(items: &GraphicItems, counts: &mut HashMap<String, usize>, tags: &mut HashSet<String>) {
+    for item in items.0.iter() {
+        match item {
+            GraphicItem::Group(group_items, attrs) => {
+                if let Some(id) = &attrs.metadata.id {
+                    tags.insert(id.clone());
+                }
+                if let Some(mask) = &attrs.mask {
+                    count_paths(&mask.0, counts, tags);
+                }
+                count_paths(group_items, counts, tags);
+            }
+            GraphicItem::Path(path, _, _, PathCacheInfo::NoCache) => {
+                if let Some((key, _, _)) = normalize(path) {
+                    *counts.entry(key).or_insert(0) += 1;
+                }
+            }
+            GraphicItem::Path(_, _, _, PathCacheInfo::Cache(tag, _, _)) => {
+                tags.insert(tag.clone());
+            }
+            _ => {}
+        }
+    }
+}
+
+struct Shared {
+    counts: HashMap<String, usize>,
+    tags: HashSet<String>,
+    // Tag and geometry of each shared key, taken from its first occurrence
+    paths: HashMap<String, (String, PathSegments)>,
+    next: usize,
+}
+
+impl Shared {
+    fn items(&mut self, items: &GraphicItems) -> GraphicItems {
+        GraphicItems(items.0.iter().map(|item| self.item(item)).collect())
+    }
+
+    fn item(&mut self, item: &GraphicItem) -> GraphicItem {
+        match item {
+            GraphicItem::Group(group_items, attrs) => {
+                let mut attrs = attrs.clone();
+                attrs.mask = attrs.mask.map(|mask| Mask(self.items(&mask.0), mask.1));
+                GraphicItem::Group(self.items(group_items), attrs)
+            }
+            GraphicItem::Path(path, stroke, fill, PathCacheInfo::NoCache) => match normalize(path) {
+                Some((key, x, y)) if self.counts[&key] > 1 => {
+                    let (tag, path) = match self.paths.get(&key) {
+                        Some(shared) => shared.clone(),
+                        None => {
+                            let shared = (self.new_tag(), path.move_path(-x, -y));
+                            self.paths.insert(key, shared.clone());
+                            shared
+                        }
+                    };
+                    GraphicItem::Path(path, stroke.clone(), fill.clone(), PathCacheInfo::Cache(tag, x, y))
+                }
+                _ => item.clone(),
+            },
+            _ => item.clone(),
+        }
+    }
+
+    fn new_tag(&mut self) -> String {
+        loop {
+            let tag = format!("shared-{}", self.next);
+            self.next += 1;
+            if !self.tags.contains(&tag) {
+                return tag;
+            }
+        }
+    }
+}
+
+/// The geometry of the path moved to start at 0, 0 as a string, and where it started.
+/// None for paths that do not start with a move.
+fn normalize(path: &PathSegments) -> Option<(String, f32, f32)> {
+    let Some(PathSegment::M(x, y)) = path.0.first() else {
+        return None;
+    };
+    let mut key = String::new();
+    let point = |key: &mut String, px: f32, py: f32| {
+        let _ = write!(key, " {} {}", key_number(px - x), key_number(py - y));
+    };
+    for segment in path.0.iter() {
+        match segment {
+            PathSegment::M(px, py) => {
+                key.push('M');
+                point(&mut key, *px, *py);
+            }
+            PathSegment::L(px, py) => {
+                key.push('L');
+                point(&mut key, *px, *py);
+            }
+            PathSegment::Q(x1, y1, px, py) => {
+                key.push('Q');
+                point(&mut key, *x1, *y1);
+                point(&mut key, *px, *py);
+            }
+            PathSegment::C(x1, y1, x2, y2, px, py) => {
+                key.push('C');
+                point(&mut key, *x1, *y1);
+                point(&mut key, *x2, *y2);
+                point(&mut key, *px, *py);
+            }
+            PathSegment::A(rx, ry, rotation, large_arc, sweep, px, py) => {
+                let _ = write!(key, "A {} {} {} {} {}", key_number(*rx), key_number(*ry), key_number(*rotation), large_arc, sweep);
+                point(&mut key, *px, *py);
+            }
+            PathSegment::Z => key.push('Z'),
+        }
+    }
+    Some((key, *x, *y))
+}
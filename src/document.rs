@@ -0,0 +1,129 @@
+//! Documents of one or more pages of a physical size.
+//!
+//! Items are laid out on each page in the unit of the paper, inside its margins, and the
+//! pages are built with any `GraphicBuilder`, which gets the page as its viewport and
+//! the paper unit as its size unit.
+
+use crate::builder::{BuilderOptions, GraphicBuilder, Padding, SizeUnit};
+use crate::item::{GraphicItem, GraphicItems, ItemAttributes};
+use crate::path::PathSegments;
+use crate::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Paper {
+    A3,
+    A4,
+    A5,
+    Letter,
+    Legal,
+    /// Width, height and their unit.
+    Custom(f32, f32, SizeUnit),
+}
+
+impl Paper {
+    /// Width, height and unit of the paper in portrait orientation.
+    pub fn size(&self) -> (f32, f32, SizeUnit) {
+        match self {
+            Paper::A3 => (297., 420., SizeUnit::Millimeter),
+            Paper::A4 => (210., 297., SizeUnit::Millimeter),
+            Paper::A5 => (148., 210., SizeUnit::Millimeter),
+            Paper::Letter => (8.5, 11., SizeUnit::Inch),
+            Paper::Legal => (8.5, 14., SizeUnit::Inch),
+            Paper::Custom(width, height, unit) => (*width, *height, *unit),
+        }
+    }
+}
+
+/// Where the content of a page goes inside the margins.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Placement {
+    TopLeft,
+    TopCenter,
+    Center,
+    /// Centered and scaled to fill the space inside the margins, ignoring `Document::scale`.
+    Fit,
+}
+
+#[derive(Debug, Clone)]
+pub struct Document {
+    pub pages: Vec<GraphicItems>,
+    pub paper: Paper,
+    pub landscape: bool,
+    /// Space between the edges of the paper and the content, in the unit of the paper.
+    pub margins: Padding,
+    /// Size of an item unit in the unit of the paper.
+    pub scale: f32,
+    pub placement: Placement,
+    pub dpi: f32,
+}
+
+impl Document {
+    pub fn new(paper: Paper) -> Self {
+        Self { pages: vec![], paper, landscape: false, margins: Padding::default(), scale: 1., placement: Placement::TopLeft, dpi: 96. }
+    }
+
+    pub fn page(mut self, items: GraphicItems) -> Self {
+        self.pages.push(items);
+        self
+    }
+
+    /// Width, height and unit of the paper as oriented.
+    pub fn page_size(&self) -> (f32, f32, SizeUnit) {
+        let (width, height, unit) = self.paper.size();
+        if self.landscape {
+            (height, width, unit)
+        } else {
+            (width, height, unit)
+        }
+    }
+
+    /// The items of page `idx` scaled and placed on the paper, in the unit of the paper.
+    pub fn page_items(&self, idx: usize) -> GraphicItems {
+        let items = &self.pages[idx];
+        let (width, height, _) = self.page_size();
+        let Padding(top, right, bottom, left) = self.margins;
+        let (space_width, space_height) = (width - left - right, height - top - bottom);
+        let bbox = if items.is_animated() { items.animated_bbox() } else { items.bbox() };
+        if bbox.is_empty() {
+            return items.clone();
+        }
+        let (content_width, content_height) = (bbox.2 - bbox.0, bbox.3 - bbox.1);
+        let scale = match self.placement {
+            Placement::Fit if content_width > 0. && content_height > 0. => (space_width / content_width).min(space_height / content_height),
+            _ => self.scale,
+        };
+        let (free_x, free_y) = (space_width - content_width * scale, space_height - content_height * scale);
+        let (x, y) = match self.placement {
+            Placement::TopLeft => (left, top),
+            Placement::TopCenter => (left + free_x / 2., top),
+            Placement::Center | Placement::Fit => (left + free_x / 2., top + free_y / 2.),
+        };
+        items.move_items(-bbox.0, -bbox.1).scale_items(scale, scale, scale).move_items(x, y)
+    }
+
+    /// Options that make a builder draw a page: the whole paper in the unit of the paper.
+    pub fn options(&self) -> BuilderOptions {
+        let (width, height, unit) = self.page_size();
+        BuilderOptions { size_unit: unit, size_scaling: 1., viewport: Some(Rectangle(0., 0., width, height)), dpi: self.dpi }
+    }
+
+    /// Every page built on its own.
+    pub fn build(&self, builder: &mut impl GraphicBuilder) -> Result<Vec<String>> {
+        (0..self.pages.len()).map(|idx| builder.build(self.page_items(idx), Some(self.options()))).collect()
+    }
+
+    /// The pages built as one drawing, one below the other with `gap` between them, each
+    /// clipped to its paper.
+    pub fn build_stacked(&self, builder: &mut impl GraphicBuilder, gap: f32) -> Result<String> {
+        let (width, height, _) = self.page_size();
+        let count = self.pages.len() as f32;
+        let pages = (0..self.pages.len()).map(|idx| {
+            let y = idx as f32 * (height + gap);
+            let page = GraphicItem::Group(self.page_items(idx).move_items(0., y), ItemAttributes::default());
+            page.with_clip(PathSegments::rect(0., y, width, height))
+        });
+        let total_height = count * height + (count - 1.).max(0.) * gap;
+        let options = BuilderOptions { viewport: Some(Rectangle(0., 0., width, total_height)), ..self.options() };
+        builder.build(GraphicItems(pages.collect()), Some(options))
+    }
+}
@@ -1,17 +1,38 @@
-use anyhow::Result;
 use thiserror::Error;
 
+/// Result of fallible operations of the crate.
+pub type Result<T, E = GraphicsError> = std::result::Result<T, E>;
+
 #[derive(Error, Debug)]
 pub enum GraphicsError {
-    Basic,
-    Generic(String),
+    /// Text or binary input that could not be read, with the position (character or byte)
+    /// where reading stopped.
+    #[error("invalid {what} at position {position}: {message}")]
+    Parse { what: &'static str, position: usize, message: String },
+    #[error("invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("invalid scene: {0}")]
+    InvalidScene(String),
+    /// Something the items use that a builder cannot express.
+    #[error("{backend} builder does not support {what}")]
+    Unsupported { backend: &'static str, what: String },
+    #[error("invalid geometry: {0}")]
+    InvalidGeometry(#[from] GeometryError),
+    #[error("glyph {0} is missing")]
+    MissingGlyph(String),
+    #[error("glyph {name} cannot be encoded: {message}")]
+    GlyphEncoding { name: String, message: String },
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
 }
 
-impl std::fmt::Display for GraphicsError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            GraphicsError::Basic => write!(f, "GraphicsError::Basic error"),
-            GraphicsError::Generic(s) => write!(f, "GraphicsError::Generic error: {}", s),
-        }
-    }
+/// Geometry that no builder can render.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeometryError {
+    #[error("coordinate is not a finite number")]
+    NonFinite,
+    #[error("path is empty")]
+    EmptyPath,
+    #[error("path does not start with a move")]
+    MissingMove,
 }
@@ -1,358 +1,339 @@
-use crate::path::PathSegment;
-use crate::path::PathSegment::*;
+use crate::core::core::{Units, YAxis};
+use crate::glyphs::{glyphs, GlyphSet};
 
-pub const EBGARAMOND_UPPER_A: &'static [PathSegment] = &[
-    M(27.0, -5.0),
-    Q(14.0, -5.0, 6.0, -1.5),
-    Q(-2.0, 2.0, -2.0, 8.0),
-    Q(-2.0, 15.0, 6.5, 20.5),
-    Q(15.0, 26.0, 31.0, 31.0),
-    Q(57.0, 39.0, 79.0, 55.0),
-    Q(101.0, 71.0, 117.0, 108.0),
-    Q(145.0, 172.0, 168.5, 228.0),
-    Q(192.0, 284.0, 213.0, 338.0),
-    Q(234.0, 392.0, 256.5, 450.5),
-    Q(279.0, 509.0, 304.0, 578.0),
-    Q(309.0, 592.0, 313.0, 606.0),
-    Q(317.0, 620.0, 318.0, 636.0),
-    Q(327.0, 639.0, 336.0, 642.0),
-    Q(345.0, 645.0, 354.0, 651.0),
-    Q(363.0, 657.0, 366.5, 669.0),
-    Q(370.0, 681.0, 373.0, 685.0),
-    Q(374.0, 686.0, 375.5, 686.0),
-    Q(377.0, 686.0, 378.0, 684.0),
-    Q(380.0, 680.0, 381.5, 674.0),
-    Q(383.0, 668.0, 385.0, 662.0),
-    Q(409.0, 586.0, 435.0, 507.0),
-    Q(461.0, 428.0, 485.5, 354.5),
-    Q(510.0, 281.0, 530.5, 222.5),
-    Q(551.0, 164.0, 563.0, 128.0),
-    Q(577.0, 88.0, 593.0, 68.0),
-    Q(609.0, 48.0, 626.5, 40.0),
-    Q(644.0, 32.0, 664.0, 28.0),
-    Q(681.0, 25.0, 689.0, 18.5),
-    Q(697.0, 12.0, 697.0, 5.0),
-    Q(697.0, -1.0, 689.0, -3.0),
-    Q(681.0, -5.0, 668.0, -5.0),
-    Q(654.0, -5.0, 630.0, -3.5),
-    Q(606.0, -2.0, 579.0, -0.5),
-    Q(552.0, 1.0, 529.0, 1.0),
-    Q(510.0, 1.0, 490.0, 1.0),
-    Q(470.0, 1.0, 453.0, 0.5),
-    Q(436.0, 0.0, 424.0, 0.0),
-    Q(411.0, 0.0, 403.0, 3.0),
-    Q(395.0, 6.0, 395.0, 12.0),
-    Q(395.0, 19.0, 405.0, 24.0),
-    Q(415.0, 29.0, 428.0, 31.0),
-    Q(461.0, 36.0, 475.0, 48.0),
-    Q(489.0, 60.0, 489.0, 76.0),
-    Q(489.0, 91.0, 482.5, 119.5),
-    Q(476.0, 148.0, 467.0, 180.5),
-    Q(458.0, 213.0, 450.0, 238.0),
-    Q(448.0, 246.0, 442.5, 247.5),
-    Q(437.0, 249.0, 423.0, 249.0),
-    L(234.0, 249.0),
-    Q(230.0, 249.0, 225.5, 245.5),
-    Q(221.0, 242.0, 217.0, 231.0),
-    Q(214.0, 221.0, 207.0, 199.5),
-    Q(200.0, 178.0, 193.0, 154.0),
-    Q(186.0, 130.0, 181.0, 111.0),
-    Q(176.0, 92.0, 176.0, 87.0),
-    Q(176.0, 65.0, 193.0, 50.5),
-    Q(210.0, 36.0, 234.0, 30.0),
-    Q(267.0, 21.0, 267.0, 10.0),
-    Q(267.0, 4.0, 259.0, 1.5),
-    Q(251.0, -1.0, 238.0, -1.0),
-    Q(225.0, -1.0, 200.0, 0.0),
-    Q(175.0, 1.0, 147.0, 1.0),
-    Q(114.0, 1.0, 83.0, -2.0),
-    Q(52.0, -5.0, 27.0, -5.0),
-    Z,
-    M(261.0, 282.0),
-    L(416.0, 282.0),
-    Q(430.0, 282.0, 430.0, 287.0),
-    Q(430.0, 289.0, 429.5, 291.5),
-    Q(429.0, 294.0, 428.0, 297.0),
-    L(350.0, 541.0),
-    Q(344.0, 559.0, 341.0, 559.0),
-    Q(339.0, 559.0, 333.0, 542.0),
-    L(242.0, 297.0),
-    Q(240.0, 291.0, 240.0, 289.0),
-    Q(240.0, 284.0, 245.5, 283.0),
-    Q(251.0, 282.0, 261.0, 282.0),
-    Z,
-];
+/// Font units per item unit of the EB Garamond outlines, the same as for Cadenza: the em of
+/// 1000 font units is 100 item units.
+pub const EBGARAMOND_UNITS: Units = Units::new(10., YAxis::Up);
 
-pub const EBGARAMOND_UPPER_B: &'static [PathSegment] = &[
-    M(48.0, -5.0),
-    Q(35.0, -5.0, 27.0, -2.5),
-    Q(19.0, 0.0, 19.0, 6.0),
-    Q(19.0, 18.0, 52.0, 24.0),
-    Q(90.0, 31.0, 110.0, 47.0),
-    Q(130.0, 63.0, 130.0, 99.0),
-    L(130.0, 549.0),
-    Q(130.0, 585.0, 115.0, 602.0),
-    Q(100.0, 619.0, 63.0, 622.0),
-    Q(46.0, 624.0, 38.0, 629.5),
-    Q(30.0, 635.0, 30.0, 642.0),
-    Q(30.0, 648.0, 38.0, 650.5),
-    Q(46.0, 653.0, 59.0, 653.0),
-    Q(86.0, 653.0, 101.0, 653.0),
-    Q(116.0, 653.0, 129.5, 652.5),
-    Q(143.0, 652.0, 163.0, 652.0),
-    Q(182.0, 652.0, 196.0, 653.5),
-    Q(210.0, 655.0, 227.5, 656.0),
-    Q(245.0, 657.0, 274.0, 657.0),
-    Q(391.0, 657.0, 448.5, 623.5),
-    Q(506.0, 590.0, 506.0, 508.0),
-    Q(506.0, 474.0, 494.5, 448.5),
-    Q(483.0, 423.0, 472.0, 412.0),
-    Q(456.0, 396.0, 443.0, 387.5),
-    Q(430.0, 379.0, 405.0, 369.0),
-    Q(401.0, 368.0, 401.0, 364.5),
-    Q(401.0, 361.0, 405.0, 359.0),
-    Q(428.0, 352.0, 452.5, 341.0),
-    Q(477.0, 330.0, 498.5, 312.5),
-    Q(520.0, 295.0, 533.5, 266.5),
-    Q(547.0, 238.0, 547.0, 194.0),
-    Q(547.0, 139.0, 529.5, 107.0),
-    Q(512.0, 75.0, 488.0, 58.0),
-    Q(464.0, 41.0, 443.0, 30.0),
-    Q(412.0, 14.0, 371.0, 5.0),
-    Q(330.0, -4.0, 273.0, -4.0),
-    Q(260.0, -4.0, 234.0, -2.0),
-    Q(208.0, 0.0, 178.0, 0.0),
-    Q(152.0, 0.0, 134.0, -1.0),
-    Q(116.0, -2.0, 96.5, -3.5),
-    Q(77.0, -5.0, 48.0, -5.0),
-    Z,
-    M(287.0, 37.0),
-    Q(331.0, 37.0, 369.0, 57.0),
-    Q(407.0, 77.0, 430.0, 111.5),
-    Q(453.0, 146.0, 453.0, 189.0),
-    Q(453.0, 228.0, 440.0, 253.5),
-    Q(427.0, 279.0, 410.0, 295.0),
-    Q(393.0, 311.0, 379.0, 318.0),
-    Q(366.0, 326.0, 338.0, 334.5),
-    Q(310.0, 343.0, 265.0, 343.0),
-    Q(233.0, 343.0, 220.5, 330.0),
-    Q(208.0, 317.0, 208.0, 307.0),
-    L(208.0, 108.0),
-    Q(208.0, 66.0, 228.5, 51.5),
-    Q(249.0, 37.0, 287.0, 37.0),
-    Z,
-    M(254.0, 371.0),
-    Q(281.0, 371.0, 312.5, 377.0),
-    Q(344.0, 383.0, 375.0, 400.0),
-    Q(386.0, 406.0, 403.0, 429.0),
-    Q(420.0, 452.0, 420.0, 495.0),
-    Q(420.0, 524.0, 409.0, 554.5),
-    Q(398.0, 585.0, 367.0, 606.0),
-    Q(336.0, 627.0, 274.0, 627.0),
-    Q(233.0, 627.0, 220.5, 616.0),
-    Q(208.0, 605.0, 208.0, 582.0),
-    L(208.0, 408.0),
-    Q(208.0, 382.0, 225.0, 376.5),
-    Q(242.0, 371.0, 254.0, 371.0),
-    Z,
-];
+/// Outlines of EB Garamond Regular: Basic Latin, Latin-1 Supplement and Latin Extended-A, with
+/// the standard ligatures, the font's metrics and its kerning. Constants are named after the
+/// PostScript glyph names, with `UPPER_` and `LOWER_` for cased letters and `LIGATURE_` for
+/// ligatures.
+pub static EBGARAMOND: GlyphSet = GlyphSet::new(include_bytes!("ebgaramond.bin"), EBGARAMOND_UNITS);
 
-pub const EBGARAMOND_UPPER_C: &'static [PathSegment] = &[
-    M(407.0, -15.0),
-    Q(337.0, -15.0, 272.0, 9.0),
-    Q(207.0, 33.0, 156.0, 77.5),
-    Q(105.0, 122.0, 75.5, 184.5),
-    Q(46.0, 247.0, 46.0, 323.0),
-    Q(46.0, 396.0, 77.0, 458.0),
-    Q(108.0, 520.0, 162.0, 566.0),
-    Q(216.0, 612.0, 285.5, 637.5),
-    Q(355.0, 663.0, 432.0, 663.0),
-    Q(471.0, 663.0, 498.0, 660.0),
-    Q(525.0, 657.0, 550.5, 651.5),
-    Q(576.0, 646.0, 610.0, 637.0),
-    Q(625.0, 633.0, 631.5, 625.0),
-    Q(638.0, 617.0, 639.0, 603.0),
-    Q(641.0, 580.0, 644.0, 553.5),
-    Q(647.0, 527.0, 648.0, 488.0),
-    Q(649.0, 481.0, 641.5, 476.5),
-    Q(634.0, 472.0, 625.5, 474.0),
-    Q(617.0, 476.0, 614.0, 487.0),
-    Q(604.0, 525.0, 591.0, 552.5),
-    Q(578.0, 580.0, 552.0, 598.0),
-    Q(527.0, 615.0, 491.5, 623.5),
-    Q(456.0, 632.0, 415.0, 632.0),
-    Q(357.0, 632.0, 307.5, 608.5),
-    Q(258.0, 585.0, 222.5, 544.5),
-    Q(187.0, 504.0, 167.0, 453.0),
-    Q(147.0, 402.0, 147.0, 347.0),
-    Q(147.0, 286.0, 166.5, 227.0),
-    Q(186.0, 168.0, 222.5, 120.5),
-    Q(259.0, 73.0, 309.5, 44.5),
-    Q(360.0, 16.0, 422.0, 16.0),
-    Q(494.0, 16.0, 541.0, 49.0),
-    Q(588.0, 82.0, 621.0, 141.0),
-    Q(628.0, 154.0, 641.5, 154.0),
-    Q(655.0, 154.0, 655.0, 138.0),
-    Q(655.0, 130.0, 651.0, 112.5),
-    Q(647.0, 95.0, 640.5, 75.5),
-    Q(634.0, 56.0, 628.0, 41.0),
-    Q(621.0, 27.0, 616.5, 23.0),
-    Q(612.0, 19.0, 596.0, 14.0),
-    Q(559.0, -1.0, 507.5, -8.0),
-    Q(456.0, -15.0, 407.0, -15.0),
-    Z,
-];
-
-pub const EBGARAMOND_LOWER_A: &'static [PathSegment] = &[
-    M(117.0, -14.0),
-    Q(99.0, -14.0, 80.5, 0.0),
-    Q(62.0, 14.0, 49.5, 34.0),
-    Q(37.0, 54.0, 37.0, 72.0),
-    Q(37.0, 104.0, 54.0, 122.0),
-    Q(71.0, 140.0, 108.0, 155.0),
-    L(210.0, 197.0),
-    Q(230.0, 205.0, 233.5, 211.0),
-    Q(237.0, 217.0, 238.0, 237.0),
-    L(240.0, 301.0),
-    Q(241.0, 327.0, 225.5, 346.0),
-    Q(210.0, 365.0, 181.0, 365.0),
-    Q(166.0, 365.0, 151.0, 360.0),
-    Q(136.0, 355.0, 126.0, 347.0),
-    Q(120.0, 343.0, 118.0, 335.0),
-    Q(116.0, 327.0, 116.0, 318.0),
-    Q(116.0, 314.0, 116.5, 309.0),
-    Q(117.0, 304.0, 117.0, 300.0),
-    Q(117.0, 295.0, 104.5, 286.5),
-    Q(92.0, 278.0, 76.5, 271.5),
-    Q(61.0, 265.0, 51.0, 265.0),
-    Q(46.0, 265.0, 43.0, 267.5),
-    Q(40.0, 270.0, 40.0, 274.0),
-    Q(40.0, 290.0, 50.5, 308.5),
-    Q(61.0, 327.0, 80.0, 343.0),
-    Q(103.0, 364.0, 130.0, 380.0),
-    Q(157.0, 396.0, 183.0, 405.0),
-    Q(209.0, 414.0, 229.0, 414.0),
-    Q(265.0, 414.0, 288.5, 388.5),
-    Q(312.0, 363.0, 311.0, 325.0),
-    L(306.0, 85.0),
-    Q(305.0, 64.0, 315.5, 51.0),
-    Q(326.0, 38.0, 342.0, 38.0),
-    Q(367.0, 38.0, 379.0, 49.0),
-    Q(384.0, 54.0, 387.0, 54.0),
-    Q(392.0, 54.0, 395.0, 51.0),
-    Q(398.0, 48.0, 398.0, 43.0),
-    Q(398.0, 32.0, 387.0, 21.0),
-    Q(371.0, 5.0, 352.5, -4.5),
-    Q(334.0, -14.0, 318.0, -14.0),
-    Q(272.0, -14.0, 245.0, 38.0),
-    L(242.0, 38.0),
-    Q(209.0, 11.0, 179.0, -1.5),
-    Q(149.0, -14.0, 117.0, -14.0),
-    Z,
-    M(164.0, 34.0),
-    Q(184.0, 34.0, 198.0, 40.5),
-    Q(212.0, 47.0, 223.0, 58.0),
-    Q(228.0, 63.0, 231.5, 71.5),
-    Q(235.0, 80.0, 236.0, 99.0),
-    L(238.0, 148.0),
-    Q(239.0, 159.0, 237.0, 164.0),
-    Q(235.0, 169.0, 229.0, 169.0),
-    Q(227.0, 169.0, 222.0, 168.0),
-    Q(217.0, 167.0, 209.0, 164.0),
-    Q(156.0, 145.0, 135.5, 124.5),
-    Q(115.0, 104.0, 115.0, 87.0),
-    Q(115.0, 61.0, 130.5, 47.5),
-    Q(146.0, 34.0, 164.0, 34.0),
-    Z,
-];
-
-pub const EBGARAMOND_LOWER_B: &'static [PathSegment] = &[
-    M(259.0, -14.0),
-    Q(237.0, -14.0, 215.0, -10.0),
-    Q(193.0, -6.0, 173.0, -1.0),
-    Q(151.0, 5.0, 134.5, 10.0),
-    Q(118.0, 15.0, 113.0, 15.0),
-    Q(107.0, 15.0, 101.5, 11.0),
-    Q(96.0, 7.0, 91.0, 2.0),
-    Q(86.0, -3.0, 82.0, -3.0),
-    Q(78.0, -3.0, 73.0, 0.5),
-    Q(68.0, 4.0, 68.0, 9.0),
-    Q(68.0, 18.0, 71.5, 40.0),
-    Q(75.0, 62.0, 75.0, 81.0),
-    L(75.0, 566.0),
-    Q(75.0, 592.0, 62.0, 611.0),
-    Q(49.0, 630.0, 29.0, 639.0),
-    Q(25.0, 641.0, 20.5, 644.5),
-    Q(16.0, 648.0, 16.0, 654.0),
-    Q(16.0, 659.0, 20.0, 661.0),
-    Q(24.0, 663.0, 28.0, 664.0),
-    Q(41.0, 667.0, 63.5, 675.0),
-    Q(86.0, 683.0, 106.0, 691.0),
-    Q(126.0, 699.0, 133.0, 702.0),
-    Q(139.0, 705.0, 142.0, 705.0),
-    Q(144.0, 705.0, 146.0, 703.0),
-    Q(148.0, 701.0, 148.0, 698.0),
-    Q(148.0, 694.0, 146.5, 664.5),
-    Q(145.0, 635.0, 145.0, 593.0),
-    L(145.0, 388.0),
-    Q(145.0, 369.0, 147.0, 362.5),
-    Q(149.0, 356.0, 154.0, 355.0),
-    Q(185.0, 381.0, 226.0, 397.5),
-    Q(267.0, 414.0, 300.0, 414.0),
-    Q(351.0, 414.0, 391.5, 388.0),
-    Q(432.0, 362.0, 456.0, 317.5),
-    Q(480.0, 273.0, 480.0, 217.0),
-    Q(480.0, 149.0, 452.0, 96.5),
-    Q(424.0, 44.0, 374.5, 15.0),
-    Q(325.0, -14.0, 259.0, -14.0),
-    Z,
-    M(282.0, 17.0),
-    Q(320.0, 17.0, 347.5, 40.5),
-    Q(375.0, 64.0, 390.0, 104.5),
-    Q(405.0, 145.0, 405.0, 195.0),
-    Q(405.0, 234.0, 390.5, 275.0),
-    Q(376.0, 316.0, 345.5, 344.0),
-    Q(315.0, 372.0, 267.0, 372.0),
-    Q(227.0, 372.0, 197.0, 359.0),
-    Q(167.0, 346.0, 145.0, 326.0),
-    L(145.0, 132.0),
-    Q(145.0, 97.0, 164.5, 71.0),
-    Q(184.0, 45.0, 215.5, 31.0),
-    Q(247.0, 17.0, 282.0, 17.0),
-    Z,
-];
-
-pub const EBGARAMOND_LOWER_C: &'static [PathSegment] = &[
-    M(213.0, -15.0),
-    Q(163.0, -15.0, 122.5, 10.5),
-    Q(82.0, 36.0, 58.5, 80.5),
-    Q(35.0, 125.0, 35.0, 180.0),
-    Q(35.0, 245.0, 63.5, 298.0),
-    Q(92.0, 351.0, 140.0, 382.5),
-    Q(188.0, 414.0, 246.0, 414.0),
-    Q(311.0, 414.0, 360.0, 379.0),
-    Q(372.0, 370.0, 372.0, 360.0),
-    Q(372.0, 352.0, 365.0, 342.0),
-    Q(358.0, 332.0, 348.0, 325.5),
-    Q(338.0, 319.0, 329.0, 319.0),
-    Q(324.0, 319.0, 319.0, 322.5),
-    Q(314.0, 326.0, 308.0, 330.0),
-    Q(286.0, 347.0, 259.0, 362.0),
-    Q(232.0, 377.0, 214.0, 377.0),
-    Q(165.0, 377.0, 134.5, 331.5),
-    Q(104.0, 286.0, 104.0, 212.0),
-    Q(104.0, 165.0, 124.5, 127.0),
-    Q(145.0, 89.0, 179.5, 67.0),
-    Q(214.0, 45.0, 256.0, 45.0),
-    Q(285.0, 45.0, 306.0, 54.5),
-    Q(327.0, 64.0, 351.0, 88.0),
-    Q(353.0, 90.0, 356.0, 91.5),
-    Q(359.0, 93.0, 361.0, 93.0),
-    Q(372.0, 93.0, 372.0, 79.0),
-    Q(372.0, 73.0, 368.5, 65.5),
-    Q(365.0, 58.0, 357.0, 48.0),
-    Q(341.0, 28.0, 319.0, 13.5),
-    Q(297.0, -1.0, 270.5, -8.0),
-    Q(244.0, -15.0, 213.0, -15.0),
-    Z,
-];
+glyphs!(EBGARAMOND:
+    EBGARAMOND_SPACE,
+    EBGARAMOND_EXCLAM,
+    EBGARAMOND_QUOTEDBL,
+    EBGARAMOND_NUMBERSIGN,
+    EBGARAMOND_DOLLAR,
+    EBGARAMOND_PERCENT,
+    EBGARAMOND_AMPERSAND,
+    EBGARAMOND_QUOTESINGLE,
+    EBGARAMOND_PARENLEFT,
+    EBGARAMOND_PARENRIGHT,
+    EBGARAMOND_ASTERISK,
+    EBGARAMOND_PLUS,
+    EBGARAMOND_COMMA,
+    EBGARAMOND_HYPHEN,
+    EBGARAMOND_PERIOD,
+    EBGARAMOND_SLASH,
+    EBGARAMOND_ZERO,
+    EBGARAMOND_ONE,
+    EBGARAMOND_TWO,
+    EBGARAMOND_THREE,
+    EBGARAMOND_FOUR,
+    EBGARAMOND_FIVE,
+    EBGARAMOND_SIX,
+    EBGARAMOND_SEVEN,
+    EBGARAMOND_EIGHT,
+    EBGARAMOND_NINE,
+    EBGARAMOND_COLON,
+    EBGARAMOND_SEMICOLON,
+    EBGARAMOND_LESS,
+    EBGARAMOND_EQUAL,
+    EBGARAMOND_GREATER,
+    EBGARAMOND_QUESTION,
+    EBGARAMOND_AT,
+    EBGARAMOND_UPPER_A,
+    EBGARAMOND_UPPER_B,
+    EBGARAMOND_UPPER_C,
+    EBGARAMOND_UPPER_D,
+    EBGARAMOND_UPPER_E,
+    EBGARAMOND_UPPER_F,
+    EBGARAMOND_UPPER_G,
+    EBGARAMOND_UPPER_H,
+    EBGARAMOND_UPPER_I,
+    EBGARAMOND_UPPER_J,
+    EBGARAMOND_UPPER_K,
+    EBGARAMOND_UPPER_L,
+    EBGARAMOND_UPPER_M,
+    EBGARAMOND_UPPER_N,
+    EBGARAMOND_UPPER_O,
+    EBGARAMOND_UPPER_P,
+    EBGARAMOND_UPPER_Q,
+    EBGARAMOND_UPPER_R,
+    EBGARAMOND_UPPER_S,
+    EBGARAMOND_UPPER_T,
+    EBGARAMOND_UPPER_U,
+    EBGARAMOND_UPPER_V,
+    EBGARAMOND_UPPER_W,
+    EBGARAMOND_UPPER_X,
+    EBGARAMOND_UPPER_Y,
+    EBGARAMOND_UPPER_Z,
+    EBGARAMOND_BRACKETLEFT,
+    EBGARAMOND_BACKSLASH,
+    EBGARAMOND_BRACKETRIGHT,
+    EBGARAMOND_ASCIICIRCUM,
+    EBGARAMOND_UNDERSCORE,
+    EBGARAMOND_GRAVE,
+    EBGARAMOND_LOWER_A,
+    EBGARAMOND_LOWER_B,
+    EBGARAMOND_LOWER_C,
+    EBGARAMOND_LOWER_D,
+    EBGARAMOND_LOWER_E,
+    EBGARAMOND_LOWER_F,
+    EBGARAMOND_LOWER_G,
+    EBGARAMOND_LOWER_H,
+    EBGARAMOND_LOWER_I,
+    EBGARAMOND_LOWER_J,
+    EBGARAMOND_LOWER_K,
+    EBGARAMOND_LOWER_L,
+    EBGARAMOND_LOWER_M,
+    EBGARAMOND_LOWER_N,
+    EBGARAMOND_LOWER_O,
+    EBGARAMOND_LOWER_P,
+    EBGARAMOND_LOWER_Q,
+    EBGARAMOND_LOWER_R,
+    EBGARAMOND_LOWER_S,
+    EBGARAMOND_LOWER_T,
+    EBGARAMOND_LOWER_U,
+    EBGARAMOND_LOWER_V,
+    EBGARAMOND_LOWER_W,
+    EBGARAMOND_LOWER_X,
+    EBGARAMOND_LOWER_Y,
+    EBGARAMOND_LOWER_Z,
+    EBGARAMOND_BRACELEFT,
+    EBGARAMOND_BAR,
+    EBGARAMOND_BRACERIGHT,
+    EBGARAMOND_ASCIITILDE,
+    EBGARAMOND_NBSPACE,
+    EBGARAMOND_EXCLAMDOWN,
+    EBGARAMOND_CENT,
+    EBGARAMOND_STERLING,
+    EBGARAMOND_CURRENCY,
+    EBGARAMOND_YEN,
+    EBGARAMOND_BROKENBAR,
+    EBGARAMOND_SECTION,
+    EBGARAMOND_DIERESIS,
+    EBGARAMOND_COPYRIGHT,
+    EBGARAMOND_ORDFEMININE,
+    EBGARAMOND_GUILLEMOTLEFT,
+    EBGARAMOND_LOGICALNOT,
+    EBGARAMOND_SOFTHYPHEN,
+    EBGARAMOND_REGISTERED,
+    EBGARAMOND_MACRON,
+    EBGARAMOND_DEGREE,
+    EBGARAMOND_PLUSMINUS,
+    EBGARAMOND_TWOSUPERIOR,
+    EBGARAMOND_THREESUPERIOR,
+    EBGARAMOND_ACUTE,
+    EBGARAMOND_LOWER_MU,
+    EBGARAMOND_PARAGRAPH,
+    EBGARAMOND_PERIODCENTERED,
+    EBGARAMOND_CEDILLA,
+    EBGARAMOND_ONESUPERIOR,
+    EBGARAMOND_ORDMASCULINE,
+    EBGARAMOND_GUILLEMOTRIGHT,
+    EBGARAMOND_ONEQUARTER,
+    EBGARAMOND_ONEHALF,
+    EBGARAMOND_THREEQUARTERS,
+    EBGARAMOND_QUESTIONDOWN,
+    EBGARAMOND_UPPER_AGRAVE,
+    EBGARAMOND_UPPER_AACUTE,
+    EBGARAMOND_UPPER_ACIRCUMFLEX,
+    EBGARAMOND_UPPER_ATILDE,
+    EBGARAMOND_UPPER_ADIERESIS,
+    EBGARAMOND_UPPER_ARING,
+    EBGARAMOND_UPPER_AE,
+    EBGARAMOND_UPPER_CCEDILLA,
+    EBGARAMOND_UPPER_EGRAVE,
+    EBGARAMOND_UPPER_EACUTE,
+    EBGARAMOND_UPPER_ECIRCUMFLEX,
+    EBGARAMOND_UPPER_EDIERESIS,
+    EBGARAMOND_UPPER_IGRAVE,
+    EBGARAMOND_UPPER_IACUTE,
+    EBGARAMOND_UPPER_ICIRCUMFLEX,
+    EBGARAMOND_UPPER_IDIERESIS,
+    EBGARAMOND_UPPER_ETH,
+    EBGARAMOND_UPPER_NTILDE,
+    EBGARAMOND_UPPER_OGRAVE,
+    EBGARAMOND_UPPER_OACUTE,
+    EBGARAMOND_UPPER_OCIRCUMFLEX,
+    EBGARAMOND_UPPER_OTILDE,
+    EBGARAMOND_UPPER_ODIERESIS,
+    EBGARAMOND_MULTIPLY,
+    EBGARAMOND_UPPER_OSLASH,
+    EBGARAMOND_UPPER_UGRAVE,
+    EBGARAMOND_UPPER_UACUTE,
+    EBGARAMOND_UPPER_UCIRCUMFLEX,
+    EBGARAMOND_UPPER_UDIERESIS,
+    EBGARAMOND_UPPER_YACUTE,
+    EBGARAMOND_UPPER_THORN,
+    EBGARAMOND_LOWER_GERMANDBLS,
+    EBGARAMOND_LOWER_AGRAVE,
+    EBGARAMOND_LOWER_AACUTE,
+    EBGARAMOND_LOWER_ACIRCUMFLEX,
+    EBGARAMOND_LOWER_ATILDE,
+    EBGARAMOND_LOWER_ADIERESIS,
+    EBGARAMOND_LOWER_ARING,
+    EBGARAMOND_LOWER_AE,
+    EBGARAMOND_LOWER_CCEDILLA,
+    EBGARAMOND_LOWER_EGRAVE,
+    EBGARAMOND_LOWER_EACUTE,
+    EBGARAMOND_LOWER_ECIRCUMFLEX,
+    EBGARAMOND_LOWER_EDIERESIS,
+    EBGARAMOND_LOWER_IGRAVE,
+    EBGARAMOND_LOWER_IACUTE,
+    EBGARAMOND_LOWER_ICIRCUMFLEX,
+    EBGARAMOND_LOWER_IDIERESIS,
+    EBGARAMOND_LOWER_ETH,
+    EBGARAMOND_LOWER_NTILDE,
+    EBGARAMOND_LOWER_OGRAVE,
+    EBGARAMOND_LOWER_OACUTE,
+    EBGARAMOND_LOWER_OCIRCUMFLEX,
+    EBGARAMOND_LOWER_OTILDE,
+    EBGARAMOND_LOWER_ODIERESIS,
+    EBGARAMOND_DIVIDE,
+    EBGARAMOND_LOWER_OSLASH,
+    EBGARAMOND_LOWER_UGRAVE,
+    EBGARAMOND_LOWER_UACUTE,
+    EBGARAMOND_LOWER_UCIRCUMFLEX,
+    EBGARAMOND_LOWER_UDIERESIS,
+    EBGARAMOND_LOWER_YACUTE,
+    EBGARAMOND_LOWER_THORN,
+    EBGARAMOND_LOWER_YDIERESIS,
+    EBGARAMOND_UPPER_AMACRON,
+    EBGARAMOND_LOWER_AMACRON,
+    EBGARAMOND_UPPER_ABREVE,
+    EBGARAMOND_LOWER_ABREVE,
+    EBGARAMOND_UPPER_AOGONEK,
+    EBGARAMOND_LOWER_AOGONEK,
+    EBGARAMOND_UPPER_CACUTE,
+    EBGARAMOND_LOWER_CACUTE,
+    EBGARAMOND_UPPER_CCIRCUMFLEX,
+    EBGARAMOND_LOWER_CCIRCUMFLEX,
+    EBGARAMOND_UPPER_CDOTACCENT,
+    EBGARAMOND_LOWER_CDOTACCENT,
+    EBGARAMOND_UPPER_CCARON,
+    EBGARAMOND_LOWER_CCARON,
+    EBGARAMOND_UPPER_DCARON,
+    EBGARAMOND_LOWER_DCARON,
+    EBGARAMOND_UPPER_DCROAT,
+    EBGARAMOND_LOWER_DCROAT,
+    EBGARAMOND_UPPER_EMACRON,
+    EBGARAMOND_LOWER_EMACRON,
+    EBGARAMOND_UPPER_EBREVE,
+    EBGARAMOND_LOWER_EBREVE,
+    EBGARAMOND_UPPER_EDOTACCENT,
+    EBGARAMOND_LOWER_EDOTACCENT,
+    EBGARAMOND_UPPER_EOGONEK,
+    EBGARAMOND_LOWER_EOGONEK,
+    EBGARAMOND_UPPER_ECARON,
+    EBGARAMOND_LOWER_ECARON,
+    EBGARAMOND_UPPER_GCIRCUMFLEX,
+    EBGARAMOND_LOWER_GCIRCUMFLEX,
+    EBGARAMOND_UPPER_GBREVE,
+    EBGARAMOND_LOWER_GBREVE,
+    EBGARAMOND_UPPER_GDOTACCENT,
+    EBGARAMOND_LOWER_GDOTACCENT,
+    EBGARAMOND_UPPER_GCOMMAACCENT,
+    EBGARAMOND_LOWER_GCOMMAACCENT,
+    EBGARAMOND_UPPER_HCIRCUMFLEX,
+    EBGARAMOND_LOWER_HCIRCUMFLEX,
+    EBGARAMOND_UPPER_HBAR,
+    EBGARAMOND_LOWER_HBAR,
+    EBGARAMOND_UPPER_ITILDE,
+    EBGARAMOND_LOWER_ITILDE,
+    EBGARAMOND_UPPER_IMACRON,
+    EBGARAMOND_LOWER_IMACRON,
+    EBGARAMOND_UPPER_IBREVE,
+    EBGARAMOND_LOWER_IBREVE,
+    EBGARAMOND_UPPER_IOGONEK,
+    EBGARAMOND_LOWER_IOGONEK,
+    EBGARAMOND_UPPER_IDOTACCENT,
+    EBGARAMOND_LOWER_DOTLESSI,
+    EBGARAMOND_UPPER_IJ,
+    EBGARAMOND_LOWER_IJ,
+    EBGARAMOND_UPPER_JCIRCUMFLEX,
+    EBGARAMOND_LOWER_JCIRCUMFLEX,
+    EBGARAMOND_UPPER_KCOMMAACCENT,
+    EBGARAMOND_LOWER_KCOMMAACCENT,
+    EBGARAMOND_KGREENLANDIC,
+    EBGARAMOND_UPPER_LACUTE,
+    EBGARAMOND_LOWER_LACUTE,
+    EBGARAMOND_UPPER_LCOMMAACCENT,
+    EBGARAMOND_LOWER_LCOMMAACCENT,
+    EBGARAMOND_UPPER_LCARON,
+    EBGARAMOND_LOWER_LCARON,
+    EBGARAMOND_UPPER_LDOT,
+    EBGARAMOND_LOWER_LDOT,
+    EBGARAMOND_UPPER_LSLASH,
+    EBGARAMOND_LOWER_LSLASH,
+    EBGARAMOND_UPPER_NACUTE,
+    EBGARAMOND_LOWER_NACUTE,
+    EBGARAMOND_UPPER_NCOMMAACCENT,
+    EBGARAMOND_LOWER_NCOMMAACCENT,
+    EBGARAMOND_UPPER_NCARON,
+    EBGARAMOND_LOWER_NCARON,
+    EBGARAMOND_LOWER_NAPOSTROPHE,
+    EBGARAMOND_UPPER_ENG,
+    EBGARAMOND_LOWER_ENG,
+    EBGARAMOND_UPPER_OMACRON,
+    EBGARAMOND_LOWER_OMACRON,
+    EBGARAMOND_UPPER_OBREVE,
+    EBGARAMOND_LOWER_OBREVE,
+    EBGARAMOND_UPPER_OHUNGARUMLAUT,
+    EBGARAMOND_LOWER_OHUNGARUMLAUT,
+    EBGARAMOND_UPPER_OE,
+    EBGARAMOND_LOWER_OE,
+    EBGARAMOND_UPPER_RACUTE,
+    EBGARAMOND_LOWER_RACUTE,
+    EBGARAMOND_UPPER_RCOMMAACCENT,
+    EBGARAMOND_LOWER_RCOMMAACCENT,
+    EBGARAMOND_UPPER_RCARON,
+    EBGARAMOND_LOWER_RCARON,
+    EBGARAMOND_UPPER_SACUTE,
+    EBGARAMOND_LOWER_SACUTE,
+    EBGARAMOND_UPPER_SCIRCUMFLEX,
+    EBGARAMOND_LOWER_SCIRCUMFLEX,
+    EBGARAMOND_UPPER_SCEDILLA,
+    EBGARAMOND_LOWER_SCEDILLA,
+    EBGARAMOND_UPPER_SCARON,
+    EBGARAMOND_LOWER_SCARON,
+    EBGARAMOND_UPPER_TCOMMAACCENT,
+    EBGARAMOND_LOWER_TCOMMAACCENT,
+    EBGARAMOND_UPPER_TCARON,
+    EBGARAMOND_LOWER_TCARON,
+    EBGARAMOND_UPPER_TBAR,
+    EBGARAMOND_LOWER_TBAR,
+    EBGARAMOND_UPPER_UTILDE,
+    EBGARAMOND_LOWER_UTILDE,
+    EBGARAMOND_UPPER_UMACRON,
+    EBGARAMOND_LOWER_UMACRON,
+    EBGARAMOND_UPPER_UBREVE,
+    EBGARAMOND_LOWER_UBREVE,
+    EBGARAMOND_UPPER_URING,
+    EBGARAMOND_LOWER_URING,
+    EBGARAMOND_UPPER_UHUNGARUMLAUT,
+    EBGARAMOND_LOWER_UHUNGARUMLAUT,
+    EBGARAMOND_UPPER_UOGONEK,
+    EBGARAMOND_LOWER_UOGONEK,
+    EBGARAMOND_UPPER_WCIRCUMFLEX,
+    EBGARAMOND_LOWER_WCIRCUMFLEX,
+    EBGARAMOND_UPPER_YCIRCUMFLEX,
+    EBGARAMOND_LOWER_YCIRCUMFLEX,
+    EBGARAMOND_UPPER_YDIERESIS,
+    EBGARAMOND_UPPER_ZACUTE,
+    EBGARAMOND_LOWER_ZACUTE,
+    EBGARAMOND_UPPER_ZDOTACCENT,
+    EBGARAMOND_LOWER_ZDOTACCENT,
+    EBGARAMOND_UPPER_ZCARON,
+    EBGARAMOND_LOWER_ZCARON,
+    EBGARAMOND_LOWER_LONGS,
+    EBGARAMOND_LIGATURE_FFI,
+    EBGARAMOND_LIGATURE_FFL,
+    EBGARAMOND_LIGATURE_FF,
+    EBGARAMOND_LIGATURE_FI,
+    EBGARAMOND_LIGATURE_FL,
+);
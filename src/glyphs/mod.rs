@@ -1,2 +1,548 @@
+//! Glyph outlines, embedded in a compact binary format and decoded on first use.
+//!
+//! A glyph set starts with the magic bytes `GLY3`, a little-endian `u16` glyph count and the
+//! units per em, ascender and descender of the font (`i16`), followed by one entry per glyph:
+//! its name and the text it stands for, each a `u8` length and UTF-8, the offset and length
+//! (`u32`) of its outline in the outline data, and its advance width (`i16`). Then come the
+//! number of kerning pairs (`u32`) and the pairs, sorted: the indices of the left and right
+//! glyph (`u16`) and the adjustment (`i16`). The outline data is last. An outline is a
+//! sequence of segments, each a tag byte (`M`, `L`, `Q`, `C` or `Z`) followed by its points.
+//! Metrics are stored in half units, and coordinates in half units as `i16` differences from
+//! the previous point.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::core::core::{Point, Units};
+use crate::error::{GraphicsError, Result};
+use crate::item::{Fill, GraphicItem, GraphicItems, PathCacheInfo, Stroke};
+use crate::path::{PathSegment, PathSegments};
+
+#[cfg(feature = "cadenza")]
 pub mod cadenza;
+#[cfg(feature = "ebgaramond")]
 pub mod ebgaramond;
+
+const MAGIC: &[u8; 4] = b"GLY3";
+// Magic, glyph count, units per em, ascender and descender
+const HEADER_LENGTH: usize = 12;
+// Left and right glyph index and adjustment
+const PAIR_LENGTH: usize = 6;
+// Coordinates are stored as multiples of this
+const UNIT: f32 = 0.5;
+
+/// Metrics of a whole font, in the units of its glyph set.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FontMetrics {
+    pub units_per_em: f32,
+    /// Height above the baseline, positive.
+    pub ascender: f32,
+    /// Depth below the baseline, negative.
+    pub descender: f32,
+}
+
+/// Horizontal metrics of a glyph, in the units of its set. The bearings are the space
+/// between the origin and the outline, and between the outline and the advance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GlyphMetrics {
+    pub advance: f32,
+    pub left_bearing: f32,
+    pub right_bearing: f32,
+}
+
+impl GlyphMetrics {
+    fn new(advance: f32, outline: &PathSegments) -> GlyphMetrics {
+        let bbox = outline.bbox();
+        if bbox.is_empty() {
+            return GlyphMetrics { advance, left_bearing: 0., right_bearing: advance };
+        }
+        GlyphMetrics { advance, left_bearing: bbox.0, right_bearing: advance - bbox.2 }
+    }
+}
+
+// A glyph as stored in the set, with its outline at `offset` in the outline data
+struct Entry {
+    name: &'static str,
+    text: &'static str,
+    offset: usize,
+    length: usize,
+    advance: f32,
+}
+
+// The decoded header of a set
+struct Table {
+    entries: Vec<Entry>,
+    // Index of each name in `entries`
+    index: HashMap<&'static str, usize>,
+    kerning: &'static [u8],
+    // Where the outline data starts
+    start: usize,
+}
+
+impl Table {
+    fn find(&self, name: &str) -> Result<(usize, &Entry)> {
+        let idx = *self.index.get(name).ok_or_else(|| GraphicsError::MissingGlyph(name.to_string()))?;
+        Ok((idx, &self.entries[idx]))
+    }
+
+    // Pairs are sorted by their glyph indices
+    fn kerning(&self, left: usize, right: usize) -> f32 {
+        let pair = |idx: usize| {
+            let chunk = &self.kerning[idx * PAIR_LENGTH..(idx + 1) * PAIR_LENGTH];
+            let glyphs = (u16::from_le_bytes([chunk[0], chunk[1]]) as usize, u16::from_le_bytes([chunk[2], chunk[3]]) as usize);
+            (glyphs, i16::from_le_bytes([chunk[4], chunk[5]]) as f32 * UNIT)
+        };
+        let (mut low, mut high) = (0, self.kerning.len() / PAIR_LENGTH);
+        while low < high {
+            let mid = (low + high) / 2;
+            let (glyphs, adjustment) = pair(mid);
+            match glyphs.cmp(&(left, right)) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return adjustment,
+            }
+        }
+        0.
+    }
+}
+
+/// A set of glyph outlines in the binary format, usually from `include_bytes!`, with the
+/// units its coordinates are in. The header is read once, on first use.
+pub struct GlyphSet(&'static [u8], Units, OnceLock<Result<Table, (usize, String)>>);
+
+impl GlyphSet {
+    pub const fn new(data: &'static [u8], units: Units) -> GlyphSet {
+        GlyphSet(data, units, OnceLock::new())
+    }
+
+    pub fn units(&self) -> Units {
+        self.1
+    }
+
+    /// Names of the glyphs in the set, in stored order.
+    pub fn names(&self) -> Result<Vec<&'static str>> {
+        Ok(self.table()?.entries.iter().map(|entry| entry.name).collect())
+    }
+
+    pub fn get(&self, name: &str) -> Result<PathSegments> {
+        let table = self.table()?;
+        let (_, entry) = table.find(name)?;
+        let data = bytes(self.0, table.start + entry.offset, entry.length).map_err(|(position, message)| parse_error(position, message))?;
+        decode(data).map_err(|(position, message)| parse_error(table.start + entry.offset + position, message))
+    }
+
+    pub fn font_metrics(&self) -> Result<FontMetrics> {
+        self.table()?;
+        let value = |pos: usize| i16::from_le_bytes([self.0[pos], self.0[pos + 1]]) as f32 * UNIT;
+        Ok(FontMetrics { units_per_em: value(6), ascender: value(8), descender: value(10) })
+    }
+
+    pub fn glyph_metrics(&self, name: &str) -> Result<GlyphMetrics> {
+        Ok(GlyphMetrics::new(self.advance(name)?, &self.get(name)?))
+    }
+
+    fn advance(&self, name: &str) -> Result<f32> {
+        Ok(self.table()?.find(name)?.1.advance)
+    }
+
+    /// Adjustment of the advance of glyph `left` when followed by glyph `right`, negative to
+    /// bring them closer.
+    pub fn kerning(&self, left: &str, right: &str) -> Result<f32> {
+        let table = self.table()?;
+        let (left, right) = (table.find(left)?.0, table.find(right)?.0);
+        Ok(table.kerning(left, right))
+    }
+
+    /// The outline of a glyph in item coordinates.
+    pub fn outline(&self, name: &str) -> Result<PathSegments> {
+        Ok(self.1.to_items(&self.get(name)?))
+    }
+
+    /// The glyphs that write `text`, taking at each position the glyph that stands for the
+    /// most characters, so that ligatures are used where the set has them.
+    pub fn shape(&'static self, text: &str) -> Result<Vec<Glyph>> {
+        let table = self.table()?;
+        let mut glyphs = vec![];
+        let mut rest = text;
+        while let Some(c) = rest.chars().next() {
+            let entry = table.entries.iter().filter(|entry| !entry.text.is_empty() && rest.starts_with(entry.text)).max_by_key(|entry| entry.text.len());
+            let entry = entry.ok_or_else(|| GraphicsError::MissingGlyph(c.to_string()))?;
+            glyphs.push(Glyph::new(self, entry.name));
+            rest = &rest[entry.text.len()..];
+        }
+        Ok(glyphs)
+    }
+
+    fn table(&self) -> Result<&Table> {
+        self.2.get_or_init(|| read_table(self.0)).as_ref().map_err(|(position, message)| parse_error(*position, message.clone()))
+    }
+}
+
+// The header of a set, or where and why it cannot be read
+fn read_table(data: &'static [u8]) -> Result<Table, (usize, String)> {
+    if data.get(..4) != Some(MAGIC) {
+        return Err((0, "not a glyph set".to_string()));
+    }
+    let count = u16::from_le_bytes(bytes(data, 4, 2)?.try_into().unwrap()) as usize;
+    bytes(data, 0, HEADER_LENGTH)?;
+    let mut pos = HEADER_LENGTH;
+    let mut entries = Vec::with_capacity(count);
+    let string = |pos: usize| -> Result<&'static str, (usize, String)> {
+        let len = bytes(data, pos, 1)?[0] as usize;
+        std::str::from_utf8(bytes(data, pos + 1, len)?).map_err(|_| (pos + 1, "glyph name or text is not UTF-8".to_string()))
+    };
+    for _ in 0..count {
+        let name = string(pos)?;
+        pos += 1 + name.len();
+        let text = string(pos)?;
+        pos += 1 + text.len();
+        let offset = u32::from_le_bytes(bytes(data, pos, 4)?.try_into().unwrap()) as usize;
+        let length = u32::from_le_bytes(bytes(data, pos + 4, 4)?.try_into().unwrap()) as usize;
+        let advance = i16::from_le_bytes(bytes(data, pos + 8, 2)?.try_into().unwrap()) as f32 * UNIT;
+        pos += 10;
+        entries.push(Entry { name, text, offset, length, advance });
+    }
+    let pairs = u32::from_le_bytes(bytes(data, pos, 4)?.try_into().unwrap()) as usize;
+    let kerning = bytes(data, pos + 4, pairs * PAIR_LENGTH)?;
+    // Inserted last to first, so that a repeated name finds its first glyph
+    let index = entries.iter().enumerate().rev().map(|(idx, entry)| (entry.name, idx)).collect();
+    Ok(Table { entries, index, kerning, start: pos + 4 + kerning.len() })
+}
+
+fn bytes(data: &[u8], start: usize, length: usize) -> Result<&[u8], (usize, String)> {
+    data.get(start..start + length).ok_or_else(|| (data.len(), "unexpected end of data".to_string()))
+}
+
+fn parse_error(position: usize, message: String) -> GraphicsError {
+    GraphicsError::Parse { what: "glyph data", position, message }
+}
+
+/// A named glyph of a set, decoded the first time its segments are used.
+pub struct Glyph {
+    set: &'static GlyphSet,
+    name: &'static str,
+    segments: OnceLock<Vec<PathSegment>>,
+}
+
+impl Glyph {
+    pub const fn new(set: &'static GlyphSet, name: &'static str) -> Glyph {
+        Glyph { set, name, segments: OnceLock::new() }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The outline, or why it cannot be decoded. Decoding failures are not cached.
+    pub fn segments(&self) -> Result<&[PathSegment]> {
+        if let Some(segments) = self.segments.get() {
+            return Ok(segments);
+        }
+        let segments = self.set.get(self.name)?.0;
+        Ok(self.segments.get_or_init(|| segments))
+    }
+
+    /// The outline in item coordinates, where the segments are in the units of the set.
+    pub fn outline(&self) -> Result<PathSegments> {
+        Ok(self.set.units().to_items(&PathSegments(self.segments()?.to_vec())))
+    }
+
+    pub fn metrics(&self) -> Result<GlyphMetrics> {
+        Ok(GlyphMetrics::new(self.set.advance(self.name)?, &PathSegments(self.segments()?.to_vec())))
+    }
+
+    pub fn set(&self) -> &'static GlyphSet {
+        self.set
+    }
+}
+
+/// Glyphs set one after the other on a baseline starting at `origin`, in item coordinates.
+/// Each glyph is a path cached under its name, so that repeated glyphs are written once;
+/// glyphs without outline, like spaces, only advance. Consecutive glyphs of the same set are
+/// kerned.
+pub fn layout_glyphs(glyphs: &[&Glyph], origin: Point, fill: Fill) -> Result<GraphicItems> {
+    let mut items = vec![];
+    let mut x = origin.0;
+    let mut previous: Option<&Glyph> = None;
+    for glyph in glyphs {
+        if let Some(previous) = previous.filter(|previous| std::ptr::eq(previous.set, glyph.set)) {
+            x += glyph.set.kerning(previous.name, glyph.name)? / glyph.set.units().per_item_unit;
+        }
+        previous = Some(glyph);
+        let outline = glyph.outline()?;
+        if !outline.0.is_empty() {
+            items.push(GraphicItem::Path(outline, Stroke::NoStroke, fill.clone(), PathCacheInfo::Cache(glyph.name.to_string(), x, origin.1)));
+        }
+        x += glyph.metrics()?.advance / glyph.set.units().per_item_unit;
+    }
+    Ok(GraphicItems(items))
+}
+
+/// `text` shaped with the glyphs of `set` and laid out like `layout_glyphs`.
+pub fn layout_text(set: &'static GlyphSet, text: &str, origin: Point, fill: Fill) -> Result<GraphicItems> {
+    let glyphs = set.shape(text)?;
+    layout_glyphs(&glyphs.iter().collect::<Vec<_>>(), origin, fill)
+}
+
+// Declares a `Glyph` static for each name, looked up in the set by the name itself.
+macro_rules! glyphs {
+    ($set:ident: $($name:ident),* $(,)?) => {
+        $(pub static $name: crate::glyphs::Glyph = crate::glyphs::Glyph::new(&$set, stringify!($name));)*
+    };
+}
+pub(crate) use glyphs;
+
+/// A glyph to encode: its name, the text it stands for, empty for none, its outline and its
+/// advance width.
+pub struct GlyphSource<'a> {
+    pub name: &'a str,
+    pub text: &'a str,
+    pub segments: &'a [PathSegment],
+    pub advance: f32,
+}
+
+/// Encodes outlines with their metrics into the binary format, with kerning pairs given as
+/// the indices of the left and right glyph and the adjustment. Fails for arcs, for
+/// coordinates and metrics that are not multiples of half a unit and for jumps between points
+/// too long for an `i16`.
+pub fn encode_glyphs(metrics: &FontMetrics, glyphs: &[GlyphSource], kerning: &[(usize, usize, f32)]) -> Result<Vec<u8>> {
+    let mut entries = vec![];
+    let mut data = vec![];
+    for GlyphSource { name, text, segments, advance } in glyphs {
+        if name.len() > u8::MAX as usize || text.len() > u8::MAX as usize {
+            return Err(encoding_error(name, "name or text too long".to_string()));
+        }
+        let start = data.len();
+        let mut last = (0, 0);
+        for segment in segments.iter() {
+            let (tag, points) = match segment {
+                PathSegment::M(x, y) => (b'M', vec![(*x, *y)]),
+                PathSegment::L(x, y) => (b'L', vec![(*x, *y)]),
+                PathSegment::Q(x1, y1, x, y) => (b'Q', vec![(*x1, *y1), (*x, *y)]),
+                PathSegment::C(x1, y1, x2, y2, x, y) => (b'C', vec![(*x1, *y1), (*x2, *y2), (*x, *y)]),
+                PathSegment::Z => (b'Z', vec![]),
+                PathSegment::A(..) => return Err(encoding_error(name, "arcs cannot be encoded".to_string())),
+            };
+            data.push(tag);
+            for (x, y) in points {
+                let point = (to_units(x, name)?, to_units(y, name)?);
+                for delta in [point.0 - last.0, point.1 - last.1] {
+                    let delta = i16::try_from(delta).map_err(|_| encoding_error(name, "coordinates too far apart".to_string()))?;
+                    data.extend(delta.to_le_bytes());
+                }
+                last = point;
+            }
+        }
+        entries.push((name, text, start, data.len() - start, metric(*advance, name)?));
+    }
+    let mut pairs = vec![];
+    for (left, right, adjustment) in kerning {
+        let (Ok(left), Ok(right)) = (u16::try_from(*left), u16::try_from(*right)) else {
+            return Err(encoding_error("kerning", "glyph index too large".to_string()));
+        };
+        pairs.push(((left, right), metric(*adjustment, "kerning")?));
+    }
+    pairs.sort_by_key(|(glyphs, _)| *glyphs);
+
+    let mut out = MAGIC.to_vec();
+    out.extend((entries.len() as u16).to_le_bytes());
+    for value in [metrics.units_per_em, metrics.ascender, metrics.descender] {
+        out.extend(metric(value, "font metrics")?);
+    }
+    for (name, text, offset, length, advance) in entries {
+        for string in [name, text] {
+            out.push(string.len() as u8);
+            out.extend(string.as_bytes());
+        }
+        out.extend((offset as u32).to_le_bytes());
+        out.extend((length as u32).to_le_bytes());
+        out.extend(advance);
+    }
+    out.extend((pairs.len() as u32).to_le_bytes());
+    for ((left, right), adjustment) in pairs {
+        out.extend(left.to_le_bytes());
+        out.extend(right.to_le_bytes());
+        out.extend(adjustment);
+    }
+    out.extend(data);
+    Ok(out)
+}
+
+fn encoding_error(name: &str, message: String) -> GraphicsError {
+    GraphicsError::GlyphEncoding { name: name.to_string(), message }
+}
+
+fn metric(v: f32, name: &str) -> Result<[u8; 2]> {
+    let units = i16::try_from(to_units(v, name)?).map_err(|_| encoding_error(name, format!("metric {} is too large", v)))?;
+    Ok(units.to_le_bytes())
+}
+
+fn to_units(v: f32, name: &str) -> Result<i32> {
+    let units = v / UNIT;
+    if units.fract() != 0. {
+        return Err(encoding_error(name, format!("coordinate {} is not a multiple of {}", v, UNIT)));
+    }
+    Ok(units as i32)
+}
+
+// Fails with the position in the outline and what is wrong there
+fn decode(data: &[u8]) -> Result<PathSegments, (usize, String)> {
+    let mut pos = 0;
+    let (mut x, mut y) = (0i32, 0i32);
+    let mut point = |pos: &mut usize| {
+        let Some(bytes) = data.get(*pos..*pos + 4) else { return Err((*pos, "unexpected end of outline".to_string())) };
+        x += i16::from_le_bytes([bytes[0], bytes[1]]) as i32;
+        y += i16::from_le_bytes([bytes[2], bytes[3]]) as i32;
+        *pos += 4;
+        Ok((x as f32 * UNIT, y as f32 * UNIT))
+    };
+    let mut segments = vec![];
+    while pos < data.len() {
+        let tag = data[pos];
+        pos += 1;
+        segments.push(match tag {
+            b'M' => {
+                let (x, y) = point(&mut pos)?;
+                PathSegment::M(x, y)
+            }
+            b'L' => {
+                let (x, y) = point(&mut pos)?;
+                PathSegment::L(x, y)
+            }
+            b'Q' => {
+                let ((x1, y1), (x, y)) = (point(&mut pos)?, point(&mut pos)?);
+                PathSegment::Q(x1, y1, x, y)
+            }
+            b'C' => {
+                let ((x1, y1), (x2, y2), (x, y)) = (point(&mut pos)?, point(&mut pos)?, point(&mut pos)?);
+                PathSegment::C(x1, y1, x2, y2, x, y)
+            }
+            b'Z' => PathSegment::Z,
+            _ => return Err((pos - 1, format!("invalid segment tag {}", tag))),
+        });
+    }
+    Ok(PathSegments(segments))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_glyphs, layout_glyphs, layout_text, FontMetrics, Glyph, GlyphMetrics, GlyphSet, GlyphSource};
+    use crate::core::core::{Point, Units, YAxis};
+    use crate::error::GraphicsError;
+    use crate::item::{Color, Fill, GraphicItem, PathCacheInfo};
+    use crate::path::PathSegment::*;
+
+    const FONT: FontMetrics = FontMetrics { units_per_em: 1000., ascender: 800., descender: -200. };
+
+    fn source<'a>(name: &'a str, segments: &'a [crate::path::PathSegment], advance: f32) -> GlyphSource<'a> {
+        GlyphSource { name, text: "", segments, advance }
+    }
+
+    #[test]
+    fn glyph_data() {
+        let glyph = [M(0., 0.), L(100.5, -20.), Q(3., 4., -5.5, 6.), C(1., 2., 3., 4., 5., 6.), Z];
+        let glyphs = [
+            GlyphSource { name: "one", text: "o", segments: &glyph[..1], advance: 50. },
+            GlyphSource { name: "two", text: "t", segments: &glyph, advance: 120.5 },
+            GlyphSource { name: "ot", text: "ot", segments: &glyph[..1], advance: 80. },
+        ];
+        let data = encode_glyphs(&FONT, &glyphs, &[(0, 1, -10.)]).unwrap();
+        assert_eq!(data.len(), 12 + (1 + 3 + 1 + 1 + 8 + 2) * 2 + (1 + 2 + 1 + 2 + 8 + 2) + (4 + 6) + 5 + (5 + 5 + 9 + 13 + 1) + 5);
+        let set: &'static GlyphSet = Box::leak(Box::new(GlyphSet::new(Box::leak(data.into_boxed_slice()), Units::new(10., YAxis::Up))));
+        assert_eq!(set.names().unwrap(), vec!["one", "two", "ot"]);
+        assert_eq!(set.get("two").unwrap().to_string(), "M 0 0 L 100.5 -20 Q 3 4 -5.5 6 C 1 2 3 4 5 6 Z ");
+        assert!(matches!(set.get("three"), Err(GraphicsError::MissingGlyph(name)) if name == "three"));
+        assert_eq!(set.font_metrics().unwrap(), FONT);
+        assert_eq!(set.glyph_metrics("one").unwrap(), GlyphMetrics { advance: 50., left_bearing: 0., right_bearing: 50. });
+        assert_eq!(set.glyph_metrics("two").unwrap(), GlyphMetrics { advance: 120.5, left_bearing: -5.5, right_bearing: 20. });
+        assert_eq!(set.outline("two").unwrap().to_string(), "M 0 -0 L 10.05 2 Q 0.3 -0.4 -0.55 -0.6 C 0.1 -0.2 0.3 -0.4 0.5 -0.6 Z ");
+        let truncated = GlyphSet::new(Box::leak(encode_glyphs(&FONT, &[source("one", &glyph, 0.)], &[]).unwrap()[..30].to_vec().into_boxed_slice()), Units::new(1., YAxis::Down));
+        assert!(matches!(truncated.get("one"), Err(GraphicsError::Parse { what: "glyph data", .. })));
+        // The header is read once, errors included
+        assert!(std::ptr::eq(set.table().unwrap(), set.table().unwrap()));
+        assert!(matches!(truncated.names(), Err(GraphicsError::Parse { what: "glyph data", .. })));
+
+        // Ligatures win, kerning applies to the pairs listed
+        let names = |text: &str| set.shape(text).map(|glyphs| glyphs.iter().map(Glyph::name).collect::<Vec<_>>());
+        assert_eq!(names("tot").unwrap(), vec!["two", "ot"]);
+        assert!(matches!(names("tox"), Err(GraphicsError::MissingGlyph(c)) if c == "x"));
+        assert_eq!((set.kerning("one", "two").unwrap(), set.kerning("two", "one").unwrap()), (-10., 0.));
+        let (one, two) = (Glyph::new(set, "one"), Glyph::new(set, "two"));
+        let items = layout_glyphs(&[&one, &two], Point(0., 0.), Fill::Fillstyle(Color::Black)).unwrap();
+        assert!(matches!(&items.0[1], GraphicItem::Path(_, _, _, PathCacheInfo::Cache(_, x, _)) if *x == 4.));
+        let items = layout_text(set, "tot", Point(0., 0.), Fill::Fillstyle(Color::Black)).unwrap();
+        assert!(matches!(&items.0[1], GraphicItem::Path(_, _, _, PathCacheInfo::Cache(tag, x, _)) if tag == "ot" && *x == 12.05));
+
+        assert!(encode_glyphs(&FONT, &[source("a", &[M(0.25, 0.)], 0.)], &[]).is_err());
+        assert!(encode_glyphs(&FONT, &[source("a", &[M(0., 0.), L(20000., 0.)], 0.)], &[]).is_err());
+        assert!(encode_glyphs(&FONT, &[source("a", &[A(1., 1., 0., false, false, 2., 2.)], 0.)], &[]).is_err());
+        assert!(encode_glyphs(&FONT, &[source("a", &[], 0.25)], &[]).is_err());
+        assert!(encode_glyphs(&FONT, &[source("a", &[], 0.)], &[(0, 70000, 1.)]).is_err());
+    }
+
+    #[cfg(feature = "cadenza")]
+    #[test]
+    fn embedded_glyphs() {
+        use crate::glyphs::cadenza::{CADENZA, CADENZA_HEAD_BLACK};
+        assert!(CADENZA.names().unwrap().contains(&"CADENZA_CLEF_G"));
+        let head: &Glyph = &CADENZA_HEAD_BLACK;
+        assert_eq!(head.name(), "CADENZA_HEAD_BLACK");
+        assert_eq!(head.segments().unwrap().len(), CADENZA.get("CADENZA_HEAD_BLACK").unwrap().0.len());
+        assert!(matches!(head.segments().unwrap()[0], M(..)));
+        // A staff space is 250 font units, 25 item units, and the stem side is up
+        let bbox = head.outline().unwrap().bbox();
+        assert_eq!((bbox.1, bbox.3), (-13.7, 13.7));
+
+        // Heads advance by their width
+        assert_eq!(head.metrics().unwrap(), GlyphMetrics { advance: 326., left_bearing: 0., right_bearing: 0. });
+        let items = layout_glyphs(&[head, head], Point(10., 50.), Fill::Fillstyle(Color::Black)).unwrap();
+        assert!(matches!(&items.0[1], GraphicItem::Path(_, _, _, PathCacheInfo::Cache(tag, x, y)) if tag == "CADENZA_HEAD_BLACK" && (*x, *y) == (42.6, 50.)));
+    }
+
+    #[cfg(feature = "ebgaramond")]
+    #[test]
+    fn embedded_metrics() {
+        use crate::glyphs::ebgaramond::{EBGARAMOND, EBGARAMOND_LOWER_A, EBGARAMOND_UPPER_C};
+        let font = EBGARAMOND.font_metrics().unwrap();
+        assert_eq!(font.units_per_em, 1000.);
+        assert!(font.ascender > 0. && font.descender < 0.);
+        let metrics = EBGARAMOND_UPPER_C.metrics().unwrap();
+        assert!(metrics.left_bearing > 0. && metrics.right_bearing > 0.);
+        // The baseline is at the origin, with the letters above it
+        let items = layout_glyphs(&[&EBGARAMOND_UPPER_C, &EBGARAMOND_LOWER_A], Point(0., 0.), Fill::Fillstyle(Color::Black)).unwrap();
+        let bbox = items.bbox();
+        assert!(bbox.1 < -60. && bbox.3 < 2.);
+        let kerning = EBGARAMOND.kerning("EBGARAMOND_UPPER_C", "EBGARAMOND_LOWER_A").unwrap();
+        assert!(matches!(&items.0[1], GraphicItem::Path(_, _, _, PathCacheInfo::Cache(_, x, _)) if *x == (metrics.advance + kerning) / 10.));
+        assert!(EBGARAMOND_LOWER_A.metrics().unwrap().advance > 0.);
+    }
+
+    #[cfg(feature = "ebgaramond")]
+    #[test]
+    fn embedded_text() {
+        use crate::glyphs::ebgaramond::EBGARAMOND;
+        let names = |text| EBGARAMOND.shape(text).unwrap().iter().map(Glyph::name).collect::<Vec<_>>();
+        assert_eq!(names("Åsa äter öl"), [
+            "EBGARAMOND_UPPER_ARING",
+            "EBGARAMOND_LOWER_S",
+            "EBGARAMOND_LOWER_A",
+            "EBGARAMOND_SPACE",
+            "EBGARAMOND_LOWER_ADIERESIS",
+            "EBGARAMOND_LOWER_T",
+            "EBGARAMOND_LOWER_E",
+            "EBGARAMOND_LOWER_R",
+            "EBGARAMOND_SPACE",
+            "EBGARAMOND_LOWER_ODIERESIS",
+            "EBGARAMOND_LOWER_L",
+        ]);
+        assert_eq!(names("offline 1,5"), ["EBGARAMOND_LOWER_O", "EBGARAMOND_LIGATURE_FFL", "EBGARAMOND_LOWER_I", "EBGARAMOND_LOWER_N", "EBGARAMOND_LOWER_E", "EBGARAMOND_SPACE", "EBGARAMOND_ONE", "EBGARAMOND_COMMA", "EBGARAMOND_FIVE"]);
+        assert_eq!(names("Łódź"), ["EBGARAMOND_UPPER_LSLASH", "EBGARAMOND_LOWER_OACUTE", "EBGARAMOND_LOWER_D", "EBGARAMOND_LOWER_ZACUTE"]);
+        assert!(matches!(EBGARAMOND.shape("€"), Err(GraphicsError::MissingGlyph(c)) if c == "€"));
+        // The font kerns pairs like T and o
+        assert!(EBGARAMOND.kerning("EBGARAMOND_UPPER_T", "EBGARAMOND_LOWER_O").unwrap() < 0.);
+        let items = layout_text(&EBGARAMOND, "To", Point(0., 0.), Fill::Fillstyle(Color::Black)).unwrap();
+        let advance = EBGARAMOND.glyph_metrics("EBGARAMOND_UPPER_T").unwrap().advance;
+        assert!(matches!(&items.0[1], GraphicItem::Path(_, _, _, PathCacheInfo::Cache(_, x, _)) if *x < advance / 10.));
+    }
+}
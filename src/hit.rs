@@ -0,0 +1,79 @@
+use crate::item::{Fill, GraphicItem, GraphicItems, Stroke};
+use crate::path::PathSegment::{L, M};
+use crate::path::PathSegments;
+
+// A shape is hit inside its fill, or within half the stroke width of its outline.
+// `tolerance` widens both, so that thin lines and small glyphs can be picked.
+fn hit_shape(path: &PathSegments, stroke: &Stroke, fill: &Fill, x: f32, y: f32, tolerance: f32) -> bool {
+    if let Fill::Fillstyle(_) = fill {
+        if path.contains(x, y) || path.distance(x, y) <= tolerance {
+            return true;
+        }
+    }
+    if let Stroke::Strokestyle(w, _) = stroke {
+        if path.distance(x, y) <= w / 2. + tolerance {
+            return true;
+        }
+    }
+    false
+}
+
+impl GraphicItem {
+    /// True if the point hits the painted area of the item.
+    pub fn hit_test(&self, x: f32, y: f32, tolerance: f32) -> bool {
+        if !self.bbox().inflate(tolerance).contains_point(x, y) {
+            return false;
+        }
+        match self {
+            GraphicItem::Line(x1, y1, x2, y2, stroke) => hit_shape(&PathSegments(vec![M(*x1, *y1), L(*x2, *y2)]), stroke, &Fill::NoFill, x, y, tolerance),
+            GraphicItem::Rect(rx, ry, w, h, stroke, fill) => hit_shape(&PathSegments::rect(*rx, *ry, *w, *h), stroke, fill, x, y, tolerance),
+            GraphicItem::Ellipse(ex, ey, w, h, stroke, fill) => hit_shape(&PathSegments::ellipse(*ex, *ey, *w, *h), stroke, fill, x, y, tolerance),
+            GraphicItem::RoundedRect(_, _, _, _, _, stroke, fill) | GraphicItem::Polygon(_, stroke, fill) => hit_shape(&self.to_path().unwrap(), stroke, fill, x, y, tolerance),
+            GraphicItem::Polyline(_, stroke) => hit_shape(&self.to_path().unwrap(), stroke, &Fill::NoFill, x, y, tolerance),
+            GraphicItem::Path(path, stroke, fill, cache) => {
+                // Test cached glyphs in their own coordinates rather than moving the path
+                let (ox, oy) = cache.offset();
+                hit_shape(path, stroke, fill, x - ox, y - oy, tolerance)
+            }
+            GraphicItem::Group(items, attrs) => {
+                if let Some(clip) = &attrs.clip {
+                    if !clip.0.contains(x, y) {
+                        return false;
+                    }
+                }
+                items.0.iter().any(|item| item.hit_test(x, y, tolerance))
+            }
+        }
+    }
+}
+
+impl GraphicItems {
+    /// Indices of the items hit by the point, topmost (last drawn) first.
+    pub fn hit_test(&self, x: f32, y: f32, tolerance: f32) -> Vec<usize> {
+        self.0.iter().enumerate().rev().filter(|(_, item)| item.hit_test(x, y, tolerance)).map(|(idx, _)| idx).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[cfg(feature = "cadenza")]
+    #[test]
+    fn hit_items() {
+        let head = CADENZA_HEAD_BLACK.outline().unwrap();
+        let items = GraphicItems(vec![
+            Rect(0., 0., 100., 100., NoStroke, Fillstyle(White)),
+            Line(0., 50., 100., 50., Strokestyle(2., Black)),
+            Path(head.clone(), NoStroke, Fillstyle(Black), PathCacheInfo::Cache("head".to_string(), 200., 0.)),
+        ]);
+        assert_eq!(items.hit_test(10., 50.5, 0.), vec![1, 0]);
+        assert_eq!(items.hit_test(10., 53., 0.), vec![0]);
+        assert_eq!(items.hit_test(10., 53., 2.), vec![1, 0]);
+
+        let bbox = head.bbox();
+        let (cx, cy) = ((bbox.0 + bbox.2) / 2., (bbox.1 + bbox.3) / 2.);
+        assert_eq!(items.hit_test(cx + 200., cy, 0.), vec![2]);
+        assert!(items.hit_test(cx, cy, 0.).iter().all(|idx| *idx != 2));
+    }
+}
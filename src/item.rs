@@ -1,25 +1,182 @@
-use std::fmt::{Display, Formatter};
-
 use serde::{Deserialize, Serialize};
 
+use crate::animation::Animation;
+pub use crate::color::Color;
 use crate::prelude::*;
 
 use crate::path::{PathSegment, PathSegments};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum GraphicItem {
     Line(f32, f32, f32, f32, Stroke),
     Rect(f32, f32, f32, f32, Stroke, Fill),
+    RoundedRect(f32, f32, f32, f32, CornerRadii, Stroke, Fill),
     Ellipse(f32, f32, f32, f32, Stroke, Fill),
+    Polyline(Vec<Point>, Stroke),
+    Polygon(Vec<Point>, Stroke, Fill),
     Path(PathSegments, Stroke, Fill, PathCacheInfo),
+    Group(GraphicItems, ItemAttributes),
+}
+
+/// Effects applied to a `GraphicItem::Group` as a whole. Effects on a single item
+/// are expressed as a group holding just that item, see `GraphicItem::with_clip`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ItemAttributes {
+    pub clip: Option<Clip>,
+    pub mask: Option<Mask>,
+    pub opacity: f32,
+    pub blend: BlendMode,
+    pub trim: Option<Trim>,
+    pub animation: Option<Animation>,
+    // Boxed, as most groups have none and it would make every item larger
+    pub metadata: Box<Metadata>,
+}
+
+impl Default for ItemAttributes {
+    fn default() -> Self {
+        Self { clip: None, mask: None, opacity: 1.0, blend: BlendMode::Normal, trim: None, animation: None, metadata: Box::default() }
+    }
+}
+
+impl ItemAttributes {
+    /// True if the group has to be rendered into a layer of its own before it is composited.
+    pub fn is_isolated(&self) -> bool {
+        self.clip.is_some() || self.mask.is_some() || self.opacity < 1.0 || self.blend != BlendMode::Normal || self.animation.as_ref().is_some_and(|animation| animation.opacity.is_some())
+    }
+
+    pub fn move_attributes(&self, move_x: f32, move_y: f32) -> ItemAttributes {
+        ItemAttributes {
+            clip: self.clip.as_ref().map(|clip| Clip(clip.0.move_path(move_x, move_y))),
+            mask: self.mask.as_ref().map(|mask| Mask(mask.0.move_items(move_x, move_y), mask.1.clone())),
+            animation: self.animation.as_ref().map(|animation| animation.move_animation(move_x, move_y)),
+            ..self.clone()
+        }
+    }
+
+    pub fn scale_attributes(&self, scale_x: f32, scale_y: f32, scale_stroke: f32) -> ItemAttributes {
+        ItemAttributes {
+            clip: self.clip.as_ref().map(|clip| Clip(clip.0.scale_path(scale_x, scale_y))),
+            mask: self.mask.as_ref().map(|mask| Mask(mask.0.scale_items(scale_x, scale_y, scale_stroke), mask.1.clone())),
+            animation: self.animation.as_ref().map(|animation| animation.scale_animation(scale_x, scale_y)),
+            ..self.clone()
+        }
+    }
+}
+
+/// Names and data for styling and scripting the output, such as the pitch of a note.
+/// Builders without a use for them, like the Fuse builder, ignore them. The SVG builder writes
+/// them on the element of a shape that carries them alone, and on the group element otherwise.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Metadata {
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+    /// Written as `data-<name>` attributes.
+    pub data: Vec<(String, String)>,
+    /// Tooltip text.
+    pub title: Option<String>,
+}
+
+/// How a group is composited onto what is below it, following the CSS/SVG `mix-blend-mode` set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BlendMode {
+    #[default]
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+    Hue,
+    Saturation,
+    Color,
+    Luminosity,
+}
+
+/// `Trim(start, end)` draws only the part of every shape in the group between two
+/// fractions 0..1 of its length, as for a "write-on" animation.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Trim(pub f32, pub f32);
+
+impl Trim {
+    /// The trim that results from applying `outer` to shapes already trimmed by `self`.
+    pub fn then(&self, outer: &Trim) -> Trim {
+        let span = self.1 - self.0;
+        Trim(self.0 + outer.0 * span, self.0 + outer.1 * span)
+    }
+}
+
+/// Clip region: only the parts of the group inside the (nonzero filled) path are visible.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Clip(pub PathSegments);
+
+/// Mask content and how its pixels are turned into coverage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Mask(pub GraphicItems, pub MaskType);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum MaskType {
+    Luminance,
+    Alpha,
+}
+
+/// Radii of the corners of a `RoundedRect`: (top left, top right, bottom right, bottom left).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CornerRadii(pub f32, pub f32, pub f32, pub f32);
+
+impl CornerRadii {
+    pub fn uniform(radius: f32) -> CornerRadii {
+        CornerRadii(radius, radius, radius, radius)
+    }
+
+    pub fn is_uniform(&self) -> bool {
+        self.0 == self.1 && self.1 == self.2 && self.2 == self.3
+    }
+
+    /// The radii reduced in proportion where adjacent corners would overlap on a w x h
+    /// rectangle, as CSS border-radius does.
+    pub fn fitted(&self, w: f32, h: f32) -> CornerRadii {
+        let CornerRadii(tl, tr, br, bl) = *self;
+        let ratio = |side: f32, sum: f32| if sum > side { side / sum } else { 1. };
+        let f = ratio(w, tl + tr).min(ratio(w, bl + br)).min(ratio(h, tl + bl)).min(ratio(h, tr + br));
+        CornerRadii(tl * f, tr * f, br * f, bl * f)
+    }
+
+    pub fn scale(&self, s: f32) -> CornerRadii {
+        CornerRadii(self.0 * s, self.1 * s, self.2 * s, self.3 * s)
+    }
 }
 
+/// `Cache(tag, x, y)` draws the path as a shared definition placed at offset (x, y).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PathCacheInfo {
     NoCache,
     Cache(String, f32, f32),
 }
 
+impl PathCacheInfo {
+    pub fn offset(&self) -> (f32, f32) {
+        match self {
+            PathCacheInfo::Cache(_, x, y) => (*x, *y),
+            PathCacheInfo::NoCache => (0., 0.),
+        }
+    }
+
+    pub fn scale(&self, scale_x: f32, scale_y: f32) -> PathCacheInfo {
+        match self {
+            PathCacheInfo::Cache(tag, x, y) => PathCacheInfo::Cache(tag.clone(), x * scale_x, y * scale_y),
+            PathCacheInfo::NoCache => PathCacheInfo::NoCache,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Stroke {
     NoStroke,
@@ -41,71 +198,241 @@ pub enum Fill {
     Fillstyle(Color), // color
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum Color {
-    RGBA(u8, u8, u8, u8),
-    Blue,
-    Dodgerblue,
-    Red,
-    Tomato,
-    Orange,
-    Purple,
-    Lime,
-    Gray,
-    LightGray,
-    Green,
-    Black,
-    White,
-}
-
-impl Display for Color {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GraphicItems(pub Vec<GraphicItem>);
+
+impl GraphicItem {
+    pub fn bbox(&self) -> Rectangle {
+        let mut x_min = f32::MAX;
+        let mut y_min = f32::MAX;
+        let mut x_max = f32::MIN;
+        let mut y_max = f32::MIN;
+
+        fn get_stroke_width(stroke: &Stroke) -> f32 {
+            match stroke {
+                Stroke::Strokestyle(w, _) => w / 2.0,
+                Stroke::NoStroke => 0.,
+            }
+        }
+
         match self {
-            Color::RGBA(r, g, b, a) => write!(f, "rgba({},{},{},{})", r, g, b, a),
-            Color::Blue => write!(f, "blue"),
-            Color::Dodgerblue => write!(f, "dodgerblue"),
-            Color::LightGray => write!(f, "lightgray"),
-            Color::Gray => write!(f, "gray"),
-            Color::Red => write!(f, "red"),
-            Color::Tomato => write!(f, "tomato"),
-            Color::Orange => write!(f, "orange"),
-            Color::Purple => write!(f, "purple"),
-            Color::Lime => write!(f, "lime"),
-            Color::Green => write!(f, "green"),
-            Color::Black => write!(f, "black"),
-            Color::White => write!(f, "white"),
+            GraphicItem::Line(x1, y1, x2, y2, stroke) => {
+                let sw = get_stroke_width(stroke);
+                x_min = x_min.min(*x1 - sw);
+                y_min = y_min.min(*y1 - sw);
+                x_max = x_max.max(*x1 + sw);
+                y_max = y_max.max(*y1 + sw);
+                x_min = x_min.min(*x2 - sw);
+                y_min = y_min.min(*y2 - sw);
+                x_max = x_max.max(*x2 + sw);
+                y_max = y_max.max(*y2 + sw);
+            }
+            GraphicItem::Rect(x, y, w, h, stroke, _) | GraphicItem::RoundedRect(x, y, w, h, _, stroke, _) => {
+                let sw = get_stroke_width(stroke);
+                x_min = x_min.min(*x - sw);
+                y_min = y_min.min(*y - sw);
+                x_max = x_max.max(*x + sw);
+                y_max = y_max.max(*y + sw);
+                x_min = x_min.min(*x + *w - sw);
+                y_min = y_min.min(*y + *h - sw);
+                x_max = x_max.max(*x + *w + sw);
+                y_max = y_max.max(*y + *h + sw);
+            }
+
+            GraphicItem::Ellipse(x, y, w, h, stroke, _) => {
+                let sw = get_stroke_width(stroke);
+                x_min = x_min.min(*x - sw);
+                y_min = y_min.min(*y - sw);
+                x_max = x_max.max(*x + sw);
+                y_max = y_max.max(*y + sw);
+                x_min = x_min.min(*x + *w - sw);
+                y_min = y_min.min(*y + *h - sw);
+                x_max = x_max.max(*x + *w + sw);
+                y_max = y_max.max(*y + *h + sw);
+            }
+
+            GraphicItem::Polyline(points, stroke) | GraphicItem::Polygon(points, stroke, _) => {
+                let sw = get_stroke_width(stroke);
+                for p in points.iter() {
+                    x_min = x_min.min(p.0 - sw);
+                    y_min = y_min.min(p.1 - sw);
+                    x_max = x_max.max(p.0 + sw);
+                    y_max = y_max.max(p.1 + sw);
+                }
+            }
+
+            GraphicItem::Path(path, stroke, _, cache) => {
+                let sw = get_stroke_width(stroke);
+                let placed;
+                let path = match cache {
+                    PathCacheInfo::Cache(_, x, y) => {
+                        placed = path.move_path(*x, *y);
+                        &placed
+                    }
+                    PathCacheInfo::NoCache => path,
+                };
+                let lowered;
+                let path = if path.has_arcs() {
+                    lowered = path.arcs_to_cubics();
+                    &lowered
+                } else {
+                    path
+                };
+
+                for segment in path.0.iter() {
+                    match segment {
+                        PathSegment::M(x, y) | PathSegment::L(x, y) => {
+                            x_min = x_min.min(*x - sw);
+                            y_min = y_min.min(*y - sw);
+                            x_max = x_max.max(*x + sw);
+                            y_max = y_max.max(*y + sw);
+                        }
+                        PathSegment::Q(x1, y1, x, y) => {
+                            x_min = x_min.min(*x - sw);
+                            y_min = y_min.min(*y - sw);
+                            x_max = x_max.max(*x + sw);
+                            y_max = y_max.max(*y + sw);
+                            x_min = x_min.min(*x1 - sw);
+                            y_min = y_min.min(*y1 - sw);
+                            x_max = x_max.max(*x1 + sw);
+                            y_max = y_max.max(*y1 + sw);
+                        }
+                        PathSegment::C(x1, y1, x2, y2, x, y) => {
+                            x_min = x_min.min(*x);
+                            y_min = y_min.min(*y);
+                            x_max = x_max.max(*x);
+                            y_max = y_max.max(*y);
+                            x_min = x_min.min(*x1);
+                            y_min = y_min.min(*y1);
+                            x_max = x_max.max(*x1);
+                            y_max = y_max.max(*y1);
+                            x_min = x_min.min(*x2);
+                            y_min = y_min.min(*y2);
+                            x_max = x_max.max(*x2);
+                            y_max = y_max.max(*y2);
+                        }
+                        // Arcs have been lowered to cubics above
+                        PathSegment::A(..) | PathSegment::Z => {}
+                    }
+                }
+            }
+            GraphicItem::Group(items, attrs) => {
+                let mut bbox = items.bbox();
+                if let Some(clip) = &attrs.clip {
+                    bbox = bbox.intersection(&clip.0.bbox());
+                }
+                if let Some(mask) = &attrs.mask {
+                    bbox = bbox.intersection(&mask.0.bbox());
+                }
+                return bbox;
+            }
         }
+        Rectangle(x_min, y_min, x_max, y_max)
     }
-}
 
-impl Color {
-    pub fn from_str(s: &str) -> Self {
-        match s.to_lowercase().as_str() {
-            "blue" => Color::Blue,
-            "red" => Color::Red,
-            "orange" => Color::Orange,
-            "purple" => Color::Purple,
-            "lime" => Color::Lime,
-            "gray" => Color::Gray,
-            "lightgray" => Color::LightGray,
-            "green" => Color::Green,
-            "black" => Color::Black,
-            "white" => Color::White,
-            _ => Color::Black,
+    // Sets attributes on the item if it is a group that `accepts` them, otherwise wraps
+    // the item in a new group first.
+    pub(crate) fn with_attributes(self, accepts: impl Fn(&ItemAttributes) -> bool, set: impl FnOnce(&mut ItemAttributes)) -> GraphicItem {
+        match self {
+            GraphicItem::Group(items, mut attrs) if accepts(&attrs) => {
+                set(&mut attrs);
+                GraphicItem::Group(items, attrs)
+            }
+            item => {
+                let mut attrs = ItemAttributes::default();
+                set(&mut attrs);
+                GraphicItem::Group(GraphicItems(vec![item]), attrs)
+            }
         }
     }
-}
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct GraphicItems(pub Vec<GraphicItem>);
+    /// Clips the item to `clip`, wrapping it in a group unless it already is an unclipped one.
+    pub fn with_clip(self, clip: PathSegments) -> GraphicItem {
+        self.with_attributes(|attrs| attrs.clip.is_none(), |attrs| attrs.clip = Some(Clip(clip)))
+    }
+
+    /// Masks the item with `mask`, wrapping it in a group unless it already is an unmasked one.
+    pub fn with_mask(self, mask: Mask) -> GraphicItem {
+        self.with_attributes(|attrs| attrs.mask.is_none(), |attrs| attrs.mask = Some(mask))
+    }
+
+    /// Multiplies the opacity of the item, fill and stroke together.
+    pub fn with_opacity(self, opacity: f32) -> GraphicItem {
+        self.with_attributes(|_| true, |attrs| attrs.opacity *= opacity.clamp(0., 1.))
+    }
+
+    pub fn with_blend(self, blend: BlendMode) -> GraphicItem {
+        self.with_attributes(|attrs| attrs.blend == BlendMode::Normal, |attrs| attrs.blend = blend)
+    }
 
-impl GraphicItems {}
+    /// Trims the item to the part between two fractions of its length.
+    pub fn with_trim(self, start: f32, end: f32) -> GraphicItem {
+        let trim = Trim(start.clamp(0., 1.), end.clamp(0., 1.));
+        self.with_attributes(|_| true, |attrs| attrs.trim = Some(attrs.trim.map_or(trim, |inner| inner.then(&trim))))
+    }
+
+    pub fn with_id(self, id: &str) -> GraphicItem {
+        self.with_attributes(|attrs| attrs.metadata.id.is_none(), |attrs| attrs.metadata.id = Some(id.to_string()))
+    }
+
+    pub fn with_class(self, class: &str) -> GraphicItem {
+        self.with_attributes(|_| true, |attrs| attrs.metadata.classes.push(class.to_string()))
+    }
+
+    /// Adds a `data-<name>` attribute.
+    pub fn with_data(self, name: &str, value: &str) -> GraphicItem {
+        self.with_attributes(|attrs| attrs.metadata.data.iter().all(|(n, _)| n != name), |attrs| attrs.metadata.data.push((name.to_string(), value.to_string())))
+    }
+
+    pub fn with_title(self, title: &str) -> GraphicItem {
+        self.with_attributes(|attrs| attrs.metadata.title.is_none(), |attrs| attrs.metadata.title = Some(title.to_string()))
+    }
+
+    /// The outline of a shape as a path, placed where it is drawn. None for groups.
+    pub fn to_path(&self) -> Option<PathSegments> {
+        match self {
+            GraphicItem::Line(x1, y1, x2, y2, _) => Some(PathSegments(vec![PathSegment::M(*x1, *y1), PathSegment::L(*x2, *y2)])),
+            GraphicItem::Rect(x, y, w, h, _, _) => Some(PathSegments::rect(*x, *y, *w, *h)),
+            GraphicItem::RoundedRect(x, y, w, h, radii, _, _) => Some(PathSegments::rounded_rect(*x, *y, *w, *h, radii)),
+            GraphicItem::Ellipse(x, y, w, h, _, _) => Some(PathSegments::ellipse(*x, *y, *w, *h)),
+            GraphicItem::Polyline(points, _) => Some(PathSegments::polyline(points)),
+            GraphicItem::Polygon(points, _, _) => Some(PathSegments::polygon(points)),
+            GraphicItem::Path(path, _, _, cache) => {
+                let (x, y) = cache.offset();
+                Some(path.move_path(x, y))
+            }
+            GraphicItem::Group(..) => None,
+        }
+    }
+}
 
 impl GraphicItems {
     pub fn new() -> Self {
         Self(vec![])
     }
 
+    /// The first geometry that no builder can render: a coordinate that is not finite, or a
+    /// path or clip path that is empty or does not start with a move.
+    pub fn check_geometry(&self) -> Result<(), GeometryError> {
+        for item in self.0.iter() {
+            match item {
+                GraphicItem::Group(items, attrs) => {
+                    items.check_geometry()?;
+                    if let Some(clip) = &attrs.clip {
+                        clip.0.check()?;
+                    }
+                    if let Some(mask) = &attrs.mask {
+                        mask.0.check_geometry()?;
+                    }
+                }
+                GraphicItem::Path(..) => item.to_path().map_or(Ok(()), |path| path.check())?,
+                _ if item.to_path().is_some_and(|path| !path.is_finite()) => return Err(GeometryError::NonFinite),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
     pub fn push(&mut self, item: GraphicItem) {
         self.0.push(item)
         // self.0.push(item);
@@ -116,101 +443,45 @@ impl GraphicItems {
     }
 
     pub fn bbox(&self) -> Rectangle {
-        let mut x_min = f32::MAX;
-        let mut y_min = f32::MAX;
-        let mut x_max = f32::MIN;
-        let mut y_max = f32::MIN;
+        self.0.iter().fold(Rectangle::empty(), |bbox, item| bbox.union(&item.bbox()))
+    }
 
-        fn get_stroke_width(stroke: &Stroke) -> f32 {
-            match stroke {
-                Stroke::Strokestyle(w, _) => w / 2.0,
-                Stroke::NoStroke => 0.,
-            }
-        }
+    /// Resolves the trims of all groups into trimmed paths, for backends that cannot trim
+    /// shapes themselves. Trimmed groups keep their other attributes.
+    pub fn apply_trims(&self) -> GraphicItems {
+        self.trim_items(None)
+    }
 
+    fn trim_items(&self, trim: Option<Trim>) -> GraphicItems {
+        let mut ret = vec![];
         for item in self.0.iter() {
-            match item {
-                GraphicItem::Line(x1, y1, x2, y2, stroke) => {
-                    let sw = get_stroke_width(stroke);
-                    x_min = x_min.min(*x1 - sw);
-                    y_min = y_min.min(*y1 - sw);
-                    x_max = x_max.max(*x1 + sw);
-                    y_max = y_max.max(*y1 + sw);
-                    x_min = x_min.min(*x2 - sw);
-                    y_min = y_min.min(*y2 - sw);
-                    x_max = x_max.max(*x2 + sw);
-                    y_max = y_max.max(*y2 + sw);
+            let new_item = match (item, trim) {
+                (GraphicItem::Group(items, attrs), _) => {
+                    let inner = match (attrs.trim, trim) {
+                        (Some(inner), Some(outer)) => Some(inner.then(&outer)),
+                        (inner, outer) => inner.or(outer),
+                    };
+                    let mask = attrs.mask.as_ref().map(|mask| Mask(mask.0.apply_trims(), mask.1.clone()));
+                    GraphicItem::Group(items.trim_items(inner), ItemAttributes { trim: None, mask, ..attrs.clone() })
                 }
-                GraphicItem::Rect(x, y, w, h, stroke, _) => {
-                    let sw = get_stroke_width(stroke);
-                    x_min = x_min.min(*x - sw);
-                    y_min = y_min.min(*y - sw);
-                    x_max = x_max.max(*x + sw);
-                    y_max = y_max.max(*y + sw);
-                    x_min = x_min.min(*x + *w - sw);
-                    y_min = y_min.min(*y + *h - sw);
-                    x_max = x_max.max(*x + *w + sw);
-                    y_max = y_max.max(*y + *h + sw);
+                (_, None) => item.clone(),
+                (GraphicItem::Line(_, _, _, _, stroke) | GraphicItem::Polyline(_, stroke), Some(Trim(start, end))) => {
+                    GraphicItem::Path(item.to_path().unwrap().trim(start, end), stroke.clone(), Fill::NoFill, PathCacheInfo::NoCache)
                 }
-
-                GraphicItem::Ellipse(x, y, w, h, stroke, _) => {
-                    let sw = get_stroke_width(stroke);
-                    x_min = x_min.min(*x - sw);
-                    y_min = y_min.min(*y - sw);
-                    x_max = x_max.max(*x + sw);
-                    y_max = y_max.max(*y + sw);
-                    x_min = x_min.min(*x + *w - sw);
-                    y_min = y_min.min(*y + *h - sw);
-                    x_max = x_max.max(*x + *w + sw);
-                    y_max = y_max.max(*y + *h + sw);
-                }
-
-                GraphicItem::Path(path, stroke, _, _) => {
-                    let sw = get_stroke_width(stroke);
-
-                    for segment in path.0.iter() {
-                        match segment {
-                            PathSegment::M(x, y) | PathSegment::L(x, y) => {
-                                x_min = x_min.min(*x - sw);
-                                y_min = y_min.min(*y - sw);
-                                x_max = x_max.max(*x + sw);
-                                y_max = y_max.max(*y + sw);
-                            }
-                            PathSegment::Q(x1, y1, x, y) => {
-                                x_min = x_min.min(*x - sw);
-                                y_min = y_min.min(*y - sw);
-                                x_max = x_max.max(*x + sw);
-                                y_max = y_max.max(*y + sw);
-                                x_min = x_min.min(*x1 - sw);
-                                y_min = y_min.min(*y1 - sw);
-                                x_max = x_max.max(*x1 + sw);
-                                y_max = y_max.max(*y1 + sw);
-                            }
-                            PathSegment::C(x1, y1, x2, y2, x, y) => {
-                                x_min = x_min.min(*x);
-                                y_min = y_min.min(*y);
-                                x_max = x_max.max(*x);
-                                y_max = y_max.max(*y);
-                                x_min = x_min.min(*x1);
-                                y_min = y_min.min(*y1);
-                                x_max = x_max.max(*x1);
-                                y_max = y_max.max(*y1);
-                                x_min = x_min.min(*x2);
-                                y_min = y_min.min(*y2);
-                                x_max = x_max.max(*x2);
-                                y_max = y_max.max(*y2);
-                            }
-                            PathSegment::Z => {}
-                        }
-                    }
+                (
+                    GraphicItem::Rect(_, _, _, _, stroke, fill)
+                    | GraphicItem::RoundedRect(_, _, _, _, _, stroke, fill)
+                    | GraphicItem::Ellipse(_, _, _, _, stroke, fill)
+                    | GraphicItem::Polygon(_, stroke, fill)
+                    | GraphicItem::Path(_, stroke, fill, _),
+                    Some(Trim(start, end)),
+                ) => {
+                    GraphicItem::Path(item.to_path().unwrap().trim(start, end), stroke.clone(), fill.clone(), PathCacheInfo::NoCache)
                 }
-            }
+            };
+            ret.push(new_item);
         }
-        // println!(
-        //     "x_min:{}, y_min:{}, x_max:{}, y_max:{}",
-        //     x_min, y_min, x_max, y_max
-        // );
-        Rectangle(x_min, y_min, x_max, y_max)
+        GraphicItems(ret)
     }
 
     pub fn move_items(&self, move_x: f32, move_y: f32) -> GraphicItems {
@@ -219,27 +490,49 @@ impl GraphicItems {
             let new_item = match item {
                 GraphicItem::Line(x1, y1, x2, y2, stroke) => GraphicItem::Line(x1 + move_x, y1 + move_y, x2 + move_x, y2 + move_y, stroke.clone()),
                 GraphicItem::Rect(x, y, w, h, stroke, fill) => GraphicItem::Rect(x + move_x, y + move_y, *w, *h, stroke.clone(), fill.clone()),
+                GraphicItem::RoundedRect(x, y, w, h, radii, stroke, fill) => GraphicItem::RoundedRect(x + move_x, y + move_y, *w, *h, *radii, stroke.clone(), fill.clone()),
                 GraphicItem::Ellipse(x, y, w, h, stroke, fill) => GraphicItem::Ellipse(*x + move_x, y + move_y, *w, *h, stroke.clone(), fill.clone()),
+                GraphicItem::Polyline(points, stroke) => GraphicItem::Polyline(points.iter().map(|p| Point(p.0 + move_x, p.1 + move_y)).collect(), stroke.clone()),
+                GraphicItem::Polygon(points, stroke, fill) => GraphicItem::Polygon(points.iter().map(|p| Point(p.0 + move_x, p.1 + move_y)).collect(), stroke.clone(), fill.clone()),
                 GraphicItem::Path(path, stroke, fill, cache) => GraphicItem::Path(path.move_path(move_x, move_y), stroke.clone(), fill.clone(), cache.clone()),
+                GraphicItem::Group(items, attrs) => GraphicItem::Group(items.move_items(move_x, move_y), attrs.move_attributes(move_x, move_y)),
             };
             ret.push(new_item);
         }
 
-        return GraphicItems(ret);
+        GraphicItems(ret)
     }
 
     pub fn scale_items(&self, scale_x: f32, scale_y: f32, scale_stroke: f32) -> GraphicItems {
         let mut ret = vec![];
+        // Mirroring keeps sizes positive by moving the corner to the other side
+        let span = |start: f32, size: f32, scale: f32| if scale < 0. { (start * scale + size * scale, -size * scale) } else { (start * scale, size * scale) };
 
         for item in self.0.iter() {
             let new_item = match item {
                 GraphicItem::Line(x1, y1, x2, y2, stroke) => GraphicItem::Line(x1 * scale_x, y1 * scale_y, x2 * scale_x, y2 * scale_y, stroke.scale(scale_stroke)),
-                GraphicItem::Rect(x, y, w, h, stroke, fill) => GraphicItem::Rect(x * scale_x, y * scale_y, w * scale_x, h * scale_y, stroke.scale(scale_stroke), fill.clone()),
-                GraphicItem::Ellipse(x, y, w, h, stroke, fill) => GraphicItem::Ellipse(x * scale_x, y * scale_y, w * scale_x, h * scale_y, stroke.clone(), fill.clone()),
-                GraphicItem::Path(path, stroke, fill, cache) => GraphicItem::Path(path.scale_path(scale_x, scale_y), stroke.scale(scale_stroke), fill.clone(), cache.clone()),
+                GraphicItem::Rect(x, y, w, h, stroke, fill) => {
+                    let ((x, w), (y, h)) = (span(*x, *w, scale_x), span(*y, *h, scale_y));
+                    GraphicItem::Rect(x, y, w, h, stroke.scale(scale_stroke), fill.clone())
+                }
+                // Corners stay circular only under uniform scaling
+                GraphicItem::RoundedRect(x, y, w, h, radii, stroke, fill) if scale_x == scale_y && scale_x > 0. => {
+                    GraphicItem::RoundedRect(x * scale_x, y * scale_y, w * scale_x, h * scale_y, radii.scale(scale_x), stroke.scale(scale_stroke), fill.clone())
+                }
+                GraphicItem::RoundedRect(_, _, _, _, _, stroke, fill) => {
+                    GraphicItem::Path(item.to_path().unwrap().scale_path(scale_x, scale_y), stroke.scale(scale_stroke), fill.clone(), PathCacheInfo::NoCache)
+                }
+                GraphicItem::Ellipse(x, y, w, h, stroke, fill) => {
+                    let ((x, w), (y, h)) = (span(*x, *w, scale_x), span(*y, *h, scale_y));
+                    GraphicItem::Ellipse(x, y, w, h, stroke.scale(scale_stroke), fill.clone())
+                }
+                GraphicItem::Polyline(points, stroke) => GraphicItem::Polyline(points.iter().map(|p| Point(p.0 * scale_x, p.1 * scale_y)).collect(), stroke.scale(scale_stroke)),
+                GraphicItem::Polygon(points, stroke, fill) => GraphicItem::Polygon(points.iter().map(|p| Point(p.0 * scale_x, p.1 * scale_y)).collect(), stroke.scale(scale_stroke), fill.clone()),
+                GraphicItem::Path(path, stroke, fill, cache) => GraphicItem::Path(path.scale_path(scale_x, scale_y), stroke.scale(scale_stroke), fill.clone(), cache.scale(scale_x, scale_y)),
+                GraphicItem::Group(items, attrs) => GraphicItem::Group(items.scale_items(scale_x, scale_y, scale_stroke), attrs.scale_attributes(scale_x, scale_y, scale_stroke)),
             };
             ret.push(new_item);
         }
-        return GraphicItems(ret);
+        GraphicItems(ret)
     }
 }
@@ -1,29 +1,44 @@
 #![allow(unused)]
+pub mod animation;
 pub mod builder;
+pub mod color;
 pub mod core;
+pub mod dedup;
+pub mod document;
 pub mod error;
 // pub mod font;
 pub mod glyphs;
+pub mod hit;
 pub mod item;
 pub mod path;
 pub mod prelude;
+pub mod scene;
+pub mod validate;
 
 #[cfg(test)]
 mod tests {
 
     use std::fs;
 
+    use ttf_parser::GlyphId;
+
     use super::{
-        builder::{fuse::FuseBuilder, svg::SvgBuilder, GraphicBuilder, TestBuilder},
-        glyphs::cadenza::*,
+        animation::{Animation, Easing, Track},
+        builder::{fuse::FuseBuilder, svg::SvgBuilder, BuilderOptions, GraphicBuilder, Padding, SizeUnit, TestBuilder},
+        core::core::{Point, Rectangle, Units, YAxis},
+        document::{Document, Paper, Placement},
+        error::{GeometryError, GraphicsError},
+        glyphs::{FontMetrics, GlyphSource},
         item::{
             Color::{Black, Blue, Lime, Purple, Red, White, RGBA},
             Fill::{Fillstyle, NoFill},
-            GraphicItem::{Ellipse, Line, Path, Rect},
-            GraphicItems,
+            GraphicItem,
+            GraphicItem::{Ellipse, Group, Line, Path, Polygon, Polyline, Rect, RoundedRect},
+            BlendMode, CornerRadii, GraphicItems, Mask, MaskType, PathCacheInfo,
             Stroke::{NoStroke, Strokestyle},
         },
         path::{
+            PathSegment,
             PathSegment::{C, L, M, Q, Z},
             PathSegments,
         },
@@ -52,7 +67,8 @@ mod tests {
             Ellipse(50., 0., 50., 50., Strokestyle(10., Purple), Fillstyle(Red)),
         ]);
         items = items.scale_items(2., 2., 2.);
-        let svg = SvgBuilder::new().build(items).unwrap();
+        assert!(matches!(items.0[1], Ellipse(100., 0., 100., 100., Strokestyle(20., _), _)));
+        let svg = SvgBuilder::new().build(items, None).unwrap();
         std::fs::write("./output/scale.svg", svg);
     }
 
@@ -62,7 +78,7 @@ mod tests {
             Rect(0., 0., 50., 50., Strokestyle(10., Lime), Fillstyle(Blue)),
             Ellipse(50., 0., 50., 50., Strokestyle(10., Purple), Fillstyle(Red)),
         ]);
-        TestBuilder::new().build(items).unwrap();
+        TestBuilder::new().build(items, None).unwrap();
     }
 
     #[test]
@@ -72,7 +88,7 @@ mod tests {
             Ellipse(50., 0., 50., 50., Strokestyle(10., Purple), Fillstyle(Red)),
             Line(0., 50., 100., 0., Strokestyle(5., Red)),
         ]);
-        let svg = SvgBuilder::new().build(items).unwrap();
+        let svg = SvgBuilder::new().build(items, None).unwrap();
         std::fs::write("./output/test.svg", svg);
     }
 
@@ -84,10 +100,281 @@ mod tests {
             Fillstyle(Blue),
             crate::prelude::PathCacheInfo::NoCache,
         )]);
-        let svg = SvgBuilder::new().build(items).unwrap();
+        let svg = SvgBuilder::new().build(items, None).unwrap();
         std::fs::write("./output/path.svg", svg).unwrap();
     }
 
+    #[test]
+    fn svg_clip_and_mask() {
+        let clip = PathSegments(vec![M(0., 0.), L(50., 0.), L(50., 50.), L(0., 50.), Z]);
+        let mask = Mask(GraphicItems(vec![Rect(0., 0., 100., 25., NoStroke, Fillstyle(White))]), MaskType::Luminance);
+        let items = GraphicItems(vec![
+            Rect(0., 0., 100., 100., NoStroke, Fillstyle(Blue)).with_clip(clip),
+            Ellipse(0., 0., 100., 100., NoStroke, Fillstyle(Red)).with_mask(mask),
+        ]);
+        let bbox = items.0[0].bbox();
+        assert_eq!((bbox.0, bbox.1, bbox.2, bbox.3), (0., 0., 50., 50.));
+
+        let svg = SvgBuilder::new().build(items.clone(), None).unwrap();
+        assert!(svg.contains("<clipPath id=\"clip-0\""));
+        assert!(svg.contains("<g clip-path=\"url(#clip-0)\">"));
+        assert!(svg.contains("<mask id=\"mask-1\""));
+        assert!(svg.contains("<g mask=\"url(#mask-1)\">"));
+
+        let fuse = FuseBuilder::new().build(items, None).unwrap();
+        assert!(fuse.contains("layer_1 = layer_1:ChannelOpOf(\"Multiply\", layer_1_clip"));
+        assert!(fuse.contains("MergeLayer(out, layer_2, \"Normal\", 1)"));
+    }
+
+    #[test]
+    fn svg_opacity_and_blend() {
+        let items = GraphicItems(vec![
+            Rect(0., 0., 50., 50., Strokestyle(5., Lime), Fillstyle(Blue)).with_opacity(0.5),
+            Ellipse(25., 0., 50., 50., NoStroke, Fillstyle(Red)).with_blend(BlendMode::Multiply),
+        ]);
+        let svg = SvgBuilder::new().build(items.clone(), None).unwrap();
+        assert!(svg.contains("<g opacity=\"0.5\">"));
+        assert!(svg.contains("<g style=\"mix-blend-mode:multiply\">"));
+
+        let fuse = FuseBuilder::new().build(items, None).unwrap();
+        assert!(fuse.contains("MergeLayer(out, layer_1, \"Normal\", 0.5)"));
+        assert!(fuse.contains("MergeLayer(out, layer_2, \"Multiply\", 1)"));
+    }
+
+    #[test]
+    fn svg_trim() {
+        let items = GraphicItems(vec![Rect(0., 0., 10., 10., Strokestyle(1., Black), NoFill).with_trim(0., 0.25)]);
+        let svg = SvgBuilder::new().build(items.clone(), None).unwrap();
+        assert!(svg.contains("d=\"M 0.5 0.5 L 10.5 0.5 \""));
+        let svg = SvgBuilder { dash_trim: true, ..SvgBuilder::new() }.build(items, None).unwrap();
+        assert!(svg.contains("pathLength=\"1\" stroke-dasharray=\"0.25 1\" stroke-dashoffset=\"-0\""));
+    }
+
+    #[test]
+    fn svg_shapes() {
+        let items = GraphicItems(vec![
+            RoundedRect(0., 0., 40., 20., CornerRadii::uniform(30.), NoStroke, Fillstyle(Blue)),
+            RoundedRect(0., 30., 40., 20., CornerRadii(5., 0., 5., 0.), NoStroke, Fillstyle(Blue)),
+            Polyline(vec![Point(0., 60.), Point(10., 70.), Point(20., 60.)], Strokestyle(1., Black)),
+            Polygon(crate::path::shape::regular_polygon(50., 50., 10., 3, 0.), NoStroke, Fillstyle(Red)),
+        ]);
+        let svg = SvgBuilder::new().build(items.clone(), None).unwrap();
+        assert!(svg.contains("rx=\"10\" ry=\"10\""));
+        assert!(svg.contains("<path d=\"M 5.5 30 L 40.5 30 L 40.5 45 A 5 5 0 0 1 35.5 50 "));
+        assert!(svg.contains("<polyline points=\"0.5,60 10.5,70 20.5,60\" stroke=\"black\" stroke-width=\"1\" fill=\"none\""));
+        assert!(svg.contains("<polygon points=\"50.5,40 "));
+
+        // Non-uniform scaling turns rounded corners into elliptical arcs
+        let scaled = items.scale_items(2., 1., 1.);
+        assert!(matches!(scaled.0[0], Path(..)));
+        let bbox = scaled.0[0].bbox();
+        assert!(bbox.0.abs() < 1e-3 && bbox.1.abs() < 1e-3 && bbox.2 == 80. && bbox.3 == 20.);
+    }
+
+    #[test]
+    fn svg_viewport() {
+        let items = GraphicItems((0..100).map(|i| Rect(i as f32 * 20., 0., 10., 10., NoStroke, Fillstyle(Blue))).collect());
+        let options = BuilderOptions { viewport: Some(Rectangle(100., 0., 150., 10.)), ..Default::default() };
+        let svg = SvgBuilder::new().build(items, Some(options)).unwrap();
+        assert!(svg.contains("viewBox=\"0 0 50 10\""));
+        assert_eq!(svg.matches("<rect").count(), 3 + 1);
+
+        // Kept when a keyframe moves it into the viewport
+        let animation = Animation { translate: Some(Track::new().key(0., (0., 0.), Easing::Linear).key(1., (200., 0.), Easing::Linear)), ..Default::default() };
+        let items = GraphicItems(vec![Rect(-100., 0., 10., 10., NoStroke, Fillstyle(Red)).with_animation(animation), Rect(300., 0., 10., 10., NoStroke, Fillstyle(Blue))]);
+        let options = BuilderOptions { viewport: Some(Rectangle(100., 0., 150., 10.)), ..Default::default() };
+        assert_eq!(items.cull(&Rectangle(100., 0., 150., 10.)).0.len(), 1);
+        let svg = SvgBuilder::new().build(items.clone(), Some(options)).unwrap();
+        assert!(svg.contains("fill=\"red\"") && !svg.contains("fill=\"blue\""));
+        assert_eq!(FuseBuilder::new().build(items, Some(options)).unwrap().matches("line = Shape()").count(), 1);
+    }
+
+    #[test]
+    fn svg_options() {
+        let items = GraphicItems(vec![
+            Rect(10., 20., 1. / 3., 10., NoStroke, Fillstyle(Blue)),
+            Path(PathSegments(vec![M(10., 20.), L(12.005, 30.)]), Strokestyle(0.5, Black), NoFill, PathCacheInfo::NoCache),
+        ]);
+        let mut builder = SvgBuilder { precision: Some(2), keep_origin: true, padding: Padding(1., 2., 3., 4.), background: Some(White), pretty: false, bounding_rect: false, ..SvgBuilder::new() };
+        let svg = builder.build(items.clone(), None).unwrap();
+        assert!(!svg.contains('\n'));
+        assert!(svg.contains("viewBox=\"5.75 18.75 8.51 14.5\""));
+        assert!(svg.contains("<rect x=\"5.75\" y=\"18.75\" width=\"8.51\" height=\"14.5\" fill=\"white\"/><rect x=\"10\" y=\"20\" width=\"0.33\""));
+        assert!(svg.contains("d=\"M 10 20 L 12.01 30 \""));
+        assert_eq!(svg.matches("<rect").count(), 2);
+
+        // An explicit view box is written as given, and full precision is the default
+        let svg = SvgBuilder { view_box: Some(Rectangle(0., 0., 100., 50.)), ..SvgBuilder::new() }.build(items, None).unwrap();
+        assert!(svg.contains("viewBox=\"0 0 100 50\""));
+        assert!(svg.contains("width=\"0.33333334\""));
+        assert!(svg.contains("\n    <rect x=\"0\" y=\"0\" width=\"100\" height=\"50\" stroke=\"transparent\""));
+    }
+
+    #[test]
+    fn svg_metadata() {
+        let note = Ellipse(0., 0., 10., 8., NoStroke, Fillstyle(Black)).with_id("note-1.25").with_class("note").with_class("tied").with_data("pitch", "C#4").with_title("C# <4>");
+        let items = GraphicItems(vec![note]);
+        let svg = SvgBuilder { precision: Some(1), ..SvgBuilder::new() }.build(items.clone(), None).unwrap();
+        assert!(svg.contains("<ellipse cx=\"5\" cy=\"4\" rx=\"5\" ry=\"4\" id=\"note-1.25\" class=\"note tied\" data-pitch=\"C#4\""));
+        assert!(svg.contains("<title>C# &lt;4></title>") && !svg.contains("<g"));
+        // Other effects keep the group
+        let faded = GraphicItems(vec![Ellipse(0., 0., 10., 8., NoStroke, Fillstyle(Black)).with_id("note").with_opacity(0.5)]);
+        assert!(SvgBuilder::new().build(faded, None).unwrap().contains("<g id=\"note\" opacity=\"0.5\">"));
+        // Precision applies to geometry only
+        let frequency = GraphicItems(vec![Ellipse(0.25, 0., 10., 8., NoStroke, Fillstyle(Black)).with_data("frequency", "261.625565").with_title("261.625565 Hz")]);
+        let svg = SvgBuilder { precision: Some(1), ..SvgBuilder::new() }.build(frequency, None).unwrap();
+        assert!(svg.contains("data-frequency=\"261.625565\"") && svg.contains("<title>261.625565 Hz</title>"));
+        assert!(svg.contains("cx=\"5\" cy=\"4\" rx=\"5\" ry=\"4\""));
+
+        // Carried through scene files, and ignored by the Fuse builder
+        let scene = crate::scene::Scene::from_items(&items);
+        assert!(scene.to_json().contains("\"data\": {\n"));
+        let Group(_, attrs) = &scene.to_items().unwrap().0[0] else { panic!() };
+        assert_eq!(attrs.metadata.data, vec![("pitch".to_string(), "C#4".to_string())]);
+        assert!(!FuseBuilder::new().build(items, None).unwrap().contains("note"));
+    }
+
+    #[test]
+    fn svg_share_paths() {
+        let head = |x: f32, y: f32| Path(PathSegments(vec![M(x, y), C(x + 2., y - 3., x + 8., y - 3., x + 10., y), Z]), NoStroke, Fillstyle(Black), PathCacheInfo::NoCache);
+        let items = GraphicItems(vec![
+            head(0., 0.),
+            Group(GraphicItems(vec![head(20.1, 5.3), Rect(0., 0., 10., 10., NoStroke, Fillstyle(Red))]), Default::default()),
+            head(40.7, -2.2),
+            Path(PathSegments(vec![M(0., 0.), L(5., 5.)]), Strokestyle(1., Black), NoFill, PathCacheInfo::Cache("shared-1".to_string(), 3., 3.)),
+            Path(PathSegments(vec![M(0., 0.), L(5., 6.)]), Strokestyle(1., Black), NoFill, PathCacheInfo::NoCache),
+        ]);
+        let shared = items.share_paths();
+        let Path(_, _, _, PathCacheInfo::Cache(tag, x, y)) = &shared.0[2] else { panic!() };
+        // The caller's tag is left alone
+        assert_eq!((tag.as_str(), *x, *y), ("shared-2", 40.7, -2.2));
+        assert!(matches!(&shared.0[4], Path(_, _, _, PathCacheInfo::NoCache)));
+        assert!(shared.validate().is_empty());
+
+        let svg = SvgBuilder { share_paths: true, ..SvgBuilder::new() }.build(items.clone(), None).unwrap();
+        assert_eq!(svg.matches("<use").count(), 4);
+        assert_eq!(svg.matches(" C ").count(), 1);
+        assert_eq!(SvgBuilder::new().build(items, None).unwrap().matches("<use").count(), 1);
+
+        // Generated ids stay clear of the ids of items
+        let clipped = Rect(0., 0., 10., 10., NoStroke, Fillstyle(Red)).with_clip(PathSegments(vec![M(0., 0.), L(5., 0.), L(5., 5.), Z]));
+        let items = GraphicItems(vec![head(0., 0.), head(20., 0.).with_id("shared-1"), clipped, Rect(0., 0., 1., 1., NoStroke, NoFill).with_id("clip-2")]);
+        let svg = SvgBuilder { share_paths: true, ..SvgBuilder::new() }.build(items, None).unwrap();
+        assert!(svg.contains("href=\"#shared-2\"") && !svg.contains("href=\"#shared-1\""));
+        assert!(svg.contains("<clipPath id=\"clip-r1-2\"") && svg.contains("clip-path=\"url(#clip-r1-2)\""));
+    }
+
+    #[test]
+    fn document_pages() {
+        let page = GraphicItems(vec![Rect(5., 5., 40., 20., NoStroke, Fillstyle(Black))]);
+        let document = Document { margins: Padding::uniform(10.), ..Document::new(Paper::A4) }.page(page.clone()).page(page.clone());
+        assert_eq!(document.page_items(0).bbox(), Rectangle(10., 10., 50., 30.));
+        let pages = document.build(&mut SvgBuilder::new()).unwrap();
+        assert_eq!(pages.len(), 2);
+        assert!(pages[0].contains("width=\"210mm\" height=\"297mm\" viewBox=\"0 0 210 297\""));
+        assert!(pages[0].contains("<rect x=\"10\" y=\"10\" width=\"40\" height=\"20\""));
+
+        let fit = Document { landscape: true, placement: Placement::Fit, margins: Padding::uniform(0.5), ..Document::new(Paper::Letter) }.page(page.clone());
+        assert_eq!(fit.page_size(), (11., 8.5, SizeUnit::Inch));
+        assert_eq!(fit.page_items(0).bbox(), Rectangle(0.5, 1.75, 10.5, 6.75));
+        let stacked = Document { pages: vec![fit.pages[0].clone(); 3], ..fit.clone() }.build_stacked(&mut SvgBuilder::new(), 0.25).unwrap();
+        assert!(stacked.contains("width=\"11in\" height=\"26in\""));
+        assert_eq!(stacked.matches("<clipPath").count(), 3);
+
+        // Fusion gets fractions of the page width, whatever the unit and resolution
+        let custom = Document { margins: Padding::uniform(16.), ..Document::new(Paper::Custom(256., 128., SizeUnit::Millimeter)) }.page(page);
+        let fuse = custom.build(&mut FuseBuilder::new()).unwrap();
+        assert!(fuse[0].contains("line:MoveTo(0.0625, 0.359375)\n\tline:LineTo(0.21875, 0.359375)\n\tline:LineTo(0.21875, 0.4375)"));
+        assert_eq!(Document { dpi: 300., ..custom.clone() }.build(&mut FuseBuilder::new()).unwrap(), fuse);
+        let rem = BuilderOptions { size_unit: SizeUnit::Rem, ..Default::default() };
+        assert_eq!(FuseBuilder::new().build(custom.page_items(0), Some(rem)).unwrap(), FuseBuilder::new().build(custom.page_items(0), None).unwrap());
+    }
+
+    #[test]
+    fn y_axis() {
+        // Items are y down everywhere; the Fuse builder flips them for Fusion
+        let items = GraphicItems(vec![Path(PathSegments(vec![M(0., 0.), L(10., 0.), L(0., 4.), Z]), NoStroke, Fillstyle(Black), PathCacheInfo::NoCache)]);
+        assert!(SvgBuilder::new().build(items.clone(), None).unwrap().contains("d=\"M 0 0 L 10 0 L 0 4 Z \""));
+        let fuse = FuseBuilder::new().build(items, None).unwrap();
+        assert!(fuse.contains("line:MoveTo(0, 4)\n\tline:LineTo(10, 4)\n\tline:LineTo(0, 0)"));
+
+        // Cached paths are drawn at their offset
+        let cached = GraphicItems(vec![
+            Path(PathSegments(vec![M(0., 0.), L(2., 0.), L(0., 2.), Z]), NoStroke, Fillstyle(Black), PathCacheInfo::Cache("dot".to_string(), 0., 0.)),
+            Path(PathSegments(vec![M(0., 0.), L(2., 0.), L(0., 2.), Z]), NoStroke, Fillstyle(Black), PathCacheInfo::Cache("dot".to_string(), 8., 2.)),
+        ]);
+        let fuse = FuseBuilder::new().build(cached, None).unwrap();
+        assert!(fuse.contains("line:MoveTo(0, 4)\n\tline:LineTo(2, 4)\n\tline:LineTo(0, 2)"));
+        assert!(fuse.contains("line:MoveTo(8, 2)\n\tline:LineTo(10, 2)\n\tline:LineTo(8, 0)"));
+
+        // Mirroring keeps sizes positive
+        let mirrored = GraphicItems(vec![Rect(0., 2., 10., 4., NoStroke, Fillstyle(Black))]).scale_items(1., -1., 1.);
+        assert!(matches!(mirrored.0[0], Rect(x, y, w, h, ..) if (x, y, w, h) == (0., -6., 10., 4.)));
+
+        let scene = crate::scene::Scene::from_json(r#"{"version": 1, "y_axis": "up", "items": [{"type": "rect", "x": 0, "y": 2, "width": 10, "height": 4}]}"#).unwrap();
+        assert_eq!(scene.to_items().unwrap().bbox(), Rectangle(0., -6., 10., -2.));
+        assert!(!crate::scene::Scene::from_items(&mirrored).to_json().contains("y_axis"));
+    }
+
+    #[test]
+    fn svg_animation() {
+        let animation = Animation {
+            translate: Some(Track::new().key(1., (0., 0.), Easing::EaseInOut).key(2., (90., 0.), Easing::Linear)),
+            scale: Some(Track::new().key(0., (1., 1.), Easing::Hold).key(2., (2., 2.), Easing::Linear)),
+            pivot: Point(5., 5.),
+            opacity: Some(Track::new().key(0., 0., Easing::Linear).key(1., 1., Easing::Linear)),
+            fill: Some(Track::new().key(0., Red, Easing::Linear).key(2., Blue, Easing::Linear)),
+            trim: Some(Track::new().key(0., (0., 0.), Easing::Linear).key(2., (0., 1.), Easing::Linear)),
+            ..Default::default()
+        };
+        let items = GraphicItems(vec![Rect(0., 0., 10., 10., Strokestyle(1., Black), Fillstyle(Red)).with_animation(animation)]);
+        let svg = SvgBuilder::new().build(items, None).unwrap();
+        // Sized for the last frame: scaled twice about the pivot, and moved 90 to the right
+        assert!(svg.contains("viewBox=\"0 0 106.5 22\""));
+        assert!(svg.contains("<animate attributeName=\"opacity\" begin=\"0s\" dur=\"1s\" values=\"0;1\" keyTimes=\"0;1\" calcMode=\"spline\" keySplines=\"0 0 1 1\" fill=\"freeze\"/>"));
+        // Held until the first keyframe, then eased
+        assert!(svg.contains("type=\"translate\" begin=\"0s\" dur=\"2s\" values=\"0 0;0 0;90 0\" keyTimes=\"0;0.5;1\" calcMode=\"spline\" keySplines=\"0 0 1 1;0.42 0 0.58 1\""));
+        // A held value jumps at the next keyframe
+        assert!(svg.contains("type=\"scale\" additive=\"sum\" begin=\"0s\" dur=\"2s\" values=\"1 1;1 1;2 2\" keyTimes=\"0;1;1\""));
+        assert!(svg.contains("<g transform=\"translate(-5.5 -11)\">"));
+        assert!(svg.contains("fill=\"red\" pathLength=\"1\" stroke-dasharray=\"1 1\""));
+        assert!(svg.contains("attributeName=\"fill\" begin=\"0s\" dur=\"2s\" values=\"red;blue\""));
+        assert!(svg.contains("attributeName=\"stroke-dasharray\" begin=\"0s\" dur=\"2s\" values=\"0 1;1 1\""));
+
+        // Precision rounds the coordinates, not the timings, opacities and scale factors
+        let animation = Animation {
+            translate: Some(Track::new().key(0., (0.25, 0.), Easing::EaseInOut).key(0.4, (9.75, 0.), Easing::Linear)),
+            scale: Some(Track::new().key(0., (1., 1.), Easing::Linear).key(0.3, (1.5, 1.5), Easing::Linear)),
+            opacity: Some(Track::new().key(0., 0.25, Easing::Linear).key(0.1, 1., Easing::Linear)),
+            ..Default::default()
+        };
+        let items = GraphicItems(vec![Rect(0., 0., 10., 10., NoStroke, Fillstyle(Red)).with_animation(animation)]);
+        let svg = SvgBuilder { precision: Some(0), ..SvgBuilder::new() }.build(items, None).unwrap();
+        assert!(svg.contains("type=\"translate\" begin=\"0s\" dur=\"0.4s\" values=\"0 0;10 0\" keyTimes=\"0;1\" calcMode=\"spline\" keySplines=\"0.42 0 0.58 1\""));
+        assert!(svg.contains("type=\"scale\" additive=\"sum\" begin=\"0s\" dur=\"0.3s\" values=\"1 1;1.5 1.5\""));
+        assert!(svg.contains("attributeName=\"opacity\" begin=\"0s\" dur=\"0.1s\" values=\"0.25;1\""));
+    }
+
+    #[test]
+    fn builder_errors() {
+        let build = |item: GraphicItem| SvgBuilder::new().build(GraphicItems(vec![item]), None);
+        let not_finite = build(Rect(0., f32::NAN, 10., 10., NoStroke, Fillstyle(Blue)));
+        assert!(matches!(not_finite, Err(GraphicsError::InvalidGeometry(GeometryError::NonFinite))));
+        let no_move = build(Path(PathSegments(vec![L(10., 10.)]), Strokestyle(1., Black), NoFill, PathCacheInfo::NoCache));
+        assert!(matches!(no_move, Err(GraphicsError::InvalidGeometry(GeometryError::MissingMove))));
+        let empty = Rect(0., 0., 10., 10., NoStroke, Fillstyle(Blue)).with_clip(PathSegments(vec![]));
+        assert!(matches!(build(empty), Err(GraphicsError::InvalidGeometry(GeometryError::EmptyPath))));
+
+        let trimmed = Line(0., 0., 10., 0., Strokestyle(1., Black)).with_animation(Animation { trim: Some(Track::new().key(0., (0., 0.), Easing::Linear)), ..Default::default() });
+        let fuse = FuseBuilder::new().build(GraphicItems(vec![trimmed]), None);
+        assert!(matches!(fuse, Err(GraphicsError::Unsupported { backend: "fuse", .. })));
+
+        let parsed = "M 0 0 L 10 x".parse::<PathSegments>();
+        assert!(matches!(parsed, Err(GraphicsError::Parse { what: "path data", position: 11, .. })));
+        assert_eq!(parsed.unwrap_err().to_string(), "invalid path data at position 11: unexpected 'x'");
+        assert!(matches!(PathSegments::from_json("[{\"M\": [0, 0]"), Err(GraphicsError::Json(_))));
+    }
+
     #[test]
     fn test_fuse() {
         let mut items = GraphicItems(vec![Path(
@@ -99,73 +386,317 @@ mod tests {
 
         let factor = 0.001;
         items = items.scale_items(factor, factor, factor);
-        let fuse = FuseBuilder::new().build(items).unwrap();
+        let fuse = FuseBuilder::new().build(items, None).unwrap();
         std::fs::write("./output/rust_test_fuse.fuse", &fuse);
         std::fs::write("C:/Users/Cambiata MusikProd/AppData/Roaming/Blackmagic Design/Fusion/Fuses/rust_test_fuse.fuse", &fuse);
     }
 
+    #[test]
+    fn fuse_animation() {
+        let animation = Animation {
+            translate: Some(Track::new().key(0., (0., 0.), Easing::EaseOut).key(1., (10., 0.), Easing::Linear)),
+            opacity: Some(Track::new().key(0., 0., Easing::Hold).key(1., 1., Easing::Linear)),
+            stroke: Some(Track::new().key(0., Black, Easing::Linear).key(1., Red, Easing::Linear)),
+            ..Default::default()
+        };
+        let items = GraphicItems(vec![Line(0., 0., 10., 0., Strokestyle(2., Black)).with_animation(animation)]);
+        let fuse = FuseBuilder::new().build(items, None).unwrap();
+        assert!(fuse.contains("local time = req.Time / self.Comp:GetPrefs(\"Comp.FrameFormat.Rate\")"));
+        assert!(fuse.contains("local track_translate_1 = {\n\t\t{0, {0, 0}, {0, 0, 0.58, 1}},\n\t\t{1, {10, 0}, {0, 0, 1, 1}},\n\t}"));
+        assert!(fuse.contains("local transform_1 = GroupTransform(IDENTITY, {1, 1}, translate_1, 1, 1)"));
+        assert!(fuse.contains("{0, {0}, false},"));
+        assert!(fuse.contains("line = TransformShape(line, transform_1)"));
+        assert!(fuse.contains("cs.Color = Pixel(ColorPixel(stroke_1, false))"));
+        assert!(fuse.contains("MergeLayer(out, layer_2, \"Normal\", math.min(math.max(opacity_1[1], 0), 1))"));
+    }
+
     #[test]
     fn test_json() {
         let json = include_str!("../cadenza/cadenza-8.json");
-        let path = PathSegments::from_json(json);
-        let path = path.scale_path(0.1, -0.1);
+        let path = PathSegments::from_json(json).unwrap();
+        let path = Units::new(10., YAxis::Up).to_items(&path);
         let items = GraphicItems(vec![Path(path, NoStroke, Fillstyle(White), crate::prelude::PathCacheInfo::NoCache)]);
-        let items_fuse = items.scale_items(0.002, -0.002, 0.002);
-        let svg = SvgBuilder::new().build(items).unwrap();
+        let items_fuse = items.scale_items(0.002, 0.002, 0.002);
+        let svg = SvgBuilder::new().build(items, None).unwrap();
         std::fs::write("cadenza.svg", svg).unwrap();
-        let fuse = FuseBuilder::new().build(items_fuse).unwrap();
+        let fuse = FuseBuilder::new().build(items_fuse, None).unwrap();
         std::fs::write("./output/cadenza-8.fuse", &fuse).unwrap();
-        std::fs::write("C:/Users/Cambiata MusikProd/AppData/Roaming/Blackmagic Design/Fusion/Fuses/rust_test_fuse.fuse", &fuse).unwrap();
+        std::fs::write("C:/Users/Cambiata MusikProd/AppData/Roaming/Blackmagic Design/Fusion/Fuses/rust_test_fuse.fuse", &fuse);
     }
 
+    #[cfg(feature = "cadenza")]
     #[test]
     fn test_data() {
         // let json = include_str!("../cadenza/cadenza-8.json");
         // let path = PathSegments::from_json(json);
         // let path = path.scale_path(0.1, -0.1);
 
-        let path = PathSegments(CADENZA_CLEF_G.to_vec()).scale_path(1.0, -1.0);
+        let path = Units { per_item_unit: 1., ..crate::glyphs::cadenza::CADENZA_UNITS }.to_items(&PathSegments(crate::glyphs::cadenza::CADENZA_CLEF_G.segments().unwrap().to_vec()));
         let mut items = GraphicItems(vec![Path(path, NoStroke, Fillstyle(White), crate::prelude::PathCacheInfo::NoCache)]);
-        let items_fuse = items.scale_items(0.002, -0.002, 0.002);
-        let svg = SvgBuilder::new().build(items).unwrap();
+        let items_fuse = items.scale_items(0.002, 0.002, 0.002);
+        let svg = SvgBuilder::new().build(items, None).unwrap();
         std::fs::write("./data.svg", svg).unwrap();
-        // let fuse = FuseBuilder::new().build(items_fuse).unwrap();
+        // let fuse = FuseBuilder::new().build(items_fuse, None).unwrap();
         // std::fs::write("./output/cadenza-8.fuse", &fuse).unwrap();
         // std::fs::write("C:/Users/Cambiata MusikProd/AppData/Roaming/Blackmagic Design/Fusion/Fuses/rust_test_fuse.fuse", &fuse).unwrap();
     }
 
+    // Constant names of the Cadenza glyphs with their glyph ids in the font
+    const CADENZA_SOURCES: [(&str, u16); 37] = [
+        ("CADENZA_PAUSE_THIRTYSECOND", 3),
+        ("CADENZA_ACCIDENTAL_SHARP", 5),
+        ("CADENZA_CLEF_G", 8),
+        ("CADENZA_DOT", 16),
+        ("CADENZA_NUMBER_ZERO", 18),
+        ("CADENZA_NUMBER_ONE", 19),
+        ("CADENZA_NUMBER_TWO", 20),
+        ("CADENZA_NUMBER_THREE", 21),
+        ("CADENZA_NUMBER_FOUR", 22),
+        ("CADENZA_NUMBER_FIVE", 23),
+        ("CADENZA_NUMBER_SIX", 24),
+        ("CADENZA_NUMBER_SEVEN", 25),
+        ("CADENZA_NUMBER_EIGHT", 26),
+        ("CADENZA_NUMBER_NINE", 27),
+        ("CADENZA_31", 31),
+        ("CADENZA_CLEF_F", 33),
+        ("CADENZA_CLEF_C", 36),
+        ("CADENZA_TIME_CUT", 37),
+        ("CADENZA_FLAG_EIGHT_DOWN", 43),
+        ("CADENZA_FLAG_EIGTH_UP", 44),
+        ("CADENZA_45", 45),
+        ("CADENZA_55", 55),
+        ("CADENZA_ACCIDENTAL_FLAT", 64),
+        ("CADENZA_TIME_COMMON", 65),
+        ("CADENZA_ACCIDENTAL_NATURAL", 74),
+        ("CADENZA_HEAD_WHOLE", 83),
+        ("CADENZA_PAUSE_WHOLE", 122),
+        ("CADENZA_FLAG_SIXTEENTH_UP", 78),
+        ("CADENZA_FLAG_SIXTEENTH_DOWN", 50),
+        ("CADENZA_PAUSE_QUARTER", 147),
+        ("CADENZA_HEAD_BLACK", 148),
+        ("CADENZA_HEAD_WHITE", 153),
+        ("CADENZA_PAUSE_EIGHTH", 165),
+        ("CADENZA_ACCIDENTAL_DOUBLESHARP", 166),
+        ("CADENZA_PAUSE_HALF", 172),
+        ("CADENZA_ACCIDENTAL_DOUBLEFLAT", 175),
+        ("CADENZA_PAUSE_SIXTEENTH", 176),
+    ];
+
+    fn cadenza_face() -> ttf_parser::Face<'static> {
+        ttf_parser::Face::parse(include_bytes!("../fonts/MTF-Cadence-Fin.ttf"), 0).unwrap()
+    }
+
+    // Encodes the named glyphs of the Cadenza font with its metrics. It rewrites the embedded
+    // data, so it only runs when asked for: `cargo test -- --ignored generate_cadenza_glyph_data`
+    #[test]
+    #[ignore]
+    fn generate_cadenza_glyph_data() {
+        let face = cadenza_face();
+        let outlines: Vec<Vec<PathSegment>> = CADENZA_SOURCES.iter().map(|(_, id)| outline(&face, GlyphId(*id))).collect();
+        let glyphs: Vec<GlyphSource> = CADENZA_SOURCES
+            .iter()
+            .zip(outlines.iter())
+            .map(|((name, id), outline)| GlyphSource { name, text: "", segments: outline, advance: face.glyph_hor_advance(GlyphId(*id)).unwrap() as f32 })
+            .collect();
+        let data = crate::glyphs::encode_glyphs(&font_metrics(&face), &glyphs, &[]).unwrap();
+        fs::write("./src/glyphs/cadenza.bin", data).unwrap();
+    }
+
+    #[cfg(feature = "cadenza")]
     #[test]
-    fn test_json_generate_cadenza_segment_data() {
-        let mut cadenza = "".to_string();
+    fn cadenza_glyph_data() {
+        use crate::glyphs::cadenza::CADENZA;
+        let face = cadenza_face();
+        assert_eq!(CADENZA.font_metrics().unwrap(), font_metrics(&face));
+        assert_eq!(CADENZA.names().unwrap(), CADENZA_SOURCES.map(|(name, _)| name));
+        for (name, id) in CADENZA_SOURCES {
+            assert_eq!(CADENZA.get(name).unwrap().to_string(), PathSegments(outline(&face, GlyphId(id))).to_string());
+            assert_eq!(CADENZA.glyph_metrics(name).unwrap().advance, face.glyph_hor_advance(GlyphId(id)).unwrap() as f32);
+        }
+    }
+
+    // Collects the outline of a TrueType glyph
+    struct Outline(Vec<PathSegment>);
+
+    impl ttf_parser::OutlineBuilder for Outline {
+        fn move_to(&mut self, x: f32, y: f32) {
+            self.0.push(M(x, y));
+        }
+
+        fn line_to(&mut self, x: f32, y: f32) {
+            self.0.push(L(x, y));
+        }
+
+        fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+            self.0.push(Q(x1, y1, x, y));
+        }
+
+        fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+            self.0.push(C(x1, y1, x2, y2, x, y));
+        }
+
+        fn close(&mut self) {
+            self.0.push(Z);
+        }
+    }
 
-        for num in 3..181 {
-            let filename = format!("cadenza-{}", num);
-            let pathname = format!("./cadenza/{}.json", filename);
-            // let json = include_str!("../cadenza/cadenza-8.json");
-            let mut json = fs::read_to_string(&pathname).unwrap();
-            let mut s = format!("// pub const {} : &'static [PathSegment] = &", filename.to_uppercase());
-            s = s.replace("-", "_");
-            s = s.replace("\r", "");
-            s = s.replace("\n", "");
-            s = s.replace("  ", " ");
+    fn outline(face: &ttf_parser::Face, id: GlyphId) -> Vec<PathSegment> {
+        let mut outline = Outline(vec![]);
+        face.outline_glyph(id, &mut outline);
+        outline.0
+    }
 
-            json = json.replace("\r", "");
-            json = json.replace("\n", "");
-            json = json.replace("  ", "==");
-            json = json.replace("==", "");
-            json = json.replace("\"", " ");
-            json = json.replace(" :[", "(");
-            json = json.replace(" : [", "(");
-            json = json.replace("]}", ")");
-            json = json.replace("{ ", " ");
-            s.push_str(&json);
+    fn font_metrics(face: &ttf_parser::Face) -> FontMetrics {
+        FontMetrics { units_per_em: face.units_per_em() as f32, ascender: face.ascender() as f32, descender: face.descender() as f32 }
+    }
 
-            s.push_str(";\r\n\r\n");
+    #[cfg(feature = "ebgaramond")]
+    fn ebgaramond_face() -> ttf_parser::Face<'static> {
+        ttf_parser::Face::parse(include_bytes!("glyphs/EBGaramond-Regular.ttf"), 0).unwrap()
+    }
 
-            cadenza.push_str(&s);
+    // The EB Garamond glyphs of Latin-1 and Latin Extended-A, and the standard ligatures of
+    // those, with the text they stand for and their constant names
+    #[cfg(feature = "ebgaramond")]
+    fn ebgaramond_sources(face: &ttf_parser::Face) -> Vec<(GlyphId, String, String)> {
+        use ttf_parser::gsub::SubstitutionSubtable;
+        use ttf_parser::Tag;
+
+        let mut sources: Vec<(GlyphId, String)> = (0x20..0x7f).chain(0xa0..0x180).filter_map(char::from_u32).map(|c| (face.glyph_index(c).unwrap(), c.to_string())).collect();
+        let gsub = face.tables().gsub.unwrap();
+        for feature in gsub.features.into_iter().filter(|feature| feature.tag == Tag::from_bytes(b"liga")) {
+            for subtable in feature.lookup_indices.into_iter().flat_map(|idx| gsub.lookups.get(idx).unwrap().subtables.into_iter::<SubstitutionSubtable>()) {
+                let SubstitutionSubtable::Ligature(ligatures) = subtable else { continue };
+                for (first, text) in sources.clone() {
+                    let Some(set) = ligatures.coverage.get(first).and_then(|idx| ligatures.ligature_sets.get(idx)) else { continue };
+                    for ligature in set {
+                        let rest: Option<String> = ligature.components.into_iter().map(|id| sources.iter().find(|(glyph, _)| *glyph == id).map(|(_, text)| text.clone())).collect();
+                        if let Some(rest) = rest {
+                            sources.push((ligature.glyph, text.clone() + &rest));
+                        }
+                    }
+                }
+            }
         }
 
-        fs::write("./cadenza/cadenza.rs", cadenza).unwrap();
+        // Constant names from the PostScript glyph names, which for some glyphs are only the code point
+        let renames = [
+            ("uni00A0", "nbspace"),
+            ("uni00AD", "softhyphen"),
+            ("uni00B2", "twosuperior"),
+            ("uni00B3", "threesuperior"),
+            ("uni00B9", "onesuperior"),
+            ("uni03BC.1", "mu"),
+            ("uni0122", "Gcommaaccent"),
+            ("uni0123", "gcommaaccent"),
+            ("uni0136", "Kcommaaccent"),
+            ("uni0137", "kcommaaccent"),
+            ("uni013B", "Lcommaaccent"),
+            ("uni013C", "lcommaaccent"),
+            ("uni0145", "Ncommaaccent"),
+            ("uni0146", "ncommaaccent"),
+            ("uni0156", "Rcommaaccent"),
+            ("uni0157", "rcommaaccent"),
+            ("uni0162", "Tcommaaccent"),
+            ("uni0163", "tcommaaccent"),
+        ];
+        let name = |id: GlyphId, text: &str| {
+            let glyph = face.glyph_name(id).unwrap();
+            let glyph = renames.iter().find(|(from, _)| *from == glyph).map_or(glyph, |(_, to)| to).to_uppercase();
+            let mut chars = text.chars();
+            match (chars.next().unwrap(), chars.next()) {
+                (_, Some(_)) => format!("EBGARAMOND_LIGATURE_{}", glyph.replace('_', "")),
+                (c, None) if c.to_lowercase().ne(c.to_uppercase()) && c.is_uppercase() => format!("EBGARAMOND_UPPER_{}", glyph),
+                (c, None) if c.to_lowercase().ne(c.to_uppercase()) => format!("EBGARAMOND_LOWER_{}", glyph),
+                _ => format!("EBGARAMOND_{}", glyph),
+            }
+        };
+        sources.into_iter().map(|(id, text)| (id, name(id, &text), text)).collect()
+    }
+
+    // Adjustment of a glyph pair by the kern feature of the font; in each lookup the first
+    // subtable that covers the pair applies
+    #[cfg(feature = "ebgaramond")]
+    fn kerning<'a>(face: &'a ttf_parser::Face) -> impl Fn(GlyphId, GlyphId) -> i16 + 'a {
+        use ttf_parser::gpos::{PairAdjustment, PositioningSubtable};
+        use ttf_parser::Tag;
+
+        let gpos = face.tables().gpos.unwrap();
+        let mut lookups: Vec<u16> = gpos.features.into_iter().filter(|feature| feature.tag == Tag::from_bytes(b"kern")).flat_map(|feature| feature.lookup_indices).collect();
+        lookups.sort_unstable();
+        lookups.dedup();
+        move |left, right| {
+            let mut adjustment = 0;
+            for idx in lookups.iter() {
+                for subtable in gpos.lookups.get(*idx).unwrap().subtables.into_iter::<PositioningSubtable>() {
+                    let PositioningSubtable::Pair(pair) = subtable else { continue };
+                    let Some(coverage_idx) = pair.coverage().get(left) else { continue };
+                    let value = match pair {
+                        PairAdjustment::Format1 { sets, .. } => sets.get(coverage_idx).and_then(|set| set.get(right)),
+                        PairAdjustment::Format2 { classes, matrix, .. } => matrix.get((classes.0.get(left), classes.1.get(right))),
+                    };
+                    if let Some((value, _)) = value {
+                        adjustment += value.x_advance;
+                        break;
+                    }
+                }
+            }
+            adjustment
+        }
+    }
+
+    // Encodes the EB Garamond glyphs with the font's metrics and the kerning of every pair of
+    // them. It rewrites the embedded data, so it only runs when asked for:
+    // `cargo test -- --ignored generate_ebgaramond_glyph_data`
+    #[cfg(feature = "ebgaramond")]
+    #[test]
+    #[ignore]
+    fn generate_ebgaramond_glyph_data() {
+        let face = ebgaramond_face();
+        let sources = ebgaramond_sources(&face);
+        let outlines: Vec<Vec<PathSegment>> = sources.iter().map(|(id, _, _)| outline(&face, *id)).collect();
+        let glyphs: Vec<GlyphSource> = sources
+            .iter()
+            .zip(outlines.iter())
+            .map(|((id, name, text), outline)| GlyphSource { name, text, segments: outline, advance: face.glyph_hor_advance(*id).unwrap() as f32 })
+            .collect();
+
+        let kerning = kerning(&face);
+        let mut pairs = vec![];
+        for (left, (left_id, _, _)) in sources.iter().enumerate() {
+            for (right, (right_id, _, _)) in sources.iter().enumerate() {
+                let adjustment = kerning(*left_id, *right_id);
+                if adjustment != 0 {
+                    pairs.push((left, right, adjustment as f32));
+                }
+            }
+        }
+
+        let data = crate::glyphs::encode_glyphs(&font_metrics(&face), &glyphs, &pairs).unwrap();
+        fs::write("./src/glyphs/ebgaramond.bin", data).unwrap();
+    }
+
+    #[cfg(feature = "ebgaramond")]
+    #[test]
+    fn ebgaramond_glyph_data() {
+        use crate::glyphs::ebgaramond::EBGARAMOND;
+        let face = ebgaramond_face();
+        let sources = ebgaramond_sources(&face);
+        assert_eq!(EBGARAMOND.font_metrics().unwrap(), font_metrics(&face));
+        assert_eq!(EBGARAMOND.names().unwrap(), sources.iter().map(|(_, name, _)| name.as_str()).collect::<Vec<_>>());
+        for (id, name, text) in sources.iter() {
+            assert_eq!(EBGARAMOND.get(name).unwrap().to_string(), PathSegments(outline(&face, *id)).to_string());
+            assert_eq!(EBGARAMOND.glyph_metrics(name).unwrap().advance, face.glyph_hor_advance(*id).unwrap() as f32);
+            assert_eq!(EBGARAMOND.shape(text).unwrap()[0].name(), name);
+        }
+        // Kerning of every pair among some letters and punctuation that the font kerns
+        let kerning = kerning(&face);
+        let sample: Vec<_> = sources.iter().filter(|(_, _, text)| text.len() == 1 && "AVTWLYfrvy.,ao".contains(text.as_str())).collect();
+        for (left_id, left, _) in sample.iter() {
+            for (right_id, right, _) in sample.iter() {
+                assert_eq!(EBGARAMOND.kerning(left, right).unwrap(), kerning(*left_id, *right_id) as f32);
+            }
+        }
     }
 }
 
@@ -186,7 +717,7 @@ mod tests {
 
 //         let items = GraphicItems(vec![Path(PathSegments(pathtext.path_segments), NoStroke, Fillstyle(Blue), crate::prelude::PathCacheInfo::NoCache)]);
 
-//         let svg = SvgBuilder::new().build(items).unwrap();
+//         let svg = SvgBuilder::new().build(items, None).unwrap();
 //         std::fs::write("./output/clef.svg", svg).unwrap();
 //     }
 
@@ -202,7 +733,7 @@ mod tests {
 
 //         let items = GraphicItems(vec![Path(PathSegments(pathtext.path_segments), NoStroke, Fillstyle(Blue), crate::prelude::PathCacheInfo::NoCache)]);
 
-//         let svg = SvgBuilder::new().build(items).unwrap();
+//         let svg = SvgBuilder::new().build(items, None).unwrap();
 //         std::fs::write("./output/avenir.svg", svg).unwrap();
 //     }
 // }
@@ -0,0 +1,202 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use crate::core::core::Point;
+use crate::path::curve::Curve;
+use crate::path::{PathSegment, PathSegments};
+
+/// Elliptical arc from `from` to `to` with SVG semantics: radii, rotation of the x axis in
+/// degrees and the large-arc and sweep flags. Radii that are too small to reach the end
+/// point are scaled up, as SVG does.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Arc {
+    pub from: Point,
+    pub rx: f32,
+    pub ry: f32,
+    pub rotation: f32,
+    pub large_arc: bool,
+    pub sweep: bool,
+    pub to: Point,
+}
+
+/// An arc in center parameterization: points are center + R(rotation) * (rx cos a, ry sin a)
+/// for angles a from `start` over `sweep` radians.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CenterArc {
+    pub center: Point,
+    pub rx: f32,
+    pub ry: f32,
+    pub rotation: f32,
+    pub start: f32,
+    pub sweep: f32,
+}
+
+impl Arc {
+    /// Converts to center parameterization (SVG implementation notes, F.6.5 and F.6.6).
+    /// None for an arc drawn as a straight line or not at all.
+    pub fn to_center(&self) -> Option<CenterArc> {
+        let (mut rx, mut ry) = (self.rx.abs(), self.ry.abs());
+        if self.from == self.to || rx == 0. || ry == 0. {
+            return None;
+        }
+        let (sin, cos) = self.rotation.to_radians().sin_cos();
+        let half = (self.from - self.to) * 0.5;
+        let x1 = cos * half.0 + sin * half.1;
+        let y1 = -sin * half.0 + cos * half.1;
+
+        let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+        if lambda > 1. {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+        let numerator = (rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1).max(0.);
+        let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+        let mut factor = (numerator / denominator).sqrt();
+        if self.large_arc == self.sweep {
+            factor = -factor;
+        }
+        let (cx1, cy1) = (factor * rx * y1 / ry, -factor * ry * x1 / rx);
+        let mid = (self.from + self.to) * 0.5;
+        let center = Point(cos * cx1 - sin * cy1 + mid.0, sin * cx1 + cos * cy1 + mid.1);
+
+        let angle = |ux: f32, uy: f32| uy.atan2(ux);
+        let start = angle((x1 - cx1) / rx, (y1 - cy1) / ry);
+        let end = angle((-x1 - cx1) / rx, (-y1 - cy1) / ry);
+        let mut sweep = end - start;
+        if self.sweep && sweep < 0. {
+            sweep += 2. * PI;
+        } else if !self.sweep && sweep > 0. {
+            sweep -= 2. * PI;
+        }
+        Some(CenterArc { center, rx, ry, rotation: self.rotation, start, sweep })
+    }
+
+    /// The arc as cubic Béziers of at most a quarter turn each, or a line if it is degenerate.
+    pub fn to_curves(&self) -> Vec<Curve> {
+        if self.from == self.to {
+            return vec![];
+        }
+        let Some(arc) = self.to_center() else { return vec![Curve::Line(self.from, self.to)] };
+        let count = (arc.sweep.abs() / FRAC_PI_2 - 1e-4).ceil().max(1.) as usize;
+        let step = arc.sweep / count as f32;
+        let k = 4. / 3. * (step / 4.).tan();
+        let mut curves = vec![];
+        let mut p0 = self.from;
+        for i in 0..count {
+            let (a0, a1) = (arc.start + step * i as f32, arc.start + step * (i + 1) as f32);
+            let p3 = if i == count - 1 { self.to } else { arc.point(a1) };
+            let c1 = p0 + arc.derivative(a0) * k;
+            let c2 = p3 - arc.derivative(a1) * k;
+            curves.push(Curve::Cubic(p0, c1, c2, p3));
+            p0 = p3;
+        }
+        curves
+    }
+}
+
+impl CenterArc {
+    pub fn point(&self, angle: f32) -> Point {
+        let (sin, cos) = self.rotation.to_radians().sin_cos();
+        let (x, y) = (self.rx * angle.cos(), self.ry * angle.sin());
+        Point(self.center.0 + cos * x - sin * y, self.center.1 + sin * x + cos * y)
+    }
+
+    // Derivative of `point` with respect to the angle.
+    fn derivative(&self, angle: f32) -> Point {
+        let (sin, cos) = self.rotation.to_radians().sin_cos();
+        let (x, y) = (-self.rx * angle.sin(), self.ry * angle.cos());
+        Point(cos * x - sin * y, sin * x + cos * y)
+    }
+}
+
+/// Radii, rotation and sweep flag of an arc after scaling the plane by (sx, sy). A rotated
+/// ellipse stays an ellipse under non-uniform scaling, with new axes found by a singular
+/// value decomposition of the combined transform.
+pub(crate) fn scale_arc(rx: f32, ry: f32, rotation: f32, sweep: bool, scale_x: f32, scale_y: f32) -> (f32, f32, f32, bool) {
+    let (sin, cos) = rotation.to_radians().sin_cos();
+    // scale * rotation * diag(rx, ry)
+    let (a, b, c, d) = (scale_x * cos * rx, -scale_x * sin * ry, scale_y * sin * rx, scale_y * cos * ry);
+    let (e, f, g, h) = ((a + d) / 2., (a - d) / 2., (c + b) / 2., (c - b) / 2.);
+    let (q, r) = ((e * e + h * h).sqrt(), (f * f + g * g).sqrt());
+    let (a1, a2) = (g.atan2(f), h.atan2(e));
+    let new_rotation = ((a2 + a1) / 2.).to_degrees();
+    let new_sweep = if scale_x * scale_y < 0. { !sweep } else { sweep };
+    (q + r, (q - r).abs(), new_rotation, new_sweep)
+}
+
+impl PathSegments {
+    /// True if the path contains elliptical arcs.
+    pub fn has_arcs(&self) -> bool {
+        self.0.iter().any(|segment| matches!(segment, PathSegment::A(..)))
+    }
+
+    /// The same path with every arc replaced by cubic Béziers, for backends without arcs.
+    pub fn arcs_to_cubics(&self) -> PathSegments {
+        let mut segments = vec![];
+        let mut start = Point(0., 0.);
+        let mut pos = Point(0., 0.);
+        for segment in self.0.iter() {
+            match segment {
+                PathSegment::A(rx, ry, rotation, large_arc, sweep, x, y) => {
+                    let arc = Arc { from: pos, rx: *rx, ry: *ry, rotation: *rotation, large_arc: *large_arc, sweep: *sweep, to: Point(*x, *y) };
+                    segments.extend(arc.to_curves().iter().map(|curve| curve.to_segment()));
+                }
+                segment => segments.push(segment.clone()),
+            }
+            pos = match segment {
+                PathSegment::M(x, y) => {
+                    start = Point(*x, *y);
+                    start
+                }
+                PathSegment::Z => start,
+                segment => segment.end_point().unwrap_or(pos),
+            };
+        }
+        PathSegments(segments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Arc;
+    use crate::core::core::Point;
+    use crate::path::PathSegment::*;
+    use crate::path::PathSegments;
+    use std::f32::consts::PI;
+
+    #[test]
+    fn arcs() {
+        // Half circle of radius 50 from the left to the right, through the top
+        let arc = Arc { from: Point(0., 50.), rx: 50., ry: 50., rotation: 0., large_arc: false, sweep: true, to: Point(100., 50.) };
+        let center = arc.to_center().unwrap();
+        assert!(center.center.distance(Point(50., 50.)) < 1e-4);
+        assert!((center.sweep - PI).abs() < 1e-4);
+        let curves = arc.to_curves();
+        assert_eq!(curves.len(), 2);
+        assert!(curves[0].end().distance(Point(50., 0.)) < 1e-3);
+
+        let path = PathSegments(vec![M(0., 50.), A(50., 50., 0., false, true, 100., 50.), Z]);
+        assert_eq!(path.to_string(), "M 0 50 A 50 50 0 0 1 100 50 Z ");
+        assert!((path.length() - (50. * PI + 100.)).abs() < 0.1);
+        assert!((path.signed_area() - 1250. * PI).abs() < 2.);
+        let bbox = path.bbox();
+        assert!(bbox.1.abs() < 1e-3 && (bbox.3 - 50.).abs() < 1e-3);
+        assert!(path.contains(50., 10.) && !path.contains(50., 60.));
+
+        // Too small radii are scaled up; a zero radius draws a line
+        let small = PathSegments(vec![M(0., 0.), A(1., 1., 0., false, false, 10., 0.)]);
+        assert!((small.length() - 5. * PI).abs() < 0.01);
+        let flat = PathSegments(vec![M(0., 0.), A(0., 5., 0., false, false, 10., 0.)]);
+        assert_eq!(flat.arcs_to_cubics().to_string(), "M 0 0 L 10 0 ");
+
+        // Scaling keeps the arc on the scaled ellipse, mirroring flips the sweep
+        let rotated = PathSegments(vec![M(0., 0.), A(30., 10., 30., true, true, 20., 5.)]);
+        for (sx, sy) in [(2., 0.5), (-1., 1.), (0.1, -0.1)] {
+            let scaled = rotated.scale_path(sx, sy);
+            let expected = rotated.arcs_to_cubics().scale_path(sx, sy);
+            for i in 0..=10 {
+                let p = expected.measure(0.01).point_at_fraction(i as f32 / 10.).unwrap();
+                assert!(scaled.distance(p.0, p.1) < 0.05);
+            }
+        }
+    }
+}
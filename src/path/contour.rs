@@ -0,0 +1,166 @@
+use crate::core::core::Point;
+use crate::path::curve::{Curve, CurveContour};
+use crate::path::measure::GAUSS;
+use crate::path::{PathSegment, PathSegments};
+
+/// Direction in which a contour runs, as seen on screen with y pointing down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Winding {
+    Clockwise,
+    CounterClockwise,
+}
+
+impl Curve {
+    /// Signed area swept between the curve and the origin, (x dy - y dx) / 2 integrated
+    /// along it. Exact, the integrand being a polynomial of at most degree 5.
+    pub fn area(&self) -> f32 {
+        if let Curve::Line(p0, p1) = self {
+            return p0.cross(*p1) / 2.;
+        }
+        GAUSS
+            .iter()
+            .map(|(x, w)| {
+                let t = (x + 1.) / 2.;
+                w * self.eval(t).cross(self.derivative(t))
+            })
+            .sum::<f32>()
+            / 4.
+    }
+}
+
+impl CurveContour {
+    /// Segments drawing the contour, with a closing line that `Z` implies left out.
+    pub fn to_segments(&self) -> Vec<PathSegment> {
+        let Some(first) = self.curves.first() else { return vec![] };
+        let start = first.start();
+        let mut segments = vec![PathSegment::M(start.0, start.1)];
+        segments.extend(self.curves.iter().map(|curve| curve.to_segment()));
+        if self.closed {
+            if let Some(PathSegment::L(x, y)) = segments.last() {
+                if segments.len() > 2 && Point(*x, *y) == start {
+                    segments.pop();
+                }
+            }
+            segments.push(PathSegment::Z);
+        }
+        segments
+    }
+}
+
+impl PathSegments {
+    /// The subpaths of the path, each starting at an `M`.
+    pub fn contours(&self) -> Vec<PathSegments> {
+        let mut contours: Vec<PathSegments> = vec![];
+        for segment in self.0.iter() {
+            match (segment, contours.last()) {
+                (PathSegment::M(..), _) | (_, None) => contours.push(PathSegments(vec![segment.clone()])),
+                // Segments after Z continue from the start point of the closed contour
+                (_, Some(last)) if matches!(last.0.last(), Some(PathSegment::Z)) => {
+                    let start = last.0[0].clone();
+                    contours.push(PathSegments(vec![start, segment.clone()]));
+                }
+                _ => contours.last_mut().unwrap().0.push(segment.clone()),
+            }
+        }
+        contours
+    }
+
+    /// True if every contour of the path ends with `Z`.
+    pub fn is_closed(&self) -> bool {
+        let contours = self.curve_contours();
+        !contours.is_empty() && contours.iter().all(|contour| contour.closed)
+    }
+
+    /// Area enclosed by the path, each contour implicitly closed. Positive for clockwise
+    /// contours, so that a hole running the other way subtracts from its outline.
+    pub fn signed_area(&self) -> f32 {
+        self.curve_contours().iter().map(contour_area).sum()
+    }
+
+    /// Direction of the path, None if it encloses no area.
+    pub fn winding(&self) -> Option<Winding> {
+        let area = self.signed_area();
+        if area > 0. {
+            Some(Winding::Clockwise)
+        } else if area < 0. {
+            Some(Winding::CounterClockwise)
+        } else {
+            None
+        }
+    }
+
+    /// The same path drawn backwards: contours in reverse order, each from its end to its start.
+    pub fn reverse(&self) -> PathSegments {
+        let mut segments = vec![];
+        for contour in self.curve_contours().iter().rev() {
+            segments.extend(reverse_contour(contour).to_segments());
+        }
+        PathSegments(segments)
+    }
+
+    /// Orients the contours so that outlines run clockwise and holes counter-clockwise,
+    /// alternating with the nesting depth of each contour. The order of contours is kept.
+    pub fn normalize_winding(&self) -> PathSegments {
+        let contours = self.curve_contours();
+        let closed: Vec<PathSegments> = contours.iter().map(|contour| PathSegments(CurveContour { curves: contour.curves.clone(), closed: true }.to_segments())).collect();
+        let mut segments = vec![];
+        for (idx, contour) in contours.iter().enumerate() {
+            let p = contour.curves[0].eval(0.5);
+            let depth = closed.iter().enumerate().filter(|(other, path)| *other != idx && path.contains(p.0, p.1)).count();
+            let area = contour_area(contour);
+            let reverse = if depth % 2 == 0 { area < 0. } else { area > 0. };
+            let contour = if reverse { reverse_contour(contour) } else { contour.clone() };
+            segments.extend(contour.to_segments());
+        }
+        PathSegments(segments)
+    }
+}
+
+fn contour_area(contour: &CurveContour) -> f32 {
+    let mut area: f32 = contour.curves.iter().map(|curve| curve.area()).sum();
+    if let (Some(first), Some(last)) = (contour.curves.first(), contour.curves.last()) {
+        area += Curve::Line(last.end(), first.start()).area();
+    }
+    area
+}
+
+fn reverse_contour(contour: &CurveContour) -> CurveContour {
+    CurveContour { curves: contour.curves.iter().rev().map(|curve| curve.reversed()).collect(), closed: contour.closed }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Winding;
+    use crate::path::PathSegment::*;
+    use crate::path::PathSegments;
+
+    #[test]
+    fn contours_area_and_winding() {
+        let ring = PathSegments(vec![M(0., 0.), L(0., 100.), L(100., 100.), L(100., 0.), Z, M(25., 25.), L(75., 25.), L(75., 75.), L(25., 75.), Z]);
+        let contours = ring.contours();
+        assert_eq!(contours.len(), 2);
+        assert!(contours.iter().all(|contour| contour.is_closed()));
+        assert_eq!(contours[0].winding(), Some(Winding::CounterClockwise));
+        assert_eq!(contours[1].winding(), Some(Winding::Clockwise));
+        assert_eq!(ring.signed_area(), -10000. + 2500.);
+
+        let normalized = ring.normalize_winding();
+        assert_eq!(normalized.signed_area(), 10000. - 2500.);
+        assert_eq!(normalized.contours()[1].to_string(), contours[1].reverse().to_string());
+        assert_eq!(contours[1].reverse().to_string(), "M 25 25 L 25 75 L 75 75 L 75 25 Z ");
+        assert_eq!(ring.reverse().reverse().to_string(), ring.to_string());
+
+        let circle = PathSegments::ellipse(0., 0., 100., 100.);
+        assert!((circle.signed_area() - 2500. * std::f32::consts::PI).abs() < 5.);
+        assert!((circle.reverse().signed_area() + circle.signed_area()).abs() < 1e-2);
+        assert!(!PathSegments(vec![M(0., 0.), L(10., 10.)]).is_closed());
+
+        // Outline and counter of a glyph end up with opposite orientations
+        #[cfg(feature = "cadenza")]
+        {
+            let glyph = PathSegments(crate::glyphs::cadenza::CADENZA_HEAD_WHOLE.segments().unwrap().to_vec()).normalize_winding();
+            let windings: Vec<_> = glyph.contours().iter().map(|contour| contour.winding()).collect();
+            assert_eq!(windings, vec![Some(Winding::Clockwise), Some(Winding::CounterClockwise)]);
+        }
+    }
+}
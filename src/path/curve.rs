@@ -0,0 +1,344 @@
+use crate::core::core::{Point, Rectangle};
+use crate::path::arc::Arc;
+use crate::path::{PathSegment, PathSegments};
+
+/// A single path segment with its start point made explicit, so that it can be
+/// evaluated, split and measured on its own.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Curve {
+    Line(Point, Point),
+    Quad(Point, Point, Point),
+    Cubic(Point, Point, Point, Point),
+}
+
+/// The curves of one subpath. Closed contours (ending with `Z`) include the closing line.
+#[derive(Debug, Clone)]
+pub struct CurveContour {
+    pub curves: Vec<Curve>,
+    pub closed: bool,
+}
+
+impl Curve {
+    pub fn start(&self) -> Point {
+        match self {
+            Curve::Line(p0, _) | Curve::Quad(p0, _, _) | Curve::Cubic(p0, _, _, _) => *p0,
+        }
+    }
+
+    pub fn end(&self) -> Point {
+        match self {
+            Curve::Line(_, p1) => *p1,
+            Curve::Quad(_, _, p2) => *p2,
+            Curve::Cubic(_, _, _, p3) => *p3,
+        }
+    }
+
+    pub fn eval(&self, t: f32) -> Point {
+        let mt = 1. - t;
+        match self {
+            Curve::Line(p0, p1) => p0.lerp(*p1, t),
+            Curve::Quad(p0, p1, p2) => *p0 * (mt * mt) + *p1 * (2. * mt * t) + *p2 * (t * t),
+            Curve::Cubic(p0, p1, p2, p3) => *p0 * (mt * mt * mt) + *p1 * (3. * mt * mt * t) + *p2 * (3. * mt * t * t) + *p3 * (t * t * t),
+        }
+    }
+
+    /// First derivative with respect to t.
+    pub fn derivative(&self, t: f32) -> Point {
+        let mt = 1. - t;
+        match self {
+            Curve::Line(p0, p1) => *p1 - *p0,
+            Curve::Quad(p0, p1, p2) => (*p1 - *p0) * (2. * mt) + (*p2 - *p1) * (2. * t),
+            Curve::Cubic(p0, p1, p2, p3) => (*p1 - *p0) * (3. * mt * mt) + (*p2 - *p1) * (6. * mt * t) + (*p3 - *p2) * (3. * t * t),
+        }
+    }
+
+    /// Unit tangent at t. Falls back to the chord direction where the derivative vanishes,
+    /// as it does at a cubic endpoint with a coincident control point.
+    pub fn tangent(&self, t: f32) -> Point {
+        let d = self.derivative(t);
+        if d.length() > 1e-6 {
+            return d.normalize();
+        }
+        let (a, b) = if t < 0.5 { (self.eval(t), self.eval((t + 0.01).min(1.))) } else { (self.eval((t - 0.01).max(0.)), self.eval(t)) };
+        let d = b - a;
+        if d.length() > 0. {
+            d.normalize()
+        } else {
+            (self.end() - self.start()).normalize()
+        }
+    }
+
+    /// De Casteljau split at t.
+    pub fn split(&self, t: f32) -> (Curve, Curve) {
+        match self {
+            Curve::Line(p0, p1) => {
+                let m = p0.lerp(*p1, t);
+                (Curve::Line(*p0, m), Curve::Line(m, *p1))
+            }
+            Curve::Quad(p0, p1, p2) => {
+                let a = p0.lerp(*p1, t);
+                let b = p1.lerp(*p2, t);
+                let m = a.lerp(b, t);
+                (Curve::Quad(*p0, a, m), Curve::Quad(m, b, *p2))
+            }
+            Curve::Cubic(p0, p1, p2, p3) => {
+                let a = p0.lerp(*p1, t);
+                let b = p1.lerp(*p2, t);
+                let c = p2.lerp(*p3, t);
+                let ab = a.lerp(b, t);
+                let bc = b.lerp(c, t);
+                let m = ab.lerp(bc, t);
+                (Curve::Cubic(*p0, a, ab, m), Curve::Cubic(m, bc, c, *p3))
+            }
+        }
+    }
+
+    /// The part of the curve between t0 and t1.
+    pub fn subsection(&self, t0: f32, t1: f32) -> Curve {
+        let t0 = t0.clamp(0., 1.);
+        let t1 = t1.clamp(0., 1.);
+        let tail = if t0 > 0. { self.split(t0).1 } else { *self };
+        if t1 >= 1. {
+            return tail;
+        }
+        let t = if t0 < 1. { (t1 - t0) / (1. - t0) } else { 0. };
+        tail.split(t).0
+    }
+
+    pub fn reversed(&self) -> Curve {
+        match self {
+            Curve::Line(p0, p1) => Curve::Line(*p1, *p0),
+            Curve::Quad(p0, p1, p2) => Curve::Quad(*p2, *p1, *p0),
+            Curve::Cubic(p0, p1, p2, p3) => Curve::Cubic(*p3, *p2, *p1, *p0),
+        }
+    }
+
+    /// The segment that draws this curve from the current point.
+    pub fn to_segment(&self) -> PathSegment {
+        match self {
+            Curve::Line(_, p1) => PathSegment::L(p1.0, p1.1),
+            Curve::Quad(_, p1, p2) => PathSegment::Q(p1.0, p1.1, p2.0, p2.1),
+            Curve::Cubic(_, p1, p2, p3) => PathSegment::C(p1.0, p1.1, p2.0, p2.1, p3.0, p3.1),
+        }
+    }
+
+    pub fn to_cubic(&self) -> Curve {
+        match self {
+            Curve::Line(p0, p1) => Curve::Cubic(*p0, p0.lerp(*p1, 1. / 3.), p0.lerp(*p1, 2. / 3.), *p1),
+            Curve::Quad(p0, p1, p2) => Curve::Cubic(*p0, p0.lerp(*p1, 2. / 3.), p2.lerp(*p1, 2. / 3.), *p2),
+            Curve::Cubic(..) => *self,
+        }
+    }
+
+    // Parameters in (0, 1) where the derivative of one coordinate is zero.
+    fn extrema(&self, coord: fn(&Point) -> f32) -> Vec<f32> {
+        let mut roots = vec![];
+        match self {
+            Curve::Line(..) => {}
+            Curve::Quad(p0, p1, p2) => {
+                let d = coord(p0) - 2. * coord(p1) + coord(p2);
+                if d != 0. {
+                    roots.push((coord(p0) - coord(p1)) / d);
+                }
+            }
+            Curve::Cubic(p0, p1, p2, p3) => {
+                // derivative / 3 = a t^2 + b t + c
+                let a = -coord(p0) + 3. * coord(p1) - 3. * coord(p2) + coord(p3);
+                let b = 2. * (coord(p0) - 2. * coord(p1) + coord(p2));
+                let c = coord(p1) - coord(p0);
+                roots = solve_quadratic(a, b, c);
+            }
+        }
+        roots.retain(|t| *t > 0. && *t < 1.);
+        roots
+    }
+
+    /// Exact bounding box, taking curve extrema rather than control points.
+    pub fn bbox(&self) -> Rectangle {
+        let mut bbox = Rectangle::empty();
+        let mut ts = vec![0., 1.];
+        ts.extend(self.extrema(|p| p.0));
+        ts.extend(self.extrema(|p| p.1));
+        for t in ts {
+            let p = self.eval(t);
+            bbox = bbox.union(&Rectangle(p.0, p.1, p.0, p.1));
+        }
+        bbox
+    }
+
+    /// Points approximating the curve within `tolerance`, start point excluded.
+    pub fn flatten(&self, tolerance: f32) -> Vec<Point> {
+        let n = match self {
+            Curve::Line(_, p1) => return vec![*p1],
+            // Wang's formula for the number of subdivisions
+            Curve::Quad(p0, p1, p2) => (0.25 * (*p0 - *p1 * 2. + *p2).length() / tolerance).sqrt(),
+            Curve::Cubic(p0, p1, p2, p3) => {
+                let dd = (*p0 - *p1 * 2. + *p2).length().max((*p1 - *p2 * 2. + *p3).length());
+                (0.75 * dd / tolerance).sqrt()
+            }
+        };
+        let n = (n.ceil() as usize).clamp(1, 1000);
+        (1..=n).map(|i| self.eval(i as f32 / n as f32)).collect()
+    }
+
+    /// Parameter and distance of the point on the curve closest to `p`.
+    pub fn nearest(&self, p: Point) -> (f32, f32) {
+        if let Curve::Line(p0, p1) = self {
+            let d = *p1 - *p0;
+            let len2 = d.dot(d);
+            let t = if len2 > 0. { ((p - *p0).dot(d) / len2).clamp(0., 1.) } else { 0. };
+            return (t, p.distance(p0.lerp(*p1, t)));
+        }
+        const SAMPLES: usize = 16;
+        let mut best_t = 0.;
+        let mut best_d = f32::MAX;
+        for i in 0..=SAMPLES {
+            let t = i as f32 / SAMPLES as f32;
+            let d = p.distance(self.eval(t));
+            if d < best_d {
+                best_d = d;
+                best_t = t;
+            }
+        }
+        // Refine by ternary search in the neighbourhood of the best sample
+        let mut lo = (best_t - 1. / SAMPLES as f32).max(0.);
+        let mut hi = (best_t + 1. / SAMPLES as f32).min(1.);
+        for _ in 0..30 {
+            let m1 = lo + (hi - lo) / 3.;
+            let m2 = hi - (hi - lo) / 3.;
+            if p.distance(self.eval(m1)) < p.distance(self.eval(m2)) {
+                hi = m2;
+            } else {
+                lo = m1;
+            }
+        }
+        let t = (lo + hi) / 2.;
+        let d = p.distance(self.eval(t));
+        if d < best_d {
+            (t, d)
+        } else {
+            (best_t, best_d)
+        }
+    }
+
+    /// Contribution of the curve to the winding number of `p`, counting crossings of the
+    /// horizontal ray from `p` towards +x. The curve is split into y-monotone pieces and
+    /// each crossing is located exactly by bisection.
+    pub fn winding(&self, p: Point) -> i32 {
+        let bbox = self.bbox();
+        if p.1 < bbox.1 || p.1 > bbox.3 || p.0 > bbox.2 {
+            return 0;
+        }
+        let mut ts = vec![0.];
+        let mut extrema = self.extrema(|p| p.1);
+        extrema.sort_by(|a, b| a.total_cmp(b));
+        ts.extend(extrema);
+        ts.push(1.);
+
+        let mut winding = 0;
+        for w in ts.windows(2) {
+            let (ta, tb) = (w[0], w[1]);
+            let ya = self.eval(ta).1;
+            let yb = self.eval(tb).1;
+            if ya == yb {
+                continue;
+            }
+            // Half-open interval so that a crossing at a shared endpoint counts once
+            let (dir, crosses) = if ya < yb { (1, ya <= p.1 && p.1 < yb) } else { (-1, yb <= p.1 && p.1 < ya) };
+            if !crosses {
+                continue;
+            }
+            let (mut lo, mut hi) = (ta, tb);
+            for _ in 0..40 {
+                let mid = (lo + hi) / 2.;
+                let y = self.eval(mid).1;
+                if (y < p.1) == (ya < yb) {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            if self.eval((lo + hi) / 2.).0 > p.0 {
+                winding += dir;
+            }
+        }
+        winding
+    }
+}
+
+pub(crate) fn solve_quadratic(a: f32, b: f32, c: f32) -> Vec<f32> {
+    if a.abs() < 1e-9 {
+        if b.abs() < 1e-12 {
+            return vec![];
+        }
+        return vec![-c / b];
+    }
+    let disc = b * b - 4. * a * c;
+    if disc < 0. {
+        return vec![];
+    }
+    let sq = disc.sqrt();
+    vec![(-b + sq) / (2. * a), (-b - sq) / (2. * a)]
+}
+
+impl PathSegments {
+    /// Splits the path into contours at each `M` and resolves every segment into a `Curve`.
+    pub fn curve_contours(&self) -> Vec<CurveContour> {
+        let mut contours: Vec<CurveContour> = vec![];
+        let mut current = CurveContour { curves: vec![], closed: false };
+        let mut start = Point(0., 0.);
+        let mut pos = Point(0., 0.);
+
+        fn finish(contours: &mut Vec<CurveContour>, current: &mut CurveContour) {
+            if !current.curves.is_empty() {
+                contours.push(std::mem::replace(current, CurveContour { curves: vec![], closed: false }));
+            }
+            current.closed = false;
+        }
+
+        for segment in self.0.iter() {
+            match segment {
+                PathSegment::M(x, y) => {
+                    finish(&mut contours, &mut current);
+                    start = Point(*x, *y);
+                    pos = start;
+                }
+                PathSegment::L(x, y) => {
+                    current.curves.push(Curve::Line(pos, Point(*x, *y)));
+                    pos = Point(*x, *y);
+                }
+                PathSegment::Q(x1, y1, x, y) => {
+                    current.curves.push(Curve::Quad(pos, Point(*x1, *y1), Point(*x, *y)));
+                    pos = Point(*x, *y);
+                }
+                PathSegment::C(x1, y1, x2, y2, x, y) => {
+                    current.curves.push(Curve::Cubic(pos, Point(*x1, *y1), Point(*x2, *y2), Point(*x, *y)));
+                    pos = Point(*x, *y);
+                }
+                PathSegment::A(rx, ry, rotation, large_arc, sweep, x, y) => {
+                    let arc = Arc { from: pos, rx: *rx, ry: *ry, rotation: *rotation, large_arc: *large_arc, sweep: *sweep, to: Point(*x, *y) };
+                    current.curves.extend(arc.to_curves());
+                    pos = Point(*x, *y);
+                }
+                PathSegment::Z => {
+                    // Segments after Z start a new contour at the same start point
+                    if !current.curves.is_empty() {
+                        if pos != start {
+                            current.curves.push(Curve::Line(pos, start));
+                        }
+                        current.closed = true;
+                        finish(&mut contours, &mut current);
+                    }
+                    pos = start;
+                }
+            }
+        }
+        finish(&mut contours, &mut current);
+        contours
+    }
+
+    /// All curves of the path, in drawing order.
+    pub fn curves(&self) -> Vec<Curve> {
+        self.curve_contours().into_iter().flat_map(|c| c.curves).collect()
+    }
+}
@@ -0,0 +1,59 @@
+use crate::core::core::Point;
+use crate::path::PathSegments;
+
+impl PathSegments {
+    /// Nonzero winding number of the point, every contour implicitly closed as when filling.
+    pub fn winding_number(&self, x: f32, y: f32) -> i32 {
+        let p = Point(x, y);
+        let mut winding = 0;
+        for contour in self.curve_contours() {
+            for curve in contour.curves.iter() {
+                winding += curve.winding(p);
+            }
+            if let (Some(first), Some(last)) = (contour.curves.first(), contour.curves.last()) {
+                if !contour.closed && first.start() != last.end() {
+                    winding += crate::path::curve::Curve::Line(last.end(), first.start()).winding(p);
+                }
+            }
+        }
+        winding
+    }
+
+    /// True if the point is inside the filled path (nonzero fill rule).
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        self.winding_number(x, y) != 0
+    }
+
+    /// Shortest distance from the point to the outline as it is stroked.
+    pub fn distance(&self, x: f32, y: f32) -> f32 {
+        let p = Point(x, y);
+        self.curves().iter().map(|curve| curve.nearest(p).1).fold(f32::MAX, f32::min)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::path::PathSegment::*;
+    use crate::path::PathSegments;
+
+    #[test]
+    fn winding_and_distance() {
+        // Quarter disc approximated by a quadratic, left open to test the implicit close
+        let path = PathSegments(vec![M(0., 0.), L(100., 0.), Q(100., 100., 0., 100.)]);
+        assert!(path.contains(50., 50.));
+        assert!(path.contains(85., 60.));
+        assert!(!path.contains(88., 60.));
+        assert!(!path.contains(95., 95.));
+        assert!(!path.contains(-1., 50.));
+        assert!((path.distance(50., -10.) - 10.).abs() < 1e-4);
+
+        // Ring with a counter-wound hole
+        let ring = PathSegments(vec![
+            M(0., 0.), C(50., -20., 100., 0., 100., 0.), L(100., 100.), L(0., 100.), Z,
+            M(25., 25.), L(25., 75.), L(75., 75.), L(75., 25.), Z,
+        ]);
+        assert!(ring.contains(10., 50.));
+        assert!(!ring.contains(50., 50.));
+        assert!(ring.contains(50., -5.));
+    }
+}
@@ -0,0 +1,383 @@
+use crate::core::core::Point;
+use crate::path::curve::Curve;
+use crate::path::{PathSegment, PathSegments};
+
+/// Accuracy used by the `PathSegments` convenience methods, in path units.
+pub const DEFAULT_MEASURE_TOLERANCE: f32 = 0.01;
+
+// 5-point Gauss-Legendre abscissae and weights on [-1, 1]
+pub(crate) const GAUSS: [(f32, f32); 5] = [
+    (0., 0.568_888_9),
+    (-0.538_469_3, 0.478_628_67),
+    (0.538_469_3, 0.478_628_67),
+    (-0.906_179_8, 0.236_926_88),
+    (0.906_179_8, 0.236_926_88),
+];
+
+impl Curve {
+    /// Arc length between the parameters t0 and t1 by Gauss-Legendre quadrature.
+    /// Accurate for short, gently bending pieces; see `length` for whole curves.
+    pub fn arc_length(&self, t0: f32, t1: f32) -> f32 {
+        if let Curve::Line(p0, p1) = self {
+            return p0.distance(*p1) * (t1 - t0).abs();
+        }
+        let half = (t1 - t0) / 2.;
+        let mid = (t1 + t0) / 2.;
+        GAUSS.iter().map(|(x, w)| w * self.derivative(mid + half * x).length()).sum::<f32>() * half.abs()
+    }
+
+    /// Arc length of the whole curve, subdividing until the estimate is within `tolerance`.
+    pub fn length(&self, tolerance: f32) -> f32 {
+        let mut breaks = vec![];
+        self.subdivide(0., 1., tolerance, 0, &mut breaks);
+        breaks.last().map(|(_, s)| *s).unwrap_or(0.)
+    }
+
+    // Appends (t, length from t0) breakpoints covering (t0, t1], splitting until halving a
+    // piece changes its length by less than the tolerance.
+    fn subdivide(&self, t0: f32, t1: f32, tolerance: f32, depth: usize, breaks: &mut Vec<(f32, f32)>) {
+        let whole = self.arc_length(t0, t1);
+        let tm = (t0 + t1) / 2.;
+        let halves = self.arc_length(t0, tm) + self.arc_length(tm, t1);
+        let offset = breaks.last().map(|(_, s)| *s).unwrap_or(0.);
+        if depth >= 16 || (whole - halves).abs() <= tolerance || matches!(self, Curve::Line(..)) {
+            breaks.push((t1, offset + halves));
+            return;
+        }
+        self.subdivide(t0, tm, tolerance / 2., depth + 1, breaks);
+        self.subdivide(tm, t1, tolerance / 2., depth + 1, breaks);
+    }
+}
+
+/// A curve with a table of parameters and the arc length up to each of them.
+#[derive(Debug, Clone)]
+struct CurveMeasure {
+    curve: Curve,
+    // Distance along the contour where the curve starts
+    start: f32,
+    // (t, length from the start of the curve), starting with (0, 0)
+    table: Vec<(f32, f32)>,
+}
+
+impl CurveMeasure {
+    fn length(&self) -> f32 {
+        self.table.last().map(|(_, s)| *s).unwrap_or(0.)
+    }
+
+    // Distance from the start of the curve to the parameter t.
+    fn distance_at(&self, t: f32) -> f32 {
+        let idx = self.table.partition_point(|(tt, _)| *tt < t).clamp(1, self.table.len() - 1);
+        let (ta, sa) = self.table[idx - 1];
+        sa + self.curve.arc_length(ta, t.clamp(ta, 1.))
+    }
+
+    // Parameter at `distance` from the start of the curve.
+    fn t_at(&self, distance: f32) -> f32 {
+        if distance <= 0. {
+            return 0.;
+        }
+        if distance >= self.length() {
+            return 1.;
+        }
+        let idx = self.table.partition_point(|(_, s)| *s < distance).clamp(1, self.table.len() - 1);
+        let (ta, sa) = self.table[idx - 1];
+        let (tb, sb) = self.table[idx];
+        // Start from linear interpolation and refine with Newton steps, keeping within the piece
+        let mut t = if sb > sa { ta + (tb - ta) * (distance - sa) / (sb - sa) } else { ta };
+        for _ in 0..8 {
+            let error = sa + self.curve.arc_length(ta, t) - distance;
+            let speed = self.curve.derivative(t).length();
+            if error.abs() < 1e-5 || speed < 1e-9 {
+                break;
+            }
+            t = (t - error / speed).clamp(ta, tb);
+        }
+        t
+    }
+}
+
+/// Arc length measurement of one contour.
+#[derive(Debug, Clone)]
+pub struct ContourMeasure {
+    curves: Vec<CurveMeasure>,
+    length: f32,
+    pub closed: bool,
+}
+
+impl ContourMeasure {
+    pub fn length(&self) -> f32 {
+        self.length
+    }
+
+    // Index of the curve at `distance` and the parameter on it.
+    fn locate(&self, distance: f32) -> (usize, f32) {
+        let distance = distance.clamp(0., self.length);
+        let idx = self.curves.partition_point(|c| c.start + c.length() < distance).min(self.curves.len() - 1);
+        (idx, self.curves[idx].t_at(distance - self.curves[idx].start))
+    }
+
+    /// Point and unit tangent at `distance` from the start of the contour, clamped to its ends.
+    pub fn pos_tan(&self, distance: f32) -> (Point, Point) {
+        let (idx, t) = self.locate(distance);
+        let curve = &self.curves[idx].curve;
+        (curve.eval(t), curve.tangent(t))
+    }
+}
+
+/// Location on a path: the curve (in `PathSegments::curves` order) and parameter on it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PathLocation {
+    pub contour: usize,
+    pub curve: usize,
+    pub t: f32,
+}
+
+/// Arc length table of a path, for repeated lookups of points and tangents by distance.
+/// Distances run through the contours in order; the jumps between contours add nothing.
+#[derive(Debug, Clone)]
+pub struct PathMeasure {
+    contours: Vec<ContourMeasure>,
+    length: f32,
+}
+
+impl PathMeasure {
+    /// Measures the path, each curve's length being accurate within `tolerance`.
+    pub fn new(path: &PathSegments, tolerance: f32) -> PathMeasure {
+        let tolerance = tolerance.max(1e-6);
+        let mut contours = vec![];
+        let mut length = 0.;
+        for contour in path.curve_contours() {
+            let mut curves = vec![];
+            let mut contour_length = 0.;
+            for curve in contour.curves {
+                let mut table = vec![(0., 0.)];
+                curve.subdivide(0., 1., tolerance, 0, &mut table);
+                let measure = CurveMeasure { curve, start: contour_length, table };
+                contour_length += measure.length();
+                curves.push(measure);
+            }
+            length += contour_length;
+            contours.push(ContourMeasure { curves, length: contour_length, closed: contour.closed });
+        }
+        PathMeasure { contours, length }
+    }
+
+    pub fn length(&self) -> f32 {
+        self.length
+    }
+
+    pub fn contours(&self) -> &[ContourMeasure] {
+        &self.contours
+    }
+
+    pub fn contour_lengths(&self) -> Vec<f32> {
+        self.contours.iter().map(|c| c.length).collect()
+    }
+
+    /// Location at `distance` along the path, clamped to its ends. None for an empty path.
+    pub fn locate(&self, distance: f32) -> Option<PathLocation> {
+        let mut distance = distance.clamp(0., self.length);
+        let mut curve_offset = 0;
+        for (idx, contour) in self.contours.iter().enumerate() {
+            if distance <= contour.length || idx == self.contours.len() - 1 {
+                let (curve, t) = contour.locate(distance);
+                return Some(PathLocation { contour: idx, curve: curve_offset + curve, t });
+            }
+            distance -= contour.length;
+            curve_offset += contour.curves.len();
+        }
+        None
+    }
+
+    /// Distance along the path of a location, the inverse of `locate`.
+    pub fn distance_at(&self, location: &PathLocation) -> f32 {
+        let mut distance = 0.;
+        let mut curve_offset = 0;
+        for contour in self.contours.iter() {
+            if location.curve < curve_offset + contour.curves.len() {
+                let measure = &contour.curves[location.curve - curve_offset];
+                return distance + measure.start + measure.distance_at(location.t.clamp(0., 1.));
+            }
+            distance += contour.length;
+            curve_offset += contour.curves.len();
+        }
+        self.length
+    }
+
+    fn curve(&self, location: &PathLocation) -> &Curve {
+        let mut idx = location.curve;
+        for contour in self.contours.iter() {
+            if idx < contour.curves.len() {
+                return &contour.curves[idx].curve;
+            }
+            idx -= contour.curves.len();
+        }
+        unreachable!("location outside of the measured path")
+    }
+
+    /// Point and unit tangent at `distance` along the path.
+    pub fn pos_tan_at(&self, distance: f32) -> Option<(Point, Point)> {
+        let location = self.locate(distance)?;
+        let curve = self.curve(&location);
+        Some((curve.eval(location.t), curve.tangent(location.t)))
+    }
+
+    pub fn point_at(&self, distance: f32) -> Option<Point> {
+        self.pos_tan_at(distance).map(|(p, _)| p)
+    }
+
+    pub fn tangent_at(&self, distance: f32) -> Option<Point> {
+        self.pos_tan_at(distance).map(|(_, t)| t)
+    }
+
+    /// Point at a fraction 0..1 of the total length.
+    pub fn point_at_fraction(&self, fraction: f32) -> Option<Point> {
+        self.point_at(fraction * self.length)
+    }
+
+    pub fn tangent_at_fraction(&self, fraction: f32) -> Option<Point> {
+        self.tangent_at(fraction * self.length)
+    }
+
+    /// Distance along the path of the outline point closest to (x, y).
+    pub fn distance_to_point(&self, x: f32, y: f32) -> Option<f32> {
+        let p = Point(x, y);
+        let mut best: Option<(f32, PathLocation)> = None;
+        let mut curve_idx = 0;
+        for (contour_idx, contour) in self.contours.iter().enumerate() {
+            for measure in contour.curves.iter() {
+                let (t, d) = measure.curve.nearest(p);
+                if best.is_none_or(|(best_d, _)| d < best_d) {
+                    best = Some((d, PathLocation { contour: contour_idx, curve: curve_idx, t }));
+                }
+                curve_idx += 1;
+            }
+        }
+        best.map(|(_, location)| self.distance_at(&location))
+    }
+
+    /// The part of the path between two distances along it. Curves are split exactly at the
+    /// cut points; a closed contour that is kept whole stays closed.
+    pub fn extract(&self, start: f32, end: f32) -> PathSegments {
+        let mut segments = vec![];
+        let mut offset = 0.;
+        for contour in self.contours.iter() {
+            let (from, to) = (start.max(offset) - offset, end.min(offset + contour.length) - offset);
+            offset += contour.length;
+            if from > to || (from == to && contour.length > 0.) {
+                continue;
+            }
+            let whole = from <= 0. && to >= contour.length;
+            let mut first = true;
+            for measure in contour.curves.iter() {
+                let (curve_start, curve_end) = (measure.start, measure.start + measure.length());
+                if !whole && (curve_end <= from || curve_start >= to) {
+                    continue;
+                }
+                let t0 = if from > curve_start { measure.t_at(from - curve_start) } else { 0. };
+                let t1 = if to < curve_end { measure.t_at(to - curve_start) } else { 1. };
+                let curve = measure.curve.subsection(t0, t1);
+                if first {
+                    let p = curve.start();
+                    segments.push(PathSegment::M(p.0, p.1));
+                    first = false;
+                }
+                segments.push(curve.to_segment());
+            }
+            if whole && contour.closed {
+                segments.push(PathSegment::Z);
+            }
+        }
+        PathSegments(segments)
+    }
+}
+
+impl PathSegments {
+    /// The part of the path between two fractions 0..1 of its total length.
+    pub fn trim(&self, start: f32, end: f32) -> PathSegments {
+        let measure = self.measure(DEFAULT_MEASURE_TOLERANCE);
+        let length = measure.length();
+        measure.extract(start.clamp(0., 1.) * length, end.clamp(0., 1.) * length)
+    }
+
+    pub fn measure(&self, tolerance: f32) -> PathMeasure {
+        PathMeasure::new(self, tolerance)
+    }
+
+    /// Total arc length of all contours.
+    pub fn length(&self) -> f32 {
+        self.measure(DEFAULT_MEASURE_TOLERANCE).length()
+    }
+
+    pub fn contour_lengths(&self) -> Vec<f32> {
+        self.measure(DEFAULT_MEASURE_TOLERANCE).contour_lengths()
+    }
+
+    pub fn point_at_length(&self, distance: f32) -> Option<Point> {
+        self.measure(DEFAULT_MEASURE_TOLERANCE).point_at(distance)
+    }
+
+    pub fn tangent_at_length(&self, distance: f32) -> Option<Point> {
+        self.measure(DEFAULT_MEASURE_TOLERANCE).tangent_at(distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::path::PathSegment::*;
+    use crate::path::{PathSegment, PathSegments};
+    use std::f32::consts::PI;
+
+    #[test]
+    fn lengths_points_and_tangents() {
+        let square = PathSegments::rect(0., 0., 10., 10.);
+        let measure = square.measure(0.001);
+        assert_eq!(measure.length(), 40.);
+        let (p, t) = measure.pos_tan_at(15.).unwrap();
+        assert!((p.0 - 10.).abs() < 1e-5 && (p.1 - 5.).abs() < 1e-5);
+        assert!((t.1 - 1.).abs() < 1e-5);
+        assert!((measure.distance_to_point(3., -2.).unwrap() - 3.).abs() < 1e-4);
+
+        let circle = PathSegments::ellipse(0., 0., 100., 100.);
+        let measure = circle.measure(0.0001);
+        // The cubic approximation is within 0.03% of a true circle
+        assert!((measure.length() - 100. * PI).abs() < 0.1);
+        let quarter = measure.point_at_fraction(0.25).unwrap();
+        assert!((quarter.0 - 50.).abs() < 0.01 && (quarter.1 - 100.).abs() < 0.01);
+        for i in 0..=10 {
+            let d = measure.length() * i as f32 / 10.;
+            let p = measure.point_at(d).unwrap();
+            assert!((measure.distance_to_point(p.0, p.1).unwrap() - d).abs() < 0.05 || i == 0 || i == 10);
+        }
+
+        // Quadratic with a known closed-form length
+        let parabola = PathSegments(vec![M(0., 0.), Q(1., 2., 2., 0.), M(5., 5.), L(8., 9.)]);
+        let lengths = parabola.contour_lengths();
+        let exact = 5f32.sqrt() + (2. + 5f32.sqrt()).ln() / 2.;
+        assert!((lengths[0] - exact).abs() < 1e-3);
+        assert_eq!(lengths[1], 5.);
+        let start = parabola.point_at_length(lengths[0]).unwrap();
+        assert!((start.0 - 2.).abs() < 1e-4 && start.1.abs() < 1e-4);
+    }
+
+    #[test]
+    fn trim() {
+        let square = PathSegments::rect(0., 0., 10., 10.);
+        assert_eq!(square.trim(0., 1.).to_string(), "M 0 0 L 10 0 L 10 10 L 0 10 L 0 0 Z ");
+        assert_eq!(square.trim(0.125, 0.375).to_string(), "M 5 0 L 10 0 L 10 5 ");
+        assert!(square.trim(0.5, 0.25).0.is_empty());
+
+        // Cut points on curves are exact splits of the original curve
+        let curve = PathSegments(vec![M(0., 0.), C(0., 50., 100., 50., 100., 0.), M(200., 0.), L(300., 0.)]);
+        let length = curve.length();
+        let first = curve.contour_lengths()[0];
+        let part = curve.trim(0.1, first / length);
+        assert!((part.length() - (first - 0.1 * length)).abs() < 0.01);
+        assert_eq!(part.0.len(), 2);
+        let head = curve.trim(0., 0.1);
+        let (PathSegment::C(.., x0, y0), PathSegment::M(x1, y1)) = (&head.0[1], &part.0[0]) else { panic!() };
+        assert!((x0 - x1).abs() < 1e-4 && (y0 - y1).abs() < 1e-4);
+        let both = curve.trim(0.5, 1.);
+        assert_eq!(both.0.iter().filter(|s| matches!(s, M(..))).count(), 2);
+    }
+}
@@ -0,0 +1,170 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::core::{Point, Rectangle};
+use crate::error::{GeometryError, Result};
+
+pub mod arc;
+pub mod contour;
+pub mod curve;
+pub mod hit;
+pub mod measure;
+pub mod offset;
+pub mod parse;
+pub mod shape;
+pub mod simplify;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum PathSegment {
+    M(f32, f32),
+    L(f32, f32),
+    Q(f32, f32, f32, f32),
+    C(f32, f32, f32, f32, f32, f32),
+    A(f32, f32, f32, bool, bool, f32, f32), // (rx, ry, x axis rotation in degrees, large arc, sweep, x, y)
+    Z,
+}
+
+impl PathSegment {
+    /// The point the segment ends at, None for `Z`.
+    pub fn end_point(&self) -> Option<Point> {
+        match self {
+            PathSegment::M(x, y) | PathSegment::L(x, y) | PathSegment::Q(_, _, x, y) | PathSegment::C(_, _, _, _, x, y) | PathSegment::A(_, _, _, _, _, x, y) => Some(Point(*x, *y)),
+            PathSegment::Z => None,
+        }
+    }
+}
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PathSegments(pub Vec<PathSegment>);
+
+impl Display for PathSegments {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for segment in self.0.iter() {
+            match segment {
+                PathSegment::M(x, y) => write!(f, "M {} {} ", x, y)?,
+                PathSegment::L(x, y) => write!(f, "L {} {} ", x, y)?,
+                PathSegment::Q(x1, y1, x, y) => write!(f, "Q {} {} {} {} ", x1, y1, x, y)?,
+                PathSegment::C(x1, y1, x2, y2, x, y) => write!(f, "C {} {} {} {} {} {} ", x1, y1, x2, y2, x, y)?,
+                PathSegment::A(rx, ry, rotation, large_arc, sweep, x, y) => write!(f, "A {} {} {} {} {} {} {} ", rx, ry, rotation, *large_arc as u8, *sweep as u8, x, y)?,
+                PathSegment::Z => write!(f, "Z ")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PathSegments {
+
+    pub fn from_json(json: &str) -> Result<PathSegments> {
+        let path: Vec<PathSegment> = serde_json::from_str(json)?;
+        Ok(PathSegments(path))
+    }
+
+    /// Whether builders can render the path: it is not empty, starts with a move and has
+    /// finite coordinates only.
+    pub fn check(&self) -> Result<(), GeometryError> {
+        match self.0.first() {
+            None => Err(GeometryError::EmptyPath),
+            Some(PathSegment::M(..)) if self.is_finite() => Ok(()),
+            Some(PathSegment::M(..)) => Err(GeometryError::NonFinite),
+            Some(_) => Err(GeometryError::MissingMove),
+        }
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.0.iter().all(|segment| match *segment {
+            PathSegment::M(x, y) | PathSegment::L(x, y) => x.is_finite() && y.is_finite(),
+            PathSegment::Q(x1, y1, x, y) => [x1, y1, x, y].iter().all(|v| v.is_finite()),
+            PathSegment::C(x1, y1, x2, y2, x, y) => [x1, y1, x2, y2, x, y].iter().all(|v| v.is_finite()),
+            PathSegment::A(rx, ry, rotation, _, _, x, y) => [rx, ry, rotation, x, y].iter().all(|v| v.is_finite()),
+            PathSegment::Z => true,
+        })
+    }
+
+    pub fn rect(x: f32, y: f32, w: f32, h: f32) -> PathSegments {
+        PathSegments(vec![PathSegment::M(x, y), PathSegment::L(x + w, y), PathSegment::L(x + w, y + h), PathSegment::L(x, y + h), PathSegment::Z])
+    }
+
+    /// Ellipse inscribed in the rectangle, as four cubic quarter arcs.
+    pub fn ellipse(x: f32, y: f32, w: f32, h: f32) -> PathSegments {
+        const KAPPA: f32 = 0.552_284_8;
+        let (rx, ry) = (w / 2., h / 2.);
+        let (cx, cy) = (x + rx, y + ry);
+        let (kx, ky) = (rx * KAPPA, ry * KAPPA);
+        PathSegments(vec![
+            PathSegment::M(cx + rx, cy),
+            PathSegment::C(cx + rx, cy + ky, cx + kx, cy + ry, cx, cy + ry),
+            PathSegment::C(cx - kx, cy + ry, cx - rx, cy + ky, cx - rx, cy),
+            PathSegment::C(cx - rx, cy - ky, cx - kx, cy - ry, cx, cy - ry),
+            PathSegment::C(cx + kx, cy - ry, cx + rx, cy - ky, cx + rx, cy),
+            PathSegment::Z,
+        ])
+    }
+
+    pub fn extend(&mut self, other: &PathSegments) {
+        self.0.extend(other.0.iter().cloned());
+    }
+}
+
+impl PathSegments {
+    pub fn move_path(&self, move_x: f32, move_y: f32) -> PathSegments {
+        let mut segments: Vec<PathSegment> = vec![];
+        for segment in self.0.iter() {
+            let new_segment: PathSegment = match segment {
+                PathSegment::M(x, y) => PathSegment::M(x + move_x, y + move_y),
+                PathSegment::L(x, y) => PathSegment::L(x + move_x, y + move_y),
+                PathSegment::Q(x1, y1, x, y) => PathSegment::Q(x1 + move_x, y1 + move_y, x + move_x, y + move_y),
+                PathSegment::C(x1, y1, x2, y2, x, y) => PathSegment::C(x1 + move_x, y1 + move_y, x2 + move_x, y2 + move_y, x + move_x, y + move_y),
+                PathSegment::A(rx, ry, rotation, large_arc, sweep, x, y) => PathSegment::A(*rx, *ry, *rotation, *large_arc, *sweep, x + move_x, y + move_y),
+                PathSegment::Z => PathSegment::Z,
+            };
+            segments.push(new_segment);
+        }
+        PathSegments(segments)
+    }
+
+    pub fn scale_path(&self, scale_x: f32, scale_y: f32) -> PathSegments {
+        let mut segments: Vec<PathSegment> = vec![];
+        for segment in self.0.iter() {
+            let new_segment: PathSegment = match segment {
+                PathSegment::M(x, y) => PathSegment::M(x * scale_x, y * scale_y),
+                PathSegment::L(x, y) => PathSegment::L(x * scale_x, y * scale_y),
+                PathSegment::Q(x1, y1, x, y) => PathSegment::Q(x1 * scale_x, y1 * scale_y, x * scale_x, y * scale_y),
+                PathSegment::C(x1, y1, x2, y2, x, y) => PathSegment::C(x1 * scale_x, y1 * scale_y, x2 * scale_x, y2 * scale_y, x * scale_x, y * scale_y),
+                PathSegment::A(rx, ry, rotation, large_arc, sweep, x, y) => {
+                    let (rx, ry, rotation, sweep) = arc::scale_arc(*rx, *ry, *rotation, *sweep, scale_x, scale_y);
+                    PathSegment::A(rx, ry, rotation, *large_arc, sweep, x * scale_x, y * scale_y)
+                }
+                PathSegment::Z => PathSegment::Z,
+            };
+            segments.push(new_segment);
+        }
+        PathSegments(segments)
+    }
+
+    /// Bounding box of all points, control points included. Arcs count with the control
+    /// points of their cubic approximation.
+    pub fn bbox(&self) -> Rectangle {
+        if self.has_arcs() {
+            return self.arcs_to_cubics().bbox();
+        }
+        let mut bbox = Rectangle::empty();
+        let mut add = |x: f32, y: f32| bbox = bbox.union(&Rectangle(x, y, x, y));
+        for segment in self.0.iter() {
+            match segment {
+                PathSegment::M(x, y) | PathSegment::L(x, y) => add(*x, *y),
+                PathSegment::Q(x1, y1, x, y) => {
+                    add(*x1, *y1);
+                    add(*x, *y);
+                }
+                PathSegment::C(x1, y1, x2, y2, x, y) => {
+                    add(*x1, *y1);
+                    add(*x2, *y2);
+                    add(*x, *y);
+                }
+                PathSegment::A(..) | PathSegment::Z => {}
+            }
+        }
+        bbox
+    }
+}
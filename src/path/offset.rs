@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+
+use crate::core::core::Point;
+use crate::path::curve::Curve;
+use crate::path::{PathSegment, PathSegments};
+
+/// How offset edges are connected around convex corners.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Join {
+    /// Extends the edges to a point, falling back to a bevel where the miter would be
+    /// longer than `limit` times the offset distance.
+    Miter(f32),
+    Round,
+    Bevel,
+}
+
+impl PathSegments {
+    /// Grows (positive distance) or shrinks (negative distance) the filled area of the path.
+    /// Contours are treated as closed. The outline is flattened, offset, cleared of the loops
+    /// that offsetting creates where it folds over itself, and refitted to curves.
+    pub fn offset(&self, distance: f32, join: Join) -> PathSegments {
+        if distance == 0. {
+            return self.clone();
+        }
+        let tolerance = (distance.abs() / 100.).max(0.01);
+        let normalized = self.normalize_winding();
+        let polygons: Vec<Vec<Point>> = normalized.curve_contours().iter().map(|contour| {
+            let mut points = vec![contour.curves[0].start()];
+            for curve in contour.curves.iter() {
+                for p in curve.flatten(tolerance) {
+                    if p != points[points.len() - 1] {
+                        points.push(p);
+                    }
+                }
+            }
+            if points.len() > 1 && points[0] == points[points.len() - 1] {
+                points.pop();
+            }
+            points
+        }).filter(|points| points.len() > 2).collect();
+
+        let mut segments = vec![];
+        for polygon in polygons.iter() {
+            let raw = offset_polygon(polygon, distance, join, tolerance);
+            for path_loop in split_loops(&raw) {
+                if path_loop.len() > 2 && is_valid_loop(&path_loop, &polygons, self, distance, tolerance) {
+                    segments.push(PathSegment::M(path_loop[0].0 .0, path_loop[0].0 .1));
+                    segments.extend(path_loop[1..].iter().map(|(p, _)| PathSegment::L(p.0, p.1)));
+                    segments.push(PathSegment::Z);
+                }
+            }
+        }
+        PathSegments(segments).simplify(tolerance)
+    }
+}
+
+// Offsets every edge of a polygon, outlines running clockwise, along its right hand normal
+// (outwards, with y pointing down) and connects consecutive edges with the join. Concave
+// corners are connected through the original vertex; the loops this leaves are removed later.
+fn offset_polygon(polygon: &[Point], distance: f32, join: Join, tolerance: f32) -> Vec<Point> {
+    let n = polygon.len();
+    let normal = |a: Point, b: Point| {
+        let d = (b - a).normalize();
+        Point(d.1, -d.0)
+    };
+    let mut out = vec![];
+    for i in 0..n {
+        let (a, b, c) = (polygon[i], polygon[(i + 1) % n], polygon[(i + 2) % n]);
+        let (n1, n2) = (normal(a, b), normal(b, c));
+        out.push(a + n1 * distance);
+        let p1 = b + n1 * distance;
+        let p2 = b + n2 * distance;
+        out.push(p1);
+        let turn = (b - a).cross(c - b);
+        if n1.dot(n2) > 0.9999 {
+            continue;
+        }
+        if turn * distance < 0. {
+            out.push(b);
+            out.push(p2);
+            continue;
+        }
+        match join {
+            Join::Bevel => {}
+            Join::Miter(limit) => {
+                let half = (n1 + n2) * 0.5;
+                let cos_half = half.length();
+                if cos_half > 0. && 1. / cos_half <= limit {
+                    out.push(b + half.normalize() * (distance / cos_half));
+                }
+            }
+            Join::Round => {
+                let radius = distance.abs();
+                let step = 2. * (1. - tolerance.min(radius) / radius).acos();
+                let sweep = n1.cross(n2).atan2(n1.dot(n2));
+                let count = (sweep.abs() / step.max(1e-3)).ceil() as usize;
+                let start = n1.1.atan2(n1.0);
+                for k in 1..count {
+                    let angle = start + sweep * k as f32 / count as f32;
+                    out.push(b + Point(angle.cos(), angle.sin()) * distance);
+                }
+            }
+        }
+        out.push(p2);
+    }
+    out.dedup();
+    if out.len() > 1 && out[0] == out[out.len() - 1] {
+        out.pop();
+    }
+    out
+}
+
+fn segment_intersection(a: Point, b: Point, c: Point, d: Point) -> Option<(f32, f32)> {
+    let (r, s) = (b - a, d - c);
+    let denominator = r.cross(s);
+    if denominator.abs() < 1e-12 {
+        return None;
+    }
+    let t = (c - a).cross(s) / denominator;
+    let u = (c - a).cross(r) / denominator;
+    if t > 0. && t < 1. && u > 0. && u < 1. {
+        Some((t, u))
+    } else {
+        None
+    }
+}
+
+// Decomposes a closed, self-intersecting polygon into simple loops. Crossing points are
+// inserted as shared nodes; walking the polygon, every return to a node on the way closes
+// a loop. Points are returned with a flag telling whether they are crossings.
+fn split_loops(polygon: &[Point]) -> Vec<Vec<(Point, bool)>> {
+    let n = polygon.len();
+    let edge = |i: usize| (polygon[i], polygon[(i + 1) % n]);
+    let bounds: Vec<(f32, f32)> = (0..n).map(|i| {
+        let (a, b) = edge(i);
+        (a.0.min(b.0), a.0.max(b.0))
+    }).collect();
+
+    let mut crossings: Vec<Vec<(f32, usize)>> = vec![vec![]; n];
+    let mut nodes: Vec<Point> = vec![];
+    for i in 0..n {
+        for j in i + 2..n {
+            if (i == 0 && j == n - 1) || bounds[i].1 < bounds[j].0 || bounds[j].1 < bounds[i].0 {
+                continue;
+            }
+            let ((a, b), (c, d)) = (edge(i), edge(j));
+            if let Some((t, u)) = segment_intersection(a, b, c, d) {
+                crossings[i].push((t, nodes.len()));
+                crossings[j].push((u, nodes.len()));
+                nodes.push(a.lerp(b, t));
+            }
+        }
+    }
+
+    // The polygon with crossings inserted, nodes marked by their index
+    let mut walk: Vec<(Point, Option<usize>)> = vec![];
+    for (i, edge_crossings) in crossings.iter_mut().enumerate() {
+        walk.push((polygon[i], None));
+        edge_crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+        walk.extend(edge_crossings.iter().map(|(_, node)| (nodes[*node], Some(*node))));
+    }
+
+    let mut loops = vec![];
+    let mut stack: Vec<(Point, bool)> = vec![];
+    let mut on_stack: HashMap<usize, usize> = HashMap::new();
+    for (p, node) in walk {
+        if let Some(node) = node {
+            if let Some(&pos) = on_stack.get(&node) {
+                loops.push(stack.split_off(pos));
+                on_stack.retain(|_, at| *at < pos);
+            }
+            on_stack.insert(node, stack.len());
+        }
+        stack.push((p, node.is_some()));
+    }
+    loops.push(stack);
+    loops
+}
+
+// A loop belongs to the result if it keeps the offset distance from the original outline
+// and lies on the side of it that the sign of the distance asks for. Its vertices are
+// tested rather than its edges, as the chords of bevels and round joins come closer.
+fn is_valid_loop(path_loop: &[(Point, bool)], polygons: &[Vec<Point>], original: &PathSegments, distance: f32, tolerance: f32) -> bool {
+    let n = path_loop.len();
+    let outline_distance = |p: Point| {
+        polygons
+            .iter()
+            .flat_map(|polygon| (0..polygon.len()).map(move |i| Curve::Line(polygon[i], polygon[(i + 1) % polygon.len()])))
+            .map(|line| line.nearest(p).1)
+            .fold(f32::MAX, f32::min)
+    };
+    let mut samples: Vec<Point> = path_loop.iter().filter(|(_, crossing)| !crossing).map(|(p, _)| *p).collect();
+    if samples.is_empty() {
+        samples = (0..n).map(|i| path_loop[i].0.lerp(path_loop[(i + 1) % n].0, 0.5)).collect();
+    }
+    if samples.iter().any(|p| outline_distance(*p) < distance.abs() - 1.5 * tolerance) {
+        return false;
+    }
+    samples.iter().all(|p| original.contains(p.0, p.1) == (distance < 0.))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Join;
+    use crate::path::PathSegment::*;
+    use crate::path::PathSegments;
+
+    #[test]
+    fn offset_outlines() {
+        let square = PathSegments::rect(0., 0., 100., 100.);
+        assert!((square.offset(10., Join::Miter(4.)).signed_area() - 14400.).abs() < 1.);
+        assert!((square.offset(10., Join::Bevel).signed_area() - 14200.).abs() < 1.);
+        let round = square.offset(10., Join::Round);
+        assert!((round.signed_area() - (14000. + 100. * std::f32::consts::PI)).abs() < 5.);
+        assert!((round.distance(-10., 50.)).abs() < 0.5);
+        assert!((square.offset(-10., Join::Round).signed_area() - 6400.).abs() < 1.);
+
+        // The inner corner of an L shape folds over itself when outset
+        let l_shape = PathSegments(vec![M(0., 0.), L(20., 0.), L(20., 80.), L(100., 80.), L(100., 100.), L(0., 100.), Z]);
+        let grown = l_shape.offset(5., Join::Miter(4.));
+        assert_eq!(grown.contours().len(), 1);
+        assert!((grown.signed_area() - (30. * 110. + 80. * 30.)).abs() < 1.);
+        // Insetting by more than half the arm width leaves nothing
+        assert!(l_shape.offset(-11., Join::Miter(4.)).0.is_empty());
+
+        // A ring keeps its hole, which shrinks as the outline grows
+        let ring = PathSegments(vec![M(0., 0.), L(100., 0.), L(100., 100.), L(0., 100.), Z, M(25., 25.), L(25., 75.), L(75., 75.), L(75., 25.), Z]);
+        let grown = ring.offset(5., Join::Miter(4.));
+        assert_eq!(grown.contours().len(), 2);
+        assert!((grown.signed_area() - (110. * 110. - 40. * 40.)).abs() < 1.);
+        assert_eq!(ring.offset(30., Join::Miter(4.)).contours().len(), 1);
+
+        #[cfg(feature = "cadenza")]
+        {
+            let head = PathSegments(crate::glyphs::cadenza::CADENZA_HEAD_BLACK.segments().unwrap().to_vec());
+            let halo = head.offset(20., Join::Round);
+            assert!(halo.signed_area() > head.signed_area().abs());
+            let head_bbox = head.bbox();
+            let halo_bbox = halo.bbox();
+            assert!((head_bbox.0 - halo_bbox.0 - 20.).abs() < 2. && (halo_bbox.2 - head_bbox.2 - 20.).abs() < 2.);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use std::str::FromStr;
+
+use crate::core::core::Point;
+use crate::error::GraphicsError;
+use crate::path::{PathSegment, PathSegments};
+
+/// Parses SVG path data. Relative commands are made absolute, and the shorthand commands
+/// `H`, `V`, `S` and `T` are written out as the lines and curves they stand for.
+impl FromStr for PathSegments {
+    type Err = GraphicsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = Tokens { s: s.as_bytes(), pos: 0 };
+        let mut segments = vec![];
+        let mut pos = Point(0., 0.);
+        let mut start = Point(0., 0.);
+        // Last control point of a cubic or quadratic, reflected by S and T
+        let mut last_cubic: Option<Point> = None;
+        let mut last_quad: Option<Point> = None;
+        let mut command = None;
+
+        while let Some(next) = tokens.peek() {
+            let cmd = if next.is_ascii_alphabetic() {
+                tokens.pos += 1;
+                next
+            } else {
+                // Coordinates after M without a command are implicit L
+                match command {
+                    Some(b'M') => b'L',
+                    Some(b'm') => b'l',
+                    Some(cmd) => cmd,
+                    None => return Err(tokens.error()),
+                }
+            };
+            command = Some(cmd);
+            let relative = cmd.is_ascii_lowercase();
+            let origin = if relative { pos } else { Point(0., 0.) };
+            let mut point = |tokens: &mut Tokens| -> Result<Point, GraphicsError> {
+                let x = tokens.number()?;
+                let y = tokens.number()?;
+                Ok(Point(origin.0 + x, origin.1 + y))
+            };
+
+            let (mut cubic, mut quad) = (None, None);
+            match cmd.to_ascii_uppercase() {
+                b'M' => {
+                    pos = point(&mut tokens)?;
+                    start = pos;
+                    segments.push(PathSegment::M(pos.0, pos.1));
+                }
+                b'L' => {
+                    pos = point(&mut tokens)?;
+                    segments.push(PathSegment::L(pos.0, pos.1));
+                }
+                b'H' => {
+                    pos = Point(origin.0 + tokens.number()?, pos.1);
+                    segments.push(PathSegment::L(pos.0, pos.1));
+                }
+                b'V' => {
+                    pos = Point(pos.0, origin.1 + tokens.number()?);
+                    segments.push(PathSegment::L(pos.0, pos.1));
+                }
+                b'C' | b'S' => {
+                    let c1 = if cmd.eq_ignore_ascii_case(&b'C') { point(&mut tokens)? } else { last_cubic.map_or(pos, |c| pos * 2. - c) };
+                    let c2 = point(&mut tokens)?;
+                    pos = point(&mut tokens)?;
+                    segments.push(PathSegment::C(c1.0, c1.1, c2.0, c2.1, pos.0, pos.1));
+                    cubic = Some(c2);
+                }
+                b'Q' | b'T' => {
+                    let c = if cmd.eq_ignore_ascii_case(&b'Q') { point(&mut tokens)? } else { last_quad.map_or(pos, |c| pos * 2. - c) };
+                    pos = point(&mut tokens)?;
+                    segments.push(PathSegment::Q(c.0, c.1, pos.0, pos.1));
+                    quad = Some(c);
+                }
+                b'A' => {
+                    let (rx, ry, rotation) = (tokens.number()?, tokens.number()?, tokens.number()?);
+                    let (large_arc, sweep) = (tokens.flag()?, tokens.flag()?);
+                    pos = point(&mut tokens)?;
+                    segments.push(PathSegment::A(rx, ry, rotation, large_arc, sweep, pos.0, pos.1));
+                }
+                b'Z' => {
+                    pos = start;
+                    segments.push(PathSegment::Z);
+                    command = None;
+                }
+                _ => return Err(tokens.error()),
+            }
+            (last_cubic, last_quad) = (cubic, quad);
+        }
+        Ok(PathSegments(segments))
+    }
+}
+
+struct Tokens<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl Tokens<'_> {
+    // The next byte that is not whitespace or a comma
+    fn peek(&mut self) -> Option<u8> {
+        while self.pos < self.s.len() && (self.s[self.pos].is_ascii_whitespace() || self.s[self.pos] == b',') {
+            self.pos += 1;
+        }
+        self.s.get(self.pos).copied()
+    }
+
+    fn number(&mut self) -> Result<f32, GraphicsError> {
+        self.peek();
+        let begin = self.pos;
+        let digits = |tokens: &mut Self| {
+            while tokens.s.get(tokens.pos).is_some_and(|c| c.is_ascii_digit()) {
+                tokens.pos += 1;
+            }
+        };
+        if matches!(self.s.get(self.pos), Some(b'+' | b'-')) {
+            self.pos += 1;
+        }
+        digits(self);
+        if self.s.get(self.pos) == Some(&b'.') {
+            self.pos += 1;
+            digits(self);
+        }
+        if matches!(self.s.get(self.pos), Some(b'e' | b'E')) && self.s.get(self.pos + 1).is_some_and(|c| c.is_ascii_digit() || *c == b'-' || *c == b'+') {
+            self.pos += 2;
+            digits(self);
+        }
+        std::str::from_utf8(&self.s[begin..self.pos]).ok().and_then(|n| n.parse().ok()).ok_or_else(|| {
+            self.pos = begin;
+            self.error()
+        })
+    }
+
+    // Arc flags are single digits that need no separator, as in "a5 5 0 015 5"
+    fn flag(&mut self) -> Result<bool, GraphicsError> {
+        let flag = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(self.error()),
+        };
+        self.pos += 1;
+        Ok(flag)
+    }
+
+    fn error(&self) -> GraphicsError {
+        let found = self.s.get(self.pos).map_or("end of data".to_string(), |c| format!("'{}'", *c as char));
+        GraphicsError::Parse { what: "path data", position: self.pos, message: format!("unexpected {}", found) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::path::PathSegments;
+
+    #[test]
+    fn parse_path_data() {
+        let path: PathSegments = "M 0 50 A 50 50 0 0 1 100 50 C 100 60 90 70 80 70 Q 50 90 0 50 Z ".parse().unwrap();
+        assert_eq!(path.to_string(), "M 0 50 A 50 50 0 0 1 100 50 C 100 60 90 70 80 70 Q 50 90 0 50 Z ");
+
+        // Relative, implicit and shorthand commands, packed numbers and flags
+        let path: PathSegments = "m10,10 10-5.5.5 0h5v-5s10 0 10 10t10 0a5 5 0 015 5z l1e1 0".parse().unwrap();
+        assert_eq!(path.to_string(), "M 10 10 L 20 4.5 L 20.5 4.5 L 25.5 4.5 L 25.5 -0.5 C 25.5 -0.5 35.5 -0.5 35.5 9.5 Q 35.5 9.5 45.5 9.5 A 5 5 0 0 1 50.5 14.5 Z L 20 10 ");
+        assert!("M 0 0 L 10".parse::<PathSegments>().is_err());
+        assert!("10 10".parse::<PathSegments>().is_err());
+    }
+}
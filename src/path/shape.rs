@@ -0,0 +1,135 @@
+use crate::core::core::Point;
+use crate::item::CornerRadii;
+use crate::path::{PathSegment, PathSegments};
+
+// Angles of shapes are in degrees, clockwise on screen from the positive x axis, as for
+// SVG arcs and rotations.
+fn on_ellipse(cx: f32, cy: f32, rx: f32, ry: f32, angle: f32) -> Point {
+    let (sin, cos) = angle.to_radians().sin_cos();
+    Point(cx + rx * cos, cy + ry * sin)
+}
+
+/// Corners of a regular polygon inscribed in a circle, the first one straight above the
+/// center when `rotation` is 0.
+pub fn regular_polygon(cx: f32, cy: f32, radius: f32, sides: usize, rotation: f32) -> Vec<Point> {
+    (0..sides).map(|i| on_ellipse(cx, cy, radius, radius, rotation - 90. + 360. * i as f32 / sides as f32)).collect()
+}
+
+/// Corners of a star with `points` tips on the outer radius, alternating with inner corners.
+pub fn star(cx: f32, cy: f32, outer_radius: f32, inner_radius: f32, points: usize, rotation: f32) -> Vec<Point> {
+    (0..points * 2)
+        .map(|i| {
+            let radius = if i % 2 == 0 { outer_radius } else { inner_radius };
+            on_ellipse(cx, cy, radius, radius, rotation - 90. + 180. * i as f32 / points as f32)
+        })
+        .collect()
+}
+
+impl PathSegments {
+    /// Rectangle with circular corners, radii reduced where they would overlap.
+    pub fn rounded_rect(x: f32, y: f32, w: f32, h: f32, radii: &CornerRadii) -> PathSegments {
+        let CornerRadii(tl, tr, br, bl) = radii.fitted(w, h);
+        let mut segments = vec![PathSegment::M(x + tl, y)];
+        // Each side, followed by the corner at its end
+        let sides = [
+            (Point(x + w - tr, y), tr, Point(x + w, y + tr)),
+            (Point(x + w, y + h - br), br, Point(x + w - br, y + h)),
+            (Point(x + bl, y + h), bl, Point(x, y + h - bl)),
+            (Point(x, y + tl), tl, Point(x + tl, y)),
+        ];
+        let mut pos = Point(x + tl, y);
+        for (side_end, r, corner_end) in sides {
+            if side_end != pos {
+                segments.push(PathSegment::L(side_end.0, side_end.1));
+            }
+            pos = side_end;
+            if r > 0. {
+                segments.push(PathSegment::A(r, r, 0., false, true, corner_end.0, corner_end.1));
+                pos = corner_end;
+            }
+        }
+        segments.push(PathSegment::Z);
+        PathSegments(segments)
+    }
+
+    pub fn polyline(points: &[Point]) -> PathSegments {
+        let mut segments = vec![];
+        for (idx, p) in points.iter().enumerate() {
+            segments.push(if idx == 0 { PathSegment::M(p.0, p.1) } else { PathSegment::L(p.0, p.1) });
+        }
+        PathSegments(segments)
+    }
+
+    pub fn polygon(points: &[Point]) -> PathSegments {
+        let mut path = PathSegments::polyline(points);
+        if !path.0.is_empty() {
+            path.0.push(PathSegment::Z);
+        }
+        path
+    }
+
+    /// Open arc of the ellipse centered at (cx, cy), from `start` over `sweep` degrees.
+    pub fn arc(cx: f32, cy: f32, rx: f32, ry: f32, start: f32, sweep: f32) -> PathSegments {
+        let p = on_ellipse(cx, cy, rx, ry, start);
+        let mut segments = vec![PathSegment::M(p.0, p.1)];
+        segments.extend(arc_segments(cx, cy, rx, ry, start, sweep));
+        PathSegments(segments)
+    }
+
+    /// Pie slice: the arc closed by two radii.
+    pub fn sector(cx: f32, cy: f32, rx: f32, ry: f32, start: f32, sweep: f32) -> PathSegments {
+        let p = on_ellipse(cx, cy, rx, ry, start);
+        let mut segments = vec![PathSegment::M(cx, cy), PathSegment::L(p.0, p.1)];
+        segments.extend(arc_segments(cx, cy, rx, ry, start, sweep));
+        segments.push(PathSegment::Z);
+        PathSegments(segments)
+    }
+}
+
+// Arc segments of at most half a turn each, so that the flags are never ambiguous and
+// full turns still have distinct end points.
+fn arc_segments(cx: f32, cy: f32, rx: f32, ry: f32, start: f32, sweep: f32) -> Vec<PathSegment> {
+    let sweep = sweep.clamp(-360., 360.);
+    let count = (sweep.abs() / 180.).ceil().max(1.) as usize;
+    (1..=count)
+        .map(|i| {
+            let p = on_ellipse(cx, cy, rx, ry, start + sweep * i as f32 / count as f32);
+            PathSegment::A(rx, ry, 0., false, sweep > 0., p.0, p.1)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{regular_polygon, star};
+    use crate::core::core::Point;
+    use crate::item::CornerRadii;
+    use crate::path::PathSegments;
+    use std::f32::consts::PI;
+
+    #[test]
+    fn shapes() {
+        let rounded = PathSegments::rounded_rect(0., 0., 100., 50., &CornerRadii(10., 0., 40., 40.));
+        assert_eq!(CornerRadii(10., 0., 40., 40.).fitted(100., 50.), CornerRadii(10., 0., 40., 40.));
+        // Radii that do not fit are all reduced in proportion
+        assert_eq!(CornerRadii(0., 0., 100., 100.).fitted(100., 50.), CornerRadii(0., 0., 50., 50.));
+        let corners = 10. * 10. + 2. * 40. * 40.;
+        assert!((rounded.signed_area() - (5000. - corners * (1. - PI / 4.))).abs() < 1.);
+        assert_eq!(rounded.to_string(), "M 10 0 L 100 0 L 100 10 A 40 40 0 0 1 60 50 L 40 50 A 40 40 0 0 1 0 10 A 10 10 0 0 1 10 0 Z ");
+        let bbox = rounded.bbox();
+        assert!(bbox.0.abs() < 1e-3 && bbox.1.abs() < 1e-3 && bbox.2 == 100. && bbox.3 == 50.);
+
+        let arc = PathSegments::arc(0., 0., 10., 10., 0., 360.);
+        assert!((arc.length() - 20. * PI).abs() < 0.01);
+        let sector = PathSegments::sector(0., 0., 10., 10., -90., 90.);
+        assert!((sector.signed_area() - 25. * PI).abs() < 0.1);
+        assert!(sector.contains(3., -3.) && !sector.contains(-3., -3.));
+
+        let hexagon = regular_polygon(0., 0., 10., 6, 0.);
+        assert_eq!(hexagon.len(), 6);
+        assert!(hexagon[0].distance(Point(0., -10.)) < 1e-5);
+        let star = PathSegments::polygon(&star(0., 0., 10., 4., 5, 0.));
+        assert_eq!(star.0.len(), 11);
+        assert!(star.contains(0., -9.) && !star.contains(3., -8.));
+    }
+}
@@ -0,0 +1,279 @@
+use crate::core::core::Point;
+use crate::path::curve::{Curve, CurveContour};
+use crate::path::{PathSegment, PathSegments};
+
+// Joins turning by more than this are kept as corners; runs of curves between corners
+// are refitted as a whole.
+const CORNER_COS: f32 = 0.985; // 10 degrees
+
+impl PathSegments {
+    /// Removes redundant geometry within `tolerance`: zero-length segments are dropped,
+    /// flat curves become lines, collinear lines are merged and smooth runs of segments
+    /// are refitted to as few cubic Béziers as possible.
+    pub fn simplify(&self, tolerance: f32) -> PathSegments {
+        let tolerance = tolerance.max(1e-6);
+        let mut segments = vec![];
+        for contour in self.curve_contours() {
+            segments.extend(simplify_contour(&contour, tolerance));
+        }
+        PathSegments(segments)
+    }
+}
+
+fn simplify_contour(contour: &CurveContour, tolerance: f32) -> Vec<PathSegment> {
+    let curves: Vec<Curve> = contour.curves.iter().filter(|curve| !is_zero_length(curve)).map(|curve| flatten_to_line(curve, tolerance)).collect();
+    if curves.is_empty() {
+        return vec![];
+    }
+
+    let mut runs: Vec<Vec<Curve>> = vec![vec![curves[0]]];
+    for pair in curves.windows(2) {
+        if pair[0].tangent(1.).dot(pair[1].tangent(0.)) < CORNER_COS {
+            runs.push(vec![]);
+        }
+        runs.last_mut().unwrap().push(pair[1]);
+    }
+
+    let start = curves[0].start();
+    let mut segments = vec![PathSegment::M(start.0, start.1)];
+    for run in runs {
+        segments.extend(simplify_run(&run, tolerance).iter().map(|curve| curve.to_segment()));
+    }
+    if contour.closed {
+        // The closing line is implied by Z
+        if let Some(PathSegment::L(x, y)) = segments.last() {
+            if segments.len() > 2 && Point(*x, *y) == start {
+                segments.pop();
+            }
+        }
+        segments.push(PathSegment::Z);
+    }
+    segments
+}
+
+fn simplify_run(run: &[Curve], tolerance: f32) -> Vec<Curve> {
+    let mut points = vec![run[0].start()];
+    let mut vertices = vec![run[0].start()];
+    for curve in run {
+        match curve {
+            Curve::Line(p0, p1) => {
+                // Long lines are sampled along their length, so that the fit cannot bulge between their ends
+                let n = (p0.distance(*p1) / (tolerance * 20.)).ceil().clamp(1., 1000.) as usize;
+                points.extend((1..=n).map(|i| p0.lerp(*p1, i as f32 / n as f32)));
+                vertices.push(*p1);
+            }
+            _ => points.extend(curve.flatten(tolerance / 10.)),
+        }
+    }
+    let (tan1, tan2) = (run[0].tangent(0.), run[run.len() - 1].tangent(1.) * -1.);
+    // Part of the tolerance is used up by flattening and sampling between the points
+    let mut fitted = vec![];
+    fit_cubics(&points, tan1, tan2, tolerance * 0.8, &mut fitted);
+
+    if run.iter().all(|curve| matches!(curve, Curve::Line(..))) {
+        let kept = douglas_peucker(&vertices, tolerance);
+        let lines: Vec<Curve> = kept.windows(2).map(|w| Curve::Line(w[0], w[1])).collect();
+        // Prefer lines unless the fit saves at least two segments
+        return if fitted.len() + 1 < lines.len() { fitted } else { lines };
+    }
+    if fitted.len() < run.len() {
+        fitted
+    } else {
+        run.to_vec()
+    }
+}
+
+fn is_zero_length(curve: &Curve) -> bool {
+    match curve {
+        Curve::Line(p0, p1) => p0 == p1,
+        Curve::Quad(p0, p1, p2) => p0 == p1 && p1 == p2,
+        Curve::Cubic(p0, p1, p2, p3) => p0 == p1 && p1 == p2 && p2 == p3,
+    }
+}
+
+// A curve whose control points lie on the chord, between its ends, is drawn as a line.
+fn flatten_to_line(curve: &Curve, tolerance: f32) -> Curve {
+    let (p0, p1) = (curve.start(), curve.end());
+    let controls = match curve {
+        Curve::Line(..) => return *curve,
+        Curve::Quad(_, c, _) => vec![*c],
+        Curve::Cubic(_, c1, c2, _) => vec![*c1, *c2],
+    };
+    let flat = controls.iter().all(|c| {
+        let (t, d) = Curve::Line(p0, p1).nearest(*c);
+        d <= tolerance && t > 0. && t < 1.
+    });
+    if flat && p0 != p1 {
+        Curve::Line(p0, p1)
+    } else {
+        *curve
+    }
+}
+
+fn douglas_peucker(points: &[Point], tolerance: f32) -> Vec<Point> {
+    fn keep(points: &[Point], tolerance: f32, out: &mut Vec<Point>) {
+        let (first, last) = (points[0], points[points.len() - 1]);
+        let chord = Curve::Line(first, last);
+        let farthest = (1..points.len() - 1).map(|i| (i, chord.nearest(points[i]).1)).fold((0, 0.), |best, cur| if cur.1 > best.1 { cur } else { best });
+        if farthest.1 > tolerance {
+            keep(&points[..=farthest.0], tolerance, out);
+            keep(&points[farthest.0..], tolerance, out);
+        } else {
+            out.push(last);
+        }
+    }
+    let mut out = vec![points[0]];
+    keep(points, tolerance, &mut out);
+    out
+}
+
+// Philip J. Schneider, "An Algorithm for Automatically Fitting Digitized Curves",
+// Graphics Gems, 1990. `tan1` points into the curve at its start, `tan2` at its end.
+fn fit_cubics(points: &[Point], tan1: Point, tan2: Point, tolerance: f32, out: &mut Vec<Curve>) {
+    let (first, last) = (points[0], points[points.len() - 1]);
+    if points.len() == 2 {
+        let d = first.distance(last) / 3.;
+        out.push(Curve::Cubic(first, first + tan1 * d, last + tan2 * d, last));
+        return;
+    }
+
+    let mut u = chord_parameters(points);
+    let mut curve = generate_bezier(points, &u, tan1, tan2);
+    let (mut error, mut split) = max_error(points, &curve, &u);
+    if error <= tolerance {
+        out.push(curve);
+        return;
+    }
+    // Chord length parameters are only a first guess; improve them before giving up and splitting
+    for _ in 0..20 {
+        u = reparameterize(points, &u, &curve);
+        let candidate = generate_bezier(points, &u, tan1, tan2);
+        let (candidate_error, candidate_split) = max_error(points, &candidate, &u);
+        if candidate_error <= tolerance {
+            out.push(candidate);
+            return;
+        }
+        if candidate_error >= error {
+            break;
+        }
+        (curve, error, split) = (candidate, candidate_error, candidate_split);
+    }
+
+    let mut center = points[split - 1] - points[split + 1];
+    if center.length() == 0. {
+        center = points[split - 1] - points[split];
+    }
+    let center = center.normalize();
+    fit_cubics(&points[..=split], tan1, center, tolerance, out);
+    fit_cubics(&points[split..], center * -1., tan2, tolerance, out);
+}
+
+fn chord_parameters(points: &[Point]) -> Vec<f32> {
+    let mut u = vec![0.];
+    for w in points.windows(2) {
+        u.push(u[u.len() - 1] + w[0].distance(w[1]));
+    }
+    let total = u[u.len() - 1];
+    u.iter().map(|d| if total > 0. { d / total } else { 0. }).collect()
+}
+
+// Least squares fit of the two inner control point distances along the end tangents.
+fn generate_bezier(points: &[Point], u: &[f32], tan1: Point, tan2: Point) -> Curve {
+    let (first, last) = (points[0], points[points.len() - 1]);
+    let (mut c00, mut c01, mut c11, mut x0, mut x1) = (0., 0., 0., 0., 0.);
+    for (p, t) in points.iter().zip(u) {
+        let mt = 1. - t;
+        let (b0, b1, b2, b3) = (mt * mt * mt, 3. * mt * mt * t, 3. * mt * t * t, t * t * t);
+        let (a0, a1) = (tan1 * b1, tan2 * b2);
+        c00 += a0.dot(a0);
+        c01 += a0.dot(a1);
+        c11 += a1.dot(a1);
+        let rest = *p - (first * (b0 + b1) + last * (b2 + b3));
+        x0 += rest.dot(a0);
+        x1 += rest.dot(a1);
+    }
+    let det = c00 * c11 - c01 * c01;
+    let (mut alpha1, mut alpha2) = if det.abs() > 1e-12 { ((x0 * c11 - x1 * c01) / det, (c00 * x1 - c01 * x0) / det) } else { (0., 0.) };
+    let length = first.distance(last);
+    if alpha1 < 1e-6 * length || alpha2 < 1e-6 * length {
+        alpha1 = length / 3.;
+        alpha2 = length / 3.;
+    }
+    Curve::Cubic(first, first + tan1 * alpha1, last + tan2 * alpha2, last)
+}
+
+fn max_error(points: &[Point], curve: &Curve, u: &[f32]) -> (f32, usize) {
+    let mut error = 0.;
+    let mut split = points.len() / 2;
+    for i in 1..points.len() - 1 {
+        let d = curve.eval(u[i]).distance(points[i]);
+        if d > error {
+            error = d;
+            split = i;
+        }
+    }
+    (error, split)
+}
+
+// One Newton-Raphson step per point towards the closest point on the curve.
+fn reparameterize(points: &[Point], u: &[f32], curve: &Curve) -> Vec<f32> {
+    let Curve::Cubic(p0, p1, p2, p3) = *curve else { return u.to_vec() };
+    points
+        .iter()
+        .zip(u)
+        .map(|(p, t)| {
+            let d = curve.eval(*t) - *p;
+            let d1 = curve.derivative(*t);
+            let d2 = (p2 - p1 * 2. + p0) * (6. * (1. - t)) + (p3 - p2 * 2. + p1) * (6. * t);
+            let denominator = d1.dot(d1) + d.dot(d2);
+            if denominator.abs() < 1e-12 {
+                *t
+            } else {
+                (t - d.dot(d1) / denominator).clamp(0., 1.)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::core::Point;
+    use crate::path::curve::Curve;
+    use crate::path::PathSegment::*;
+    use crate::path::PathSegments;
+
+    #[test]
+    fn simplify() {
+        let path = PathSegments(vec![M(0., 0.), L(5., 0.), L(5., 0.), L(10., 0.), C(10., 2., 10., 8., 10., 10.), L(0., 10.), Z]);
+        assert_eq!(path.simplify(0.01).to_string(), "M 0 0 L 10 0 L 10 10 L 0 10 Z ");
+
+        // A densely sampled circle becomes a handful of cubics
+        let samples: Vec<_> = (0..=64).map(|i| (i as f32 / 64. * std::f32::consts::TAU).sin_cos()).collect();
+        let mut circle = vec![M(100., 50.)];
+        circle.extend(samples[1..].iter().map(|(s, c)| L(50. + 50. * c, 50. + 50. * s)));
+        let circle = PathSegments(circle);
+        let simplified = circle.simplify(0.1);
+        assert!(simplified.0.len() <= 16);
+        for (s, c) in samples.iter() {
+            assert!(simplified.distance(50. + 50. * c, 50. + 50. * s) <= 0.1);
+        }
+
+        // A curve cut into pieces is joined again
+        let curve = Curve::Cubic(Point(0., 0.), Point(0., 50.), Point(100., 80.), Point(100., 0.));
+        let mut pieces = vec![M(0., 0.)];
+        pieces.extend((0..4).map(|i| curve.subsection(i as f32 / 4., (i + 1) as f32 / 4.).to_segment()));
+        assert_eq!(PathSegments(pieces).simplify(0.01).0.len(), 2);
+
+        // Font outlines are already economical, but never grow
+        #[cfg(feature = "cadenza")]
+        {
+            let glyph = PathSegments(crate::glyphs::cadenza::CADENZA_CLEF_G.segments().unwrap().to_vec());
+            let simplified = glyph.simplify(0.5);
+            assert!(simplified.0.len() <= glyph.0.len());
+            for curve in glyph.curves() {
+                let p = curve.eval(0.5);
+                assert!(simplified.distance(p.0, p.1) <= 0.5);
+            }
+        }
+    }
+}
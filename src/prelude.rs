@@ -1,11 +1,13 @@
-pub use crate::error::GraphicsError::{Basic, Generic};
-pub type Result<T> = anyhow::Result<T>;
+pub use crate::error::{GeometryError, GraphicsError, Result};
 
+pub use crate::animation::*;
 pub use crate::builder::fuse::*;
 pub use crate::builder::svg::*;
 pub use crate::core::core::*;
 pub use crate::core::*;
+#[cfg(feature = "cadenza")]
 pub use crate::glyphs::cadenza::*;
+#[cfg(feature = "ebgaramond")]
 pub use crate::glyphs::ebgaramond::*;
 pub use crate::item::Color::*;
 pub use crate::item::Fill::*;
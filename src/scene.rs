@@ -0,0 +1,628 @@
+//! Versioned scene files: the JSON format in which other tools hand scenes to this crate.
+//!
+//! Unlike the derived serde form of `GraphicItems`, every item is an object tagged with its
+//! `type` and named fields, optional properties can be left out, colors are CSS strings and
+//! paths are SVG path data. Files carry a `version`; older versions are migrated on load.
+//! `Scene::json_schema` describes the current version.
+
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::animation::{Animation, Easing, Keyframe, Track};
+use crate::error::GraphicsError;
+use crate::item::{BlendMode, Clip, Color, CornerRadii, Fill, GraphicItem, GraphicItems, ItemAttributes, Mask, MaskType, PathCacheInfo, Stroke, Trim};
+use crate::prelude::*;
+use crate::path::PathSegments;
+
+/// Version written by this crate. Version 0 is the unversioned serde form of `GraphicItems`.
+pub const SCENE_VERSION: u32 = 1;
+
+/// A scene file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Scene {
+    /// Format version of the file.
+    pub version: u32,
+    /// Which way y points in the file. Scenes that are y up are flipped about y = 0 on load.
+    #[serde(default, skip_serializing_if = "is_y_down")]
+    pub y_axis: YAxis,
+    /// Items in drawing order, the last one on top.
+    #[serde(default)]
+    pub items: Vec<SceneItem>,
+}
+
+fn is_y_down(y_axis: &YAxis) -> bool {
+    *y_axis == YAxis::Down
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum SceneItem {
+    Line {
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stroke: Option<SceneStroke>,
+    },
+    Rect {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        /// Corner radius, or radii for the top left, top right, bottom right and bottom left corners.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        radius: Option<SceneRadius>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stroke: Option<SceneStroke>,
+        /// CSS color of the fill.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fill: Option<String>,
+    },
+    /// Ellipse inscribed in the rectangle.
+    Ellipse {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stroke: Option<SceneStroke>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fill: Option<String>,
+    },
+    Polyline {
+        points: Vec<[f32; 2]>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stroke: Option<SceneStroke>,
+    },
+    Polygon {
+        points: Vec<[f32; 2]>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stroke: Option<SceneStroke>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fill: Option<String>,
+    },
+    Path {
+        /// SVG path data.
+        d: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stroke: Option<SceneStroke>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fill: Option<String>,
+        /// Draws the path as a shared definition, placed at an offset.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cache: Option<SceneCache>,
+    },
+    Group {
+        #[serde(default)]
+        items: Vec<SceneItem>,
+        /// SVG path data of the clip region.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        clip: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        mask: Option<SceneMask>,
+        #[serde(default = "default_opacity", skip_serializing_if = "is_opaque")]
+        opacity: f32,
+        #[serde(default, skip_serializing_if = "is_normal")]
+        blend: SceneBlend,
+        /// Fractions 0..1 of the length of the shapes that are drawn.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        trim: Option<[f32; 2]>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        animation: Option<Box<SceneAnimation>>,
+        /// Id of the group in the output, for styling and scripting.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        classes: Vec<String>,
+        /// Written as `data-<name>` attributes.
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        data: BTreeMap<String, String>,
+        /// Tooltip text.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SceneStroke {
+    #[serde(default = "default_stroke_width")]
+    pub width: f32,
+    /// CSS color.
+    #[serde(default = "default_stroke_color")]
+    pub color: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum SceneRadius {
+    Uniform(f32),
+    Corners([f32; 4]),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SceneCache {
+    pub id: String,
+    #[serde(default)]
+    pub x: f32,
+    #[serde(default)]
+    pub y: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SceneMask {
+    pub items: Vec<SceneItem>,
+    #[serde(default, rename = "type")]
+    pub mask_type: SceneMaskType,
+}
+
+/// Keyframe tracks of a group. The group is scaled about `pivot` and then translated;
+/// opacity and trim replace those of the group, colors the fills and strokes of its items.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SceneAnimation {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translate: Option<Vec<SceneKeyframe<[f32; 2]>>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale: Option<Vec<SceneKeyframe<[f32; 2]>>>,
+    #[serde(default, skip_serializing_if = "is_origin")]
+    pub pivot: [f32; 2],
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opacity: Option<Vec<SceneKeyframe<f32>>>,
+    /// CSS colors.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fill: Option<Vec<SceneKeyframe<String>>>,
+    /// CSS colors.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stroke: Option<Vec<SceneKeyframe<String>>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trim: Option<Vec<SceneKeyframe<[f32; 2]>>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SceneKeyframe<T> {
+    /// Time in seconds.
+    pub time: f32,
+    pub value: T,
+    /// How the value moves towards the next keyframe.
+    #[serde(default, skip_serializing_if = "is_linear")]
+    pub easing: SceneEasing,
+}
+
+/// Easings by their CSS timing function names, and `hold` to keep the value until the next
+/// keyframe.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum SceneEasing {
+    #[default]
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    CubicBezier([f32; 4]),
+    Hold,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SceneMaskType {
+    #[default]
+    Luminance,
+    Alpha,
+}
+
+/// Blend modes by their CSS `mix-blend-mode` names.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum SceneBlend {
+    #[default]
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+    Hue,
+    Saturation,
+    Color,
+    Luminosity,
+}
+
+fn default_opacity() -> f32 {
+    1.0
+}
+
+fn is_opaque(opacity: &f32) -> bool {
+    *opacity == 1.0
+}
+
+fn is_origin(point: &[f32; 2]) -> bool {
+    *point == [0., 0.]
+}
+
+fn is_linear(easing: &SceneEasing) -> bool {
+    *easing == SceneEasing::Linear
+}
+
+fn is_normal(blend: &SceneBlend) -> bool {
+    *blend == SceneBlend::Normal
+}
+
+fn default_stroke_width() -> f32 {
+    1.0
+}
+
+fn default_stroke_color() -> String {
+    "black".to_string()
+}
+
+impl Scene {
+    pub fn from_items(items: &GraphicItems) -> Scene {
+        Scene { version: SCENE_VERSION, y_axis: YAxis::Down, items: items.0.iter().map(SceneItem::from_item).collect() }
+    }
+
+    pub fn to_items(&self) -> Result<GraphicItems> {
+        let items = to_items(&self.items)?;
+        Ok(match self.y_axis {
+            YAxis::Down => items,
+            YAxis::Up => items.scale_items(1., -1., 1.),
+        })
+    }
+
+    /// Reads a scene file of any known version, migrating it to the current one.
+    pub fn from_json(json: &str) -> Result<Scene> {
+        let mut value: Value = serde_json::from_str(json)?;
+        let version = match &value {
+            Value::Object(map) if map.contains_key("version") => {
+                map["version"].as_u64().ok_or_else(|| GraphicsError::InvalidScene("version is not a number".to_string()))? as u32
+            }
+            _ => 0,
+        };
+        if version > SCENE_VERSION {
+            return Err(GraphicsError::InvalidScene(format!("version {} is newer than the supported version {}", version, SCENE_VERSION)));
+        }
+        for from in version..SCENE_VERSION {
+            value = migrate(value, from)?;
+        }
+        Ok(serde_json::from_value(value)?)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// JSON Schema of the current version of the format.
+    pub fn json_schema() -> String {
+        serde_json::to_string_pretty(&schemars::schema_for!(Scene)).unwrap()
+    }
+}
+
+// Upgrades a scene from version `from` to version `from + 1`.
+fn migrate(value: Value, from: u32) -> Result<Value> {
+    match from {
+        0 => {
+            let items: GraphicItems = serde_json::from_value(value)?;
+            Ok(serde_json::to_value(Scene::from_items(&items))?)
+        }
+        _ => unreachable!("no migration from scene version {}", from),
+    }
+}
+
+impl GraphicItems {
+    pub fn from_scene_json(json: &str) -> Result<GraphicItems> {
+        Scene::from_json(json)?.to_items()
+    }
+
+    pub fn to_scene_json(&self) -> String {
+        Scene::from_items(self).to_json()
+    }
+}
+
+impl SceneItem {
+    pub fn from_item(item: &GraphicItem) -> SceneItem {
+        let points = |points: &Vec<Point>| points.iter().map(|p| [p.0, p.1]).collect();
+        match item {
+            GraphicItem::Line(x1, y1, x2, y2, stroke) => SceneItem::Line { x1: *x1, y1: *y1, x2: *x2, y2: *y2, stroke: scene_stroke(stroke) },
+            GraphicItem::Rect(x, y, w, h, stroke, fill) => {
+                SceneItem::Rect { x: *x, y: *y, width: *w, height: *h, radius: None, stroke: scene_stroke(stroke), fill: scene_fill(fill) }
+            }
+            GraphicItem::RoundedRect(x, y, w, h, radii, stroke, fill) => {
+                let radius = if radii.is_uniform() { SceneRadius::Uniform(radii.0) } else { SceneRadius::Corners([radii.0, radii.1, radii.2, radii.3]) };
+                SceneItem::Rect { x: *x, y: *y, width: *w, height: *h, radius: Some(radius), stroke: scene_stroke(stroke), fill: scene_fill(fill) }
+            }
+            GraphicItem::Ellipse(x, y, w, h, stroke, fill) => SceneItem::Ellipse { x: *x, y: *y, width: *w, height: *h, stroke: scene_stroke(stroke), fill: scene_fill(fill) },
+            GraphicItem::Polyline(p, stroke) => SceneItem::Polyline { points: points(p), stroke: scene_stroke(stroke) },
+            GraphicItem::Polygon(p, stroke, fill) => SceneItem::Polygon { points: points(p), stroke: scene_stroke(stroke), fill: scene_fill(fill) },
+            GraphicItem::Path(path, stroke, fill, cache) => SceneItem::Path {
+                d: path.to_string().trim_end().to_string(),
+                stroke: scene_stroke(stroke),
+                fill: scene_fill(fill),
+                cache: match cache {
+                    PathCacheInfo::Cache(id, x, y) => Some(SceneCache { id: id.clone(), x: *x, y: *y }),
+                    PathCacheInfo::NoCache => None,
+                },
+            },
+            GraphicItem::Group(items, attrs) => SceneItem::Group {
+                items: items.0.iter().map(SceneItem::from_item).collect(),
+                clip: attrs.clip.as_ref().map(|clip| clip.0.to_string().trim_end().to_string()),
+                mask: attrs.mask.as_ref().map(|mask| SceneMask {
+                    items: mask.0 .0.iter().map(SceneItem::from_item).collect(),
+                    mask_type: match mask.1 {
+                        MaskType::Luminance => SceneMaskType::Luminance,
+                        MaskType::Alpha => SceneMaskType::Alpha,
+                    },
+                }),
+                opacity: attrs.opacity,
+                blend: scene_blend(attrs.blend),
+                trim: attrs.trim.map(|trim| [trim.0, trim.1]),
+                animation: attrs.animation.as_ref().map(|animation| Box::new(scene_animation(animation))),
+                id: attrs.metadata.id.clone(),
+                classes: attrs.metadata.classes.clone(),
+                data: attrs.metadata.data.iter().cloned().collect(),
+                title: attrs.metadata.title.clone(),
+            },
+        }
+    }
+
+    pub fn to_item(&self) -> Result<GraphicItem> {
+        let points = |points: &Vec<[f32; 2]>| points.iter().map(|p| Point(p[0], p[1])).collect();
+        Ok(match self {
+            SceneItem::Line { x1, y1, x2, y2, stroke } => GraphicItem::Line(*x1, *y1, *x2, *y2, item_stroke(stroke)?),
+            SceneItem::Rect { x, y, width, height, radius, stroke, fill } => match radius {
+                None => GraphicItem::Rect(*x, *y, *width, *height, item_stroke(stroke)?, item_fill(fill)?),
+                Some(radius) => {
+                    let radii = match radius {
+                        SceneRadius::Uniform(r) => CornerRadii::uniform(*r),
+                        SceneRadius::Corners([tl, tr, br, bl]) => CornerRadii(*tl, *tr, *br, *bl),
+                    };
+                    GraphicItem::RoundedRect(*x, *y, *width, *height, radii, item_stroke(stroke)?, item_fill(fill)?)
+                }
+            },
+            SceneItem::Ellipse { x, y, width, height, stroke, fill } => GraphicItem::Ellipse(*x, *y, *width, *height, item_stroke(stroke)?, item_fill(fill)?),
+            SceneItem::Polyline { points: p, stroke } => GraphicItem::Polyline(points(p), item_stroke(stroke)?),
+            SceneItem::Polygon { points: p, stroke, fill } => GraphicItem::Polygon(points(p), item_stroke(stroke)?, item_fill(fill)?),
+            SceneItem::Path { d, stroke, fill, cache } => GraphicItem::Path(
+                d.parse::<PathSegments>()?,
+                item_stroke(stroke)?,
+                item_fill(fill)?,
+                match cache {
+                    Some(cache) => PathCacheInfo::Cache(cache.id.clone(), cache.x, cache.y),
+                    None => PathCacheInfo::NoCache,
+                },
+            ),
+            SceneItem::Group { items, clip, mask, opacity, blend, trim, animation, id, classes, data, title } => {
+                let attrs = ItemAttributes {
+                    clip: clip.as_ref().map(|d| d.parse::<PathSegments>().map(Clip)).transpose()?,
+                    mask: match mask {
+                        Some(mask) => Some(Mask(
+                            to_items(&mask.items)?,
+                            match mask.mask_type {
+                                SceneMaskType::Luminance => MaskType::Luminance,
+                                SceneMaskType::Alpha => MaskType::Alpha,
+                            },
+                        )),
+                        None => None,
+                    },
+                    opacity: *opacity,
+                    blend: item_blend(*blend),
+                    trim: trim.map(|[start, end]| Trim(start, end)),
+                    animation: animation.as_deref().map(item_animation).transpose()?,
+                    metadata: Box::new(Metadata { id: id.clone(), classes: classes.clone(), data: data.clone().into_iter().collect(), title: title.clone() }),
+                };
+                GraphicItem::Group(to_items(items)?, attrs)
+            }
+        })
+    }
+}
+
+fn to_items(items: &[SceneItem]) -> Result<GraphicItems> {
+    Ok(GraphicItems(items.iter().map(|item| item.to_item()).collect::<Result<Vec<_>>>()?))
+}
+
+fn scene_stroke(stroke: &Stroke) -> Option<SceneStroke> {
+    match stroke {
+        Stroke::Strokestyle(width, color) => Some(SceneStroke { width: *width, color: color.to_string() }),
+        Stroke::NoStroke => None,
+    }
+}
+
+fn scene_fill(fill: &Fill) -> Option<String> {
+    match fill {
+        Fill::Fillstyle(color) => Some(color.to_string()),
+        Fill::NoFill => None,
+    }
+}
+
+fn item_stroke(stroke: &Option<SceneStroke>) -> Result<Stroke> {
+    Ok(match stroke {
+        Some(stroke) => Stroke::Strokestyle(stroke.width, stroke.color.parse::<Color>()?),
+        None => Stroke::NoStroke,
+    })
+}
+
+fn item_fill(fill: &Option<String>) -> Result<Fill> {
+    Ok(match fill {
+        Some(color) => Fill::Fillstyle(color.parse::<Color>()?),
+        None => Fill::NoFill,
+    })
+}
+
+fn scene_track<T, U>(track: &Option<Track<T>>, value: impl Fn(&T) -> U) -> Option<Vec<SceneKeyframe<U>>> {
+    let keyframe = |keyframe: &Keyframe<T>| SceneKeyframe { time: keyframe.time, value: value(&keyframe.value), easing: scene_easing(keyframe.easing) };
+    track.as_ref().map(|track| track.0.iter().map(keyframe).collect())
+}
+
+fn item_track<T, U>(track: &Option<Vec<SceneKeyframe<T>>>, value: impl Fn(&T) -> Result<U>) -> Result<Option<Track<U>>> {
+    let keyframe = |keyframe: &SceneKeyframe<T>| Ok(Keyframe { time: keyframe.time, value: value(&keyframe.value)?, easing: item_easing(keyframe.easing) });
+    track.as_ref().map(|track| track.iter().map(keyframe).collect::<Result<Vec<_>>>().map(Track)).transpose()
+}
+
+fn scene_animation(animation: &Animation) -> SceneAnimation {
+    let pair = |value: &(f32, f32)| [value.0, value.1];
+    SceneAnimation {
+        translate: scene_track(&animation.translate, pair),
+        scale: scene_track(&animation.scale, pair),
+        pivot: [animation.pivot.0, animation.pivot.1],
+        opacity: scene_track(&animation.opacity, |opacity| *opacity),
+        fill: scene_track(&animation.fill, Color::to_string),
+        stroke: scene_track(&animation.stroke, Color::to_string),
+        trim: scene_track(&animation.trim, pair),
+    }
+}
+
+fn item_animation(animation: &SceneAnimation) -> Result<Animation> {
+    let pair = |value: &[f32; 2]| Ok((value[0], value[1]));
+    Ok(Animation {
+        translate: item_track(&animation.translate, pair)?,
+        scale: item_track(&animation.scale, pair)?,
+        pivot: Point(animation.pivot[0], animation.pivot[1]),
+        opacity: item_track(&animation.opacity, |opacity| Ok(*opacity))?,
+        fill: item_track(&animation.fill, |color| color.parse::<Color>())?,
+        stroke: item_track(&animation.stroke, |color| color.parse::<Color>())?,
+        trim: item_track(&animation.trim, pair)?,
+    })
+}
+
+fn scene_easing(easing: Easing) -> SceneEasing {
+    match easing {
+        Easing::Linear => SceneEasing::Linear,
+        Easing::EaseIn => SceneEasing::EaseIn,
+        Easing::EaseOut => SceneEasing::EaseOut,
+        Easing::EaseInOut => SceneEasing::EaseInOut,
+        Easing::CubicBezier(x1, y1, x2, y2) => SceneEasing::CubicBezier([x1, y1, x2, y2]),
+        Easing::Hold => SceneEasing::Hold,
+    }
+}
+
+fn item_easing(easing: SceneEasing) -> Easing {
+    match easing {
+        SceneEasing::Linear => Easing::Linear,
+        SceneEasing::EaseIn => Easing::EaseIn,
+        SceneEasing::EaseOut => Easing::EaseOut,
+        SceneEasing::EaseInOut => Easing::EaseInOut,
+        SceneEasing::CubicBezier([x1, y1, x2, y2]) => Easing::CubicBezier(x1, y1, x2, y2),
+        SceneEasing::Hold => Easing::Hold,
+    }
+}
+
+fn scene_blend(blend: BlendMode) -> SceneBlend {
+    match blend {
+        BlendMode::Normal => SceneBlend::Normal,
+        BlendMode::Multiply => SceneBlend::Multiply,
+        BlendMode::Screen => SceneBlend::Screen,
+        BlendMode::Overlay => SceneBlend::Overlay,
+        BlendMode::Darken => SceneBlend::Darken,
+        BlendMode::Lighten => SceneBlend::Lighten,
+        BlendMode::ColorDodge => SceneBlend::ColorDodge,
+        BlendMode::ColorBurn => SceneBlend::ColorBurn,
+        BlendMode::HardLight => SceneBlend::HardLight,
+        BlendMode::SoftLight => SceneBlend::SoftLight,
+        BlendMode::Difference => SceneBlend::Difference,
+        BlendMode::Exclusion => SceneBlend::Exclusion,
+        BlendMode::Hue => SceneBlend::Hue,
+        BlendMode::Saturation => SceneBlend::Saturation,
+        BlendMode::Color => SceneBlend::Color,
+        BlendMode::Luminosity => SceneBlend::Luminosity,
+    }
+}
+
+fn item_blend(blend: SceneBlend) -> BlendMode {
+    match blend {
+        SceneBlend::Normal => BlendMode::Normal,
+        SceneBlend::Multiply => BlendMode::Multiply,
+        SceneBlend::Screen => BlendMode::Screen,
+        SceneBlend::Overlay => BlendMode::Overlay,
+        SceneBlend::Darken => BlendMode::Darken,
+        SceneBlend::Lighten => BlendMode::Lighten,
+        SceneBlend::ColorDodge => BlendMode::ColorDodge,
+        SceneBlend::ColorBurn => BlendMode::ColorBurn,
+        SceneBlend::HardLight => BlendMode::HardLight,
+        SceneBlend::SoftLight => BlendMode::SoftLight,
+        SceneBlend::Difference => BlendMode::Difference,
+        SceneBlend::Exclusion => BlendMode::Exclusion,
+        SceneBlend::Hue => BlendMode::Hue,
+        SceneBlend::Saturation => BlendMode::Saturation,
+        SceneBlend::Color => BlendMode::Color,
+        SceneBlend::Luminosity => BlendMode::Luminosity,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Scene, SceneItem, SCENE_VERSION};
+    use crate::animation::{Animation, Easing, Track};
+    use crate::core::core::Point;
+    use crate::item::ItemAttributes;
+    use crate::item::{Color::*, Fill::*, GraphicItem::*, GraphicItems, Stroke::*};
+    use crate::path::PathSegment::*;
+    use crate::path::PathSegments;
+    use crate::prelude::PathCacheInfo;
+
+    #[test]
+    fn scene_json() {
+        let json = r##"{
+            "version": 1,
+            "items": [
+                { "type": "rect", "x": 0, "y": 0, "width": 10, "height": 5, "fill": "#ff000080" },
+                { "type": "line", "x1": 0, "y1": 0, "x2": 10, "y2": 10, "stroke": { "width": 2 } },
+                { "type": "group", "opacity": 0.5, "blend": "color-dodge", "trim": [0, 0.5], "items": [
+                    { "type": "path", "d": "M0 0 h10 v10 z", "fill": "blue" }
+                ] }
+            ]
+        }"##;
+        let items = GraphicItems::from_scene_json(json).unwrap();
+        let Rect(_, _, w, _, NoStroke, Fillstyle(RGBA(255, 0, 0, 128))) = &items.0[0] else { panic!() };
+        assert_eq!(*w, 10.);
+        assert!(matches!(&items.0[1], Line(_, _, _, _, Strokestyle(w, Black)) if *w == 2.));
+        let Group(group, attrs) = &items.0[2] else { panic!() };
+        assert_eq!(attrs.opacity, 0.5);
+        assert_eq!(group.0[0].to_path().unwrap().to_string(), "M 0 0 L 10 0 L 10 10 Z ");
+
+        // Items survive a round trip; defaults are left out of the file
+        let scene = Scene::from_items(&items);
+        let written = scene.to_json();
+        assert!(!written.contains("null") && !written.contains("\"cache\""));
+        assert_eq!(Scene::from_json(&written).unwrap(), scene);
+        assert_eq!(Scene::from_items(&scene.to_items().unwrap()), scene);
+
+        // Group animations are kept both ways
+        let animation = Animation {
+            translate: Some(Track::new().key(0., (0., 0.), Easing::EaseOut).key(1., (10., 0.), Easing::Linear)),
+            pivot: Point(5., 5.),
+            fill: Some(Track::new().key(0., Red, Easing::Hold).key(2., Blue, Easing::CubicBezier(0.1, 0.2, 0.3, 1.))),
+            ..Default::default()
+        };
+        let animated = GraphicItems(vec![Group(items.clone(), ItemAttributes { animation: Some(animation.clone()), ..Default::default() })]);
+        let written = animated.to_scene_json();
+        assert!(written.contains("\"easing\": \"ease-out\"") && written.contains("\"cubic-bezier\"") && written.contains("\"value\": \"red\""));
+        let Group(_, attrs) = &GraphicItems::from_scene_json(&written).unwrap().0[0] else { panic!() };
+        assert_eq!(attrs.animation, Some(animation));
+
+        // Files in the unversioned layout are migrated
+        let legacy = GraphicItems(vec![Path(PathSegments(vec![M(0., 0.), L(5., 5.)]), Strokestyle(1., Red), NoFill, PathCacheInfo::Cache("a".to_string(), 1., 2.))]);
+        let migrated = Scene::from_json(&serde_json::to_string(&legacy).unwrap()).unwrap();
+        assert_eq!(migrated.version, SCENE_VERSION);
+        assert!(matches!(&migrated.items[0], SceneItem::Path { d, cache: Some(cache), .. } if d == "M 0 0 L 5 5" && cache.id == "a"));
+
+        assert!(Scene::from_json(r#"{ "version": 99 }"#).is_err());
+        assert!(Scene::from_json(r#"{ "version": 1, "items": [{ "type": "rect", "x": 0 }] }"#).is_err());
+        assert!(GraphicItems::from_scene_json(r#"{ "version": 1, "items": [{ "type": "path", "d": "M 0 0", "fill": "nocolor" }] }"#).is_err());
+
+        let schema = Scene::json_schema();
+        assert!(schema.contains("\"color-dodge\"") && schema.contains("SVG path data."));
+    }
+}
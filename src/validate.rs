@@ -0,0 +1,313 @@
+//! Checks of items before building. `GraphicItems::validate` reports every problem with
+//! where it is in the item tree, and `GraphicItems::repair` fixes the ones that have an
+//! obvious fix.
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use thiserror::Error;
+
+use crate::error::GeometryError;
+use crate::item::{Fill, GraphicItem, GraphicItems, PathCacheInfo, Stroke};
+use crate::prelude::*;
+
+/// One step down the item tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Item(usize),
+    Clip,
+    Mask,
+}
+
+/// Where a problem is: item indices from the top level down through groups, and into the
+/// clip path or mask of a group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location(pub Vec<Step>);
+
+impl Display for Location {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let steps: Vec<String> = self
+            .0
+            .iter()
+            .map(|step| match step {
+                Step::Item(idx) => idx.to_string(),
+                Step::Clip => "clip".to_string(),
+                Step::Mask => "mask".to_string(),
+            })
+            .collect();
+        write!(f, "{}", steps.join("/"))
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum Problem {
+    #[error("{0}")]
+    Geometry(GeometryError),
+    /// Index of a segment that follows a `Z` without starting a new subpath.
+    #[error("segment {0} follows a close without a move")]
+    SegmentAfterClose(usize),
+    #[error("width or height is zero")]
+    ZeroSize,
+    #[error("width or height is negative")]
+    NegativeSize,
+    #[error("stroke width is zero")]
+    ZeroWidthStroke,
+    /// A cache tag used before for other geometry; builders would draw the first one.
+    #[error("cache tag {0} is used for different paths")]
+    CacheConflict(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Issue {
+    pub location: Location,
+    pub problem: Problem,
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "item {}: {}", self.location, self.problem)
+    }
+}
+
+impl GraphicItems {
+    /// Every problem in the items, in tree order. Empty if the items are fine.
+    pub fn validate(&self) -> Vec<Issue> {
+        let mut issues = vec![];
+        validate_items(self, &mut vec![], &mut HashMap::new(), &mut issues);
+        issues
+    }
+
+    /// The items with what can be fixed fixed: negative sizes are flipped, zero width strokes
+    /// removed, missing moves added, cache tags that clash dropped. Items that cannot be
+    /// drawn are removed: paths that are empty or not finite, and invisible zero size shapes.
+    pub fn repair(&self) -> GraphicItems {
+        repair_items(self, &mut HashMap::new())
+    }
+}
+
+fn validate_items(items: &GraphicItems, location: &mut Vec<Step>, cache: &mut HashMap<String, String>, issues: &mut Vec<Issue>) {
+    for (idx, item) in items.0.iter().enumerate() {
+        location.push(Step::Item(idx));
+        let mut report = |problem: Problem| issues.push(Issue { location: Location(location.clone()), problem });
+        match item {
+            GraphicItem::Group(group_items, attrs) => {
+                if let Some(clip) = &attrs.clip {
+                    let mut clip_location = location.clone();
+                    clip_location.push(Step::Clip);
+                    issues.extend(path_problems(&clip.0).into_iter().map(|problem| Issue { location: Location(clip_location.clone()), problem }));
+                }
+                if let Some(mask) = &attrs.mask {
+                    location.push(Step::Mask);
+                    validate_items(&mask.0, location, cache, issues);
+                    location.pop();
+                }
+                validate_items(group_items, location, cache, issues);
+            }
+            GraphicItem::Path(path, stroke, _, cache_info) => {
+                path_problems(path).into_iter().for_each(&mut report);
+                if let PathCacheInfo::Cache(tag, _, _) = cache_info {
+                    let geometry = path.to_string();
+                    if cache.entry(tag.clone()).or_insert_with(|| geometry.clone()) != &geometry {
+                        report(Problem::CacheConflict(tag.clone()));
+                    }
+                }
+                stroke_problems(stroke).into_iter().for_each(&mut report);
+            }
+            _ => {
+                if item.to_path().is_some_and(|path| !path.is_finite()) {
+                    report(Problem::Geometry(GeometryError::NonFinite));
+                }
+                if let Some((w, h)) = size(item) {
+                    if w < 0. || h < 0. {
+                        report(Problem::NegativeSize);
+                    } else if w == 0. || h == 0. {
+                        report(Problem::ZeroSize);
+                    }
+                }
+                stroke_problems(stroke(item)).into_iter().for_each(&mut report);
+            }
+        }
+        location.pop();
+    }
+}
+
+fn path_problems(path: &PathSegments) -> Vec<Problem> {
+    let mut problems = vec![];
+    match path.0.first() {
+        None => problems.push(Problem::Geometry(GeometryError::EmptyPath)),
+        Some(PathSegment::M(..)) => {}
+        Some(_) => problems.push(Problem::Geometry(GeometryError::MissingMove)),
+    }
+    if !path.is_finite() {
+        problems.push(Problem::Geometry(GeometryError::NonFinite));
+    }
+    for (idx, pair) in path.0.windows(2).enumerate() {
+        if matches!(pair[0], PathSegment::Z) && !matches!(pair[1], PathSegment::M(..) | PathSegment::Z) {
+            problems.push(Problem::SegmentAfterClose(idx + 1));
+        }
+    }
+    problems
+}
+
+fn stroke_problems(stroke: &Stroke) -> Option<Problem> {
+    match stroke {
+        Stroke::Strokestyle(width, _) if *width == 0. => Some(Problem::ZeroWidthStroke),
+        Stroke::Strokestyle(width, _) if !width.is_finite() => Some(Problem::Geometry(GeometryError::NonFinite)),
+        _ => None,
+    }
+}
+
+fn stroke(item: &GraphicItem) -> &Stroke {
+    match item {
+        GraphicItem::Line(_, _, _, _, stroke)
+        | GraphicItem::Rect(_, _, _, _, stroke, _)
+        | GraphicItem::RoundedRect(_, _, _, _, _, stroke, _)
+        | GraphicItem::Ellipse(_, _, _, _, stroke, _)
+        | GraphicItem::Polyline(_, stroke)
+        | GraphicItem::Polygon(_, stroke, _)
+        | GraphicItem::Path(_, stroke, _, _) => stroke,
+        GraphicItem::Group(..) => &Stroke::NoStroke,
+    }
+}
+
+// Width and height of the shapes that have them
+fn size(item: &GraphicItem) -> Option<(f32, f32)> {
+    match item {
+        GraphicItem::Rect(_, _, w, h, _, _) | GraphicItem::RoundedRect(_, _, w, h, _, _, _) | GraphicItem::Ellipse(_, _, w, h, _, _) => Some((*w, *h)),
+        _ => None,
+    }
+}
+
+fn repair_items(items: &GraphicItems, cache: &mut HashMap<String, String>) -> GraphicItems {
+    GraphicItems(items.0.iter().filter_map(|item| repair_item(item, cache)).collect())
+}
+
+fn repair_item(item: &GraphicItem, cache: &mut HashMap<String, String>) -> Option<GraphicItem> {
+    let repair_stroke = |stroke: &Stroke| match stroke {
+        Stroke::Strokestyle(width, _) if *width == 0. || !width.is_finite() => Stroke::NoStroke,
+        stroke => stroke.clone(),
+    };
+    // Negative sizes come from mirroring; the same box has its corner on the other side
+    let flip = |x: f32, w: f32| if w < 0. { (x + w, -w) } else { (x, w) };
+
+    let repaired = match item {
+        GraphicItem::Group(group_items, attrs) => {
+            let mut attrs = attrs.clone();
+            attrs.clip = attrs.clip.and_then(|clip| repair_path(&clip.0).map(Clip));
+            attrs.mask = attrs.mask.map(|mask| Mask(repair_items(&mask.0, cache), mask.1));
+            return Some(GraphicItem::Group(repair_items(group_items, cache), attrs));
+        }
+        GraphicItem::Path(path, stroke, fill, cache_info) => {
+            let path = repair_path(path)?;
+            let cache_info = match cache_info {
+                PathCacheInfo::Cache(tag, x, y) => {
+                    let geometry = path.to_string();
+                    if cache.entry(tag.clone()).or_insert_with(|| geometry.clone()) != &geometry {
+                        // Drawn where the cached copy would have been
+                        return Some(GraphicItem::Path(path.move_path(*x, *y), repair_stroke(stroke), fill.clone(), PathCacheInfo::NoCache));
+                    }
+                    cache_info.clone()
+                }
+                PathCacheInfo::NoCache => PathCacheInfo::NoCache,
+            };
+            GraphicItem::Path(path, repair_stroke(stroke), fill.clone(), cache_info)
+        }
+        GraphicItem::Rect(x, y, w, h, stroke, fill) => {
+            let ((x, w), (y, h)) = (flip(*x, *w), flip(*y, *h));
+            GraphicItem::Rect(x, y, w, h, repair_stroke(stroke), fill.clone())
+        }
+        GraphicItem::RoundedRect(x, y, w, h, radii, stroke, fill) => {
+            let ((x, w), (y, h)) = (flip(*x, *w), flip(*y, *h));
+            GraphicItem::RoundedRect(x, y, w, h, *radii, repair_stroke(stroke), fill.clone())
+        }
+        GraphicItem::Ellipse(x, y, w, h, stroke, fill) => {
+            let ((x, w), (y, h)) = (flip(*x, *w), flip(*y, *h));
+            GraphicItem::Ellipse(x, y, w, h, repair_stroke(stroke), fill.clone())
+        }
+        GraphicItem::Line(x1, y1, x2, y2, stroke) => GraphicItem::Line(*x1, *y1, *x2, *y2, repair_stroke(stroke)),
+        GraphicItem::Polyline(points, stroke) => GraphicItem::Polyline(points.clone(), repair_stroke(stroke)),
+        GraphicItem::Polygon(points, stroke, fill) => GraphicItem::Polygon(points.clone(), repair_stroke(stroke), fill.clone()),
+    };
+
+    if repaired.to_path().is_some_and(|path| !path.is_finite()) {
+        return None;
+    }
+    // A zero size shape only shows its stroke
+    if size(&repaired).is_some_and(|(w, h)| w == 0. || h == 0.) && matches!(stroke(&repaired), Stroke::NoStroke) {
+        return None;
+    }
+    Some(repaired)
+}
+
+// Starts the path with a move and moves back to the start of each closed subpath that is
+// continued. None for paths that cannot be drawn.
+fn repair_path(path: &PathSegments) -> Option<PathSegments> {
+    if path.0.is_empty() || !path.is_finite() {
+        return None;
+    }
+    let mut segments = vec![];
+    if !matches!(path.0[0], PathSegment::M(..)) {
+        segments.push(PathSegment::M(0., 0.));
+    }
+    let mut start = Point(0., 0.);
+    let mut closed = false;
+    for segment in path.0.iter() {
+        if closed && !matches!(segment, PathSegment::M(..) | PathSegment::Z) {
+            segments.push(PathSegment::M(start.0, start.1));
+        }
+        if let PathSegment::M(x, y) = segment {
+            start = Point(*x, *y);
+        }
+        closed = matches!(segment, PathSegment::Z);
+        segments.push(segment.clone());
+    }
+    Some(PathSegments(segments))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Location, Problem, Step};
+    use crate::error::GeometryError;
+    use crate::prelude::*;
+
+    #[test]
+    fn validate_and_repair() {
+        let closed_twice = PathSegments(vec![M(0., 0.), L(10., 0.), L(10., 10.), Z, L(0., 10.), Z]);
+        let items = GraphicItems(vec![
+            Rect(10., 0., -10., 5., Strokestyle(0., Black), Fillstyle(Red)),
+            Group(
+                GraphicItems(vec![
+                    Path(closed_twice, NoStroke, Fillstyle(Blue), PathCacheInfo::Cache("a".to_string(), 0., 0.)),
+                    Path(PathSegments(vec![L(5., 5.)]), Strokestyle(1., Black), NoFill, PathCacheInfo::Cache("a".to_string(), 0., 0.)),
+                    Ellipse(0., 0., 0., 5., NoStroke, Fillstyle(Blue)),
+                    Line(0., f32::NAN, 1., 1., Strokestyle(1., Black)),
+                ]),
+                ItemAttributes::default(),
+            ),
+        ]);
+        let issues = items.validate();
+        let found: Vec<(String, Problem)> = issues.iter().map(|issue| (issue.location.to_string(), issue.problem.clone())).collect();
+        assert_eq!(
+            found,
+            vec![
+                ("0".to_string(), Problem::NegativeSize),
+                ("0".to_string(), Problem::ZeroWidthStroke),
+                ("1/0".to_string(), Problem::SegmentAfterClose(4)),
+                ("1/1".to_string(), Problem::Geometry(GeometryError::MissingMove)),
+                ("1/1".to_string(), Problem::CacheConflict("a".to_string())),
+                ("1/2".to_string(), Problem::ZeroSize),
+                ("1/3".to_string(), Problem::Geometry(GeometryError::NonFinite)),
+            ]
+        );
+        assert_eq!(issues[2].location, Location(vec![Step::Item(1), Step::Item(0)]));
+        assert_eq!(issues[3].to_string(), "item 1/1: path does not start with a move");
+
+        let repaired = items.repair();
+        assert!(repaired.validate().is_empty());
+        assert!(matches!(repaired.0[0], Rect(0., 0., 10., 5., NoStroke, _)));
+        let Group(group, _) = &repaired.0[1] else { panic!() };
+        assert_eq!(group.0.len(), 2);
+        assert_eq!(group.0[0].to_path().unwrap().to_string(), "M 0 0 L 10 0 L 10 10 Z M 0 0 L 0 10 Z ");
+        assert!(matches!(&group.0[1], Path(path, _, _, PathCacheInfo::NoCache) if path.to_string() == "M 0 0 L 5 5 "));
+    }
+}